@@ -0,0 +1,8 @@
+(function () {
+  let testArr = [];
+  for (let a = 0; a < 100000; a++) {
+    testArr[a] = a * 1.5;
+  }
+
+  return testArr.join(",");
+})();
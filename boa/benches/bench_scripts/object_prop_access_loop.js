@@ -0,0 +1,15 @@
+(function () {
+  let point = {
+    x: 1,
+    y: 2,
+    total: 0,
+  };
+
+  for (let i = 0; i < 1000; i++) {
+    point.total += point.x * point.y;
+    point.x = point.y;
+    point.y = i;
+  }
+
+  return point.total;
+})();
@@ -0,0 +1,8 @@
+(function () {
+  let sum = 0;
+  for (let i = 0; i < 10000; i++) {
+    sum += i * 2 - 1;
+  }
+
+  return sum;
+})();
@@ -14,6 +14,10 @@ fn create_realm(c: &mut Criterion) {
     c.bench_function("Create Realm", move |b| b.iter(Realm::create));
 }
 
+fn create_context(c: &mut Criterion) {
+    c.bench_function("Create Context", move |b| b.iter(Context::default));
+}
+
 macro_rules! full_benchmarks {
     ($({$id:literal, $name:ident}),*) => {
         fn bench_parser(c: &mut Criterion) {
@@ -62,10 +66,12 @@ macro_rules! full_benchmarks {
 full_benchmarks!(
     {"Symbols", symbol_creation},
     {"For loop", for_loop},
+    {"Summation loop", summation_loop},
     {"Fibonacci", fibonacci},
     {"Object Creation", object_creation},
     {"Static Object Property Access", object_prop_access_const},
     {"Dynamic Object Property Access", object_prop_access_dyn},
+    {"Object Property Access Loop", object_prop_access_loop},
     {"RegExp Literal Creation", regexp_literal_creation},
     {"RegExp Creation", regexp_creation},
     {"RegExp Literal", regexp_literal},
@@ -73,6 +79,7 @@ full_benchmarks!(
     {"Array access", array_access},
     {"Array creation", array_create},
     {"Array pop", array_pop},
+    {"Array join", array_join},
     {"String concatenation", string_concat},
     {"String comparison", string_compare},
     {"String copy", string_copy},
@@ -87,6 +94,7 @@ full_benchmarks!(
 criterion_group!(
     benches,
     create_realm,
+    create_context,
     bench_parser,
     bench_compile,
     bench_execution,
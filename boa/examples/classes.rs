@@ -148,6 +148,19 @@ fn main() {
         console.log(Person.staticProperty);
         console.log(person.inheritedProperty);
 	    console.log(Person.prototype.inheritedProperty === person.inheritedProperty);
+
+        // Native classes can be extended by Javascript classes. Calling `super` runs the
+        // constructor of `Person`, so the new object holds the native data of a `Person`.
+        class Student extends Person {
+            constructor(name, age, school) {
+                super(name, age);
+                this.school = school;
+            }
+        }
+
+        let student = new Student('Jane', 20, 'MIT');
+        student.sayHello();
+        console.log(`${Person.is(student)} ${student instanceof Student} ${student.school}`);
     ",
         )
         .unwrap();
@@ -119,6 +119,17 @@ impl JsBigInt {
             string = &string[2..];
         }
 
+        // Only decimal literals can have a sign, and numeric separators are not allowed at all,
+        // but both would be accepted by `parse_bytes`.
+        let digits = if radix == 10 {
+            string.strip_prefix(&['+', '-'][..]).unwrap_or(string)
+        } else {
+            string
+        };
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+            return None;
+        }
+
         Self::from_string_radix(string, radix)
     }
 
@@ -15,6 +15,7 @@ mod tests;
 
 use crate::{
     builtins::array::array_iterator::ArrayIterator,
+    builtins::iterable::IteratorHint,
     builtins::BuiltIn,
     builtins::Number,
     context::StandardObjects,
@@ -27,7 +28,10 @@ use crate::{
     value::{IntegerOrInfinity, JsValue},
     BoaProfiler, Context, JsResult, JsString,
 };
-use std::cmp::{max, min, Ordering};
+use std::{
+    cmp::{max, min, Ordering},
+    fmt::Write,
+};
 
 use super::JsArgs;
 
@@ -53,6 +57,11 @@ impl BuiltIn for Array {
             .build();
 
         let values_function = Self::values_intrinsic(context);
+        let to_string_function = FunctionBuilder::native(context, Self::to_string)
+            .name("toString")
+            .length(0)
+            .constructor(false)
+            .build();
 
         let array = ConstructorBuilder::with_standard_object(
             context,
@@ -94,7 +103,11 @@ impl BuiltIn for Array {
         .method(Self::filter, "filter", 1)
         .method(Self::pop, "pop", 0)
         .method(Self::join, "join", 1)
-        .method(Self::to_string, "toString", 0)
+        .property(
+            "toString",
+            to_string_function.clone(),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
         .method(Self::reverse, "reverse", 0)
         .method(Self::shift, "shift", 0)
         .method(Self::unshift, "unshift", 1)
@@ -113,12 +126,25 @@ impl BuiltIn for Array {
         .method(Self::reduce_right, "reduceRight", 2)
         .method(Self::keys, "keys", 0)
         .method(Self::entries, "entries", 0)
-        .method(Self::copy_within, "copyWithin", 3)
+        .method(Self::copy_within, "copyWithin", 2)
         // Static Methods
+        .static_method(Self::from, "from", 1)
         .static_method(Self::is_array, "isArray", 1)
         .static_method(Self::of, "of", 0)
         .build();
 
+        // %TypedArray%.prototype.toString ( )
+        // The initial value of the %TypedArray%.prototype.toString data property is the same
+        // built-in function object as the Array.prototype.toString method.
+        context.typed_array_constructor().prototype.insert_property(
+            "toString",
+            PropertyDescriptor::builder()
+                .value(to_string_function)
+                .writable(true)
+                .enumerable(false)
+                .configurable(true),
+        );
+
         array.into()
     }
 }
@@ -424,6 +450,161 @@ impl Array {
         args.get_or_undefined(0).is_array(context).map(Into::into)
     }
 
+    /// `Array.from(items[, mapFn[, thisArg]])`
+    ///
+    /// The Array.from method creates a new Array instance from an iterable or array-like object,
+    /// optionally mapping every element with `mapFn`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/from
+    pub(crate) fn from(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let items = args.get_or_undefined(0);
+        let mapfn = args.get_or_undefined(1);
+        let this_arg = args.get_or_undefined(2);
+
+        // 2. If mapfn is undefined, let mapping be false.
+        // 3. Else,
+        //     a. If IsCallable(mapfn) is false, throw a TypeError exception.
+        //     b. Let mapping be true.
+        let mapfn = match mapfn {
+            JsValue::Undefined => None,
+            JsValue::Object(o) if o.is_callable() => Some(o),
+            _ => return context.throw_type_error("Array.from: mapFn is not callable"),
+        };
+
+        // 4. Let usingIterator be ? GetMethod(items, @@iterator).
+        let using_iterator = items.get_method(WellKnownSymbols::iterator(), context)?;
+
+        // 5. If usingIterator is not undefined, then
+        if let Some(using_iterator) = using_iterator {
+            // a. If IsConstructor(C) is true, then
+            //     i. Let A be ? Construct(C).
+            // b. Else,
+            //     i. Let A be ! ArrayCreate(0).
+            let a = match this.as_constructor() {
+                Some(constructor) => constructor
+                    .construct(&[], this, context)?
+                    .as_object()
+                    .cloned()
+                    .ok_or_else(|| {
+                        context.construct_type_error("object constructor didn't return an object")
+                    })?,
+                _ => Self::array_create(0, None, context)?,
+            };
+
+            // c. Let iteratorRecord be ? GetIterator(items, sync, usingIterator).
+            let iterator_record = items.get_iterator(
+                context,
+                Some(IteratorHint::Sync),
+                Some(using_iterator.into()),
+            )?;
+
+            // d. Let k be 0.
+            // e. Repeat,
+            for k in 0.. {
+                // i. If k ≥ 2^53 - 1, then
+                if k >= Number::MAX_SAFE_INTEGER as usize {
+                    // 1. Let error be ThrowCompletion(a newly created TypeError object).
+                    let error = context.construct_type_error("Array.from: too many elements");
+                    // 2. Return ? IteratorClose(iteratorRecord, error).
+                    return iterator_record.close(Err(error), context);
+                }
+
+                // ii. Let Pk be ! ToString(𝔽(k)).
+                // iii. Let next be ? IteratorStep(iteratorRecord).
+                let next = iterator_record.next(context)?;
+
+                // iv. If next is false, then
+                if next.done {
+                    // 1. Perform ? Set(A, "length", 𝔽(k), true).
+                    a.set("length", k, true, context)?;
+                    // 2. Return A.
+                    return Ok(a.into());
+                }
+
+                // v. Let nextValue be ? IteratorValue(next).
+                let next_value = next.value;
+
+                // vi. If mapping is true, then
+                //     1. Let mappedValue be Call(mapfn, thisArg, « nextValue, 𝔽(k) »).
+                //     2. IfAbruptCloseIterator(mappedValue, iteratorRecord).
+                // vii. Else, let mappedValue be nextValue.
+                let mapped_value = match mapfn {
+                    Some(mapfn) => match mapfn.call(this_arg, &[next_value, k.into()], context) {
+                        Ok(value) => value,
+                        Err(error) => return iterator_record.close(Err(error), context),
+                    },
+                    None => next_value,
+                };
+
+                // viii. Let defineStatus be CreateDataPropertyOrThrow(A, Pk, mappedValue).
+                // ix. IfAbruptCloseIterator(defineStatus, iteratorRecord).
+                if let Err(error) = a.create_data_property_or_throw(k, mapped_value, context) {
+                    return iterator_record.close(Err(error), context);
+                }
+
+                // x. Set k to k + 1.
+            }
+        }
+
+        // 6. NOTE: items is not an Iterable so assume it is an array-like object.
+        // 7. Let arrayLike be ! ToObject(items).
+        let array_like = items.to_object(context)?;
+
+        // 8. Let len be ? LengthOfArrayLike(arrayLike).
+        let len = array_like.length_of_array_like(context)?;
+
+        // 9. If IsConstructor(C) is true, then
+        //     a. Let A be ? Construct(C, « 𝔽(len) »).
+        // 10. Else,
+        //     a. Let A be ? ArrayCreate(len).
+        let a = match this.as_constructor() {
+            Some(constructor) => constructor
+                .construct(&[len.into()], this, context)?
+                .as_object()
+                .cloned()
+                .ok_or_else(|| {
+                    context.construct_type_error("object constructor didn't return an object")
+                })?,
+            _ => Self::array_create(len, None, context)?,
+        };
+
+        // 11. Let k be 0.
+        // 12. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Let kValue be ? Get(arrayLike, Pk).
+            let k_value = array_like.get(k, context)?;
+
+            // c. If mapping is true, then
+            //     i. Let mappedValue be ? Call(mapfn, thisArg, « kValue, 𝔽(k) »).
+            // d. Else, let mappedValue be kValue.
+            let mapped_value = match mapfn {
+                Some(mapfn) => mapfn.call(this_arg, &[k_value, k.into()], context)?,
+                None => k_value,
+            };
+
+            // e. Perform ? CreateDataPropertyOrThrow(A, Pk, mappedValue).
+            a.create_data_property_or_throw(k, mapped_value, context)?;
+
+            // f. Set k to k + 1.
+        }
+
+        // 13. Perform ? Set(A, "length", 𝔽(len), true).
+        a.set("length", len, true, context)?;
+
+        // 14. Return A.
+        Ok(a.into())
+    }
+
     /// `Array.of(...items)`
     ///
     /// The Array.of method creates a new Array instance from a variable number of arguments,
@@ -746,6 +927,13 @@ impl Array {
 
         // 5. Let R be the empty String.
         let mut r = String::new();
+
+        // Elements of an array that are own data properties holding numbers are formatted
+        // straight into `R`, skipping the generic `Get` and `ToString` and their allocations.
+        // This gives the same result, since reading an own data property has no side effects.
+        let is_array = o.is_array();
+        let mut number_buffer = ryu_js::Buffer::new();
+
         // 6. Let k be 0.
         // 7. Repeat, while k < len,
         for k in 0..len {
@@ -753,6 +941,26 @@ impl Array {
             if k > 0 {
                 r.push_str(&separator);
             }
+
+            if is_array {
+                let object = o.borrow();
+                match object
+                    .properties()
+                    .get(&k.into())
+                    .and_then(PropertyDescriptor::value)
+                {
+                    Some(JsValue::Integer(integer)) => {
+                        write!(r, "{integer}").expect("writing to a String cannot fail");
+                        continue;
+                    }
+                    Some(JsValue::Rational(rational)) => {
+                        r.push_str(number_buffer.format(*rational));
+                        continue;
+                    }
+                    _ => {}
+                }
+            }
+
             // b. Let element be ? Get(O, ! ToString(𝔽(k))).
             let element = o.get(k, context)?;
             // c. If element is undefined or null, let next be the empty String; otherwise, let next be ? ToString(element).
@@ -1415,7 +1623,7 @@ impl Array {
             let test_result = predicate
                 .call(
                     this_arg,
-                    &[k_value.clone(), k.into(), this.clone()],
+                    &[k_value.clone(), k.into(), o.clone().into()],
                     context,
                 )?
                 .to_boolean();
@@ -1465,7 +1673,7 @@ impl Array {
             let k_value = o.get(k, context)?;
             // c. Let testResult be ! ToBoolean(? Call(predicate, thisArg, « kValue, 𝔽(k), O »)).
             let test_result = predicate
-                .call(this_arg, &[k_value, k.into(), this.clone()], context)?
+                .call(this_arg, &[k_value, k.into(), o.clone().into()], context)?
                 .to_boolean();
             // d. If testResult is true, return 𝔽(k).
             if test_result {
@@ -2489,7 +2697,7 @@ impl Array {
                 // ii. Set accumulator to ? Call(callbackfn, undefined, « accumulator, kValue, 𝔽(k), O »).
                 accumulator = callback.call(
                     &JsValue::undefined(),
-                    &[accumulator.clone(), k_value, k.into(), o.clone().into()],
+                    &[accumulator, k_value, k.into(), o.clone().into()],
                     context,
                 )?;
             }
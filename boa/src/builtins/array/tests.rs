@@ -117,6 +117,103 @@ fn of() {
     assert_eq!(context.eval("a.length").unwrap(), JsValue::new(3));
 }
 
+#[test]
+fn from() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(&mut context, "Array.from(new Set([1, 2, 2, 3])).join()"),
+        "\"1,2,3\""
+    );
+    assert_eq!(
+        forward(&mut context, "Array.from({ length: 3 }).length"),
+        "3"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.from({ length: 3, 0: 'a', 2: 'c' }, (v, i) => v + i).join()"
+        ),
+        "\"a0,NaN,c2\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.from([1, 2], function (v, i) { return v * this.factor + i; }, { factor: 10 }).join()"
+        ),
+        "\"10,21\""
+    );
+    assert_eq!(forward(&mut context, "Array.from(5).length"), "0");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Array.from([1], 5) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn from_string_iterates_code_points() {
+    let mut context = Context::default();
+    forward(&mut context, "let a = Array.from('a\\u{1F600}b');");
+    assert_eq!(forward(&mut context, "a.length"), "3");
+    assert_eq!(forward(&mut context, "a[1] === '\\u{1F600}'"), "true");
+}
+
+#[test]
+fn from_closes_iterator_when_map_fn_throws() {
+    let mut context = Context::default();
+    let init = r#"
+        let closed = false;
+        const iterable = {
+            [Symbol.iterator]() {
+                let i = 0;
+                return {
+                    next() { return { value: i++, done: false }; },
+                    return() { closed = true; return {}; },
+                };
+            },
+        };
+        let message;
+        try {
+            Array.from(iterable, (v) => {
+                if (v === 2) {
+                    throw new Error("stop");
+                }
+                return v;
+            });
+        } catch (e) {
+            message = e.message;
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "message"), "\"stop\"");
+    assert_eq!(forward(&mut context, "closed"), "true");
+}
+
+#[test]
+fn from_custom_constructor() {
+    let mut context = Context::default();
+    let init = r#"
+        function C() {
+            this.custom = true;
+        }
+        const fromIterable = Array.from.call(C, [1, 2]);
+        const fromArrayLike = Array.from.call(C, { length: 2, 0: "x" });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "fromIterable.custom"), "true");
+    assert_eq!(
+        forward(&mut context, "Array.isArray(fromIterable)"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "fromIterable.length"), "2");
+    assert_eq!(forward(&mut context, "fromIterable[1]"), "2");
+    assert_eq!(forward(&mut context, "fromArrayLike.custom"), "true");
+    assert_eq!(forward(&mut context, "fromArrayLike.length"), "2");
+    assert_eq!(forward(&mut context, "fromArrayLike[0]"), "\"x\"");
+}
+
 #[test]
 fn concat() {
     let mut context = Context::default();
@@ -172,6 +269,55 @@ fn copy_within() {
     assert_eq!(negatives, String::from("\"1.2.3.3.4\""));
 }
 
+#[test]
+fn copy_within_overlapping() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(&mut context, "Array.prototype.copyWithin.length"),
+        "2"
+    );
+
+    // The source range is copied as it was before the copy, even if the ranges overlap.
+    let forward_copy = forward(&mut context, "[1,2,3,4,5].copyWithin(1, 0, 3).join('.')");
+    assert_eq!(forward_copy, String::from("\"1.1.2.3.5\""));
+
+    let backward_copy = forward(&mut context, "[1,2,3,4,5].copyWithin(0, 2).join('.')");
+    assert_eq!(backward_copy, String::from("\"3.4.5.4.5\""));
+
+    let negative_target = forward(&mut context, "[1,2,3,4,5].copyWithin(-4, 0, 2).join('.')");
+    assert_eq!(negative_target, String::from("\"1.1.2.4.5\""));
+
+    let clamped = forward(
+        &mut context,
+        "[1,2,3,4,5].copyWithin(-10, 3, 100).join('.')",
+    );
+    assert_eq!(clamped, String::from("\"4.5.3.4.5\""));
+
+    let out_of_range = forward(&mut context, "[1,2,3,4,5].copyWithin(5, 0).join('.')");
+    assert_eq!(out_of_range, String::from("\"1.2.3.4.5\""));
+}
+
+#[test]
+fn copy_within_holes() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "var a = [1, , 3, 4, 5]; a.copyWithin(2, 0, 2);",
+    );
+
+    assert_eq!(forward(&mut context, "a.length"), "5");
+    assert_eq!(forward(&mut context, "a[2]"), "1");
+    assert_eq!(forward(&mut context, "3 in a"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.prototype.copyWithin.call({ length: 3, 2: 'x' }, 0, 1)[0]"
+        ),
+        "undefined"
+    );
+}
+
 #[test]
 fn join() {
     let mut context = Context::default();
@@ -192,6 +338,31 @@ fn join() {
     assert_eq!(many, String::from("\"a.b.c\""));
 }
 
+#[test]
+fn join_numbers() {
+    let mut context = Context::default();
+    let init = r#"
+        var numbers = [1, -42, 1.5, -0, NaN, Infinity, -Infinity, 1e21, 1e-7, 0.1 + 0.2];
+        var mixed = [1, , null, undefined, "x", { toString() { return "o"; } }, 2.5];
+        var accessor = [1, 2];
+        Object.defineProperty(accessor, 1, { get() { return 7; } });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "numbers.join()"),
+        "\"1,-42,1.5,0,NaN,Infinity,-Infinity,1e+21,1e-7,0.30000000000000004\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "numbers.join() === numbers.map(String).join()"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "mixed.join('|')"), "\"1||||x|o|2.5\"");
+    assert_eq!(forward(&mut context, "accessor.join()"), "\"1,7\"");
+}
+
 #[test]
 fn to_string() {
     let mut context = Context::default();
@@ -295,6 +466,94 @@ fn find_index() {
     assert_eq!(missing, String::from("-1"));
 }
 
+#[test]
+fn find_not_callable() {
+    let mut context = Context::default();
+
+    let init = r#"
+        var visited = false;
+        var obj = { length: 1, get 0() { visited = true; return 1; } };
+        function throwsTypeError(method) {
+            try {
+                Array.prototype[method].call(obj, "not a function");
+            } catch (err) {
+                return err.name === "TypeError";
+            }
+            return false;
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "throwsTypeError('find')"), "true");
+    assert_eq!(
+        forward(&mut context, "throwsTypeError('findIndex')"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "throwsTypeError('findLast')"), "true");
+    assert_eq!(
+        forward(&mut context, "throwsTypeError('findLastIndex')"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "visited"), "false");
+}
+
+#[test]
+fn find_last_sparse() {
+    let mut context = Context::default();
+    let init = r#"
+        var sparse = [1, , 3, , ];
+        var visited = [];
+        function isUndefined(value, index, array) {
+            visited.push(index + ":" + value + ":" + (array === sparse));
+            return value === undefined;
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "sparse.findLast(v => v !== undefined)"),
+        "3"
+    );
+    assert_eq!(
+        forward(&mut context, "sparse.findLastIndex(v => v === 1)"),
+        "0"
+    );
+    assert_eq!(
+        forward(&mut context, "sparse.findLastIndex(v => v > 3)"),
+        "-1"
+    );
+    assert_eq!(
+        forward(&mut context, "sparse.findLast(v => v > 3)"),
+        "undefined"
+    );
+
+    // Holes are visited as `undefined`, from the last index down.
+    assert_eq!(
+        forward(&mut context, "sparse.findLastIndex(isUndefined)"),
+        "3"
+    );
+    assert_eq!(
+        forward(&mut context, "visited.join()"),
+        "\"3:undefined:true\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[1, 2, 3].findLast(function (v) { return v < this.max; }, { max: 3 })"
+        ),
+        "2"
+    );
+
+    // The predicate receives the object created from the `this` value.
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.prototype.findLast.call('ab', (v, i, o) => typeof o === 'object')"
+        ),
+        "\"b\""
+    );
+}
+
 #[test]
 fn flat() {
     let mut context = Context::default();
@@ -474,6 +733,66 @@ fn unshift() {
     assert_eq!(forward(&mut context, "arr[1]"), "2");
 }
 
+#[test]
+fn shift_and_unshift_preserve_holes() {
+    let mut context = Context::default();
+    let init = r#"
+        var shifted = [1, , 3, , 5];
+        var first = shifted.shift();
+        var unshifted = [1, , 3];
+        var unshifted_length = unshifted.unshift(0);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "first"), "1");
+    assert_eq!(forward(&mut context, "shifted.length"), "4");
+    assert_eq!(
+        forward(&mut context, "Object.keys(shifted).join()"),
+        "\"1,3\""
+    );
+    assert_eq!(forward(&mut context, "0 in shifted"), "false");
+    assert_eq!(forward(&mut context, "unshifted_length"), "4");
+    assert_eq!(
+        forward(&mut context, "Object.keys(unshifted).join()"),
+        "\"0,1,3\""
+    );
+    assert_eq!(forward(&mut context, "2 in unshifted"), "false");
+}
+
+#[test]
+fn push_and_unshift_length_limit() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Array.prototype.push.call({ length: 2 ** 53 - 1 }, 1) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Array.prototype.unshift.call({ length: 2 ** 53 - 1 }, 1) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "var o = { length: -5 }; [Array.prototype.pop.call(o), o.length].join()"
+        ),
+        "\",0\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "var o = { length: '2', 0: 'a', 1: 'b' }; [Array.prototype.shift.call(o), o.length, o[0]].join()"
+        ),
+        "\"a,1,b\""
+    );
+}
+
 #[test]
 fn reverse() {
     let mut context = Context::default();
@@ -624,6 +943,35 @@ fn fill_obj_ref() {
     assert_eq!(forward(&mut context, "a[0].hi"), "\"hi\"");
 }
 
+#[test]
+fn fill_clamps_indices() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(&mut context, "[1, 2, 3, 4, 5].fill(0, -3, -1).join()"),
+        String::from("\"1,2,0,0,5\"")
+    );
+    assert_eq!(
+        forward(&mut context, "[1, 2, 3].fill(0, -10, 1).join()"),
+        String::from("\"0,2,3\"")
+    );
+    assert_eq!(
+        forward(&mut context, "[1, 2, 3].fill(0, 1, 100).join()"),
+        String::from("\"1,0,0\"")
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[1, 2, 3].fill(0, -Infinity, Infinity).join()"
+        ),
+        String::from("\"0,0,0\"")
+    );
+    assert_eq!(
+        forward(&mut context, "[1, 2, 3].fill(0, 2, -2).join()"),
+        String::from("\"1,2,3\"")
+    );
+}
+
 #[test]
 fn fill() {
     let mut context = Context::default();
@@ -1115,6 +1463,43 @@ fn reduce() {
     );
 }
 
+#[test]
+fn reduce_edge_cases() {
+    let mut context = Context::default();
+
+    // An empty array without an initial value throws a `TypeError`, but an explicitly undefined
+    // initial value is still present.
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { [].reduce((acc, x) => acc + x); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "[].reduce((acc, x) => acc + x, undefined)"),
+        "undefined"
+    );
+
+    // Holes are skipped, including the leading holes before the first accumulator.
+    forward(
+        &mut context,
+        r#"
+        var calls = [];
+        var sparse = [, 1, , 3, , 5, ,];
+        var sum = sparse.reduce((acc, value, index, array) => {
+            calls.push(`${acc}+${value}@${index}:${array === sparse}`);
+            return acc + value;
+        });
+        "#,
+    );
+    assert_eq!(forward(&mut context, "sum"), "9");
+    assert_eq!(
+        forward(&mut context, "calls.join()"),
+        r#""1+3@3:true,4+5@5:true""#
+    );
+}
+
 #[test]
 fn reduce_right() {
     let mut context = Context::default();
@@ -1240,6 +1625,50 @@ fn reduce_right() {
     );
 }
 
+#[test]
+fn reduce_right_edge_cases() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { [].reduceRight((acc, x) => acc + x); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+
+    // The elements are visited from the highest index to the lowest, skipping holes.
+    forward(
+        &mut context,
+        r#"
+        var indices = [];
+        var letters = [, "a", , "b", "c", ,];
+        var reversed = letters.reduceRight((acc, value, index, array) => {
+            indices.push(index);
+            return array === letters ? acc + value : acc;
+        }, "");
+        "#,
+    );
+    assert_eq!(forward(&mut context, "reversed"), r#""cba""#);
+    assert_eq!(forward(&mut context, "indices.join()"), r#""4,3,1""#);
+
+    // Without an initial value the last present element is the first accumulator.
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"["a", "b", "c", ,].reduceRight((acc, value) => acc + value)"#
+        ),
+        r#""cba""#
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"Array.prototype.reduceRight.call({ length: 3, 0: "x", 2: "z" }, (acc, value) => acc + value)"#
+        ),
+        r#""zx""#
+    );
+}
+
 #[test]
 fn call_array_constructor_with_one_argument() {
     let mut context = Context::default();
@@ -1417,6 +1846,19 @@ fn array_spread_non_iterable() {
     assert_eq!(forward(&mut context, init), "true");
 }
 
+#[test]
+fn array_spread_set() {
+    let mut context = Context::default();
+    let init = r#"
+        const set = new Set([1, 2, 2, 3]);
+        const array = [0, ...set, , ...new Set(), 4];
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "array.length"), "6");
+    assert_eq!(forward(&mut context, "array.join()"), "\"0,1,2,3,,4\"");
+    assert_eq!(forward(&mut context, "4 in array"), "false");
+}
+
 #[test]
 fn get_relative_start() {
     let mut context = Context::default();
@@ -1568,3 +2010,129 @@ fn array_sort() {
         "\"1,5,9,40,80,200,700\""
     );
 }
+
+#[test]
+fn array_index_boundary() {
+    let mut context = Context::default();
+    let init = r#"
+        var not_index = [];
+        not_index[4294967295] = 'a';
+        var max_index = [];
+        max_index[4294967294] = 'b';
+        var non_canonical = [];
+        non_canonical['01'] = 1;
+        non_canonical['+1'] = 1;
+        non_canonical[1.5] = 1;
+        var obj = { b: 1 };
+        obj[4294967295] = 1;
+        obj[4294967294] = 1;
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "not_index.length"), "0");
+    assert_eq!(forward(&mut context, "not_index[4294967295]"), "\"a\"");
+    assert_eq!(forward(&mut context, "max_index.length"), "4294967295");
+    assert_eq!(forward(&mut context, "max_index['4294967294']"), "\"b\"");
+    assert_eq!(forward(&mut context, "non_canonical.length"), "0");
+    assert_eq!(
+        forward(&mut context, "Object.keys(non_canonical).join()"),
+        "\"01,+1,1.5\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.keys(obj).join()"),
+        "\"4294967294,b,4294967295\""
+    );
+}
+
+#[test]
+fn delete_length() {
+    let mut context = Context::default();
+    let init = r#"
+        var arr = [1, 2, 3];
+        var deleted_length = delete arr.length;
+        var deleted_element = delete arr[0];
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "deleted_length"), "false");
+    assert_eq!(forward(&mut context, "deleted_element"), "true");
+    assert_eq!(forward(&mut context, "arr.length"), "3");
+    assert_eq!(forward(&mut context, "0 in arr"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "(function () { 'use strict'; try { delete arr.length; } catch (e) { return e.name; } })()"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn define_length_truncates_and_freezes() {
+    let mut context = Context::default();
+    let init = r#"
+        var arr = [1, 2, 3, 4];
+        Object.defineProperty(arr, "length", { value: 2 });
+        var truncated = arr.join();
+        Object.defineProperty(arr, "length", { writable: false });
+        arr[5] = 6;
+        var push_error;
+        try {
+            arr.push(5);
+        } catch (e) {
+            push_error = e.name;
+        }
+
+        var pinned = [1, 2, 3];
+        Object.defineProperty(pinned, "1", { configurable: false });
+        var pinned_result = Reflect.defineProperty(pinned, "length", { value: 0, writable: false });
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "truncated"), "\"1,2\"");
+    assert_eq!(forward(&mut context, "2 in arr"), "false");
+    assert_eq!(forward(&mut context, "5 in arr"), "false");
+    assert_eq!(forward(&mut context, "push_error"), "\"TypeError\"");
+    assert_eq!(forward(&mut context, "arr.length"), "2");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Reflect.defineProperty(arr, 'length', { value: 3 })"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Reflect.defineProperty(arr, 'length', { value: 2 })"
+        ),
+        "true"
+    );
+
+    // Truncation stops at the first non-configurable element, and still applies the writable flag.
+    assert_eq!(forward(&mut context, "pinned_result"), "false");
+    assert_eq!(forward(&mut context, "pinned.join()"), "\"1,2\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(pinned, 'length').writable"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn array_literal_holes() {
+    let mut context = Context::default();
+    forward(&mut context, "var arr = [0, , 2];");
+    assert_eq!(forward(&mut context, "1 in [0, , 2]"), "false");
+    assert_eq!(forward(&mut context, "arr.length"), "3");
+    assert_eq!(forward(&mut context, "0 in arr && 2 in arr"), "true");
+    assert_eq!(forward(&mut context, "arr.hasOwnProperty(1)"), "false");
+    assert_eq!(forward(&mut context, "Object.keys(arr).join()"), "\"0,2\"");
+    assert_eq!(
+        forward(&mut context, "Object.getOwnPropertyDescriptor(arr, 1)"),
+        "undefined"
+    );
+    assert_eq!(forward(&mut context, "[,].length"), "1");
+    assert_eq!(forward(&mut context, "[1, , ].length"), "2");
+    assert_eq!(forward(&mut context, "1 in [1, , ]"), "false");
+    assert_eq!(forward(&mut context, "1 in [...arr]"), "true");
+}
@@ -46,6 +46,11 @@ impl BuiltIn for ArrayBuffer {
             .constructor(false)
             .build();
 
+        let get_byte_length = FunctionBuilder::native(context, Self::byte_length)
+            .name("get byteLength")
+            .constructor(false)
+            .build();
+
         ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -60,7 +65,12 @@ impl BuiltIn for ArrayBuffer {
             Attribute::CONFIGURABLE,
         )
         .static_method(Self::is_view, "isView", 1)
-        .method(Self::byte_length, "byteLength", 0)
+        .accessor(
+            "byteLength",
+            Some(get_byte_length),
+            None,
+            Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+        )
         .method(Self::slice, "slice", 2)
         .property(
             WellKnownSymbols::to_string_tag(),
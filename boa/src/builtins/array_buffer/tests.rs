@@ -1,4 +1,5 @@
 use super::*;
+use crate::forward;
 
 #[test]
 fn ut_sunnyy_day_create_byte_data_block() {
@@ -13,3 +14,27 @@ fn ut_rainy_day_create_byte_data_block() {
 
     assert!(create_byte_data_block(usize::MAX, &mut context).is_err());
 }
+
+#[test]
+fn byte_length_and_slice() {
+    let mut context = Context::default();
+    let init = r#"
+        var buffer = new ArrayBuffer(8);
+        new Uint8Array(buffer).set([1, 2, 3, 4, 5, 6, 7, 8]);
+        var sliced = buffer.slice(2, -2);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "buffer.byteLength"), "8");
+    assert_eq!(forward(&mut context, "sliced.byteLength"), "4");
+    assert_eq!(
+        forward(&mut context, "new Uint8Array(sliced).join()"),
+        "\"3,4,5,6\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "typeof Object.getOwnPropertyDescriptor(ArrayBuffer.prototype, 'byteLength').get"
+        ),
+        "\"function\""
+    );
+}
@@ -0,0 +1,215 @@
+//! This module implements the suspension and resumption of async functions.
+//!
+//! An async function runs like a generator: every `await` suspends its execution context, which is
+//! resumed from a promise reaction once the awaited value settles.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-async-function-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/async_function
+
+use crate::{
+    builtins::{
+        generator::GeneratorContext,
+        promise::{Promise, PromiseCapability},
+        JsArgs,
+    },
+    gc::{Finalize, Trace},
+    object::FunctionBuilder,
+    vm::{GeneratorResumeKind, ReturnType},
+    Context, JsResult, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The state of a suspended async function.
+#[derive(Debug, Clone, Finalize, Trace)]
+struct AsyncFunctionContext {
+    generator_context: GeneratorContext,
+    promise_capability: PromiseCapability,
+}
+
+/// Operations on the execution of async functions.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AsyncFunction;
+
+impl AsyncFunction {
+    /// Handles the result of running the body of an async function until it completes or awaits.
+    ///
+    /// The promise of the function is settled if the body has completed, otherwise the function is
+    /// resumed once the awaited value settles.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asyncblockstart
+    pub(crate) fn handle_completion(
+        result: JsResult<(JsValue, ReturnType)>,
+        generator_context: GeneratorContext,
+        promise_capability: PromiseCapability,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        match result {
+            // d. If result.[[Type]] is normal, then
+            //     i. Perform ! Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+            // e. Else if result.[[Type]] is return, then
+            //     i. Perform ! Call(promiseCapability.[[Resolve]], undefined, « result.[[Value]] »).
+            Ok((value, ReturnType::Normal)) => {
+                promise_capability
+                    .resolve()
+                    .call(&JsValue::undefined(), &[value], context)?;
+            }
+            Ok((value, ReturnType::Yield)) => {
+                Self::r#await(
+                    value,
+                    AsyncFunctionContext {
+                        generator_context,
+                        promise_capability,
+                    },
+                    context,
+                )?;
+            }
+            // f. Else,
+            //     i. Assert: result.[[Type]] is throw.
+            //     ii. Perform ! Call(promiseCapability.[[Reject]], undefined, « result.[[Value]] »).
+            Err(error) => {
+                promise_capability
+                    .reject()
+                    .call(&JsValue::undefined(), &[error], context)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// `Await ( value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#await
+    fn r#await(
+        value: JsValue,
+        async_context: AsyncFunctionContext,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 2. Let promise be ? PromiseResolve(%Promise%, value).
+        let promise_constructor = context.standard_objects().promise_object().constructor();
+        let promise = match Promise::promise_resolve(promise_constructor, value, context) {
+            Ok(promise) => promise,
+            Err(error) => {
+                return Self::resume(&async_context, error, GeneratorResumeKind::Throw, context)
+            }
+        };
+
+        // 3. Let fulfilledClosure be a new Abstract Closure with parameters (value) that captures
+        //    asyncContext and performs the following steps when called:
+        //     a. Let prevContext be the running execution context.
+        //     b. Suspend prevContext.
+        //     c. Push asyncContext onto the execution context stack; asyncContext is now the
+        //        running execution context.
+        //     d. Resume the suspended evaluation of asyncContext using NormalCompletion(value) as
+        //        the result of the operation that suspended it.
+        //     e. Assert: When we reach this step, asyncContext has already been removed from the
+        //        execution context stack and prevContext is the currently running execution
+        //        context.
+        //     f. Return undefined.
+        // 4. Let onFulfilled be ! CreateBuiltinFunction(fulfilledClosure, 1, "", « »).
+        let on_fulfilled = FunctionBuilder::closure_with_captures(
+            context,
+            |_this, args, async_context, context| {
+                Self::resume(
+                    async_context,
+                    args.get_or_undefined(0).clone(),
+                    GeneratorResumeKind::Normal,
+                    context,
+                )?;
+                Ok(JsValue::undefined())
+            },
+            async_context.clone(),
+        )
+        .name("")
+        .length(1)
+        .build();
+
+        // 5. Let rejectedClosure be a new Abstract Closure with parameters (reason) that captures
+        //    asyncContext and performs the following steps when called:
+        //     a-c. [...]
+        //     d. Resume the suspended evaluation of asyncContext using ThrowCompletion(reason) as
+        //        the result of the operation that suspended it.
+        //     e-f. [...]
+        // 6. Let onRejected be ! CreateBuiltinFunction(rejectedClosure, 1, "", « »).
+        let on_rejected = FunctionBuilder::closure_with_captures(
+            context,
+            |_this, args, async_context, context| {
+                Self::resume(
+                    async_context,
+                    args.get_or_undefined(0).clone(),
+                    GeneratorResumeKind::Throw,
+                    context,
+                )?;
+                Ok(JsValue::undefined())
+            },
+            async_context,
+        )
+        .name("")
+        .length(1)
+        .build();
+
+        // 7. Perform PerformPromiseThen(promise, onFulfilled, onRejected).
+        Promise::perform_promise_then(
+            promise
+                .as_object()
+                .expect("PromiseResolve must return an object"),
+            &on_fulfilled.into(),
+            &on_rejected.into(),
+            None,
+            context,
+        );
+
+        // 8. Remove asyncContext from the execution context stack and restore the execution
+        //    context that is at the top of the execution context stack as the running execution
+        //    context.
+        Ok(())
+    }
+
+    /// Resumes a suspended async function with the settled value of the awaited promise.
+    fn resume(
+        async_context: &AsyncFunctionContext,
+        value: JsValue,
+        resume_kind: GeneratorResumeKind,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let mut generator_context = async_context.generator_context.clone();
+
+        std::mem::swap(
+            &mut context.realm.environments,
+            &mut generator_context.environments,
+        );
+        std::mem::swap(&mut context.vm.stack, &mut generator_context.stack);
+        context.vm.push_frame(generator_context.call_frame.clone());
+        context.vm.frame_mut().generator_resume_kind = resume_kind;
+
+        context.vm.push(value);
+        let result = context.run();
+
+        generator_context.call_frame = *context
+            .vm
+            .pop_frame()
+            .expect("async function call frame must exist");
+        std::mem::swap(
+            &mut context.realm.environments,
+            &mut generator_context.environments,
+        );
+        std::mem::swap(&mut context.vm.stack, &mut generator_context.stack);
+
+        Self::handle_completion(
+            result,
+            generator_context,
+            async_context.promise_capability.clone(),
+            context,
+        )
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{forward, Context};
+
+#[test]
+fn await_resolved_value() {
+    let mut context = Context::default();
+    let init = r#"
+        var log = [];
+        async function f() {
+            log.push("start");
+            const a = await 1;
+            const b = await Promise.resolve(2);
+            return a + b;
+        }
+        f().then((x) => log.push("result " + x));
+        log.push("sync");
+        log.join();
+        "#;
+    assert_eq!(forward(&mut context, init), "\"start,sync\"");
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"start,sync,result 3\""
+    );
+}
+
+#[test]
+fn await_rejected_promise() {
+    let mut context = Context::default();
+    let init = r#"
+        var result;
+        async function f() {
+            try {
+                await Promise.reject("oops");
+                return "unreachable";
+            } catch (e) {
+                return "caught " + e;
+            }
+        }
+        f().then((x) => { result = x; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "\"caught oops\"");
+}
+
+#[test]
+fn sync_throw_rejects_promise() {
+    let mut context = Context::default();
+    let init = r#"
+        var reason;
+        async function f() {
+            throw new Error("oops");
+        }
+        var promise = f();
+        promise.catch((e) => { reason = e.message; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "promise instanceof Promise"), "true");
+    assert_eq!(forward(&mut context, "reason"), "\"oops\"");
+}
+
+#[test]
+fn async_function_expression() {
+    let mut context = Context::default();
+    let init = r#"
+        var result;
+        const f = async function (x) { return (await x) * 2; };
+        f(21).then((x) => { result = x; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "42");
+    assert_eq!(forward(&mut context, "f.prototype"), "undefined");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new f() } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn async_class_methods() {
+    let mut context = Context::default();
+    let init = r#"
+        var results = [];
+        class C {
+            constructor(x) { this.x = x; }
+            async m(y) { return this.x + (await y); }
+            static async s() { return await "static"; }
+            async ["computed"]() { return await Promise.resolve("computed"); }
+        }
+        const c = new C(1);
+        c.m(Promise.resolve(2)).then((x) => results.push(x));
+        C.s().then((x) => results.push(x));
+        c.computed().then((x) => results.push(x));
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "results.join()"),
+        "\"3,static,computed\""
+    );
+    assert_eq!(
+        forward(&mut context, "typeof C.prototype.m"),
+        "\"function\""
+    );
+    assert_eq!(forward(&mut context, "c.m(0) instanceof Promise"), "true");
+    assert_eq!(
+        forward(&mut context, "C.prototype.m.prototype"),
+        "undefined"
+    );
+}
+
+#[test]
+fn async_object_literal_methods() {
+    let mut context = Context::default();
+    let init = r#"
+        var results = [];
+        const o = {
+            x: 1,
+            async m(y) { return this.x + (await y); },
+            async ["computed"]() { return await Promise.resolve("computed"); },
+        };
+        o.m(Promise.resolve(2)).then((x) => results.push(x));
+        o.computed().then((x) => results.push(x));
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "results.join()"), "\"3,computed\"");
+    assert_eq!(forward(&mut context, "typeof o.m"), "\"function\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new o.m() } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn for_await_of_sync_iterable() {
+    let mut context = Context::default();
+    let init = r#"
+        var log = [];
+        async function f() {
+            for await (const x of [1, Promise.resolve(2), 3]) {
+                log.push(x);
+            }
+            try {
+                for await (const x of [Promise.reject("oops")]) {
+                    log.push(x);
+                }
+            } catch (e) {
+                log.push("caught " + e);
+            }
+        }
+        f();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "log.join()"), "\"1,2,3,caught oops\"");
+}
+
+#[test]
+fn for_await_of_async_iterable() {
+    let mut context = Context::default();
+    let init = r#"
+        var log = [];
+        const iterable = {
+            [Symbol.asyncIterator]() {
+                let i = 0;
+                return {
+                    next() {
+                        i++;
+                        return Promise.resolve({ value: i * 10, done: i > 3 });
+                    }
+                };
+            }
+        };
+        async function f() {
+            for await (let x of iterable) {
+                if (x === 30) {
+                    break;
+                }
+                log.push(x);
+            }
+            log.push("end");
+        }
+        f();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "log.join()"), "\"10,20,end\"");
+}
@@ -145,6 +145,25 @@ fn bigint_function_conversion_from_string() {
     assert_eq!(forward(&mut context, "BigInt('0b1111')"), "15n");
     assert_eq!(forward(&mut context, "BigInt('0o70')"), "56n");
     assert_eq!(forward(&mut context, "BigInt('0xFF')"), "255n");
+    assert_eq!(forward(&mut context, "BigInt('0x1F')"), "31n");
+    assert_eq!(forward(&mut context, "BigInt('0X1f')"), "31n");
+    assert_eq!(forward(&mut context, "BigInt('123')"), "123n");
+    assert_eq!(forward(&mut context, "BigInt('-123')"), "-123n");
+    assert_eq!(forward(&mut context, "BigInt(' \\n0xff\\t ')"), "255n");
+}
+
+#[test]
+fn bigint_function_conversion_from_invalid_string() {
+    let mut context = Context::default();
+
+    assert_throws(&mut context, "BigInt('1.5')", "SyntaxError");
+    assert_throws(&mut context, "BigInt('1e3')", "SyntaxError");
+    assert_throws(&mut context, "BigInt('0x')", "SyntaxError");
+    assert_throws(&mut context, "BigInt('-0x1F')", "SyntaxError");
+    assert_throws(&mut context, "BigInt('0x-1')", "SyntaxError");
+    assert_throws(&mut context, "BigInt('1_000')", "SyntaxError");
+    assert_throws(&mut context, "BigInt('12n')", "SyntaxError");
+    assert_throws(&mut context, "BigInt(1.5)", "RangeError");
 }
 
 #[test]
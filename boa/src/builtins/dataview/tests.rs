@@ -0,0 +1,61 @@
+use crate::{check_output, forward, Context, TestAction};
+
+#[test]
+fn little_endian_write_big_endian_read() {
+    let mut context = Context::default();
+    let init = r#"
+        var buffer = new ArrayBuffer(20);
+        var view = new DataView(buffer, 4);
+        var bytes = new Uint8Array(buffer);
+        view.setUint16(0, 0x1234, true);
+        view.setInt32(2, -2, true);
+        view.setFloat64(6, 1.5, true);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "view.getUint16(0).toString(16)"),
+        "\"3412\""
+    );
+    assert_eq!(
+        forward(&mut context, "view.getUint16(0, true).toString(16)"),
+        "\"1234\""
+    );
+    assert_eq!(forward(&mut context, "bytes[4]"), "52");
+    assert_eq!(forward(&mut context, "bytes[5]"), "18");
+    assert_eq!(forward(&mut context, "view.getInt32(2)"), "-16777217");
+    assert_eq!(
+        forward(&mut context, "view.getUint32(2, true)"),
+        "4294967294"
+    );
+    assert_eq!(forward(&mut context, "view.getFloat64(6, true)"), "1.5");
+    assert_eq!(forward(&mut context, "bytes[16]"), "248");
+    assert_eq!(forward(&mut context, "bytes[17]"), "63");
+    assert_eq!(forward(&mut context, "view.getUint16(12)"), "63551");
+}
+
+#[test]
+fn shares_buffer_with_typed_arrays() {
+    check_output(&[
+        TestAction::Execute("var buffer = new ArrayBuffer(4);"),
+        TestAction::Execute("var view = new DataView(buffer);"),
+        TestAction::Execute("var bytes = new Uint8Array(buffer);"),
+        TestAction::TestEq("bytes[1] = 0xab; view.getUint8(1)", "171"),
+        TestAction::TestEq("view.setInt8(2, -1); bytes[2]", "255"),
+    ]);
+}
+
+#[test]
+fn out_of_bounds_access() {
+    check_output(&[
+        TestAction::Execute("var view = new DataView(new ArrayBuffer(8), 2);"),
+        TestAction::TestEq("view.byteLength", "6"),
+        TestAction::TestStartsWith("view.getUint32(3)", "Uncaught \"RangeError\""),
+        TestAction::TestStartsWith("view.setFloat64(0, 1)", "Uncaught \"RangeError\""),
+        TestAction::TestStartsWith("view.getInt8(-1)", "Uncaught \"RangeError\""),
+        TestAction::TestStartsWith(
+            "new DataView(new ArrayBuffer(8), 9)",
+            "Uncaught \"RangeError\"",
+        ),
+        TestAction::TestEq("view.getUint16(4)", "0"),
+    ]);
+}
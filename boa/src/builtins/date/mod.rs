@@ -92,6 +92,8 @@ impl BuiltIn for Date {
         .union(Attribute::NON_ENUMERABLE)
         .union(Attribute::CONFIGURABLE);
 
+    const LAZY: bool = true;
+
     fn init(context: &mut Context) -> JsValue {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
@@ -403,10 +405,7 @@ impl Date {
         let tv = match this_time_value(value, context) {
             Ok(dt) => dt.0,
             _ => match value.to_primitive(context, PreferredType::Default)? {
-                JsValue::String(ref str) => match chrono::DateTime::parse_from_rfc3339(str) {
-                    Ok(dt) => Some(dt.naive_utc()),
-                    _ => None,
-                },
+                JsValue::String(ref str) => parse_date(str),
                 tv => {
                     let tv = tv.to_number(context)?;
                     if tv.is_nan() {
@@ -1846,15 +1845,12 @@ impl Date {
     pub(crate) fn parse(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // This method is implementation-defined and discouraged, so we just require the same format as the string
         // constructor.
+        let date = parse_date(&args.get_or_undefined(0).to_string(context)?);
 
-        if args.is_empty() {
-            return Ok(JsValue::nan());
-        }
-
-        match DateTime::parse_from_rfc3339(&args[0].to_string(context)?) {
-            Ok(v) => Ok(JsValue::new(v.naive_utc().timestamp_millis() as f64)),
-            _ => Ok(JsValue::new(f64::NAN)),
-        }
+        Ok(JsValue::new(
+            date.and_then(|date| Self::time_clip(date.timestamp_millis() as f64))
+                .unwrap_or(f64::NAN),
+        ))
     }
 
     /// `Date.UTC()`
@@ -1868,7 +1864,7 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.utc
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/UTC
     pub(crate) fn utc(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        let year = args
+        let mut year = args
             .get(0)
             .map_or(Ok(f64::NAN), |value| value.to_number(context))?;
         let month = args
@@ -1894,24 +1890,24 @@ impl Date {
             return Ok(JsValue::nan());
         }
 
-        let year = year as i32;
-        let month = month as u32;
-        let day = day as u32;
-        let hour = hour as u32;
-        let min = min as u32;
-        let sec = sec as u32;
-        let milli = milli as u32;
+        if (0.0..=99.0).contains(&year) {
+            year += 1900.0;
+        }
 
-        let year = if (0..=99).contains(&year) {
-            1900 + year
-        } else {
-            year
-        };
+        // Out of range components are normalized the same way as in the constructor, only in UTC.
+        let mut date = Self(NaiveDateTime::from_timestamp_opt(0, 0));
+        date.set_components(
+            true,
+            Some(year),
+            Some(month),
+            Some(day),
+            Some(hour),
+            Some(min),
+            Some(sec),
+            Some(milli),
+        );
 
-        NaiveDate::from_ymd_opt(year, month + 1, day)
-            .and_then(|f| f.and_hms_milli_opt(hour, min, sec, milli))
-            .and_then(|f| Self::time_clip(f.timestamp_millis() as f64))
-            .map_or(Ok(JsValue::nan()), |time| Ok(JsValue::new(time)))
+        Ok(JsValue::new(date.get_time()))
     }
 }
 
@@ -1934,3 +1930,141 @@ pub fn this_time_value(value: &JsValue, context: &mut Context) -> JsResult<Date>
         .and_then(|obj| obj.borrow().as_date().copied())
         .ok_or_else(|| context.construct_type_error("'this' is not a Date"))
 }
+
+/// Parses a date string into the UTC date-time it represents.
+///
+/// Besides the [Date Time String Format][spec], the formats produced by `toUTCString` and
+/// `toString` are accepted. Returns `None` if the string is not in a recognized format.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date-time-string-format
+fn parse_date(date: &str) -> Option<NaiveDateTime> {
+    parse_iso_date_time(date).or_else(|| {
+        DateTime::parse_from_rfc2822(date)
+            .or_else(|_| DateTime::parse_from_str(date, "%a %b %d %Y %H:%M:%S GMT%z"))
+            .ok()
+            .map(|date| date.naive_utc())
+    })
+}
+
+/// Parses a string in the [Date Time String Format][spec] into the UTC date-time it represents.
+///
+/// Date-only forms are interpreted as UTC time, while date-time forms without an offset are
+/// interpreted as local time.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date-time-string-format
+fn parse_iso_date_time(date: &str) -> Option<NaiveDateTime> {
+    let bytes = date.as_bytes();
+    let mut pos = 0;
+
+    // Consumes exactly `n` ASCII digits.
+    let digits = |pos: &mut usize, n: usize| -> Option<u32> {
+        let digits = bytes.get(*pos..*pos + n)?;
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        *pos += n;
+        Some(
+            digits
+                .iter()
+                .fold(0, |acc, digit| acc * 10 + u32::from(digit - b'0')),
+        )
+    };
+    // Consumes `byte` if it is the next one.
+    let next_if = |pos: &mut usize, byte: u8| -> bool {
+        let found = bytes.get(*pos) == Some(&byte);
+        if found {
+            *pos += 1;
+        }
+        found
+    };
+
+    // `YYYY` or the expanded `±YYYYYY` year.
+    let year = match bytes.first()? {
+        sign @ (b'+' | b'-') => {
+            pos += 1;
+            let year = digits(&mut pos, 6)? as i32;
+            match sign {
+                // `-000000` is not a valid year.
+                b'-' if year == 0 => return None,
+                b'-' => -year,
+                _ => year,
+            }
+        }
+        _ => digits(&mut pos, 4)? as i32,
+    };
+    let (mut month, mut day) = (1, 1);
+    if next_if(&mut pos, b'-') {
+        month = digits(&mut pos, 2)?;
+        if next_if(&mut pos, b'-') {
+            day = digits(&mut pos, 2)?;
+        }
+    }
+    let date = NaiveDate::from_ymd_opt(year, month, day)?;
+
+    if pos == bytes.len() {
+        return Some(date.and_hms(0, 0, 0));
+    }
+
+    // `THH:mm`, `THH:mm:ss` or `THH:mm:ss.sss`.
+    if !next_if(&mut pos, b'T') {
+        return None;
+    }
+    let hour = digits(&mut pos, 2)?;
+    if !next_if(&mut pos, b':') {
+        return None;
+    }
+    let minute = digits(&mut pos, 2)?;
+    let (mut second, mut millis) = (0, 0);
+    if next_if(&mut pos, b':') {
+        second = digits(&mut pos, 2)?;
+        if next_if(&mut pos, b'.') {
+            let fraction_start = pos;
+            while bytes.get(pos).map_or(false, u8::is_ascii_digit) {
+                pos += 1;
+            }
+            if pos == fraction_start {
+                return None;
+            }
+            // Only millisecond precision is kept.
+            millis = bytes[fraction_start..pos]
+                .iter()
+                .chain(std::iter::repeat(&b'0'))
+                .take(3)
+                .fold(0, |acc, digit| acc * 10 + u32::from(digit - b'0'));
+        }
+    }
+
+    // `24:00` is the end of the day, which is the start of the next one.
+    let date_time = if hour == 24 && minute == 0 && second == 0 && millis == 0 {
+        date.and_hms(0, 0, 0)
+            .checked_add_signed(Duration::days(1))?
+    } else if second < 60 {
+        date.and_hms_milli_opt(hour, minute, second, millis)?
+    } else {
+        return None;
+    };
+
+    // `Z` or a `±HH:mm` offset, or local time if there is none.
+    match bytes.get(pos) {
+        None => ignore_ambiguity(Local.from_local_datetime(&date_time)).map(|dt| dt.naive_utc()),
+        Some(b'Z') if pos + 1 == bytes.len() => Some(date_time),
+        Some(&sign @ (b'+' | b'-')) => {
+            pos += 1;
+            let offset_hour = digits(&mut pos, 2)?;
+            if !next_if(&mut pos, b':') {
+                return None;
+            }
+            let offset_minute = digits(&mut pos, 2)?;
+            if pos != bytes.len() || offset_hour > 23 || offset_minute > 59 {
+                return None;
+            }
+            let offset = Duration::minutes(i64::from(offset_hour * 60 + offset_minute));
+            if sign == b'+' {
+                date_time.checked_sub_signed(offset)
+            } else {
+                date_time.checked_add_signed(offset)
+            }
+        }
+        _ => None,
+    }
+}
@@ -204,6 +204,82 @@ fn date_ctor_parse_call() {
     assert_eq!(Ok(JsValue::new(1591634775779f64)), date_time);
 }
 
+#[test]
+fn date_ctor_parse_call_iso_formats() {
+    fn check(src: &str, expected: f64) {
+        let mut context = Context::default();
+        let date_time = forward_val(&mut context, format!("Date.parse('{src}')"));
+        assert_eq!(Ok(JsValue::new(expected)), date_time, "{src}");
+    }
+
+    // Date-only forms are UTC time.
+    check("2020", 1577836800000f64);
+    check("2020-03", 1583020800000f64);
+    check("2020-01-01", 1577836800000f64);
+    check("+002020-01-01", 1577836800000f64);
+    check("-000001-01-01T00:00:00Z", -62198755200000f64);
+
+    check("2020-01-01T10:20Z", 1577874000000f64);
+    check("2020-01-01T10:20:30Z", 1577874030000f64);
+    check("2020-01-01T10:20:30.5Z", 1577874030500f64);
+    check("2020-01-01T10:20:30.123456Z", 1577874030123f64);
+    check("2020-01-01T10:20:30+02:00", 1577866830000f64);
+    check("2020-01-01T10:20:30.123-05:30", 1577893830123f64);
+    check("2020-01-01T24:00Z", 1577923200000f64);
+
+    // Around the leap second inserted at the end of 2016, which is not representable.
+    check("2016-12-31T23:59:59.999Z", 1483228799999f64);
+    check("2017-01-01T00:00:00.000Z", 1483228800000f64);
+    check("2016-12-31T23:59:60Z", f64::NAN);
+
+    // At the ends of the supported year range.
+    check("+262143-12-31T23:59Z", 8210298412740000f64);
+    check("-262144-01-01T00:00Z", -8334632851200000f64);
+    check("+262143-12-31T24:00Z", f64::NAN);
+    check("+262143-12-31T23:59-23:59", f64::NAN);
+    check("-262144-01-01T00:00+00:01", f64::NAN);
+}
+
+#[test]
+fn date_ctor_parse_call_local_time() {
+    let mut context = Context::default();
+
+    let date_time = forward_val(
+        &mut context,
+        "Date.parse('2020-07-08T09:16:15') === new Date(2020, 06, 08, 09, 16, 15).getTime()",
+    );
+    assert_eq!(Ok(JsValue::new(true)), date_time);
+
+    let date_time = forward_val(
+        &mut context,
+        "var d = new Date(2020, 06, 08, 09, 16, 15);
+        Date.parse(d.toString()) === d.getTime() && Date.parse(d.toUTCString()) === d.getTime()",
+    );
+    assert_eq!(Ok(JsValue::new(true)), date_time);
+}
+
+#[test]
+fn date_ctor_parse_call_invalid() {
+    fn check(src: &str) {
+        let mut context = Context::default();
+        let date_time = forward_val(&mut context, src).expect("Expected Success");
+        assert_eq!(JsValue::nan(), date_time, "{src}");
+    }
+
+    check("Date.parse()");
+    check("Date.parse('')");
+    check("Date.parse('garbage')");
+    check("Date.parse('2020-1-1')");
+    check("Date.parse('2020-02-30')");
+    check("Date.parse('2020-13-01')");
+    check("Date.parse('-000000-01-01')");
+    check("Date.parse('2020-01-01T')");
+    check("Date.parse('2020-01-01T24:01Z')");
+    check("Date.parse('2020-01-01T10:20:30.Z')");
+    check("Date.parse('2020-01-01T10:20:30+0200')");
+    check("new Date('2020-01-01T10:20:30+0200').getTime()");
+}
+
 #[test]
 fn date_ctor_utc_call() {
     let mut context = Context::default();
@@ -213,6 +289,57 @@ fn date_ctor_utc_call() {
     assert_eq!(Ok(JsValue::new(1594199775779f64)), date_time);
 }
 
+#[test]
+fn date_ctor_utc_call_normalizes_components() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(&mut context, "Date.UTC(2024, 0, 1)"),
+        "1704067200000"
+    );
+    assert_eq!(forward(&mut context, "Date.UTC(2024)"), "1704067200000");
+    assert_eq!(forward(&mut context, "Date.UTC(99, 0)"), "915148800000");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Date.UTC(2024, 12, 1) === Date.UTC(2025, 0, 1)"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Date.UTC(2024, -1, 1) === Date.UTC(2023, 11, 1)"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Date.UTC(2024, 0, 32, 25, 61, 61, 1001) === Date.UTC(2024, 1, 2, 2, 2, 2, 1)"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "Date.UTC()"), "NaN");
+    assert_eq!(
+        forward(&mut context, "Date.UTC(275760, 8, 13, 0, 0, 0, 1)"),
+        "NaN"
+    );
+}
+
+#[test]
+fn date_ctor_now_is_finite_positive() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "const now = Date.now(); Number.isFinite(now) && now > 0"
+        ),
+        "true"
+    );
+}
+
 #[test]
 fn date_ctor_utc_call_nan() {
     fn check(src: &str) {
@@ -0,0 +1,131 @@
+//! This module implements the global `AggregateError` object.
+//!
+//! Represents several errors wrapped in a single error. It is thrown, for example, by
+//! `Promise.any()` when all of the passed promises are rejected.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-aggregate-error-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError
+
+use crate::{
+    builtins::{iterable::iterable_to_list, Array, BuiltIn, JsArgs},
+    context::StandardObjects,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+    },
+    profiler::BoaProfiler,
+    property::{Attribute, PropertyDescriptor},
+    Context, JsResult, JsValue,
+};
+
+/// JavaScript `AggregateError` implementation.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AggregateError;
+
+impl BuiltIn for AggregateError {
+    const NAME: &'static str = "AggregateError";
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE
+        .union(Attribute::NON_ENUMERABLE)
+        .union(Attribute::CONFIGURABLE);
+
+    fn init(context: &mut Context) -> JsValue {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let error_prototype = context.standard_objects().error_object().prototype();
+        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        let aggregate_error_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().aggregate_error_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .inherit(error_prototype)
+        .property("name", Self::NAME, attribute)
+        .property("message", "", attribute)
+        .build();
+
+        aggregate_error_object.into()
+    }
+}
+
+impl AggregateError {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 2;
+
+    /// Create a new aggregate error object.
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardObjects::aggregate_error_object,
+            context,
+        )?;
+        let obj = JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::error(context.capture_stack_trace()),
+        );
+        let message = args.get_or_undefined(1);
+        if !message.is_undefined() {
+            let msg = message.to_string(context)?;
+            obj.define_property_or_throw(
+                "message",
+                PropertyDescriptor::builder()
+                    .configurable(true)
+                    .enumerable(false)
+                    .writable(true)
+                    .value(msg),
+                context,
+            )?;
+        }
+
+        let errors = iterable_to_list(context, args.get_or_undefined(0), None)?;
+        let errors = Array::create_array_from_list(errors, context);
+        obj.define_property_or_throw(
+            "errors",
+            PropertyDescriptor::builder()
+                .configurable(true)
+                .enumerable(false)
+                .writable(true)
+                .value(errors),
+            context,
+        )?;
+
+        Ok(obj.into())
+    }
+
+    /// Creates a new `AggregateError` object with the given `errors` and no message, as done by
+    /// `Promise.any()` when all of its promises are rejected.
+    pub(crate) fn create<I>(errors: I, context: &mut Context) -> JsObject
+    where
+        I: IntoIterator<Item = JsValue>,
+    {
+        let prototype = context
+            .standard_objects()
+            .aggregate_error_object()
+            .prototype();
+        let obj = JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::error(context.capture_stack_trace()),
+        );
+        let errors = Array::create_array_from_list(errors, context);
+        obj.define_property_or_throw(
+            "errors",
+            PropertyDescriptor::builder()
+                .configurable(true)
+                .enumerable(false)
+                .writable(true)
+                .value(errors),
+            context,
+        )
+        .expect("defining a property on a new object cannot fail");
+        obj
+    }
+}
@@ -0,0 +1,109 @@
+//! This module implements the call site objects of the V8 stack trace API.
+//!
+//! A call site describes a frame of the stack trace of an error object, and is what
+//! `Error.prepareStackTrace` receives to format the `stack` of the error.
+//!
+//! More information:
+//!  - [V8 documentation][v8]
+//!
+//! [v8]: https://v8.dev/docs/stack-trace-api#customizing-stack-traces
+
+use crate::{
+    builtins::Array,
+    object::{JsObject, ObjectData, ObjectInitializer},
+    vm::StackTraceFrame,
+    Context, JsResult, JsValue,
+};
+
+/// The call site objects passed to `Error.prepareStackTrace`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CallSite;
+
+impl CallSite {
+    /// Creates an array with a call site object for each frame of `stack_trace`.
+    pub(crate) fn create_array(stack_trace: &[StackTraceFrame], context: &mut Context) -> JsValue {
+        let prototype = ObjectInitializer::new(context)
+            .function(Self::get_function_name, "getFunctionName", 0)
+            .function(Self::get_file_name, "getFileName", 0)
+            .function(Self::get_line_number, "getLineNumber", 0)
+            .function(Self::get_column_number, "getColumnNumber", 0)
+            .function(Self::to_string, "toString", 0)
+            .build();
+
+        let call_sites = stack_trace.iter().map(|frame| {
+            JsObject::from_proto_and_data(
+                prototype.clone(),
+                ObjectData::native_object(Box::new(frame.clone())),
+            )
+            .into()
+        });
+        Array::create_array_from_list(call_sites, context).into()
+    }
+
+    /// Gets the frame of the call site `this`.
+    fn this_frame(this: &JsValue, context: &mut Context) -> JsResult<StackTraceFrame> {
+        this.as_object()
+            .and_then(|object| {
+                object
+                    .downcast_ref::<StackTraceFrame>()
+                    .map(|frame| frame.clone())
+            })
+            .map_or_else(|| context.throw_type_error("'this' is not a call site"), Ok)
+    }
+
+    /// `CallSite.prototype.getFunctionName()`
+    ///
+    /// Returns the name of the function of the frame, or `null` if it's anonymous or the top level
+    /// code of a script.
+    fn get_function_name(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let frame = Self::this_frame(this, context)?;
+        Ok(frame
+            .named_function()
+            .map_or_else(JsValue::null, Into::into))
+    }
+
+    /// `CallSite.prototype.getFileName()`
+    ///
+    /// Scripts don't have a file name, so this always returns `undefined`.
+    fn get_file_name(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::this_frame(this, context)?;
+        Ok(JsValue::undefined())
+    }
+
+    /// `CallSite.prototype.getLineNumber()`
+    ///
+    /// Returns the line of the statement that was executing in the frame, or `null` if unknown.
+    fn get_line_number(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let frame = Self::this_frame(this, context)?;
+        Ok(frame
+            .position()
+            .map_or_else(JsValue::null, |position| position.line_number().into()))
+    }
+
+    /// `CallSite.prototype.getColumnNumber()`
+    ///
+    /// Returns the column of the statement that was executing in the frame, or `null` if unknown.
+    fn get_column_number(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let frame = Self::this_frame(this, context)?;
+        Ok(frame
+            .position()
+            .map_or_else(JsValue::null, |position| position.column_number().into()))
+    }
+
+    /// `CallSite.prototype.toString()`
+    ///
+    /// Formats the frame like a line of the default `stack` of an error, e.g. `f (2:9)`.
+    #[allow(clippy::wrong_self_convention)]
+    fn to_string(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let frame = Self::this_frame(this, context)?;
+        Ok(frame.to_string().into())
+    }
+}
@@ -11,16 +11,22 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error
 
 use crate::{
-    builtins::BuiltIn,
+    builtins::{BuiltIn, JsArgs},
     context::StandardObjects,
     object::{
-        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
     },
     profiler::BoaProfiler,
-    property::Attribute,
+    property::{Attribute, PropertyDescriptor},
     Context, JsResult, JsValue,
 };
 
+use self::call_site::CallSite;
+use std::fmt::Write;
+
+pub(crate) mod aggregate;
+pub(crate) mod call_site;
 pub(crate) mod eval;
 pub(crate) mod range;
 pub(crate) mod reference;
@@ -31,6 +37,7 @@ pub(crate) mod uri;
 #[cfg(test)]
 mod tests;
 
+pub(crate) use self::aggregate::AggregateError;
 pub(crate) use self::eval::EvalError;
 pub(crate) use self::r#type::TypeError;
 pub(crate) use self::range::RangeError;
@@ -53,6 +60,15 @@ impl BuiltIn for Error {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
         let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        let get_stack = FunctionBuilder::native(context, Self::get_stack)
+            .name("get stack")
+            .constructor(false)
+            .build();
+        let set_stack = FunctionBuilder::native(context, Self::set_stack)
+            .name("set stack")
+            .length(1)
+            .constructor(false)
+            .build();
         let error_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -63,6 +79,12 @@ impl BuiltIn for Error {
         .property("name", Self::NAME, attribute)
         .property("message", "", attribute)
         .method(Self::to_string, "toString", 0)
+        .accessor(
+            "stack",
+            Some(get_stack),
+            Some(set_stack),
+            Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
         .build();
 
         error_object.into()
@@ -83,7 +105,10 @@ impl Error {
     ) -> JsResult<JsValue> {
         let prototype =
             get_prototype_from_constructor(new_target, StandardObjects::error_object, context)?;
-        let obj = JsObject::from_proto_and_data(prototype, ObjectData::error());
+        let obj = JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::error(context.capture_stack_trace()),
+        );
         if let Some(message) = args.get(0) {
             if !message.is_undefined() {
                 obj.set("message", message.to_string(context)?, false, context)?;
@@ -137,4 +162,91 @@ impl Error {
             Ok(format!("{name}: {message}").into())
         }
     }
+
+    /// `get Error.prototype.stack`
+    ///
+    /// Formats the stack trace captured when the error was created. If `Error.prepareStackTrace`
+    /// is a function, it's called with the error and an array of call sites, and its result is
+    /// used as the stack. Otherwise each frame is formatted on its own line after the string
+    /// representation of the error, e.g. `    at f (2:9)`.
+    ///
+    /// The stack is computed the first time it's read, and then stored as an own `stack`
+    /// property of the error. This is a V8 extension.
+    ///
+    /// More information:
+    ///  - [V8 documentation][v8]
+    ///
+    /// [v8]: https://v8.dev/docs/stack-trace-api
+    pub(crate) fn get_stack(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let object = match this.as_object() {
+            Some(object) => object,
+            None => return Ok(JsValue::undefined()),
+        };
+        let stack_trace = match object.borrow().as_error() {
+            Some(stack_trace) => stack_trace.to_vec(),
+            None => return Ok(JsValue::undefined()),
+        };
+
+        let error_constructor = context.standard_objects().error_object().constructor();
+        let prepare_stack_trace = error_constructor.get("prepareStackTrace", context)?;
+        let stack = if prepare_stack_trace.is_callable() {
+            // Reading the stack from `Error.prepareStackTrace` must not call it again.
+            Self::define_stack(object, JsValue::undefined(), context)?;
+
+            let call_sites = CallSite::create_array(&stack_trace, context);
+            context.call(
+                &prepare_stack_trace,
+                &error_constructor.into(),
+                &[this.clone(), call_sites],
+            )?
+        } else {
+            let mut stack = Self::to_string(this, &[], context)?
+                .to_string(context)?
+                .to_string();
+            for frame in &stack_trace {
+                write!(stack, "\n    at {frame}").expect("writing to a String cannot fail");
+            }
+            stack.into()
+        };
+
+        Self::define_stack(object, stack.clone(), context)?;
+        Ok(stack)
+    }
+
+    /// `set Error.prototype.stack`
+    ///
+    /// Replaces the stack of the error with an own `stack` property. This is a V8 extension.
+    ///
+    /// More information:
+    ///  - [V8 documentation][v8]
+    ///
+    /// [v8]: https://v8.dev/docs/stack-trace-api
+    pub(crate) fn set_stack(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        if let Some(object) = this.as_object() {
+            Self::define_stack(object, args.get_or_undefined(0).clone(), context)?;
+        }
+        Ok(JsValue::undefined())
+    }
+
+    /// Defines the own `stack` property of an error.
+    fn define_stack(object: &JsObject, stack: JsValue, context: &mut Context) -> JsResult<()> {
+        object.define_property_or_throw(
+            "stack",
+            PropertyDescriptor::builder()
+                .value(stack)
+                .writable(true)
+                .enumerable(false)
+                .configurable(true),
+            context,
+        )?;
+        Ok(())
+    }
 }
@@ -64,7 +64,10 @@ impl RangeError {
     ) -> JsResult<JsValue> {
         let prototype =
             get_prototype_from_constructor(new_target, StandardObjects::error_object, context)?;
-        let obj = JsObject::from_proto_and_data(prototype, ObjectData::error());
+        let obj = JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::error(context.capture_stack_trace()),
+        );
         if let Some(message) = args.get(0) {
             if !message.is_undefined() {
                 obj.set("message", message.to_string(context)?, false, context)?;
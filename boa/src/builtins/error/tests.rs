@@ -84,3 +84,79 @@ fn uri_error_to_string() {
         "\"URIError\""
     );
 }
+
+#[test]
+fn aggregate_error() {
+    let mut context = Context::default();
+    let init = r#"
+        var error = new AggregateError([1, 'two'], 'message');
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "error.message"), "\"message\"");
+    assert_eq!(forward(&mut context, "error.name"), "\"AggregateError\"");
+    assert_eq!(forward(&mut context, "error.errors.join()"), "\"1,two\"");
+    assert_eq!(forward(&mut context, "error instanceof Error"), "true");
+    assert_eq!(forward(&mut context, "Object.keys(error).length"), "0");
+}
+
+#[test]
+fn error_stack() {
+    let mut context = Context::default();
+    let init = r#"
+        function inner() {
+            return new Error('boom');
+        }
+        function outer() { return inner(); }
+        let e = outer();
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "e.stack"),
+        "\"Error: boom\n    at inner (3:13)\n    at outer (5:28)\n    at 6:9\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyNames(e).includes('stack')"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "e.stack = 'custom'; e.stack"),
+        "\"custom\""
+    );
+}
+
+#[test]
+fn error_prepare_stack_trace() {
+    let mut context = Context::default();
+    let init = r#"
+        function thrower() {
+            null.x;
+        }
+        Error.prepareStackTrace = (error, callSites) => {
+            const frames = callSites.map(site =>
+                `${site.getFunctionName()}:${site.getLineNumber()}:${site.getColumnNumber()}`
+            );
+            return `${error.name} [${frames.join(', ')}] ${callSites[0]}`;
+        };
+        let stack;
+        try {
+            thrower();
+        } catch (e) {
+            stack = e.stack;
+        }
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "stack"),
+        "\"TypeError [thrower:3:13, null:13:13] thrower (3:13)\""
+    );
+
+    // Reading the stack from the hook doesn't call it again.
+    forward(
+        &mut context,
+        "Error.prepareStackTrace = (error) => error.stack;",
+    );
+    assert_eq!(forward(&mut context, "new Error().stack"), "undefined");
+}
@@ -17,14 +17,14 @@ use crate::{
     environments::DeclarativeEnvironmentStack,
     gc::{self, Finalize, Gc, Trace},
     object::{
-        internal_methods::get_prototype_from_constructor, JsObject, NativeObject, Object,
-        ObjectData,
+        internal_methods::get_prototype_from_constructor, push_private_method, JsObject,
+        NativeObject, Object, ObjectData, PrivateElement,
     },
     object::{ConstructorBuilder, FunctionBuilder, Ref, RefMut},
     property::{Attribute, PropertyDescriptor, PropertyKey},
     symbol::WellKnownSymbols,
     value::IntegerOrInfinity,
-    BoaProfiler, Context, JsResult, JsString, JsValue,
+    BoaProfiler, Context, JsResult, JsString, JsSymbol, JsValue,
 };
 use dyn_clone::DynClone;
 use std::{
@@ -177,6 +177,15 @@ pub enum Function {
     VmOrdinary {
         code: Gc<crate::vm::CodeBlock>,
         environments: DeclarativeEnvironmentStack,
+
+        /// The [[HomeObject]] of a method, used to resolve `super` property lookups.
+        home_object: Option<JsObject>,
+
+        /// The [[Fields]] of a class constructor.
+        fields: Vec<ClassFieldDefinition>,
+
+        /// The [[PrivateMethods]] of a class constructor, added to each instance of the class.
+        private_methods: Vec<(JsSymbol, PrivateElement)>,
     },
 }
 
@@ -194,6 +203,81 @@ impl Function {
             Self::VmOrdinary { code, .. } => code.constructor,
         }
     }
+
+    /// Returns the [[HomeObject]] of the function, if it is a method.
+    pub(crate) fn home_object(&self) -> Option<&JsObject> {
+        match self {
+            Self::VmOrdinary { home_object, .. } => home_object.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Sets the [[HomeObject]] of the function.
+    ///
+    /// This has no effect on native functions.
+    pub(crate) fn set_home_object(&mut self, object: JsObject) {
+        if let Self::VmOrdinary { home_object, .. } = self {
+            *home_object = Some(object);
+        }
+    }
+
+    /// Returns the [[Fields]] of the function, which are only present on class constructors.
+    pub(crate) fn fields(&self) -> &[ClassFieldDefinition] {
+        match self {
+            Self::VmOrdinary { fields, .. } => fields,
+            _ => &[],
+        }
+    }
+
+    /// Appends a field definition to the [[Fields]] of the function.
+    ///
+    /// This has no effect on native functions.
+    pub(crate) fn push_field(&mut self, field: ClassFieldDefinition) {
+        if let Self::VmOrdinary { fields, .. } = self {
+            fields.push(field);
+        }
+    }
+
+    /// Returns the [[PrivateMethods]] of the function, which are only present on class
+    /// constructors.
+    pub(crate) fn private_methods(&self) -> &[(JsSymbol, PrivateElement)] {
+        match self {
+            Self::VmOrdinary {
+                private_methods, ..
+            } => private_methods,
+            _ => &[],
+        }
+    }
+
+    /// Appends a private method or accessor to the [[PrivateMethods]] of the function.
+    ///
+    /// This has no effect on native functions.
+    pub(crate) fn push_private_method(&mut self, name: JsSymbol, method: PrivateElement) {
+        if let Self::VmOrdinary {
+            private_methods, ..
+        } = self
+        {
+            push_private_method(private_methods, name, method);
+        }
+    }
+}
+
+/// A field of a class, which is defined on each instance of the class when it is constructed.
+///
+/// Each variant holds the name of the field and the function which evaluates the initializer of
+/// the field, with `this` bound to the instance, if the field has an initializer.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-classfielddefinition-record-specification-type
+#[derive(Clone, Debug, Trace, Finalize)]
+pub enum ClassFieldDefinition {
+    /// A public field, defined as an own property.
+    Public(PropertyKey, Option<JsObject>),
+
+    /// A private field, keyed by the private name of the class which declares it.
+    Private(JsSymbol, Option<JsObject>),
 }
 
 /// Creates a new member function of a `Object` or `prototype`.
@@ -423,14 +507,36 @@ impl BuiltInFunctionObject {
         func.call(this_arg, args.get(1..).unwrap_or(&[]), context)
     }
 
+    /// `Function.prototype.toString ( )`
+    ///
+    /// Returns the source text of functions defined in source code, including the `get`, `set`,
+    /// `async` and `*` prefixes of methods.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function.prototype.tostring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/toString
     #[allow(clippy::wrong_self_convention)]
     fn to_string(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let func be the this value.
         let object = this.as_object().map(JsObject::borrow);
         let function = object
             .as_deref()
             .and_then(Object::as_function)
             .ok_or_else(|| context.construct_type_error("Not a function"))?;
 
+        // 2. If Type(func) is Object and func has a [[SourceText]] internal slot and
+        //    func.[[SourceText]] is a sequence of Unicode code points and HostHasSourceTextAvailable(func)
+        //    is true, then
+        //     a. Return CodePointsToString(func.[[SourceText]]).
+        if let Function::VmOrdinary { code, .. } = function {
+            if let Some(source_text) = code.source_text() {
+                return Ok(source_text.into());
+            }
+        }
+
         let name = {
             // Is there a case here where if there is no name field on a value
             // name should default to None? Do all functions have names set?
@@ -529,7 +635,7 @@ impl BuiltIn for BuiltInFunctionObject {
 ///  - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-setfunctionname
-fn set_function_name(
+pub(crate) fn set_function_name(
     function: &JsObject,
     name: &PropertyKey,
     prefix: Option<&str>,
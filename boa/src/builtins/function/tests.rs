@@ -97,6 +97,24 @@ fn call_function_prototype_with_new() {
     assert!(value.is_err());
 }
 
+#[test]
+fn unqualified_call_this() {
+    let mut context = Context::default();
+    let init = r#"
+        const o = {
+            m() {
+                function inner() {
+                    return this;
+                }
+                return inner();
+            }
+        };
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "o.m() === o"), "false");
+    assert_eq!(forward(&mut context, "o.m() === globalThis"), "true");
+}
+
 #[test]
 fn function_prototype_name() {
     let mut context = Context::default();
@@ -218,6 +236,171 @@ fn function_prototype_apply_on_object() {
     assert!(boolean);
 }
 
+#[test]
+fn function_prototype_bind_partial_application() {
+    let mut context = Context::default();
+    let init = r#"
+        function add(a, b, c) {
+            return [this === obj, a + b + c];
+        }
+        var obj = {};
+        var bound = add.bind(obj, 1);
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "bound(2, 3).join()"), "\"true,6\"");
+    assert_eq!(
+        forward(&mut context, "bound.call({}, 2, 3).join()"),
+        "\"true,6\""
+    );
+    assert_eq!(forward(&mut context, "bound.bind(null, 2)(3)[1]"), "6");
+    assert_eq!(forward(&mut context, "bound.name"), "\"bound add\"");
+    assert_eq!(
+        forward(&mut context, "bound.bind(null).name"),
+        "\"bound bound add\""
+    );
+}
+
+#[test]
+fn function_prototype_bind_length() {
+    let mut context = Context::default();
+    let init = r#"
+        function f(a, b, c) {}
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "f.bind(null).length"), "3");
+    assert_eq!(forward(&mut context, "f.bind(null, 1).length"), "2");
+    assert_eq!(
+        forward(&mut context, "f.bind(null, 1, 2, 3, 4).length"),
+        "0"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.defineProperty(f, 'length', { value: Infinity }); f.bind(null, 1).length"
+        ),
+        "Infinity"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.defineProperty(f, 'length', { value: 'abc' }); f.bind(null).length"
+        ),
+        "0"
+    );
+}
+
+#[test]
+fn function_prototype_to_string() {
+    let mut context = Context::default();
+    let init = r#"
+        function  f(a, b) { return a + b; }
+        const arrow = (x) =>
+            x * 2;
+        class C {
+            method(a) { return a; }
+            static *gen() { yield 1; }
+            get value() { return 1; }
+        }
+        const obj = { async m() {}, set value(v) {} };
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "f.toString()"),
+        "\"function  f(a, b) { return a + b; }\""
+    );
+    assert_eq!(
+        forward(&mut context, "arrow.toString()"),
+        "\"(x) =>\n            x * 2\""
+    );
+    assert_eq!(
+        forward(&mut context, "C.prototype.method.toString()"),
+        "\"method(a) { return a; }\""
+    );
+    assert_eq!(
+        forward(&mut context, "C.gen.toString()"),
+        "\"*gen() { yield 1; }\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(C.prototype, 'value').get.toString()"
+        ),
+        "\"get value() { return 1; }\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(obj, 'value').set.toString()"
+        ),
+        "\"set value(v) {}\""
+    );
+    assert_eq!(
+        forward(&mut context, "(async function () {}).toString()"),
+        "\"async function () {}\""
+    );
+
+    // The source text is sliced from the source code by byte offsets, which are not affected by
+    // multi-byte characters before or inside of the function.
+    forward(
+        &mut context,
+        "const é = '€'; function g() { return /ü/.source + `${é}`; }",
+    );
+    assert_eq!(
+        forward(&mut context, "g.toString()"),
+        "\"function g() { return /ü/.source + `${é}`; }\""
+    );
+}
+
+#[test]
+fn function_prototype_bind_construct() {
+    let mut context = Context::default();
+    let init = r#"
+        function Point(x, y) {
+            this.x = x;
+            this.y = y;
+        }
+        var BoundPoint = Point.bind({ ignored: true }, 1);
+        var p = new BoundPoint(2);
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "p.x"), "1");
+    assert_eq!(forward(&mut context, "p.y"), "2");
+    assert_eq!(forward(&mut context, "p.ignored"), "undefined");
+    assert_eq!(forward(&mut context, "p instanceof Point"), "true");
+    assert_eq!(forward(&mut context, "p instanceof BoundPoint"), "true");
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(p) === Point.prototype"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new (Math.max.bind(null)) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn function_prototype_bind_self_mutating_target() {
+    let mut context = Context::default();
+    let init = r#"
+        function f() {
+            bound.called = true;
+            return 1;
+        }
+        var bound = f.bind(null);
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "bound()"), "1");
+    assert_eq!(forward(&mut context, "bound.called"), "true");
+}
+
 #[test]
 fn closure_capture_clone() {
     let mut context = Context::default();
@@ -0,0 +1,332 @@
+//! This module implements the `Generator` object.
+//!
+//! A `Generator` is returned by a generator function and conforms to both the iterator and the
+//! iterable protocols.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-generator-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator
+
+use crate::{
+    builtins::{function::make_builtin_fn, iterable::create_iter_result_object},
+    environments::DeclarativeEnvironmentStack,
+    gc::{Finalize, Trace},
+    object::{JsObject, ObjectData},
+    property::PropertyDescriptor,
+    symbol::WellKnownSymbols,
+    vm::{CallFrame, GeneratorResumeKind, ReturnType},
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Indicates the state of a generator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum GeneratorState {
+    SuspendedStart,
+    SuspendedYield,
+    Executing,
+    Completed,
+}
+
+/// Holds all information that a generator needs to continue it's execution.
+///
+/// All of the fields must be changed with those that are currently present in the
+/// context/vm before the generator execution starts/resumes and after it has ended/yielded.
+#[derive(Debug, Clone, Finalize, Trace)]
+pub(crate) struct GeneratorContext {
+    pub(crate) environments: DeclarativeEnvironmentStack,
+    pub(crate) call_frame: CallFrame,
+    pub(crate) stack: Vec<JsValue>,
+}
+
+/// The internal representation on a `Generator` object.
+#[derive(Debug, Clone, Finalize, Trace)]
+pub struct Generator {
+    /// The `[[GeneratorState]]` internal slot.
+    #[unsafe_ignore_trace]
+    pub(crate) state: GeneratorState,
+
+    /// The `[[GeneratorContext]]` internal slot.
+    ///
+    /// This is `None` while the generator is executing and after it has completed.
+    pub(crate) context: Option<GeneratorContext>,
+}
+
+impl Generator {
+    pub(crate) const NAME: &'static str = "Generator";
+
+    /// Create the `%GeneratorFunction.prototype.prototype%` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-properties-of-generator-prototype
+    pub(crate) fn create_prototype(
+        iterator_prototype: JsObject,
+        context: &mut Context,
+    ) -> JsObject {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let generator = JsObject::from_proto_and_data(iterator_prototype, ObjectData::ordinary());
+        make_builtin_fn(Self::next, "next", &generator, 1, context);
+        make_builtin_fn(Self::r#return, "return", &generator, 1, context);
+        make_builtin_fn(Self::throw, "throw", &generator, 1, context);
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+        let to_string_tag_property = PropertyDescriptor::builder()
+            .value(Self::NAME)
+            .writable(false)
+            .enumerable(false)
+            .configurable(true);
+        generator.insert(to_string_tag, to_string_tag_property);
+        generator
+    }
+
+    /// Create the `%GeneratorFunction.prototype%` object.
+    ///
+    /// This is the prototype of every generator function.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-properties-of-the-generatorfunction-prototype-object
+    pub(crate) fn create_function_prototype(
+        generator_prototype: &JsObject,
+        context: &mut Context,
+    ) -> JsObject {
+        let _timer = BoaProfiler::global().start_event("GeneratorFunction", "init");
+
+        let generator_function = JsObject::from_proto_and_data(
+            context.standard_objects().function_object().prototype(),
+            ObjectData::ordinary(),
+        );
+
+        let prototype_property = PropertyDescriptor::builder()
+            .value(generator_prototype.clone())
+            .writable(false)
+            .enumerable(false)
+            .configurable(true);
+        generator_function.insert("prototype", prototype_property);
+
+        let constructor_property = PropertyDescriptor::builder()
+            .value(generator_function.clone())
+            .writable(false)
+            .enumerable(false)
+            .configurable(true);
+        generator_prototype.insert("constructor", constructor_property);
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+        let to_string_tag_property = PropertyDescriptor::builder()
+            .value("GeneratorFunction")
+            .writable(false)
+            .enumerable(false)
+            .configurable(true);
+        generator_function.insert(to_string_tag, to_string_tag_property);
+        generator_function
+    }
+
+    /// `Generator.prototype.next ( value )`
+    ///
+    /// The `next()` method returns an object with two properties done and value.
+    /// You can also provide a parameter to the next method to send a value to the generator.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.next
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/next
+    pub(crate) fn next(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Return ? GeneratorResume(this value, value, empty).
+        Self::generator_resume(
+            this,
+            args.get(0).cloned().unwrap_or_default(),
+            GeneratorResumeKind::Normal,
+            context,
+        )
+    }
+
+    /// `Generator.prototype.return ( value )`
+    ///
+    /// The `return()` method returns the given value and finishes the generator.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.return
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/return
+    pub(crate) fn r#return(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let g be the this value.
+        // 2. Let C be Completion { [[Type]]: return, [[Value]]: value, [[Target]]: empty }.
+        // 3. Return ? GeneratorResumeAbrupt(g, C, empty).
+        Self::generator_resume(
+            this,
+            args.get(0).cloned().unwrap_or_default(),
+            GeneratorResumeKind::Return,
+            context,
+        )
+    }
+
+    /// `Generator.prototype.throw ( exception )`
+    ///
+    /// The `throw()` method resumes the execution of a generator by throwing an error into it
+    /// and returns an object with two properties done and value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.throw
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/throw
+    pub(crate) fn throw(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let g be the this value.
+        // 2. Let C be ThrowCompletion(exception).
+        // 3. Return ? GeneratorResumeAbrupt(g, C, empty).
+        Self::generator_resume(
+            this,
+            args.get(0).cloned().unwrap_or_default(),
+            GeneratorResumeKind::Throw,
+            context,
+        )
+    }
+
+    /// `GeneratorResume ( generator, value, generatorBrand )` and
+    /// `GeneratorResumeAbrupt ( generator, abruptCompletion, generatorBrand )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generatorresume
+    fn generator_resume(
+        generator: &JsValue,
+        value: JsValue,
+        resume_kind: GeneratorResumeKind,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let state be ? GeneratorValidate(generator, generatorBrand).
+        let generator_object = generator.as_object().ok_or_else(|| {
+            context.construct_type_error("Generator method called on non generator")
+        })?;
+        let mut generator_object_mut = generator_object.borrow_mut();
+        let generator = generator_object_mut.as_generator_mut().ok_or_else(|| {
+            context.construct_type_error("generator resumed on non generator object")
+        })?;
+        let state = generator.state;
+
+        if state == GeneratorState::Executing {
+            return context.throw_type_error("Generator should not be executing");
+        }
+
+        // GeneratorResumeAbrupt:
+        // 2. If state is suspendedStart, then
+        if state == GeneratorState::SuspendedStart && resume_kind != GeneratorResumeKind::Normal {
+            // a. Set generator.[[GeneratorState]] to completed.
+            generator.state = GeneratorState::Completed;
+            // b. Once a generator enters the completed state it never leaves it and its associated
+            //    execution context is never resumed. Any execution state associated with generator
+            //    can be discarded at this point.
+            generator.context = None;
+        }
+
+        // GeneratorResume:
+        // 2. If state is completed, return CreateIterResultObject(undefined, true).
+        // GeneratorResumeAbrupt:
+        // 3. If state is completed, then
+        if generator.state == GeneratorState::Completed {
+            return match resume_kind {
+                GeneratorResumeKind::Normal => Ok(create_iter_result_object(
+                    JsValue::undefined(),
+                    true,
+                    context,
+                )),
+                // a. If abruptCompletion.[[Type]] is return, then
+                //     i. Return CreateIterResultObject(abruptCompletion.[[Value]], true).
+                GeneratorResumeKind::Return => Ok(create_iter_result_object(value, true, context)),
+                // b. Return Completion(abruptCompletion).
+                GeneratorResumeKind::Throw => Err(value),
+            };
+        }
+
+        // 3. Assert: state is either suspendedStart or suspendedYield.
+        // 4. Let genContext be generator.[[GeneratorContext]].
+        let mut generator_context = generator
+            .context
+            .take()
+            .expect("generator context cannot be empty here");
+
+        // 7. Set generator.[[GeneratorState]] to executing.
+        generator.state = GeneratorState::Executing;
+        drop(generator_object_mut);
+
+        // 5. Let methodContext be the running execution context.
+        // 6. Suspend methodContext.
+        // 8. Push genContext onto the execution context stack; genContext is now the running execution context.
+        std::mem::swap(
+            &mut context.realm.environments,
+            &mut generator_context.environments,
+        );
+        std::mem::swap(&mut context.vm.stack, &mut generator_context.stack);
+        context.vm.push_frame(generator_context.call_frame.clone());
+        context.vm.frame_mut().generator_resume_kind = resume_kind;
+
+        // 9. Resume the suspended evaluation of genContext using NormalCompletion(value) as the
+        //    result of the operation that suspended it. Let result be the value returned by the
+        //    resumed computation.
+        context.vm.push(value);
+        let result = context.run();
+
+        // 10. Assert: When we return here, genContext has already been removed from the execution
+        //     context stack and methodContext is the currently running execution context.
+        generator_context.call_frame = *context
+            .vm
+            .pop_frame()
+            .expect("generator call frame must exist");
+        std::mem::swap(
+            &mut context.realm.environments,
+            &mut generator_context.environments,
+        );
+        std::mem::swap(&mut context.vm.stack, &mut generator_context.stack);
+
+        let mut generator_object_mut = generator_object.borrow_mut();
+        let generator = generator_object_mut
+            .as_generator_mut()
+            .expect("already checked this object type");
+
+        // 11. Return Completion(result).
+        match result {
+            Ok((value, ReturnType::Yield)) => {
+                generator.state = GeneratorState::SuspendedYield;
+                generator.context = Some(generator_context);
+                drop(generator_object_mut);
+                Ok(create_iter_result_object(value, false, context))
+            }
+            Ok((value, ReturnType::Normal)) => {
+                generator.state = GeneratorState::Completed;
+                drop(generator_object_mut);
+                Ok(create_iter_result_object(value, true, context))
+            }
+            Err(value) => {
+                generator.state = GeneratorState::Completed;
+                Err(value)
+            }
+        }
+    }
+}
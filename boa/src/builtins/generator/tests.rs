@@ -0,0 +1,272 @@
+use crate::{forward, Context};
+
+#[test]
+fn fibonacci() {
+    let mut context = Context::default();
+    let init = r#"
+        function* fibonacci() {
+            let current = 0;
+            let next = 1;
+            while (true) {
+                yield current;
+                const sum = current + next;
+                current = next;
+                next = sum;
+            }
+        }
+        const sequence = fibonacci();
+        const values = [];
+        for (let i = 0; i < 10; i++) {
+            values.push(sequence.next().value);
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "values.join()"),
+        "\"0,1,1,2,3,5,8,13,21,34\""
+    );
+}
+
+#[test]
+fn next_passes_value_to_yield() {
+    let mut context = Context::default();
+    let init = r#"
+        function* adder(a) {
+            const b = yield a;
+            return a + b;
+        }
+        const gen = adder(1);
+        const first = gen.next("ignored");
+        const second = gen.next(41);
+        const third = gen.next();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "first.value"), "1");
+    assert_eq!(forward(&mut context, "first.done"), "false");
+    assert_eq!(forward(&mut context, "second.value"), "42");
+    assert_eq!(forward(&mut context, "second.done"), "true");
+    assert_eq!(forward(&mut context, "third.value"), "undefined");
+    assert_eq!(forward(&mut context, "third.done"), "true");
+}
+
+#[test]
+fn yield_star_delegates_to_array() {
+    let mut context = Context::default();
+    let init = r#"
+        function* delegating() {
+            yield 0;
+            yield* [1, 2, 3];
+            yield 4;
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "[...delegating()].join()"),
+        "\"0,1,2,3,4\""
+    );
+}
+
+#[test]
+fn yield_star_forwards_return() {
+    let mut context = Context::default();
+    let init = r#"
+        let closed = false;
+        function* inner() {
+            try {
+                yield 1;
+                yield 2;
+            } finally {
+                closed = true;
+            }
+        }
+        function* outer() {
+            yield* inner();
+        }
+        const gen = outer();
+        gen.next();
+        const result = gen.return(5);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "closed"), "true");
+    assert_eq!(forward(&mut context, "result.value"), "5");
+    assert_eq!(forward(&mut context, "result.done"), "true");
+}
+
+#[test]
+fn throw_caught_inside_generator() {
+    let mut context = Context::default();
+    let init = r#"
+        function* catching() {
+            try {
+                yield 1;
+            } catch (e) {
+                yield "caught " + e;
+            }
+            yield 3;
+        }
+        const gen = catching();
+        gen.next();
+        const caught = gen.throw("error");
+        const after = gen.next();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "caught.value"), "\"caught error\"");
+    assert_eq!(forward(&mut context, "caught.done"), "false");
+    assert_eq!(forward(&mut context, "after.value"), "3");
+}
+
+#[test]
+fn throw_completes_generator() {
+    let mut context = Context::default();
+    let init = r#"
+        function* gen() {
+            yield 1;
+            yield 2;
+        }
+        const it = gen();
+        it.next();
+        let message;
+        try {
+            it.throw(new Error("boom"));
+        } catch (e) {
+            message = e.message;
+        }
+        const after = it.next();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "message"), "\"boom\"");
+    assert_eq!(forward(&mut context, "after.done"), "true");
+}
+
+#[test]
+fn return_runs_finally() {
+    let mut context = Context::default();
+    let init = r#"
+        function* gen() {
+            try {
+                yield 1;
+                yield 2;
+            } finally {
+                yield "cleanup";
+            }
+        }
+        const it = gen();
+        it.next();
+        const cleanup = it.return(7);
+        const returned = it.next();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "cleanup.value"), "\"cleanup\"");
+    assert_eq!(forward(&mut context, "cleanup.done"), "false");
+    assert_eq!(forward(&mut context, "returned.value"), "7");
+    assert_eq!(forward(&mut context, "returned.done"), "true");
+}
+
+#[test]
+fn generator_objects() {
+    let mut context = Context::default();
+    let init = r#"
+        function* gen() {}
+        const it = gen();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(it)"),
+        "\"[object Generator]\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(it) === gen.prototype"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "it[Symbol.iterator]() === it"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(gen) === Object.getPrototypeOf(function* () {})"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new gen(); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn generator_function_prototype() {
+    let mut context = Context::default();
+    let init = r#"
+        function* gen() {}
+        function* other() {}
+        const generator_prototype = Object.getPrototypeOf(Object.getPrototypeOf(gen()));
+        const descriptor = Object.getOwnPropertyDescriptor(gen, "prototype");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "gen.prototype !== generator_prototype"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "gen.prototype !== other.prototype"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(gen.prototype) === generator_prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyNames(gen.prototype).length"
+        ),
+        "0"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[descriptor.writable, descriptor.enumerable, descriptor.configurable].join()"
+        ),
+        "\"true,false,false\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "gen().hasOwnProperty('next') || gen().next !== generator_prototype.next"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            gen.prototype = null;
+            Object.getPrototypeOf(gen()) === generator_prototype
+            "#
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn generator_method() {
+    let mut context = Context::default();
+    let init = r#"
+        const obj = {
+            value: 7,
+            *values() {
+                yield this.value;
+            },
+        };
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "obj.values().next().value"), "7");
+}
@@ -28,6 +28,8 @@ impl BuiltIn for Intl {
         .union(Attribute::NON_ENUMERABLE)
         .union(Attribute::CONFIGURABLE);
 
+    const LAZY: bool = true;
+
     fn init(context: &mut Context) -> JsValue {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
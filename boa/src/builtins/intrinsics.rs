@@ -5,6 +5,9 @@ use crate::{
     Context, JsResult, JsValue,
 };
 
+/// Intrinsic objects that are not standard constructors or prototypes.
+///
+/// See [`Context::intrinsics`].
 #[derive(Debug, Default)]
 pub struct IntrinsicObjects {
     throw_type_error: JsObject,
@@ -17,6 +20,12 @@ impl IntrinsicObjects {
         }
     }
 
+    /// Return the `%ThrowTypeError%` intrinsic function.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%throwtypeerror%
     pub fn throw_type_error(&self) -> JsObject {
         self.throw_type_error.clone()
     }
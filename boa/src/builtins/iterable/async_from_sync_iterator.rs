@@ -0,0 +1,337 @@
+//! This module implements the `%AsyncFromSyncIteratorPrototype%` object.
+//!
+//! An async-from-sync iterator adapts a synchronous iterator to the async iterator protocol, so
+//! that `for await` loops can iterate over any iterable. The values produced by the synchronous
+//! iterator are awaited before being handed to the loop.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-async-from-sync-iterator-objects
+
+use crate::{
+    builtins::{
+        function::make_builtin_fn,
+        iterable::{create_iter_result_object, IteratorRecord},
+        promise::{if_abrupt_reject_promise, Promise, PromiseCapability},
+        JsArgs,
+    },
+    gc::{Finalize, Trace},
+    object::{FunctionBuilder, JsObject, ObjectData},
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+/// The internal slots of an async-from-sync iterator object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-properties-of-async-from-sync-iterator-instances
+#[derive(Debug, Clone, Finalize, Trace)]
+pub struct AsyncFromSyncIterator {
+    sync_iterator: JsValue,
+    sync_next: JsValue,
+}
+
+impl AsyncFromSyncIterator {
+    pub(crate) const NAME: &'static str = "AsyncFromSyncIterator";
+
+    /// Create the `%AsyncFromSyncIteratorPrototype%` object
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%asyncfromsynciteratorprototype%-object
+    pub(crate) fn create_prototype(
+        async_iterator_prototype: JsObject,
+        context: &mut Context,
+    ) -> JsObject {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let prototype =
+            JsObject::from_proto_and_data(async_iterator_prototype, ObjectData::ordinary());
+        make_builtin_fn(Self::next, "next", &prototype, 1, context);
+        make_builtin_fn(Self::r#return, "return", &prototype, 1, context);
+        make_builtin_fn(Self::throw, "throw", &prototype, 1, context);
+        prototype
+    }
+
+    /// `CreateAsyncFromSyncIterator ( syncIteratorRecord )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createasyncfromsynciterator
+    pub(crate) fn create(
+        sync_iterator_record: &IteratorRecord,
+        context: &mut Context,
+    ) -> IteratorRecord {
+        // 1. Let asyncIterator be ! OrdinaryObjectCreate(%AsyncFromSyncIteratorPrototype%, « [[SyncIteratorRecord]] »).
+        // 2. Set asyncIterator.[[SyncIteratorRecord]] to syncIteratorRecord.
+        let async_iterator = JsObject::from_proto_and_data(
+            context.iterator_prototypes().async_from_sync_iterator(),
+            ObjectData::async_from_sync_iterator(Self {
+                sync_iterator: sync_iterator_record.iterator_object().clone(),
+                sync_next: sync_iterator_record.next_function().clone(),
+            }),
+        );
+
+        // 3. Let nextMethod be ! Get(asyncIterator, "next").
+        let next_method = async_iterator
+            .get("next", context)
+            .expect("async from sync iterator prototype must have next method");
+
+        // 4. Let iteratorRecord be the Record { [[Iterator]]: asyncIterator, [[NextMethod]]: nextMethod, [[Done]]: false }.
+        // 5. Return iteratorRecord.
+        IteratorRecord::new(async_iterator.into(), next_method)
+    }
+
+    /// Gets the `[[SyncIteratorRecord]]` of the `this` value, and a new capability for the
+    /// promise returned by the methods of the prototype.
+    fn sync_iterator_record(
+        this: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<(Self, PromiseCapability)> {
+        // 1. Let O be the this value.
+        // 2. Assert: Type(O) is Object and O has a [[SyncIteratorRecord]] internal slot.
+        let iterator = this
+            .as_object()
+            .and_then(|object| object.borrow().as_async_from_sync_iterator().cloned())
+            .ok_or_else(|| {
+                context.construct_type_error("`this` is not an AsyncFromSyncIterator")
+            })?;
+
+        // 3. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let promise_constructor = context.standard_objects().promise_object().constructor();
+        let promise_capability = PromiseCapability::new(&promise_constructor.into(), context)
+            .expect("cannot fail with the %Promise% constructor");
+
+        Ok((iterator, promise_capability))
+    }
+
+    /// `%AsyncFromSyncIteratorPrototype%.next ( [ value ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%asyncfromsynciteratorprototype%.next
+    fn next(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        // 4. Let syncIteratorRecord be O.[[SyncIteratorRecord]].
+        let (iterator, promise_capability) = Self::sync_iterator_record(this, context)?;
+
+        // 5. If value is present, then
+        //     a. Let result be IteratorNext(syncIteratorRecord, value).
+        // 6. Else,
+        //     a. Let result be IteratorNext(syncIteratorRecord).
+        let result = context
+            .call(
+                &iterator.sync_next,
+                &iterator.sync_iterator,
+                args.first().map(std::slice::from_ref).unwrap_or_default(),
+            )
+            .and_then(|result| {
+                if result.is_object() {
+                    Ok(result)
+                } else {
+                    context.throw_type_error("iterator result is not an object")
+                }
+            });
+
+        // 7. IfAbruptRejectPromise(result, promiseCapability).
+        if_abrupt_reject_promise!(result, promise_capability, context);
+
+        // 8. Return ! AsyncFromSyncIteratorContinuation(result, promiseCapability).
+        Self::continuation(&result, &promise_capability, context)
+    }
+
+    /// `%AsyncFromSyncIteratorPrototype%.return ( [ value ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%asyncfromsynciteratorprototype%.return
+    fn r#return(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        // 4. Let syncIterator be O.[[SyncIteratorRecord]].[[Iterator]].
+        let (iterator, promise_capability) = Self::sync_iterator_record(this, context)?;
+
+        // 5. Let return be GetMethod(syncIterator, "return").
+        let r#return = iterator.sync_iterator.get_method("return", context);
+
+        // 6. IfAbruptRejectPromise(return, promiseCapability).
+        if_abrupt_reject_promise!(r#return, promise_capability, context);
+
+        // 7. If return is undefined, then
+        let r#return = if let Some(r#return) = r#return {
+            r#return
+        } else {
+            // a. Let iterResult be ! CreateIterResultObject(value, true).
+            let iter_result =
+                create_iter_result_object(args.get_or_undefined(0).clone(), true, context);
+
+            // b. Perform ! Call(promiseCapability.[[Resolve]], undefined, « iterResult »).
+            promise_capability
+                .resolve()
+                .call(&JsValue::undefined(), &[iter_result], context)?;
+
+            // c. Return promiseCapability.[[Promise]].
+            return Ok(promise_capability.promise().clone().into());
+        };
+
+        // 8. If value is present, then
+        //     a. Let result be Call(return, syncIterator, « value »).
+        // 9. Else,
+        //     a. Let result be Call(return, syncIterator).
+        let result = r#return.call(
+            &iterator.sync_iterator,
+            args.first().map(std::slice::from_ref).unwrap_or_default(),
+            context,
+        );
+
+        // 10. IfAbruptRejectPromise(result, promiseCapability).
+        if_abrupt_reject_promise!(result, promise_capability, context);
+
+        // 11. If Type(result) is not Object, then
+        if !result.is_object() {
+            // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+            let error = context.construct_type_error("iterator result is not an object");
+            promise_capability
+                .reject()
+                .call(&JsValue::undefined(), &[error], context)?;
+
+            // b. Return promiseCapability.[[Promise]].
+            return Ok(promise_capability.promise().clone().into());
+        }
+
+        // 12. Return ! AsyncFromSyncIteratorContinuation(result, promiseCapability).
+        Self::continuation(&result, &promise_capability, context)
+    }
+
+    /// `%AsyncFromSyncIteratorPrototype%.throw ( [ value ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%asyncfromsynciteratorprototype%.throw
+    fn throw(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1-3.
+        // 4. Let syncIterator be O.[[SyncIteratorRecord]].[[Iterator]].
+        let (iterator, promise_capability) = Self::sync_iterator_record(this, context)?;
+
+        // 5. Let throw be GetMethod(syncIterator, "throw").
+        let throw = iterator.sync_iterator.get_method("throw", context);
+
+        // 6. IfAbruptRejectPromise(throw, promiseCapability).
+        if_abrupt_reject_promise!(throw, promise_capability, context);
+
+        // 7. If throw is undefined, then
+        let throw = if let Some(throw) = throw {
+            throw
+        } else {
+            // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « value »).
+            promise_capability.reject().call(
+                &JsValue::undefined(),
+                &[args.get_or_undefined(0).clone()],
+                context,
+            )?;
+
+            // b. Return promiseCapability.[[Promise]].
+            return Ok(promise_capability.promise().clone().into());
+        };
+
+        // 8. Let result be Call(throw, syncIterator, « value »).
+        let result = throw.call(
+            &iterator.sync_iterator,
+            &[args.get_or_undefined(0).clone()],
+            context,
+        );
+
+        // 9. IfAbruptRejectPromise(result, promiseCapability).
+        if_abrupt_reject_promise!(result, promise_capability, context);
+
+        // 10. If Type(result) is not Object, then
+        if !result.is_object() {
+            // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+            let error = context.construct_type_error("iterator result is not an object");
+            promise_capability
+                .reject()
+                .call(&JsValue::undefined(), &[error], context)?;
+
+            // b. Return promiseCapability.[[Promise]].
+            return Ok(promise_capability.promise().clone().into());
+        }
+
+        // 11. Return ! AsyncFromSyncIteratorContinuation(result, promiseCapability).
+        Self::continuation(&result, &promise_capability, context)
+    }
+
+    /// `AsyncFromSyncIteratorContinuation ( result, promiseCapability )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asyncfromsynciteratorcontinuation
+    fn continuation(
+        result: &JsValue,
+        promise_capability: &PromiseCapability,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let done be IteratorComplete(result).
+        let done = result
+            .get_field("done", context)
+            .map(|done| done.to_boolean());
+
+        // 2. IfAbruptRejectPromise(done, promiseCapability).
+        if_abrupt_reject_promise!(done, promise_capability, context);
+
+        // 3. Let value be IteratorValue(result).
+        let value = result.get_field("value", context);
+
+        // 4. IfAbruptRejectPromise(value, promiseCapability).
+        if_abrupt_reject_promise!(value, promise_capability, context);
+
+        // 5. Let valueWrapper be PromiseResolve(%Promise%, value).
+        let promise_constructor = context.standard_objects().promise_object().constructor();
+        let value_wrapper = Promise::promise_resolve(promise_constructor, value, context);
+
+        // 6. IfAbruptRejectPromise(valueWrapper, promiseCapability).
+        if_abrupt_reject_promise!(value_wrapper, promise_capability, context);
+
+        // 7. Let unwrap be a new Abstract Closure with parameters (value) that captures done and
+        //    performs the following steps when called:
+        //     a. Return ! CreateIterResultObject(value, done).
+        // 8. Let onFulfilled be ! CreateBuiltinFunction(unwrap, 1, "", « »).
+        let on_fulfilled = FunctionBuilder::closure_with_captures(
+            context,
+            |_this, args, done, context| {
+                Ok(create_iter_result_object(
+                    args.get_or_undefined(0).clone(),
+                    *done,
+                    context,
+                ))
+            },
+            done,
+        )
+        .name("")
+        .length(1)
+        .build();
+
+        // 9. NOTE: onFulfilled is used when processing the "value" property of an IteratorResult
+        //    object in order to wait for its value if it is a promise and re-package the result
+        //    in a new "unwrapped" IteratorResult object.
+        // 10. Perform ! PerformPromiseThen(valueWrapper, onFulfilled, undefined, promiseCapability).
+        Promise::perform_promise_then(
+            value_wrapper
+                .as_object()
+                .expect("PromiseResolve must return an object"),
+            &on_fulfilled.into(),
+            &JsValue::undefined(),
+            Some(promise_capability.clone()),
+            context,
+        );
+
+        // 11. Return promiseCapability.[[Promise]].
+        Ok(promise_capability.promise().clone().into())
+    }
+}
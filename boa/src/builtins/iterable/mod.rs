@@ -1,6 +1,10 @@
+mod async_from_sync_iterator;
+
+pub use async_from_sync_iterator::AsyncFromSyncIterator;
+
 use crate::{
     builtins::{
-        regexp::regexp_string_iterator::RegExpStringIterator,
+        generator::Generator, regexp::regexp_string_iterator::RegExpStringIterator,
         string::string_iterator::StringIterator, ArrayIterator, ForInIterator, MapIterator,
         SetIterator,
     },
@@ -12,18 +16,30 @@ use crate::{
 #[derive(Debug, Default)]
 pub struct IteratorPrototypes {
     iterator_prototype: JsObject,
+    async_iterator_prototype: JsObject,
+    async_from_sync_iterator: JsObject,
     array_iterator: JsObject,
     set_iterator: JsObject,
     string_iterator: JsObject,
     regexp_string_iterator: JsObject,
     map_iterator: JsObject,
     for_in_iterator: JsObject,
+    generator: JsObject,
+    generator_function: JsObject,
 }
 
 impl IteratorPrototypes {
     pub(crate) fn init(context: &mut Context) -> Self {
         let iterator_prototype = create_iterator_prototype(context);
+        let generator = Generator::create_prototype(iterator_prototype.clone(), context);
+        let generator_function = Generator::create_function_prototype(&generator, context);
+        let async_iterator_prototype = create_async_iterator_prototype(context);
         Self {
+            async_from_sync_iterator: AsyncFromSyncIterator::create_prototype(
+                async_iterator_prototype.clone(),
+                context,
+            ),
+            async_iterator_prototype,
             array_iterator: ArrayIterator::create_prototype(iterator_prototype.clone(), context),
             set_iterator: SetIterator::create_prototype(iterator_prototype.clone(), context),
             string_iterator: StringIterator::create_prototype(iterator_prototype.clone(), context),
@@ -33,6 +49,8 @@ impl IteratorPrototypes {
             ),
             map_iterator: MapIterator::create_prototype(iterator_prototype.clone(), context),
             for_in_iterator: ForInIterator::create_prototype(iterator_prototype.clone(), context),
+            generator,
+            generator_function,
             iterator_prototype,
         }
     }
@@ -47,6 +65,18 @@ impl IteratorPrototypes {
         self.iterator_prototype.clone()
     }
 
+    /// Returns the `%AsyncIteratorPrototype%` object.
+    #[inline]
+    pub fn async_iterator_prototype(&self) -> JsObject {
+        self.async_iterator_prototype.clone()
+    }
+
+    /// Returns the `%AsyncFromSyncIteratorPrototype%` object.
+    #[inline]
+    pub fn async_from_sync_iterator(&self) -> JsObject {
+        self.async_from_sync_iterator.clone()
+    }
+
     #[inline]
     pub fn set_iterator(&self) -> JsObject {
         self.set_iterator.clone()
@@ -71,6 +101,18 @@ impl IteratorPrototypes {
     pub fn for_in_iterator(&self) -> JsObject {
         self.for_in_iterator.clone()
     }
+
+    /// Returns the `%GeneratorFunction.prototype.prototype%` object.
+    #[inline]
+    pub fn generator(&self) -> JsObject {
+        self.generator.clone()
+    }
+
+    /// Returns the `%GeneratorFunction.prototype%` object.
+    #[inline]
+    pub fn generator_function(&self) -> JsObject {
+        self.generator_function.clone()
+    }
 }
 
 /// `CreateIterResultObject( value, done )`
@@ -132,10 +174,13 @@ impl JsValue {
                         .get_method(WellKnownSymbols::iterator(), context)?
                         .map_or(Self::Undefined, Self::from);
                     // 2. Let syncIteratorRecord be ? GetIterator(obj, sync, syncMethod).
-                    let _sync_iterator_record =
-                        self.get_iterator(context, Some(IteratorHint::Sync), Some(sync_method));
+                    let sync_iterator_record =
+                        self.get_iterator(context, Some(IteratorHint::Sync), Some(sync_method))?;
                     // 3. Return ! CreateAsyncFromSyncIterator(syncIteratorRecord).
-                    todo!("CreateAsyncFromSyncIterator");
+                    return Ok(AsyncFromSyncIterator::create(
+                        &sync_iterator_record,
+                        context,
+                    ));
                 }
             } else {
                 // b. Otherwise, set method to ? GetMethod(obj, @@iterator).
@@ -181,6 +226,25 @@ fn create_iterator_prototype(context: &mut Context) -> JsObject {
     iterator_prototype
 }
 
+/// Create the `%AsyncIteratorPrototype%` object
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-asynciteratorprototype
+fn create_async_iterator_prototype(context: &mut Context) -> JsObject {
+    let _timer = BoaProfiler::global().start_event("AsyncIteratorPrototype", "init");
+
+    let symbol_async_iterator = WellKnownSymbols::async_iterator();
+    ObjectInitializer::new(context)
+        .function(
+            |v, _, _| Ok(v.clone()),
+            (symbol_async_iterator, "[Symbol.asyncIterator]"),
+            0,
+        )
+        .build()
+}
+
 #[derive(Debug)]
 pub struct IteratorRecord {
     iterator_object: JsValue,
@@ -14,10 +14,7 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON
 
 use crate::{
-    builtins::{
-        string::{is_leading_surrogate, is_trailing_surrogate},
-        BuiltIn,
-    },
+    builtins::BuiltIn,
     object::{JsObject, ObjectInitializer, RecursionLimiter},
     property::{Attribute, PropertyNameKind},
     symbol::WellKnownSymbols,
@@ -483,32 +480,28 @@ impl Json {
         let mut product = String::from('"');
 
         // 2. For each code point C of ! StringToCodePoints(value), do
-        for code_point in value.encode_utf16() {
+        for code_point in value.chars() {
             match code_point {
                 // a. If C is listed in the “Code Point” column of Table 73, then
                 // i. Set product to the string-concatenation of product and the escape sequence for C as specified in the “Escape Sequence” column of the corresponding row.
-                0x8 => product.push_str("\\b"),
-                0x9 => product.push_str("\\t"),
-                0xA => product.push_str("\\n"),
-                0xC => product.push_str("\\f"),
-                0xD => product.push_str("\\r"),
-                0x22 => product.push_str("\\\""),
-                0x5C => product.push_str("\\\\"),
+                '\u{8}' => product.push_str("\\b"),
+                '\t' => product.push_str("\\t"),
+                '\n' => product.push_str("\\n"),
+                '\u{C}' => product.push_str("\\f"),
+                '\r' => product.push_str("\\r"),
+                '"' => product.push_str("\\\""),
+                '\\' => product.push_str("\\\\"),
                 // b. Else if C has a numeric value less than 0x0020 (SPACE), or if C has the same numeric value as a leading surrogate or trailing surrogate, then
-                code_point
-                    if is_leading_surrogate(code_point) || is_trailing_surrogate(code_point) =>
-                {
+                // Note: `JsString` is valid UTF-8, so it can't contain lone surrogates.
+                code_point if code_point < ' ' => {
                     // i. Let unit be the code unit whose numeric value is that of C.
                     // ii. Set product to the string-concatenation of product and UnicodeEscape(unit).
-                    product.push_str(&format!("\\\\uAA{code_point:x}"));
+                    product.push_str(&format!("\\u{:04x}", u32::from(code_point)));
                 }
                 // c. Else,
                 code_point => {
                     // i. Set product to the string-concatenation of product and ! UTF16EncodeCodePoint(C).
-                    product.push(
-                        char::from_u32(u32::from(code_point))
-                            .expect("char from code point cannot fail here"),
-                    );
+                    product.push(code_point);
                 }
             }
         }
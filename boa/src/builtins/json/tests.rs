@@ -327,6 +327,91 @@ fn json_stringify_pretty_print_with_string_object() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn json_stringify_replacer_function_called_for_root() {
+    let mut context = Context::default();
+    let init = r#"
+        const calls = [];
+        const result = JSON.stringify({ a: 1, b: [2] }, function (key, value) {
+            calls.push(key === "" ? "<root>" : key);
+            if (key === "") {
+                return { wrapped: value, rootHolderHasValue: this[""] === value };
+            }
+            return value;
+        });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "result"),
+        r#""{"wrapped":{"a":1,"b":[2]},"rootHolderHasValue":true}""#
+    );
+    assert_eq!(
+        forward(&mut context, "calls.join()"),
+        r#""<root>,wrapped,a,b,0,rootHolderHasValue""#
+    );
+}
+
+#[test]
+fn json_stringify_replacer_array_is_allow_list() {
+    let mut context = Context::default();
+    let actual = forward(
+        &mut context,
+        r#"JSON.stringify({ a: 1, b: 2, c: { a: 3, d: 4 }, 1: 5 }, ["c", new String("a"), 1, "a"])"#,
+    );
+    let expected = forward(&mut context, r#"'{"c":{"a":3},"a":1,"1":5}'"#);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn json_stringify_calls_to_json() {
+    let mut context = Context::default();
+    let actual = forward(
+        &mut context,
+        r#"JSON.stringify({ date: new Date(0), custom: { toJSON(key) { return "key:" + key; } } })"#,
+    );
+    let expected = forward(
+        &mut context,
+        r#"'{"date":"1970-01-01T00:00:00.000Z","custom":"key:custom"}'"#,
+    );
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn json_stringify_cyclic_object_throws() {
+    let mut context = Context::default();
+    let init = r#"
+        const cyclic = { a: [] };
+        cyclic.a.push(cyclic);
+        const shared = {};
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { JSON.stringify(cyclic) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "JSON.stringify({ x: shared, y: shared })"),
+        r#""{"x":{},"y":{}}""#
+    );
+}
+
+#[test]
+fn json_stringify_escapes_strings() {
+    let mut context = Context::default();
+    let actual = forward(
+        &mut context,
+        r#"JSON.stringify("\"\\\b\f\n\r\t\u0007\u{1F600}")"#,
+    );
+    let expected = forward(
+        &mut context,
+        r#"'"\\"\\\\\\b\\f\\n\\r\\t\\u0007\u{1F600}"'"#,
+    );
+    assert_eq!(actual, expected);
+}
+
 #[test]
 fn json_parse_array_with_reviver() {
     let mut context = Context::default();
@@ -331,19 +331,7 @@ impl Map {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        const JS_ZERO: &JsValue = &JsValue::Rational(0f64);
-
         let key = args.get_or_undefined(0);
-        let key = match key {
-            JsValue::Rational(r) => {
-                if r.is_zero() {
-                    JS_ZERO
-                } else {
-                    key
-                }
-            }
-            _ => key,
-        };
 
         // 1. Let M be the this value.
         if let JsValue::Object(ref object) = this {
@@ -403,19 +391,7 @@ impl Map {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        const JS_ZERO: &JsValue = &JsValue::Rational(0f64);
-
         let key = args.get_or_undefined(0);
-        let key = match key {
-            JsValue::Rational(r) => {
-                if r.is_zero() {
-                    JS_ZERO
-                } else {
-                    key
-                }
-            }
-            _ => key,
-        };
 
         // 1. Let M be the this value.
         if let JsValue::Object(ref object) = this {
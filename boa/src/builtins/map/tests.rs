@@ -404,3 +404,23 @@ fn for_of_delete() {
     assert_eq!(forward(&mut context, "result[3][0]"), "3");
     assert_eq!(forward(&mut context, "result[3][1]"), "\"d\"");
 }
+
+#[test]
+fn same_value_zero_keys() {
+    let mut context = Context::default();
+    let init = r#"
+        let map = new Map([[-0, "zero"], [NaN, "nan"]]);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "map.size"), "2");
+    assert_eq!(forward(&mut context, "map.get(+0)"), "\"zero\"");
+    assert_eq!(forward(&mut context, "map.get(-0)"), "\"zero\"");
+    assert_eq!(forward(&mut context, "map.get(0 / 0)"), "\"nan\"");
+    assert_eq!(
+        forward(&mut context, "Object.is([...map.keys()][0], +0)"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "map.set(0, 'other').size"), "2");
+    assert_eq!(forward(&mut context, "map.delete(-0)"), "true");
+    assert_eq!(forward(&mut context, "map.has(0)"), "false");
+}
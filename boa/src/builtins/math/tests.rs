@@ -562,6 +562,33 @@ fn min() {
     assert_eq!(c.to_number(&mut context).unwrap(), -10_f64);
 }
 
+#[test]
+fn min_max_signed_zero_and_nan() {
+    let mut context = Context::default();
+
+    let cases = [
+        ("Object.is(Math.max(-0, 0), 0)", "true"),
+        ("Object.is(Math.max(0, -0), 0)", "true"),
+        ("Object.is(Math.min(-0, 0), -0)", "true"),
+        ("Object.is(Math.min(0, -0), -0)", "true"),
+        ("Object.is(Math.max(-0, -0), -0)", "true"),
+        ("Math.max(1, NaN, 3)", "NaN"),
+        ("Math.min(NaN, -Infinity)", "NaN"),
+        ("Math.max()", "-Infinity"),
+        ("Math.min()", "Infinity"),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(forward(&mut context, source), expected, "{source}");
+    }
+
+    // Every argument is converted, even after a `NaN`.
+    forward(
+        &mut context,
+        "var calls = 0; Math.max(NaN, { valueOf() { calls++; return 1; } });",
+    );
+    assert_eq!(forward(&mut context, "calls"), "1");
+}
+
 #[test]
 fn pow() {
     let mut context = Context::default();
@@ -727,3 +754,37 @@ fn trunc() {
     assert_eq!(a.to_number(&mut context).unwrap(), 13_f64);
     assert_eq!(b.to_number(&mut context).unwrap(), 0_f64);
 }
+
+#[test]
+fn edge_cases() {
+    let mut context = Context::default();
+
+    assert_eq!(forward(&mut context, "Math.imul(0xffffffff, 5)"), "-5");
+    assert_eq!(forward(&mut context, "Math.imul(2 ** 31, 2)"), "0");
+    assert_eq!(
+        forward(&mut context, "Math.fround(1.1)"),
+        "1.100000023841858"
+    );
+    assert_eq!(forward(&mut context, "Math.fround(2 ** 128)"), "Infinity");
+    assert_eq!(forward(&mut context, "Math.clz32(1)"), "31");
+    assert_eq!(forward(&mut context, "Math.clz32(2 ** 32)"), "32");
+    assert_eq!(forward(&mut context, "Math.hypot()"), "0");
+    assert_eq!(
+        forward(&mut context, "Math.hypot(NaN, -Infinity)"),
+        "Infinity"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.is(Math.sign(-0), -0)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.is(Math.trunc(-0.5), -0)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.is(Math.expm1(-0), -0)"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "Math.log1p(-1)"), "-Infinity");
+    assert_eq!(forward(&mut context, "Math.cbrt('-27')"), "-3");
+}
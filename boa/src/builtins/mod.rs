@@ -2,6 +2,7 @@
 
 pub mod array;
 pub mod array_buffer;
+pub mod async_function;
 pub mod bigint;
 pub mod boolean;
 #[cfg(feature = "console")]
@@ -10,6 +11,7 @@ pub mod dataview;
 pub mod date;
 pub mod error;
 pub mod function;
+pub mod generator;
 pub mod global_this;
 pub mod infinity;
 pub mod intl;
@@ -21,6 +23,7 @@ pub mod math;
 pub mod nan;
 pub mod number;
 pub mod object;
+pub mod promise;
 pub mod proxy;
 pub mod reflect;
 pub mod regexp;
@@ -29,6 +32,8 @@ pub mod string;
 pub mod symbol;
 pub mod typed_array;
 pub mod undefined;
+pub mod weak_map;
+pub mod weak_set;
 
 pub(crate) use self::{
     array::{array_iterator::ArrayIterator, Array},
@@ -36,7 +41,10 @@ pub(crate) use self::{
     boolean::Boolean,
     dataview::DataView,
     date::Date,
-    error::{Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError, UriError},
+    error::{
+        AggregateError, Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError,
+        UriError,
+    },
     function::BuiltInFunctionObject,
     global_this::GlobalThis,
     infinity::Infinity,
@@ -49,6 +57,7 @@ pub(crate) use self::{
     number::Number,
     object::for_in_iterator::ForInIterator,
     object::Object as BuiltInObjectObject,
+    promise::Promise,
     proxy::Proxy,
     reflect::Reflect,
     regexp::RegExp,
@@ -61,6 +70,8 @@ pub(crate) use self::{
         Int8Array, Uint16Array, Uint32Array, Uint8Array, Uint8ClampedArray,
     },
     undefined::Undefined,
+    weak_map::WeakMap,
+    weak_set::WeakSet,
 };
 
 use crate::{
@@ -86,6 +97,13 @@ pub(crate) trait BuiltIn {
     /// Check [Attribute] for more information.
     const ATTRIBUTE: Attribute;
 
+    /// Whether the initialization of the built-in is deferred until its global binding is first
+    /// used.
+    ///
+    /// The binding is still defined when the context is created, so this isn't observable from
+    /// Javascript, but it makes creating a context cheaper for built-ins that are heavy to set up.
+    const LAZY: bool = false;
+
     /// Initialization code for the built-in.
     /// This is where the methods, properties, static methods and the constructor
     /// of a built-in must be initialized to be accessible from Javascript.
@@ -96,7 +114,12 @@ pub(crate) trait BuiltIn {
 /// initializing it as a global built-in.
 #[inline]
 fn init_builtin<B: BuiltIn>(context: &mut Context) {
-    let value = B::init(context);
+    let value = if B::LAZY {
+        context.realm.lazy_globals.insert(B::NAME, B::init);
+        JsValue::undefined()
+    } else {
+        B::init(context)
+    };
     let property = PropertyDescriptor::builder()
         .value(value)
         .writable(B::ATTRIBUTE.writable())
@@ -104,7 +127,7 @@ fn init_builtin<B: BuiltIn>(context: &mut Context) {
         .configurable(B::ATTRIBUTE.configurable())
         .build();
     context
-        .global_bindings_mut()
+        .global_string_bindings_mut()
         .insert(B::NAME.into(), property);
 }
 
@@ -138,6 +161,8 @@ pub fn init(context: &mut Context) {
         Map,
         Number,
         Set,
+        WeakMap,
+        WeakSet,
         String,
         RegExp,
         Int8Array,
@@ -159,7 +184,9 @@ pub fn init(context: &mut Context) {
         SyntaxError,
         EvalError,
         UriError,
-        Reflect
+        AggregateError,
+        Reflect,
+        Promise
     };
 
     #[cfg(feature = "console")]
@@ -17,13 +17,14 @@ use crate::{
     builtins::{string::is_trimmable_whitespace, BuiltIn, JsArgs},
     context::StandardObjects,
     object::{
-        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
     },
-    property::Attribute,
+    property::{Attribute, PropertyDescriptor},
     value::{AbstractRelation, IntegerOrInfinity, JsValue},
     BoaProfiler, Context, JsResult,
 };
-use num_traits::{float::FloatCore, Num};
+use num_traits::float::FloatCore;
 
 mod conversions;
 
@@ -48,6 +49,19 @@ impl BuiltIn for Number {
     fn init(context: &mut Context) -> JsValue {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
+        // `Number.parseInt` and `Number.parseFloat` are the same function objects as the global
+        // `parseInt` and `parseFloat` functions.
+        let parse_int = FunctionBuilder::native(context, Self::parse_int)
+            .name("parseInt")
+            .length(2)
+            .constructor(false)
+            .build();
+        let parse_float = FunctionBuilder::native(context, Self::parse_float)
+            .name("parseFloat")
+            .length(1)
+            .constructor(false)
+            .build();
+
         let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT;
         let number_object = ConstructorBuilder::with_standard_object(
             context,
@@ -74,10 +88,30 @@ impl BuiltIn for Number {
         .static_method(Self::number_is_nan, "isNaN", 1)
         .static_method(Self::is_safe_integer, "isSafeInteger", 1)
         .static_method(Self::number_is_integer, "isInteger", 1)
+        .static_property(
+            "parseInt",
+            parse_int.clone(),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .static_property(
+            "parseFloat",
+            parse_float.clone(),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
         .build();
 
-        context.register_global_builtin_function("parseInt", 2, Self::parse_int);
-        context.register_global_builtin_function("parseFloat", 1, Self::parse_float);
+        for (name, function) in [("parseInt", parse_int), ("parseFloat", parse_float)] {
+            context.global_string_bindings_mut().insert(
+                name.into(),
+                PropertyDescriptor::builder()
+                    .value(function)
+                    .writable(true)
+                    .enumerable(false)
+                    .configurable(true)
+                    .build(),
+            );
+        }
+
         context.register_global_builtin_function("isFinite", 1, Self::global_is_finite);
         context.register_global_builtin_function("isNaN", 1, Self::global_is_nan);
 
@@ -803,7 +837,18 @@ impl Number {
             //     mathInt may be an implementation-approximated value representing the integer value that is
             //     represented by Z in radix-R notation.)
             let math_int = u64::from_str_radix(var_z, var_r as u32).map_or_else(
-                |_| f64::from_str_radix(var_z, var_r as u32).expect("invalid_float_conversion"),
+                |_| {
+                    if var_r == 10 {
+                        var_z.parse().expect("Z only contains decimal digits")
+                    } else {
+                        var_z.chars().fold(0.0, |acc, digit| {
+                            let digit = digit
+                                .to_digit(var_r as u32)
+                                .expect("Z only contains radix-R digits");
+                            acc * f64::from(var_r) + f64::from(digit)
+                        })
+                    }
+                },
                 |i| i as f64,
             );
 
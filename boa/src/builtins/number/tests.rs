@@ -100,6 +100,47 @@ fn to_fixed() {
     assert_eq!(nan_fixed, "\"NaN\"");
 }
 
+#[test]
+fn to_fixed_and_to_precision_receiver() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"try { Number.prototype.toFixed.call("5", 2) } catch (e) { e instanceof TypeError }"#
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"try { Number.prototype.toPrecision.call("5", 2) } catch (e) { e instanceof TypeError }"#
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Number.prototype.toFixed.call({ valueOf() { return 5; } }) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Number.prototype.toFixed.call(new Number(5), 2)"
+        ),
+        "\"5.00\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Number.prototype.toPrecision.call(new Number(5), 2)"
+        ),
+        "\"5.0\""
+    );
+}
+
 #[test]
 fn to_locale_string() {
     let mut context = Context::default();
@@ -707,6 +748,51 @@ fn parse_float_too_many_args() {
     assert_eq!(&forward(&mut context, "parseFloat(\"100.5\", 10)"), "100.5");
 }
 
+#[test]
+fn parse_int_and_parse_float_are_shared() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        &forward(&mut context, "Number.parseInt === parseInt"),
+        "true"
+    );
+    assert_eq!(
+        &forward(&mut context, "Number.parseFloat === parseFloat"),
+        "true"
+    );
+    assert_eq!(
+        &forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(Number, 'parseInt').enumerable"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn parse_int_radix() {
+    let mut context = Context::default();
+
+    assert_eq!(&forward(&mut context, "parseInt(\"0x1F\")"), "31");
+    assert_eq!(&forward(&mut context, "parseInt(\"0x1F\", 16)"), "31");
+    assert_eq!(&forward(&mut context, "parseInt(\"0x1F\", 10)"), "0");
+    assert_eq!(&forward(&mut context, "parseInt(\"10\", 2)"), "2");
+    assert_eq!(&forward(&mut context, "parseInt(\"z\", 36)"), "35");
+    assert_eq!(&forward(&mut context, "parseInt(\"10\", 37)"), "NaN");
+    assert_eq!(&forward(&mut context, "parseInt(\"10\", 1)"), "NaN");
+    assert_eq!(&forward(&mut context, "parseInt(\"0x\")"), "NaN");
+    assert_eq!(&forward(&mut context, "parseInt(\" \\n\\t-0x1f\")"), "-31");
+    assert_eq!(&forward(&mut context, "parseInt(\"+42px\")"), "42");
+    assert_eq!(&forward(&mut context, "1 / parseInt(\"-0\")"), "-Infinity");
+    assert_eq!(
+        &forward(
+            &mut context,
+            "parseInt(\"12345678901234567890123\") === 12345678901234567890123"
+        ),
+        "true"
+    );
+}
+
 #[test]
 fn global_is_finite() {
     let mut context = Context::default();
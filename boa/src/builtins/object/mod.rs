@@ -198,27 +198,33 @@ impl Object {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
+        // 1. Let obj be ? ToObject(O).
         let object = args.get_or_undefined(0).to_object(context)?;
+
+        // 2. Let ownKeys be ? obj.[[OwnPropertyKeys]]().
+        let own_keys = object.__own_property_keys__(context)?;
+
+        // 3. Let descriptors be ! OrdinaryObjectCreate(%Object.prototype%).
         let descriptors = context.construct_object();
 
-        for key in object.borrow().properties().keys() {
-            let descriptor = {
-                let desc = object.__get_own_property__(&key, context)?;
-                Self::from_property_descriptor(desc, context)
-            };
+        // 4. For each element key of ownKeys, do
+        for key in own_keys {
+            // a. Let desc be ? obj.[[GetOwnProperty]](key).
+            let desc = object.__get_own_property__(&key, context)?;
 
+            // b. Let descriptor be ! FromPropertyDescriptor(desc).
+            let descriptor = Self::from_property_descriptor(desc, context);
+
+            // c. If descriptor is not undefined,
+            //    perform ! CreateDataPropertyOrThrow(descriptors, key, descriptor).
             if !descriptor.is_undefined() {
-                descriptors.borrow_mut().insert(
-                    key,
-                    PropertyDescriptor::builder()
-                        .value(descriptor)
-                        .writable(true)
-                        .enumerable(true)
-                        .configurable(true),
-                );
+                descriptors
+                    .create_data_property_or_throw(key, descriptor, context)
+                    .expect("CreateDataPropertyOrThrow cannot fail here");
             }
         }
 
+        // 5. Return descriptors.
         Ok(JsValue::Object(descriptors))
     }
 
@@ -287,7 +293,17 @@ impl Object {
         }
     }
 
-    /// Uses the `SameValue` algorithm to check equality of objects
+    /// `Object.is( value1, value2 )`
+    ///
+    /// Determines whether two values are the same value, using the `SameValue` algorithm. Unlike
+    /// `===`, `NaN` is the same value as itself and `+0` is not the same value as `-0`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.is
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/is
     pub fn is(_: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         let x = args.get_or_undefined(0);
         let y = args.get_or_undefined(1);
@@ -503,7 +519,7 @@ impl Object {
                 ObjectKind::Array => "Array",
                 ObjectKind::Arguments(_) => "Arguments",
                 ObjectKind::Function(_) => "Function",
-                ObjectKind::Error => "Error",
+                ObjectKind::Error(_) => "Error",
                 ObjectKind::Boolean(_) => "Boolean",
                 ObjectKind::Number(_) => "Number",
                 ObjectKind::String(_) => "String",
@@ -89,6 +89,8 @@ fn object_is() {
     assert_eq!(forward(&mut context, "Object.is(0, -0)"), "false");
     assert_eq!(forward(&mut context, "Object.is(-0, -0)"), "true");
     assert_eq!(forward(&mut context, "Object.is(NaN, 0/0)"), "true");
+    assert_eq!(forward(&mut context, "Object.is(-0, +0)"), "false");
+    assert_eq!(forward(&mut context, "Object.is(NaN, NaN)"), "true");
     assert_eq!(forward(&mut context, "Object.is()"), "true");
     assert_eq!(forward(&mut context, "Object.is(undefined)"), "true");
     assert!(context.global_object().is_global());
@@ -228,6 +230,47 @@ fn object_to_string() {
     assert_eq!(forward(&mut context, "o.toString()"), "\"[object Object]\"");
 }
 
+#[test]
+fn object_value_of() {
+    let mut context = Context::default();
+
+    let init = r#"
+        let o = {};
+        class A {}
+        let tagged = { [Symbol.toStringTag]: "Tagged" };
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "({}).toString()"),
+        "\"[object Object]\""
+    );
+    assert_eq!(forward(&mut context, "o.valueOf() === o"), "true");
+    assert_eq!(
+        forward(&mut context, "typeof Object.prototype.valueOf.call(1)"),
+        "\"object\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.prototype.valueOf.call('s') instanceof String"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.valueOf.call(null)"),
+        "Uncaught \"TypeError\": \"cannot convert 'null' or 'undefined' to object\""
+    );
+
+    // ToPrimitive falls back to the defaults of `Object.prototype`.
+    assert_eq!(forward(&mut context, "o + ''"), "\"[object Object]\"");
+    assert_eq!(forward(&mut context, "`${new A()}`"), "\"[object Object]\"");
+    assert_eq!(
+        forward(&mut context, "String(tagged)"),
+        "\"[object Tagged]\""
+    );
+}
+
 #[test]
 fn define_symbol_property() {
     let mut context = Context::default();
@@ -310,6 +353,131 @@ fn object_define_properties() {
     assert_eq!(forward(&mut context, "obj.p"), "42");
 }
 
+#[test]
+fn get_own_property_descriptors_uses_own_property_keys() {
+    check_output(&[
+        TestAction::TestEq(
+            "Object.keys(Object.getOwnPropertyDescriptors('ab')).join()",
+            "\"0,1,length\"",
+        ),
+        TestAction::TestEq(
+            "Object.keys(Object.getOwnPropertyDescriptors(new Proxy({ a: 1 }, {}))).join()",
+            "\"a\"",
+        ),
+        TestAction::TestEq(
+            "Reflect.ownKeys(Object.getOwnPropertyDescriptors({ b: 1, 1: 2, [Symbol.iterator]: 3 })).map(String).join()",
+            "\"1,b,Symbol(Symbol.iterator)\"",
+        ),
+    ]);
+}
+
+#[test]
+fn define_properties_round_trip() {
+    let mut context = Context::default();
+
+    let init = r#"
+        const sym = Symbol("sym");
+        let setterValue;
+        const source = {
+            a: 1,
+            [sym]: 2,
+            get accessor() { return 3; },
+            set accessor(value) { setterValue = value; },
+        };
+        Object.defineProperty(source, "hidden", { value: 4, enumerable: false });
+        const copy = Object.defineProperties({}, Object.getOwnPropertyDescriptors(source));
+        const accessor = Object.getOwnPropertyDescriptor(copy, "accessor");
+        copy.accessor = 5;
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "copy.a"), "1");
+    assert_eq!(forward(&mut context, "copy[sym]"), "2");
+    assert_eq!(forward(&mut context, "copy.accessor"), "3");
+    assert_eq!(forward(&mut context, "setterValue"), "5");
+    assert_eq!(forward(&mut context, "typeof accessor.get"), "\"function\"");
+    assert_eq!(forward(&mut context, "accessor.enumerable"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(copy, 'hidden').enumerable"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn define_properties_validates_before_defining() {
+    check_output(&[
+        TestAction::Execute("var target = {};"),
+        TestAction::TestStartsWith(
+            "Object.defineProperties(target, { a: { value: 1 }, b: { get: 1 } })",
+            "Uncaught \"TypeError\"",
+        ),
+        TestAction::TestEq("'a' in target", "false"),
+        TestAction::TestStartsWith(
+            "Object.defineProperties(target, { a: { value: 1, get() {} } })",
+            "Uncaught \"TypeError\"",
+        ),
+        TestAction::TestStartsWith("Object.defineProperties(1, {})", "Uncaught \"TypeError\""),
+    ]);
+}
+
+#[test]
+fn object_assign() {
+    check_output(&[
+        TestAction::Execute("var obj = { a: 1 };"),
+        TestAction::TestEq("Object.assign(obj) === obj", "true"),
+        TestAction::TestEq("Object.assign(obj, null, undefined) === obj", "true"),
+        TestAction::TestEq("Object.assign(1) instanceof Number", "true"),
+        TestAction::TestEq("Object.assign(obj, { b: 2 }, 'xy') === obj", "true"),
+        TestAction::TestEq("Object.keys(obj).join()", "\"0,1,a,b\""),
+        TestAction::TestStartsWith("Object.assign()", "Uncaught \"TypeError\""),
+        TestAction::TestStartsWith("Object.assign(null)", "Uncaught \"TypeError\""),
+    ]);
+}
+
+#[test]
+fn object_assign_accessors() {
+    check_output(&[
+        TestAction::Execute(
+            r#"
+            var gets = 0;
+            var sets = [];
+            var sym = Symbol("sym");
+            var source = { get a() { gets++; return 1; }, b: 2, [sym]: 3 };
+            Object.defineProperty(source, "hidden", { value: 4, enumerable: false });
+            var target = { set b(value) { sets.push(value); } };
+            Object.assign(target, source);
+            "#,
+        ),
+        TestAction::TestEq("gets", "1"),
+        TestAction::TestEq("sets.join()", "\"2\""),
+        TestAction::TestEq("Object.getOwnPropertyDescriptor(target, 'a').value", "1"),
+        TestAction::TestEq("target[sym]", "3"),
+        TestAction::TestEq("target.hasOwnProperty('hidden')", "false"),
+        // A throwing setter stops the assignment of the following properties.
+        TestAction::Execute(
+            r#"
+            var partial = { set y(value) { throw new Error("stop"); } };
+            var error;
+            try {
+                Object.assign(partial, { x: 1, y: 2, z: 3 });
+            } catch (e) {
+                error = e.message;
+            }
+            "#,
+        ),
+        TestAction::TestEq("error", "\"stop\""),
+        TestAction::TestEq("partial.x", "1"),
+        TestAction::TestEq("partial.hasOwnProperty('z')", "false"),
+        TestAction::TestStartsWith(
+            "Object.assign(Object.freeze({ a: 1 }), { a: 2 })",
+            "Uncaught \"TypeError\"",
+        ),
+    ]);
+}
+
 #[test]
 fn object_is_prototype_of() {
     let mut context = Context::default();
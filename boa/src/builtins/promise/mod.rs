@@ -0,0 +1,1817 @@
+//! This module implements the global `Promise` object.
+//!
+//! A `Promise` represents the eventual completion (or failure) of an asynchronous operation and
+//! its resulting value.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-promise-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise
+
+#[cfg(test)]
+mod tests;
+
+mod promise_job;
+
+use self::promise_job::PromiseJob;
+use super::{iterable::IteratorRecord, JsArgs};
+use crate::{
+    builtins::{error::AggregateError, Array, BuiltIn},
+    context::StandardObjects,
+    gc::{empty_trace, Cell, Finalize, Gc, Trace},
+    job::JobCallback,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::JsValue,
+    BoaProfiler, Context, JsResult,
+};
+
+/// `IfAbruptRejectPromise ( value, capability )`
+///
+/// If `value` is an abrupt completion, rejects the promise of `capability` with it and returns
+/// that promise from the enclosing function. Otherwise, unwraps `value`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ifabruptrejectpromise
+macro_rules! if_abrupt_reject_promise {
+    ($value:ident, $capability:expr, $context: expr) => {
+        let $value = match $value {
+            // 1. If value is an abrupt completion, then
+            Err(value) => {
+                // a. Perform ? Call(capability.[[Reject]], undefined, « value.[[Value]] »).
+                $capability
+                    .reject()
+                    .call(&JsValue::undefined(), &[value], $context)?;
+
+                // b. Return capability.[[Promise]].
+                return Ok($capability.promise().clone().into());
+            }
+            // 2. Else if value is a Completion Record, set value to value.[[Value]].
+            Ok(value) => value,
+        };
+    };
+}
+
+pub(crate) use if_abrupt_reject_promise;
+
+/// The internal `[[PromiseState]]` slot of a promise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromiseState {
+    Pending,
+    Fulfilled,
+    Rejected,
+}
+
+// SAFETY: `PromiseState` doesn't contain any traceable types.
+unsafe impl Trace for PromiseState {
+    empty_trace!();
+}
+
+impl Finalize for PromiseState {}
+
+/// The internal representation of a `Promise` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Promise {
+    result: Option<JsValue>,
+    state: PromiseState,
+    fulfill_reactions: Vec<ReactionRecord>,
+    reject_reactions: Vec<ReactionRecord>,
+    is_handled: bool,
+}
+
+/// The operation reported to `HostPromiseRejectionTracker`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-host-promise-rejection-tracker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OperationType {
+    /// A promise was rejected without any handlers.
+    Reject,
+    /// A handler was added to a rejected promise for the first time.
+    Handle,
+}
+
+/// The type of a `PromiseReaction` Record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ReactionType {
+    Fulfill,
+    Reject,
+}
+
+// SAFETY: `ReactionType` doesn't contain any traceable types.
+unsafe impl Trace for ReactionType {
+    empty_trace!();
+}
+
+impl Finalize for ReactionType {}
+
+/// The `PromiseReaction` Record.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisereaction-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct ReactionRecord {
+    promise_capability: Option<PromiseCapability>,
+    reaction_type: ReactionType,
+    handler: Option<JobCallback>,
+}
+
+/// The `PromiseCapability` Record.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisecapability-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct PromiseCapability {
+    promise: JsObject,
+    resolve: JsObject,
+    reject: JsObject,
+}
+
+/// The resolving functions of a promise, as returned by `CreateResolvingFunctions`.
+#[derive(Debug, Clone)]
+pub(crate) struct ResolvingFunctionsRecord {
+    pub(crate) resolve: JsObject,
+    pub(crate) reject: JsObject,
+}
+
+impl PromiseCapability {
+    /// `NewPromiseCapability ( C )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisecapability
+    pub(crate) fn new(c: &JsValue, context: &mut Context) -> JsResult<Self> {
+        #[derive(Debug, Clone, Trace, Finalize)]
+        struct RejectResolve {
+            reject: JsValue,
+            resolve: JsValue,
+        }
+
+        // 1. If IsConstructor(C) is false, throw a TypeError exception.
+        let c = match c.as_constructor() {
+            Some(c) => c.clone(),
+            None => {
+                return context.throw_type_error("PromiseCapability: expected constructor");
+            }
+        };
+
+        // 2. NOTE: C is assumed to be a constructor function that supports the parameter conventions of the Promise constructor (see 27.2.3.1).
+        // 3. Let promiseCapability be the PromiseCapability Record { [[Promise]]: undefined, [[Resolve]]: undefined, [[Reject]]: undefined }.
+        let promise_capability = Gc::new(Cell::new(RejectResolve {
+            reject: JsValue::undefined(),
+            resolve: JsValue::undefined(),
+        }));
+
+        // 4. Let executorClosure be a new Abstract Closure with parameters (resolve, reject) that captures promiseCapability and performs the following steps when called:
+        // 5. Let executor be ! CreateBuiltinFunction(executorClosure, 2, "", « »).
+        let executor = FunctionBuilder::closure_with_captures(
+            context,
+            |_this, args: &[JsValue], captures, context| {
+                let mut promise_capability = captures.borrow_mut();
+
+                // a. If promiseCapability.[[Resolve]] is not undefined, throw a TypeError exception.
+                if !promise_capability.resolve.is_undefined() {
+                    return context
+                        .throw_type_error("promiseCapability.[[Resolve]] is not undefined");
+                }
+
+                // b. If promiseCapability.[[Reject]] is not undefined, throw a TypeError exception.
+                if !promise_capability.reject.is_undefined() {
+                    return context
+                        .throw_type_error("promiseCapability.[[Reject]] is not undefined");
+                }
+
+                // c. Set promiseCapability.[[Resolve]] to resolve.
+                promise_capability.resolve = args.get_or_undefined(0).clone();
+
+                // d. Set promiseCapability.[[Reject]] to reject.
+                promise_capability.reject = args.get_or_undefined(1).clone();
+
+                // e. Return undefined.
+                Ok(JsValue::undefined())
+            },
+            promise_capability.clone(),
+        )
+        .name("")
+        .length(2)
+        .build()
+        .into();
+
+        // 6. Let promise be ? Construct(C, « executor »).
+        let promise = c.construct(&[executor], &c.clone().into(), context)?;
+
+        let promise_capability = promise_capability.borrow();
+
+        // 7. If IsCallable(promiseCapability.[[Resolve]]) is false, throw a TypeError exception.
+        let resolve = match promise_capability.resolve.as_callable() {
+            Some(resolve) => resolve.clone(),
+            None => {
+                return context.throw_type_error("promiseCapability.[[Resolve]] is not callable")
+            }
+        };
+
+        // 8. If IsCallable(promiseCapability.[[Reject]]) is false, throw a TypeError exception.
+        let reject = match promise_capability.reject.as_callable() {
+            Some(reject) => reject.clone(),
+            None => {
+                return context.throw_type_error("promiseCapability.[[Reject]] is not callable")
+            }
+        };
+
+        let promise = match promise.as_object() {
+            Some(promise) => promise.clone(),
+            None => return context.throw_type_error("the constructor did not return an object"),
+        };
+
+        // 9. Set promiseCapability.[[Promise]] to promise.
+        // 10. Return promiseCapability.
+        Ok(Self {
+            promise,
+            resolve,
+            reject,
+        })
+    }
+
+    /// Returns the promise object.
+    pub(crate) fn promise(&self) -> &JsObject {
+        &self.promise
+    }
+
+    /// Returns the resolve function.
+    pub(crate) fn resolve(&self) -> &JsObject {
+        &self.resolve
+    }
+
+    /// Returns the reject function.
+    pub(crate) fn reject(&self) -> &JsObject {
+        &self.reject
+    }
+}
+
+impl BuiltIn for Promise {
+    const NAME: &'static str = "Promise";
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE
+        .union(Attribute::NON_ENUMERABLE)
+        .union(Attribute::CONFIGURABLE);
+
+    fn init(context: &mut Context) -> JsValue {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let get_species = FunctionBuilder::native(context, Self::get_species)
+            .name("get [Symbol.species]")
+            .constructor(false)
+            .build();
+
+        let promise_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().promise_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_method(Self::all, "all", 1)
+        .static_method(Self::all_settled, "allSettled", 1)
+        .static_method(Self::any, "any", 1)
+        .static_method(Self::race, "race", 1)
+        .static_method(Self::reject, "reject", 1)
+        .static_method(Self::resolve, "resolve", 1)
+        .static_accessor(
+            WellKnownSymbols::species(),
+            Some(get_species),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .method(Self::then, "then", 2)
+        .method(Self::catch, "catch", 1)
+        .method(Self::finally, "finally", 1)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build();
+
+        promise_object.into()
+    }
+}
+
+impl Promise {
+    const LENGTH: usize = 1;
+
+    /// Creates a new, pending `Promise`.
+    pub(crate) fn new() -> Self {
+        Self {
+            result: None,
+            state: PromiseState::Pending,
+            fulfill_reactions: Vec::new(),
+            reject_reactions: Vec::new(),
+            is_handled: false,
+        }
+    }
+
+    /// Gets the current state of the promise.
+    pub fn state(&self) -> PromiseState {
+        self.state
+    }
+
+    /// Gets the result of the promise, if it is already settled.
+    pub fn result(&self) -> Option<&JsValue> {
+        self.result.as_ref()
+    }
+
+    /// `Promise ( executor )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-executor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/Promise
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error("Promise NewTarget cannot be undefined");
+        }
+
+        let executor = args.get_or_undefined(0);
+
+        // 2. If IsCallable(executor) is false, throw a TypeError exception.
+        let executor = match executor.as_callable() {
+            Some(executor) => executor.clone(),
+            None => return context.throw_type_error("Promise executor is not callable"),
+        };
+
+        // 3. Let promise be ? OrdinaryCreateFromConstructor(NewTarget, "%Promise.prototype%", « [[PromiseState]], [[PromiseResult]], [[PromiseFulfillReactions]], [[PromiseRejectReactions]], [[PromiseIsHandled]] »).
+        let promise =
+            get_prototype_from_constructor(new_target, StandardObjects::promise_object, context)?;
+
+        // 4. Set promise.[[PromiseState]] to pending.
+        // 5. Set promise.[[PromiseFulfillReactions]] to a new empty List.
+        // 6. Set promise.[[PromiseRejectReactions]] to a new empty List.
+        // 7. Set promise.[[PromiseIsHandled]] to false.
+        let promise = JsObject::from_proto_and_data(promise, ObjectData::promise(Self::new()));
+
+        // 8. Let resolvingFunctions be CreateResolvingFunctions(promise).
+        let resolving_functions = Self::create_resolving_functions(&promise, context);
+
+        // 9. Let completion Call(executor, undefined, « resolvingFunctions.[[Resolve]], resolvingFunctions.[[Reject]] »).
+        let completion = executor.call(
+            &JsValue::undefined(),
+            &[
+                resolving_functions.resolve.clone().into(),
+                resolving_functions.reject.clone().into(),
+            ],
+            context,
+        );
+
+        // 10. If completion is an abrupt completion, then
+        if let Err(value) = completion {
+            // a. Perform ? Call(resolvingFunctions.[[Reject]], undefined, « completion.[[Value]] »).
+            resolving_functions
+                .reject
+                .call(&JsValue::undefined(), &[value], context)?;
+        }
+
+        // 11. Return promise.
+        Ok(promise.into())
+    }
+
+    /// `CreateResolvingFunctions ( promise )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createresolvingfunctions
+    pub(crate) fn create_resolving_functions(
+        promise: &JsObject,
+        context: &mut Context,
+    ) -> ResolvingFunctionsRecord {
+        #[derive(Debug, Clone, Trace, Finalize)]
+        struct RejectResolveCaptures {
+            promise: JsObject,
+            already_resolved: Gc<Cell<bool>>,
+        }
+
+        // 1. Let alreadyResolved be the Record { [[Value]]: false }.
+        let already_resolved = Gc::new(Cell::new(false));
+
+        // 2. Let stepsResolve be the algorithm steps defined in Promise Resolve Functions.
+        // 3. Let lengthResolve be the number of non-optional parameters of the function definition in Promise Resolve Functions.
+        // 4. Let resolve be ! CreateBuiltinFunction(stepsResolve, lengthResolve, "", « [[Promise]], [[AlreadyResolved]] »).
+        // 5. Set resolve.[[Promise]] to promise.
+        // 6. Set resolve.[[AlreadyResolved]] to alreadyResolved.
+        let resolve = FunctionBuilder::closure_with_captures(
+            context,
+            |_this, args, captures, context| {
+                // https://tc39.es/ecma262/#sec-promise-resolve-functions
+
+                // 1. Let F be the active function object.
+                // 2. Assert: F has a [[Promise]] internal slot whose value is an Object.
+                // 3. Let promise be F.[[Promise]].
+                // 4. Let alreadyResolved be F.[[AlreadyResolved]].
+                let RejectResolveCaptures {
+                    promise,
+                    already_resolved,
+                } = captures;
+
+                // 5. If alreadyResolved.[[Value]] is true, return undefined.
+                if *already_resolved.borrow() {
+                    return Ok(JsValue::undefined());
+                }
+
+                // 6. Set alreadyResolved.[[Value]] to true.
+                *already_resolved.borrow_mut() = true;
+
+                let resolution = args.get_or_undefined(0);
+
+                // 7. If SameValue(resolution, promise) is true, then
+                if JsValue::same_value(resolution, &promise.clone().into()) {
+                    // a. Let selfResolutionError be a newly created TypeError object.
+                    let self_resolution_error =
+                        context.construct_type_error("SameValue(resolution, promise) is true");
+
+                    // b. Perform RejectPromise(promise, selfResolutionError).
+                    Self::reject_promise(promise, &self_resolution_error, context);
+
+                    // c. Return undefined.
+                    return Ok(JsValue::undefined());
+                }
+
+                // 8. If Type(resolution) is not Object, then
+                let then = if let Some(resolution) = resolution.as_object() {
+                    // 9. Let then be Completion(Get(resolution, "then")).
+                    resolution.get("then", context)
+                } else {
+                    // a. Perform FulfillPromise(promise, resolution).
+                    Self::fulfill_promise(promise, resolution, context);
+
+                    // b. Return undefined.
+                    return Ok(JsValue::undefined());
+                };
+
+                let then_action = match then {
+                    // 10. If then is an abrupt completion, then
+                    Err(value) => {
+                        // a. Perform RejectPromise(promise, then.[[Value]]).
+                        Self::reject_promise(promise, &value, context);
+
+                        // b. Return undefined.
+                        return Ok(JsValue::undefined());
+                    }
+                    // 11. Let thenAction be then.[[Value]].
+                    Ok(then) => then,
+                };
+
+                // 12. If IsCallable(thenAction) is false, then
+                let then_action = if let Some(then_action) = then_action.as_callable() {
+                    then_action.clone()
+                } else {
+                    // a. Perform FulfillPromise(promise, resolution).
+                    Self::fulfill_promise(promise, resolution, context);
+
+                    // b. Return undefined.
+                    return Ok(JsValue::undefined());
+                };
+
+                // 13. Let thenJobCallback be HostMakeJobCallback(thenAction).
+                let then_job_callback = JobCallback::make_job_callback(then_action);
+
+                // 14. Let job be NewPromiseResolveThenableJob(promise, resolution, thenJobCallback).
+                let job = PromiseJob::new_promise_resolve_thenable_job(
+                    promise.clone(),
+                    resolution.clone(),
+                    then_job_callback,
+                    context,
+                );
+
+                // 15. Perform HostEnqueuePromiseJob(job.[[Job]], job.[[Realm]]).
+                context.host_enqueue_promise_job(job);
+
+                // 16. Return undefined.
+                Ok(JsValue::undefined())
+            },
+            RejectResolveCaptures {
+                promise: promise.clone(),
+                already_resolved: already_resolved.clone(),
+            },
+        )
+        .name("")
+        .length(1)
+        .build();
+
+        // 7. Let stepsReject be the algorithm steps defined in Promise Reject Functions.
+        // 8. Let lengthReject be the number of non-optional parameters of the function definition in Promise Reject Functions.
+        // 9. Let reject be ! CreateBuiltinFunction(stepsReject, lengthReject, "", « [[Promise]], [[AlreadyResolved]] »).
+        // 10. Set reject.[[Promise]] to promise.
+        // 11. Set reject.[[AlreadyResolved]] to alreadyResolved.
+        let reject = FunctionBuilder::closure_with_captures(
+            context,
+            |_this, args, captures, context| {
+                // https://tc39.es/ecma262/#sec-promise-reject-functions
+
+                // 1. Let F be the active function object.
+                // 2. Assert: F has a [[Promise]] internal slot whose value is an Object.
+                // 3. Let promise be F.[[Promise]].
+                // 4. Let alreadyResolved be F.[[AlreadyResolved]].
+                let RejectResolveCaptures {
+                    promise,
+                    already_resolved,
+                } = captures;
+
+                // 5. If alreadyResolved.[[Value]] is true, return undefined.
+                if *already_resolved.borrow() {
+                    return Ok(JsValue::undefined());
+                }
+
+                // 6. Set alreadyResolved.[[Value]] to true.
+                *already_resolved.borrow_mut() = true;
+
+                // 7. Perform RejectPromise(promise, reason).
+                Self::reject_promise(promise, args.get_or_undefined(0), context);
+
+                // 8. Return undefined.
+                Ok(JsValue::undefined())
+            },
+            RejectResolveCaptures {
+                promise: promise.clone(),
+                already_resolved,
+            },
+        )
+        .name("")
+        .length(1)
+        .build();
+
+        // 12. Return the Record { [[Resolve]]: resolve, [[Reject]]: reject }.
+        ResolvingFunctionsRecord { resolve, reject }
+    }
+
+    /// `FulfillPromise ( promise, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-fulfillpromise
+    pub(crate) fn fulfill_promise(promise: &JsObject, value: &JsValue, context: &mut Context) {
+        let reactions = {
+            let mut promise = promise.borrow_mut();
+            let promise = promise
+                .as_promise_mut()
+                .expect("FulfillPromise called on a non-promise object");
+
+            // 1. Assert: The value of promise.[[PromiseState]] is pending.
+            assert_eq!(
+                promise.state,
+                PromiseState::Pending,
+                "promise was not pending"
+            );
+
+            // 2. Let reactions be promise.[[PromiseFulfillReactions]].
+            let reactions = std::mem::take(&mut promise.fulfill_reactions);
+
+            // 3. Set promise.[[PromiseResult]] to value.
+            promise.result = Some(value.clone());
+
+            // 4. Set promise.[[PromiseFulfillReactions]] to undefined.
+            // 5. Set promise.[[PromiseRejectReactions]] to undefined.
+            promise.reject_reactions.clear();
+
+            // 6. Set promise.[[PromiseState]] to fulfilled.
+            promise.state = PromiseState::Fulfilled;
+
+            reactions
+        };
+
+        // 7. Perform TriggerPromiseReactions(reactions, value).
+        Self::trigger_promise_reactions(reactions, value, context);
+
+        // 8. Return unused.
+    }
+
+    /// `RejectPromise ( promise, reason )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-rejectpromise
+    pub(crate) fn reject_promise(promise: &JsObject, reason: &JsValue, context: &mut Context) {
+        let (reactions, is_handled) = {
+            let mut promise = promise.borrow_mut();
+            let promise = promise
+                .as_promise_mut()
+                .expect("RejectPromise called on a non-promise object");
+
+            // 1. Assert: The value of promise.[[PromiseState]] is pending.
+            assert_eq!(
+                promise.state,
+                PromiseState::Pending,
+                "promise was not pending"
+            );
+
+            // 2. Let reactions be promise.[[PromiseRejectReactions]].
+            let reactions = std::mem::take(&mut promise.reject_reactions);
+
+            // 3. Set promise.[[PromiseResult]] to reason.
+            promise.result = Some(reason.clone());
+
+            // 4. Set promise.[[PromiseFulfillReactions]] to undefined.
+            promise.fulfill_reactions.clear();
+
+            // 5. Set promise.[[PromiseRejectReactions]] to undefined.
+            // 6. Set promise.[[PromiseState]] to rejected.
+            promise.state = PromiseState::Rejected;
+
+            (reactions, promise.is_handled)
+        };
+
+        // 7. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "reject").
+        if !is_handled {
+            context.host_promise_rejection_tracker(promise, reason, OperationType::Reject);
+        }
+
+        // 8. Perform TriggerPromiseReactions(reactions, reason).
+        Self::trigger_promise_reactions(reactions, reason, context);
+
+        // 9. Return unused.
+    }
+
+    /// `TriggerPromiseReactions ( reactions, argument )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-triggerpromisereactions
+    fn trigger_promise_reactions(
+        reactions: Vec<ReactionRecord>,
+        argument: &JsValue,
+        context: &mut Context,
+    ) {
+        // 1. For each element reaction of reactions, do
+        for reaction in reactions {
+            // a. Let job be NewPromiseReactionJob(reaction, argument).
+            let job = PromiseJob::new_promise_reaction_job(reaction, argument.clone(), context);
+
+            // b. Perform HostEnqueuePromiseJob(job.[[Job]], job.[[Realm]]).
+            context.host_enqueue_promise_job(job);
+        }
+
+        // 2. Return unused.
+    }
+
+    /// `Promise.all ( iterable )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.all
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/all
+    pub(crate) fn all(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::perform_combinator(this, args, context, Self::perform_promise_all)
+    }
+
+    /// `Promise.allSettled ( iterable )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.allsettled
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/allSettled
+    pub(crate) fn all_settled(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::perform_combinator(this, args, context, Self::perform_promise_all_settled)
+    }
+
+    /// `Promise.any ( iterable )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.any
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/any
+    pub(crate) fn any(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::perform_combinator(this, args, context, Self::perform_promise_any)
+    }
+
+    /// `Promise.race ( iterable )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.race
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/race
+    pub(crate) fn race(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::perform_combinator(this, args, context, Self::perform_promise_race)
+    }
+
+    /// Shared steps of the `Promise.all`, `Promise.allSettled`, `Promise.any` and `Promise.race`
+    /// combinators.
+    ///
+    /// `perform` is called with the iterator of the iterable argument, the constructor, the new
+    /// promise capability and the `resolve` function of the constructor. It must set its
+    /// `iterator_done` argument once the iterator has thrown or has been exhausted.
+    fn perform_combinator<F>(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+        perform: F,
+    ) -> JsResult<JsValue>
+    where
+        F: FnOnce(
+            &IteratorRecord,
+            &mut bool,
+            &JsObject,
+            &PromiseCapability,
+            &JsObject,
+            &mut Context,
+        ) -> JsResult<JsValue>,
+    {
+        let iterable = args.get_or_undefined(0);
+
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let promise_capability = PromiseCapability::new(this, context)?;
+        let c = this
+            .as_object()
+            .expect("NewPromiseCapability ensures the this value is a constructor");
+
+        // 3. Let promiseResolve be Completion(GetPromiseResolve(C)).
+        let promise_resolve = Self::get_promise_resolve(c, context);
+
+        // 4. IfAbruptRejectPromise(promiseResolve, promiseCapability).
+        if_abrupt_reject_promise!(promise_resolve, promise_capability, context);
+
+        // 5. Let iteratorRecord be Completion(GetIterator(iterable)).
+        let iterator_record = iterable.get_iterator(context, None, None);
+
+        // 6. IfAbruptRejectPromise(iteratorRecord, promiseCapability).
+        if_abrupt_reject_promise!(iterator_record, promise_capability, context);
+
+        // 7. Let result be Completion(PerformPromiseX(iteratorRecord, C, promiseCapability, promiseResolve)).
+        let mut iterator_done = false;
+        let mut result = perform(
+            &iterator_record,
+            &mut iterator_done,
+            c,
+            &promise_capability,
+            &promise_resolve,
+            context,
+        );
+
+        // 8. If result is an abrupt completion, then
+        if result.is_err() {
+            // a. If iteratorRecord.[[Done]] is false, set result to Completion(IteratorClose(iteratorRecord, result)).
+            if !iterator_done {
+                result = iterator_record.close(result, context);
+            }
+
+            // b. IfAbruptRejectPromise(result, promiseCapability).
+            if_abrupt_reject_promise!(result, promise_capability, context);
+
+            return Ok(result);
+        }
+
+        // 9. Return ? result.
+        result
+    }
+
+    /// Runs `IteratorStep` and `IteratorValue` on `iterator_record`, setting `iterator_done` if
+    /// the iterator throws or is exhausted.
+    ///
+    /// Returns `None` once the iterator is done.
+    fn iterator_step_value(
+        iterator_record: &IteratorRecord,
+        iterator_done: &mut bool,
+        context: &mut Context,
+    ) -> JsResult<Option<JsValue>> {
+        // a. Let next be Completion(IteratorStep(iteratorRecord)).
+        // b. If next is an abrupt completion, set iteratorRecord.[[Done]] to true.
+        // c. ReturnIfAbrupt(next).
+        // e. Let nextValue be Completion(IteratorValue(next)).
+        // f. If nextValue is an abrupt completion, set iteratorRecord.[[Done]] to true.
+        // g. ReturnIfAbrupt(nextValue).
+        let next = iterator_record.next(context).map_err(|e| {
+            *iterator_done = true;
+            e
+        })?;
+
+        // d. If next is false, then
+        if next.done {
+            // i. Set iteratorRecord.[[Done]] to true.
+            *iterator_done = true;
+            return Ok(None);
+        }
+
+        Ok(Some(next.value))
+    }
+
+    /// `PerformPromiseAll ( iteratorRecord, constructor, resultCapability, promiseResolve )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromiseall
+    fn perform_promise_all(
+        iterator_record: &IteratorRecord,
+        iterator_done: &mut bool,
+        constructor: &JsObject,
+        result_capability: &PromiseCapability,
+        promise_resolve: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        #[derive(Debug, Trace, Finalize)]
+        struct ResolveElementCaptures {
+            already_called: bool,
+            index: usize,
+            values: Gc<Cell<Vec<JsValue>>>,
+            capability_resolve: JsObject,
+            remaining_elements_count: Gc<Cell<i32>>,
+        }
+
+        // 1. Let values be a new empty List.
+        let values = Gc::new(Cell::new(Vec::new()));
+
+        // 2. Let remainingElementsCount be the Record { [[Value]]: 1 }.
+        let remaining_elements_count = Gc::new(Cell::new(1));
+
+        // 3. Let index be 0.
+        let mut index = 0;
+
+        // 4. Repeat,
+        loop {
+            // a-g. Let nextValue be ? IteratorStep(iteratorRecord) and IteratorValue(next).
+            let next_value = if let Some(next_value) =
+                Self::iterator_step_value(iterator_record, iterator_done, context)?
+            {
+                next_value
+            } else {
+                // d. If next is false, then
+                // ii. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+                *remaining_elements_count.borrow_mut() -= 1;
+
+                // iii. If remainingElementsCount.[[Value]] is 0, then
+                if *remaining_elements_count.borrow() == 0 {
+                    // 1. Let valuesArray be CreateArrayFromList(values).
+                    let values_array =
+                        Array::create_array_from_list(values.borrow().iter().cloned(), context);
+
+                    // 2. Perform ? Call(resultCapability.[[Resolve]], undefined, « valuesArray »).
+                    result_capability.resolve.call(
+                        &JsValue::undefined(),
+                        &[values_array.into()],
+                        context,
+                    )?;
+                }
+
+                // iv. Return resultCapability.[[Promise]].
+                return Ok(result_capability.promise.clone().into());
+            };
+
+            // h. Append undefined to values.
+            values.borrow_mut().push(JsValue::undefined());
+
+            // i. Let nextPromise be ? Call(promiseResolve, constructor, « nextValue »).
+            let next_promise =
+                promise_resolve.call(&constructor.clone().into(), &[next_value], context)?;
+
+            // j. Let steps be the algorithm steps defined in Promise.all Resolve Element Functions.
+            // k. Let length be the number of non-optional parameters of the function definition in Promise.all Resolve Element Functions.
+            // l. Let onFulfilled be ! CreateBuiltinFunction(steps, length, "", « [[AlreadyCalled]], [[Index]], [[Values]], [[Capability]], [[RemainingElements]] »).
+            // m. Set onFulfilled.[[AlreadyCalled]] to false.
+            // n. Set onFulfilled.[[Index]] to index.
+            // o. Set onFulfilled.[[Values]] to values.
+            // p. Set onFulfilled.[[Capability]] to resultCapability.
+            // q. Set onFulfilled.[[RemainingElements]] to remainingElementsCount.
+            let on_fulfilled = FunctionBuilder::closure_with_captures(
+                context,
+                |_, args, captures, context| {
+                    // https://tc39.es/ecma262/#sec-promise.all-resolve-element-functions
+
+                    // 1. Let F be the active function object.
+                    // 2. If F.[[AlreadyCalled]] is true, return undefined.
+                    if captures.already_called {
+                        return Ok(JsValue::undefined());
+                    }
+
+                    // 3. Set F.[[AlreadyCalled]] to true.
+                    captures.already_called = true;
+
+                    // 4. Let index be F.[[Index]].
+                    // 5. Let values be F.[[Values]].
+                    // 6. Let promiseCapability be F.[[Capability]].
+                    // 7. Let remainingElementsCount be F.[[RemainingElements]].
+
+                    // 8. Set values[index] to x.
+                    captures.values.borrow_mut()[captures.index] = args.get_or_undefined(0).clone();
+
+                    // 9. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+                    *captures.remaining_elements_count.borrow_mut() -= 1;
+
+                    // 10. If remainingElementsCount.[[Value]] is 0, then
+                    if *captures.remaining_elements_count.borrow() == 0 {
+                        // a. Let valuesArray be CreateArrayFromList(values).
+                        let values_array = Array::create_array_from_list(
+                            captures.values.borrow().iter().cloned(),
+                            context,
+                        );
+
+                        // b. Return ? Call(promiseCapability.[[Resolve]], undefined, « valuesArray »).
+                        return captures.capability_resolve.call(
+                            &JsValue::undefined(),
+                            &[values_array.into()],
+                            context,
+                        );
+                    }
+
+                    // 11. Return undefined.
+                    Ok(JsValue::undefined())
+                },
+                ResolveElementCaptures {
+                    already_called: false,
+                    index,
+                    values: values.clone(),
+                    capability_resolve: result_capability.resolve.clone(),
+                    remaining_elements_count: remaining_elements_count.clone(),
+                },
+            )
+            .name("")
+            .length(1)
+            .build();
+
+            // r. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] + 1.
+            *remaining_elements_count.borrow_mut() += 1;
+
+            // s. Perform ? Invoke(nextPromise, "then", « onFulfilled, resultCapability.[[Reject]] »).
+            next_promise.invoke(
+                "then",
+                &[on_fulfilled.into(), result_capability.reject.clone().into()],
+                context,
+            )?;
+
+            // t. Set index to index + 1.
+            index += 1;
+        }
+    }
+
+    /// `PerformPromiseAllSettled ( iteratorRecord, constructor, resultCapability, promiseResolve )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromiseallsettled
+    fn perform_promise_all_settled(
+        iterator_record: &IteratorRecord,
+        iterator_done: &mut bool,
+        constructor: &JsObject,
+        result_capability: &PromiseCapability,
+        promise_resolve: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        #[derive(Debug, Trace, Finalize)]
+        struct SettleElementCaptures {
+            already_called: Gc<Cell<bool>>,
+            index: usize,
+            values: Gc<Cell<Vec<JsValue>>>,
+            capability_resolve: JsObject,
+            remaining_elements_count: Gc<Cell<i32>>,
+        }
+
+        /// Shared steps of the `Promise.allSettled` Resolve and Reject Element Functions.
+        fn settle_element(
+            captures: &SettleElementCaptures,
+            status: &str,
+            key: &str,
+            x: &JsValue,
+            context: &mut Context,
+        ) -> JsResult<JsValue> {
+            // 1. Let F be the active function object.
+            // 2. Let alreadyCalled be F.[[AlreadyCalled]].
+            // 3. If alreadyCalled.[[Value]] is true, return undefined.
+            if *captures.already_called.borrow() {
+                return Ok(JsValue::undefined());
+            }
+
+            // 4. Set alreadyCalled.[[Value]] to true.
+            *captures.already_called.borrow_mut() = true;
+
+            // 5. Let index be F.[[Index]].
+            // 6. Let values be F.[[Values]].
+            // 7. Let promiseCapability be F.[[Capability]].
+            // 8. Let remainingElementsCount be F.[[RemainingElements]].
+
+            // 9. Let obj be OrdinaryObjectCreate(%Object.prototype%).
+            let obj = context.construct_object();
+
+            // 10. Perform ! CreateDataPropertyOrThrow(obj, "status", status).
+            obj.create_data_property_or_throw("status", status, context)
+                .expect("cannot fail per spec");
+
+            // 11. Perform ! CreateDataPropertyOrThrow(obj, key, x).
+            obj.create_data_property_or_throw(key, x.clone(), context)
+                .expect("cannot fail per spec");
+
+            // 12. Set values[index] to obj.
+            captures.values.borrow_mut()[captures.index] = obj.into();
+
+            // 13. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+            *captures.remaining_elements_count.borrow_mut() -= 1;
+
+            // 14. If remainingElementsCount.[[Value]] is 0, then
+            if *captures.remaining_elements_count.borrow() == 0 {
+                // a. Let valuesArray be CreateArrayFromList(values).
+                let values_array = Array::create_array_from_list(
+                    captures.values.borrow().iter().cloned(),
+                    context,
+                );
+
+                // b. Return ? Call(promiseCapability.[[Resolve]], undefined, « valuesArray »).
+                return captures.capability_resolve.call(
+                    &JsValue::undefined(),
+                    &[values_array.into()],
+                    context,
+                );
+            }
+
+            // 15. Return undefined.
+            Ok(JsValue::undefined())
+        }
+
+        // 1. Let values be a new empty List.
+        let values = Gc::new(Cell::new(Vec::new()));
+
+        // 2. Let remainingElementsCount be the Record { [[Value]]: 1 }.
+        let remaining_elements_count = Gc::new(Cell::new(1));
+
+        // 3. Let index be 0.
+        let mut index = 0;
+
+        // 4. Repeat,
+        loop {
+            // a-g. Let nextValue be ? IteratorStep(iteratorRecord) and IteratorValue(next).
+            let next_value = if let Some(next_value) =
+                Self::iterator_step_value(iterator_record, iterator_done, context)?
+            {
+                next_value
+            } else {
+                // d. If next is false, then
+                // ii. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+                *remaining_elements_count.borrow_mut() -= 1;
+
+                // iii. If remainingElementsCount.[[Value]] is 0, then
+                if *remaining_elements_count.borrow() == 0 {
+                    // 1. Let valuesArray be CreateArrayFromList(values).
+                    let values_array =
+                        Array::create_array_from_list(values.borrow().iter().cloned(), context);
+
+                    // 2. Perform ? Call(resultCapability.[[Resolve]], undefined, « valuesArray »).
+                    result_capability.resolve.call(
+                        &JsValue::undefined(),
+                        &[values_array.into()],
+                        context,
+                    )?;
+                }
+
+                // iv. Return resultCapability.[[Promise]].
+                return Ok(result_capability.promise.clone().into());
+            };
+
+            // h. Append undefined to values.
+            values.borrow_mut().push(JsValue::undefined());
+
+            // i. Let nextPromise be ? Call(promiseResolve, constructor, « nextValue »).
+            let next_promise =
+                promise_resolve.call(&constructor.clone().into(), &[next_value], context)?;
+
+            // k. Let alreadyCalled be the Record { [[Value]]: false }.
+            let already_called = Gc::new(Cell::new(false));
+
+            // j. Let stepsFulfilled be the algorithm steps defined in Promise.allSettled Resolve Element Functions.
+            // l-r. Let onFulfilled be ! CreateBuiltinFunction(stepsFulfilled, lengthFulfilled, "", « [[AlreadyCalled]], [[Index]], [[Values]], [[Capability]], [[RemainingElements]] »).
+            let on_fulfilled = FunctionBuilder::closure_with_captures(
+                context,
+                |_, args, captures, context| {
+                    // https://tc39.es/ecma262/#sec-promise.allsettled-resolve-element-functions
+                    settle_element(
+                        captures,
+                        "fulfilled",
+                        "value",
+                        args.get_or_undefined(0),
+                        context,
+                    )
+                },
+                SettleElementCaptures {
+                    already_called: already_called.clone(),
+                    index,
+                    values: values.clone(),
+                    capability_resolve: result_capability.resolve.clone(),
+                    remaining_elements_count: remaining_elements_count.clone(),
+                },
+            )
+            .name("")
+            .length(1)
+            .build();
+
+            // s. Let stepsRejected be the algorithm steps defined in Promise.allSettled Reject Element Functions.
+            // t-z. Let onRejected be ! CreateBuiltinFunction(stepsRejected, lengthRejected, "", « [[AlreadyCalled]], [[Index]], [[Values]], [[Capability]], [[RemainingElements]] »).
+            let on_rejected = FunctionBuilder::closure_with_captures(
+                context,
+                |_, args, captures, context| {
+                    // https://tc39.es/ecma262/#sec-promise.allsettled-reject-element-functions
+                    settle_element(
+                        captures,
+                        "rejected",
+                        "reason",
+                        args.get_or_undefined(0),
+                        context,
+                    )
+                },
+                SettleElementCaptures {
+                    already_called,
+                    index,
+                    values: values.clone(),
+                    capability_resolve: result_capability.resolve.clone(),
+                    remaining_elements_count: remaining_elements_count.clone(),
+                },
+            )
+            .name("")
+            .length(1)
+            .build();
+
+            // aa. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] + 1.
+            *remaining_elements_count.borrow_mut() += 1;
+
+            // ab. Perform ? Invoke(nextPromise, "then", « onFulfilled, onRejected »).
+            next_promise.invoke("then", &[on_fulfilled.into(), on_rejected.into()], context)?;
+
+            // ac. Set index to index + 1.
+            index += 1;
+        }
+    }
+
+    /// `PerformPromiseAny ( iteratorRecord, constructor, resultCapability, promiseResolve )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromiseany
+    fn perform_promise_any(
+        iterator_record: &IteratorRecord,
+        iterator_done: &mut bool,
+        constructor: &JsObject,
+        result_capability: &PromiseCapability,
+        promise_resolve: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        #[derive(Debug, Trace, Finalize)]
+        struct RejectElementCaptures {
+            already_called: bool,
+            index: usize,
+            errors: Gc<Cell<Vec<JsValue>>>,
+            capability_reject: JsObject,
+            remaining_elements_count: Gc<Cell<i32>>,
+        }
+
+        // 1. Let errors be a new empty List.
+        let errors = Gc::new(Cell::new(Vec::new()));
+
+        // 2. Let remainingElementsCount be the Record { [[Value]]: 1 }.
+        let remaining_elements_count = Gc::new(Cell::new(1));
+
+        // 3. Let index be 0.
+        let mut index = 0;
+
+        // 4. Repeat,
+        loop {
+            // a-g. Let nextValue be ? IteratorStep(iteratorRecord) and IteratorValue(next).
+            let next_value = if let Some(next_value) =
+                Self::iterator_step_value(iterator_record, iterator_done, context)?
+            {
+                next_value
+            } else {
+                // d. If next is false, then
+                // ii. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+                *remaining_elements_count.borrow_mut() -= 1;
+
+                // iii. If remainingElementsCount.[[Value]] is 0, then
+                if *remaining_elements_count.borrow() == 0 {
+                    // 1. Let error be a newly created AggregateError object.
+                    // 2. Perform ! DefinePropertyOrThrow(error, "errors", PropertyDescriptor { [[Configurable]]: true, [[Enumerable]]: false, [[Writable]]: true, [[Value]]: CreateArrayFromList(errors) }).
+                    let error = AggregateError::create(errors.borrow().iter().cloned(), context);
+
+                    // 3. Return ThrowCompletion(error).
+                    return Err(error.into());
+                }
+
+                // iv. Return resultCapability.[[Promise]].
+                return Ok(result_capability.promise.clone().into());
+            };
+
+            // h. Append undefined to errors.
+            errors.borrow_mut().push(JsValue::undefined());
+
+            // i. Let nextPromise be ? Call(promiseResolve, constructor, « nextValue »).
+            let next_promise =
+                promise_resolve.call(&constructor.clone().into(), &[next_value], context)?;
+
+            // j. Let stepsRejected be the algorithm steps defined in Promise.any Reject Element Functions.
+            // k-q. Let onRejected be ! CreateBuiltinFunction(stepsRejected, lengthRejected, "", « [[AlreadyCalled]], [[Index]], [[Errors]], [[Capability]], [[RemainingElements]] »).
+            let on_rejected = FunctionBuilder::closure_with_captures(
+                context,
+                |_, args, captures, context| {
+                    // https://tc39.es/ecma262/#sec-promise.any-reject-element-functions
+
+                    // 1. Let F be the active function object.
+                    // 2. If F.[[AlreadyCalled]] is true, return undefined.
+                    if captures.already_called {
+                        return Ok(JsValue::undefined());
+                    }
+
+                    // 3. Set F.[[AlreadyCalled]] to true.
+                    captures.already_called = true;
+
+                    // 4. Let index be F.[[Index]].
+                    // 5. Let errors be F.[[Errors]].
+                    // 6. Let promiseCapability be F.[[Capability]].
+                    // 7. Let remainingElementsCount be F.[[RemainingElements]].
+
+                    // 8. Set errors[index] to x.
+                    captures.errors.borrow_mut()[captures.index] = args.get_or_undefined(0).clone();
+
+                    // 9. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+                    *captures.remaining_elements_count.borrow_mut() -= 1;
+
+                    // 10. If remainingElementsCount.[[Value]] is 0, then
+                    if *captures.remaining_elements_count.borrow() == 0 {
+                        // a. Let error be a newly created AggregateError object.
+                        // b. Perform ! DefinePropertyOrThrow(error, "errors", PropertyDescriptor { [[Configurable]]: true, [[Enumerable]]: false, [[Writable]]: true, [[Value]]: CreateArrayFromList(errors) }).
+                        let error = AggregateError::create(
+                            captures.errors.borrow().iter().cloned(),
+                            context,
+                        );
+
+                        // c. Return ? Call(promiseCapability.[[Reject]], undefined, « error »).
+                        return captures.capability_reject.call(
+                            &JsValue::undefined(),
+                            &[error.into()],
+                            context,
+                        );
+                    }
+
+                    // 11. Return undefined.
+                    Ok(JsValue::undefined())
+                },
+                RejectElementCaptures {
+                    already_called: false,
+                    index,
+                    errors: errors.clone(),
+                    capability_reject: result_capability.reject.clone(),
+                    remaining_elements_count: remaining_elements_count.clone(),
+                },
+            )
+            .name("")
+            .length(1)
+            .build();
+
+            // r. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] + 1.
+            *remaining_elements_count.borrow_mut() += 1;
+
+            // s. Perform ? Invoke(nextPromise, "then", « resultCapability.[[Resolve]], onRejected »).
+            next_promise.invoke(
+                "then",
+                &[result_capability.resolve.clone().into(), on_rejected.into()],
+                context,
+            )?;
+
+            // t. Set index to index + 1.
+            index += 1;
+        }
+    }
+
+    /// `PerformPromiseRace ( iteratorRecord, constructor, resultCapability, promiseResolve )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromiserace
+    fn perform_promise_race(
+        iterator_record: &IteratorRecord,
+        iterator_done: &mut bool,
+        constructor: &JsObject,
+        result_capability: &PromiseCapability,
+        promise_resolve: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Repeat,
+        // a-g. Let nextValue be ? IteratorStep(iteratorRecord) and IteratorValue(next).
+        while let Some(next_value) =
+            Self::iterator_step_value(iterator_record, iterator_done, context)?
+        {
+            // h. Let nextPromise be ? Call(promiseResolve, constructor, « nextValue »).
+            let next_promise =
+                promise_resolve.call(&constructor.clone().into(), &[next_value], context)?;
+
+            // i. Perform ? Invoke(nextPromise, "then", « resultCapability.[[Resolve]], resultCapability.[[Reject]] »).
+            next_promise.invoke(
+                "then",
+                &[
+                    result_capability.resolve.clone().into(),
+                    result_capability.reject.clone().into(),
+                ],
+                context,
+            )?;
+        }
+
+        // d. If next is false, then
+        // ii. Return resultCapability.[[Promise]].
+        Ok(result_capability.promise.clone().into())
+    }
+
+    /// `Promise.reject ( r )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.reject
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/reject
+    pub(crate) fn reject(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let r = args.get_or_undefined(0);
+
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let promise_capability = PromiseCapability::new(this, context)?;
+
+        // 3. Perform ? Call(promiseCapability.[[Reject]], undefined, « r »).
+        promise_capability
+            .reject
+            .call(&JsValue::undefined(), std::slice::from_ref(r), context)?;
+
+        // 4. Return promiseCapability.[[Promise]].
+        Ok(promise_capability.promise.clone().into())
+    }
+
+    /// `Promise.resolve ( x )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.resolve
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/resolve
+    pub(crate) fn resolve(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let x = args.get_or_undefined(0);
+
+        // 1. Let C be the this value.
+        // 2. If Type(C) is not Object, throw a TypeError exception.
+        let c = match this.as_object() {
+            Some(c) => c,
+            None => return context.throw_type_error("Promise.resolve() called on a non-object"),
+        };
+
+        // 3. Return ? PromiseResolve(C, x).
+        Self::promise_resolve(c.clone(), x.clone(), context)
+    }
+
+    /// `get Promise [ @@species ]`
+    ///
+    /// The `Promise [ @@species ]` accessor property returns the Promise constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-promise-@@species
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/@@species
+    #[allow(clippy::unnecessary_wraps)]
+    fn get_species(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Return the this value.
+        Ok(this.clone())
+    }
+
+    /// `Promise.prototype.catch ( onRejected )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.catch
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/catch
+    pub(crate) fn catch(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let on_rejected = args.get_or_undefined(0);
+
+        // 1. Let promise be the this value.
+        // 2. Return ? Invoke(promise, "then", « undefined, onRejected »).
+        this.invoke(
+            "then",
+            &[JsValue::undefined(), on_rejected.clone()],
+            context,
+        )
+    }
+
+    /// `Promise.prototype.finally ( onFinally )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.finally
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/finally
+    pub(crate) fn finally(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        #[derive(Debug, Clone, Trace, Finalize)]
+        struct FinallyCaptures {
+            on_finally: JsObject,
+            c: JsObject,
+        }
+
+        #[derive(Debug, Clone, Trace, Finalize)]
+        struct ValueCaptures {
+            value: JsValue,
+        }
+
+        // 1. Let promise be the this value.
+        // 2. If Type(promise) is not Object, throw a TypeError exception.
+        let promise = match this.as_object() {
+            Some(promise) => promise,
+            None => {
+                return context.throw_type_error("Promise.prototype.finally called on a non-object")
+            }
+        };
+
+        // 3. Let C be ? SpeciesConstructor(promise, %Promise%).
+        let c = promise.species_constructor(StandardObjects::promise_object, context)?;
+
+        // 4. Assert: IsConstructor(C) is true.
+        debug_assert!(c.is_constructor());
+
+        let on_finally = args.get_or_undefined(0);
+
+        // 5. If IsCallable(onFinally) is false, then
+        let (then_finally, catch_finally) = if let Some(on_finally) = on_finally.as_callable() {
+            // 6. Else,
+            // a. Let thenFinallyClosure be a new Abstract Closure with parameters (value) that captures onFinally and C and performs the following steps when called:
+            // b. Let thenFinally be CreateBuiltinFunction(thenFinallyClosure, 1, "", « »).
+            let then_finally = FunctionBuilder::closure_with_captures(
+                context,
+                |_this, args, captures, context| {
+                    let value = args.get_or_undefined(0);
+
+                    // i. Let result be ? Call(onFinally, undefined).
+                    let result = captures
+                        .on_finally
+                        .call(&JsValue::undefined(), &[], context)?;
+
+                    // ii. Let promise be ? PromiseResolve(C, result).
+                    let promise = Self::promise_resolve(captures.c.clone(), result, context)?;
+
+                    // iii. Let returnValue be a new Abstract Closure with no parameters that captures value and performs the following steps when called:
+                    // iv. Let valueThunk be CreateBuiltinFunction(returnValue, 0, "", « »).
+                    let value_thunk = FunctionBuilder::closure_with_captures(
+                        context,
+                        |_this, _args, captures, _context| {
+                            // 1. Return value.
+                            Ok(captures.value.clone())
+                        },
+                        ValueCaptures {
+                            value: value.clone(),
+                        },
+                    )
+                    .name("")
+                    .length(0)
+                    .build();
+
+                    // v. Return ? Invoke(promise, "then", « valueThunk »).
+                    promise.invoke("then", &[value_thunk.into()], context)
+                },
+                FinallyCaptures {
+                    on_finally: on_finally.clone(),
+                    c: c.clone(),
+                },
+            )
+            .name("")
+            .length(1)
+            .build();
+
+            // c. Let catchFinallyClosure be a new Abstract Closure with parameters (reason) that captures onFinally and C and performs the following steps when called:
+            // d. Let catchFinally be CreateBuiltinFunction(catchFinallyClosure, 1, "", « »).
+            let catch_finally = FunctionBuilder::closure_with_captures(
+                context,
+                |_this, args, captures, context| {
+                    let reason = args.get_or_undefined(0);
+
+                    // i. Let result be ? Call(onFinally, undefined).
+                    let result = captures
+                        .on_finally
+                        .call(&JsValue::undefined(), &[], context)?;
+
+                    // ii. Let promise be ? PromiseResolve(C, result).
+                    let promise = Self::promise_resolve(captures.c.clone(), result, context)?;
+
+                    // iii. Let throwReason be a new Abstract Closure with no parameters that captures reason and performs the following steps when called:
+                    // iv. Let thrower be CreateBuiltinFunction(throwReason, 0, "", « »).
+                    let thrower = FunctionBuilder::closure_with_captures(
+                        context,
+                        |_this, _args, captures, _context| {
+                            // 1. Return ThrowCompletion(reason).
+                            Err(captures.value.clone())
+                        },
+                        ValueCaptures {
+                            value: reason.clone(),
+                        },
+                    )
+                    .name("")
+                    .length(0)
+                    .build();
+
+                    // v. Return ? Invoke(promise, "then", « thrower »).
+                    promise.invoke("then", &[thrower.into()], context)
+                },
+                FinallyCaptures {
+                    on_finally: on_finally.clone(),
+                    c,
+                },
+            )
+            .name("")
+            .length(1)
+            .build();
+
+            (then_finally.into(), catch_finally.into())
+        } else {
+            // a. Let thenFinally be onFinally.
+            // b. Let catchFinally be onFinally.
+            (on_finally.clone(), on_finally.clone())
+        };
+
+        // 7. Return ? Invoke(promise, "then", « thenFinally, catchFinally »).
+        this.invoke("then", &[then_finally, catch_finally], context)
+    }
+
+    /// `Promise.prototype.then ( onFulfilled, onRejected )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.then
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/then
+    pub(crate) fn then(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let promise be the this value.
+        // 2. If IsPromise(promise) is false, throw a TypeError exception.
+        let promise = match this.as_object() {
+            Some(promise) if promise.is_promise() => promise,
+            _ => {
+                return context
+                    .throw_type_error("Promise.prototype.then called on a non-promise object")
+            }
+        };
+
+        let on_fulfilled = args.get_or_undefined(0);
+        let on_rejected = args.get_or_undefined(1);
+
+        // 3. Let C be ? SpeciesConstructor(promise, %Promise%).
+        let c = promise.species_constructor(StandardObjects::promise_object, context)?;
+
+        // 4. Let resultCapability be ? NewPromiseCapability(C).
+        let result_capability = PromiseCapability::new(&c.into(), context)?;
+
+        // 5. Return PerformPromiseThen(promise, onFulfilled, onRejected, resultCapability).
+        Ok(Self::perform_promise_then(
+            promise,
+            on_fulfilled,
+            on_rejected,
+            Some(result_capability),
+            context,
+        ))
+    }
+
+    /// `PerformPromiseThen ( promise, onFulfilled, onRejected [ , resultCapability ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromisethen
+    pub(crate) fn perform_promise_then(
+        promise: &JsObject,
+        on_fulfilled: &JsValue,
+        on_rejected: &JsValue,
+        result_capability: Option<PromiseCapability>,
+        context: &mut Context,
+    ) -> JsValue {
+        // 1. Assert: IsPromise(promise) is true.
+        // 2. If resultCapability is not present, then
+        //   a. Set resultCapability to undefined.
+
+        // 3. If IsCallable(onFulfilled) is false, then
+        //   a. Let onFulfilledJobCallback be empty.
+        // 4. Else,
+        //   a. Let onFulfilledJobCallback be HostMakeJobCallback(onFulfilled).
+        let on_fulfilled_job_callback = on_fulfilled
+            .as_callable()
+            .cloned()
+            .map(JobCallback::make_job_callback);
+
+        // 5. If IsCallable(onRejected) is false, then
+        //   a. Let onRejectedJobCallback be empty.
+        // 6. Else,
+        //   a. Let onRejectedJobCallback be HostMakeJobCallback(onRejected).
+        let on_rejected_job_callback = on_rejected
+            .as_callable()
+            .cloned()
+            .map(JobCallback::make_job_callback);
+
+        // 7. Let fulfillReaction be the PromiseReaction { [[Capability]]: resultCapability, [[Type]]: Fulfill, [[Handler]]: onFulfilledJobCallback }.
+        let fulfill_reaction = ReactionRecord {
+            promise_capability: result_capability.clone(),
+            reaction_type: ReactionType::Fulfill,
+            handler: on_fulfilled_job_callback,
+        };
+
+        // 8. Let rejectReaction be the PromiseReaction { [[Capability]]: resultCapability, [[Type]]: Reject, [[Handler]]: onRejectedJobCallback }.
+        let reject_reaction = ReactionRecord {
+            promise_capability: result_capability.clone(),
+            reaction_type: ReactionType::Reject,
+            handler: on_rejected_job_callback,
+        };
+
+        let (state, result, is_handled) = {
+            let promise = promise.borrow();
+            let promise = promise.as_promise().expect("IsPromise(promise) is false");
+            (promise.state, promise.result.clone(), promise.is_handled)
+        };
+
+        match state {
+            // 9. If promise.[[PromiseState]] is pending, then
+            PromiseState::Pending => {
+                let mut promise = promise.borrow_mut();
+                let promise = promise
+                    .as_promise_mut()
+                    .expect("IsPromise(promise) is false");
+
+                //   a. Append fulfillReaction as the last element of the List that is promise.[[PromiseFulfillReactions]].
+                promise.fulfill_reactions.push(fulfill_reaction);
+
+                //   b. Append rejectReaction as the last element of the List that is promise.[[PromiseRejectReactions]].
+                promise.reject_reactions.push(reject_reaction);
+            }
+
+            // 10. Else if promise.[[PromiseState]] is fulfilled, then
+            PromiseState::Fulfilled => {
+                //   a. Let value be promise.[[PromiseResult]].
+                let value = result.expect("fulfilled promise has no result");
+
+                //   b. Let fulfillJob be NewPromiseReactionJob(fulfillReaction, value).
+                let fulfill_job =
+                    PromiseJob::new_promise_reaction_job(fulfill_reaction, value, context);
+
+                //   c. Perform HostEnqueuePromiseJob(fulfillJob.[[Job]], fulfillJob.[[Realm]]).
+                context.host_enqueue_promise_job(fulfill_job);
+            }
+
+            // 11. Else,
+            //   a. Assert: The value of promise.[[PromiseState]] is rejected.
+            PromiseState::Rejected => {
+                //   b. Let reason be promise.[[PromiseResult]].
+                let reason = result.expect("rejected promise has no result");
+
+                //   c. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "handle").
+                if !is_handled {
+                    context.host_promise_rejection_tracker(promise, &reason, OperationType::Handle);
+                }
+
+                //   d. Let rejectJob be NewPromiseReactionJob(rejectReaction, reason).
+                let reject_job =
+                    PromiseJob::new_promise_reaction_job(reject_reaction, reason, context);
+
+                //   e. Perform HostEnqueuePromiseJob(rejectJob.[[Job]], rejectJob.[[Realm]]).
+                context.host_enqueue_promise_job(reject_job);
+            }
+        }
+
+        // 12. Set promise.[[PromiseIsHandled]] to true.
+        promise
+            .borrow_mut()
+            .as_promise_mut()
+            .expect("IsPromise(promise) is false")
+            .is_handled = true;
+
+        // 13. If resultCapability is undefined, then
+        //   a. Return undefined.
+        // 14. Else,
+        //   a. Return resultCapability.[[Promise]].
+        match result_capability {
+            Some(result_capability) => result_capability.promise.clone().into(),
+            None => JsValue::undefined(),
+        }
+    }
+
+    /// `PromiseResolve ( C, x )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-resolve
+    pub(crate) fn promise_resolve(
+        c: JsObject,
+        x: JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If IsPromise(x) is true, then
+        if let Some(x) = x.as_object().filter(|x| x.is_promise()) {
+            // a. Let xConstructor be ? Get(x, "constructor").
+            let x_constructor = x.get("constructor", context)?;
+
+            // b. If SameValue(xConstructor, C) is true, return x.
+            if JsValue::same_value(&x_constructor, &c.clone().into()) {
+                return Ok(x.clone().into());
+            }
+        }
+
+        // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let promise_capability = PromiseCapability::new(&c.into(), context)?;
+
+        // 3. Perform ? Call(promiseCapability.[[Resolve]], undefined, « x »).
+        promise_capability
+            .resolve
+            .call(&JsValue::undefined(), &[x], context)?;
+
+        // 4. Return promiseCapability.[[Promise]].
+        Ok(promise_capability.promise.clone().into())
+    }
+
+    /// `GetPromiseResolve ( promiseConstructor )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getpromiseresolve
+    fn get_promise_resolve(
+        promise_constructor: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let promiseResolve be ? Get(promiseConstructor, "resolve").
+        let promise_resolve = promise_constructor.get("resolve", context)?;
+
+        // 2. If IsCallable(promiseResolve) is false, throw a TypeError exception.
+        match promise_resolve.as_callable() {
+            // 3. Return promiseResolve.
+            Some(promise_resolve) => Ok(promise_resolve.clone()),
+            None => context.throw_type_error("retrieving a non-callable promise resolver"),
+        }
+    }
+}
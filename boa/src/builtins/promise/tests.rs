@@ -0,0 +1,248 @@
+use crate::{forward, Context};
+
+#[test]
+fn promise() {
+    let mut context = Context::default();
+    let init = r#"
+        let count = 0;
+        const promise = new Promise((resolve, reject) => {
+            count += 1;
+            resolve(undefined);
+        }).then((_) => (count += 1));
+        count += 1;
+        count;
+        "#;
+    let result = forward(&mut context, init);
+    assert_eq!(result, "2");
+    let after_completion = forward(&mut context, "count");
+    assert_eq!(after_completion, "3");
+}
+
+#[test]
+fn then_callbacks_run_as_microtasks() {
+    let mut context = Context::default();
+    let init = r#"
+        var log = [];
+        Promise.resolve(1).then((x) => log.push("then " + x));
+        Promise.reject(2).catch((x) => log.push("catch " + x));
+        var resolve;
+        new Promise((r) => { resolve = r; }).then((x) => log.push("late " + x));
+        resolve(3);
+        log.push("sync");
+        log.join();
+        "#;
+    assert_eq!(forward(&mut context, init), "\"sync\"");
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"sync,then 1,catch 2,late 3\""
+    );
+}
+
+#[test]
+fn executor_throws() {
+    let mut context = Context::default();
+    let init = r#"
+        var reason;
+        new Promise(() => { throw 'oops'; }).catch((r) => { reason = r; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "reason"), "\"oops\"");
+}
+
+#[test]
+fn executor_throws_after_settling() {
+    let mut context = Context::default();
+    let init = r#"
+        var log = [];
+        new Promise((resolve) => { resolve(1); throw 2; })
+            .then((x) => log.push("fulfilled " + x), (x) => log.push("rejected " + x));
+        new Promise((resolve, reject) => { reject(3); throw 4; })
+            .catch((x) => log.push("rejected " + x));
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"fulfilled 1,rejected 3\""
+    );
+}
+
+#[test]
+fn settle_only_once() {
+    let mut context = Context::default();
+    let init = r#"
+        var log = [];
+        new Promise((resolve, reject) => { resolve(1); reject(2); resolve(3); })
+            .then((x) => log.push("fulfilled " + x), (x) => log.push("rejected " + x));
+        new Promise((resolve, reject) => { reject(4); resolve(5); reject(6); })
+            .then((x) => log.push("fulfilled " + x), (x) => log.push("rejected " + x));
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"fulfilled 1,rejected 4\""
+    );
+}
+
+#[test]
+fn then_chain() {
+    let mut context = Context::default();
+    let init = r#"
+        var result;
+        Promise.resolve(1)
+            .then((x) => x + 1)
+            .then((x) => { throw x * 2; })
+            .catch((x) => x + 1)
+            .finally(() => 100)
+            .then((x) => { result = x; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "5");
+}
+
+#[test]
+fn resolve_with_thenable() {
+    let mut context = Context::default();
+    let init = r#"
+        var result;
+        const thenable = { then(resolve) { resolve(42); } };
+        Promise.resolve(thenable).then((x) => { result = x; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "42");
+}
+
+#[test]
+fn all() {
+    let mut context = Context::default();
+    let init = r#"
+        var fulfilled, rejected;
+        Promise.all([Promise.resolve(1), 2, new Promise((r) => r(3))])
+            .then((values) => { fulfilled = values.join(); });
+        Promise.all([Promise.resolve(1), Promise.reject('no'), Promise.reject('nope')])
+            .catch((reason) => { rejected = reason; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "fulfilled"), "\"1,2,3\"");
+    assert_eq!(forward(&mut context, "rejected"), "\"no\"");
+}
+
+#[test]
+fn all_settled() {
+    let mut context = Context::default();
+    let init = r#"
+        var result;
+        Promise.allSettled([Promise.resolve(1), Promise.reject('no'), 3])
+            .then((values) => {
+                result = values.map((v) => v.status + ':' + (v.status === 'fulfilled' ? v.value : v.reason)).join();
+            });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "result"),
+        "\"fulfilled:1,rejected:no,fulfilled:3\""
+    );
+}
+
+#[test]
+fn any() {
+    let mut context = Context::default();
+    let init = r#"
+        var fulfilled, rejected;
+        Promise.any([Promise.reject('no'), Promise.resolve(2), Promise.resolve(3)])
+            .then((value) => { fulfilled = value; });
+        Promise.any([Promise.reject('a'), Promise.reject('b')])
+            .catch((error) => { rejected = error; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "fulfilled"), "2");
+    assert_eq!(
+        forward(&mut context, "rejected instanceof AggregateError"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "rejected.errors.join()"), "\"a,b\"");
+}
+
+#[test]
+fn any_empty() {
+    let mut context = Context::default();
+    let init = r#"
+        var rejected;
+        Promise.any([]).catch((error) => { rejected = error; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "rejected instanceof AggregateError"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "rejected.errors.length"), "0");
+}
+
+#[test]
+fn race() {
+    let mut context = Context::default();
+    let init = r#"
+        var fulfilled, rejected;
+        Promise.race([new Promise(() => {}), Promise.resolve(1), Promise.reject('no')])
+            .then((value) => { fulfilled = value; });
+        Promise.race([Promise.reject('no'), Promise.resolve(2)])
+            .catch((reason) => { rejected = reason; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "fulfilled"), "1");
+    assert_eq!(forward(&mut context, "rejected"), "\"no\"");
+}
+
+#[test]
+fn combinators_reject_non_iterable() {
+    let mut context = Context::default();
+    let init = r#"
+        var errors = [];
+        for (const name of ['all', 'allSettled', 'any', 'race']) {
+            Promise[name](1).catch((e) => { errors.push(e instanceof TypeError); });
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "errors.join()"),
+        "\"true,true,true,true\""
+    );
+}
+
+#[test]
+fn unhandled_rejections() {
+    let mut context = Context::default();
+    let init = r#"
+        Promise.reject(1);
+        new Promise((resolve, reject) => reject(2)).then(() => {});
+        Promise.reject(3).catch(() => {});
+        async function f() { throw 4; }
+        f();
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+
+    // The promise returned by `then` is rejected without a handler, but the promise it was
+    // called on is handled.
+    let rejections: Vec<_> = context
+        .take_unhandled_rejections()
+        .iter()
+        .map(|reason| reason.display().to_string())
+        .collect();
+    assert_eq!(rejections, ["1", "4", "2"]);
+    assert!(context.take_unhandled_rejections().is_empty());
+}
+
+#[test]
+fn late_rejection_handler() {
+    let mut context = Context::default();
+    let init = r#"
+        var rejected = Promise.reject(new Error("handled later"));
+        Promise.resolve().then(() => {
+            rejected.catch(() => {});
+        });
+        "#;
+    forward(&mut context, init);
+    context.run_jobs().unwrap();
+
+    assert!(context.take_unhandled_rejections().is_empty());
+}
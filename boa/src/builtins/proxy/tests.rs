@@ -0,0 +1,135 @@
+use crate::{check_output, forward, Context, TestAction};
+
+#[test]
+fn delete_property_trap() {
+    let mut context = Context::default();
+    let init = r#"
+        var log = [];
+        var proxy = new Proxy({ x: 1 }, {
+            deleteProperty(target, key) {
+                log.push(key);
+                return key !== "x";
+            }
+        });
+        var deleted_x = delete proxy.x;
+        var deleted_y = delete proxy["y"];
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "deleted_x"), "false");
+    assert_eq!(forward(&mut context, "deleted_y"), "true");
+    assert_eq!(forward(&mut context, "log.join()"), "\"x,y\"");
+    assert_eq!(
+        forward(&mut context, "Reflect.deleteProperty(proxy, 'x')"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "log.length"), "3");
+}
+
+#[test]
+fn delete_property_trap_errors() {
+    let scenario = r#"
+        (function () {
+            "use strict";
+            var proxy = new Proxy({}, { deleteProperty() { return false; } });
+            delete proxy.x;
+        })();
+    "#;
+
+    // A trap cannot report a non-configurable property as deleted.
+    let invariant = r#"
+        var target = Object.defineProperty({}, "x", { value: 1 });
+        var proxy = new Proxy(target, { deleteProperty() { return true; } });
+        delete proxy.x;
+    "#;
+
+    check_output(&[
+        TestAction::TestStartsWith(scenario, "Uncaught \"TypeError\": "),
+        TestAction::TestStartsWith(invariant, "Uncaught \"TypeError\": "),
+    ]);
+}
+
+#[test]
+fn set_trap_arguments() {
+    let mut context = Context::default();
+    let init = r#"
+        var target = {};
+        var args;
+        var proxy = new Proxy(target, {
+            set(...trap_args) {
+                args = trap_args;
+                return Reflect.set(...trap_args);
+            }
+        });
+        proxy.x = 1;
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "args.length"), "4");
+    assert_eq!(forward(&mut context, "args[0] === target"), "true");
+    assert_eq!(forward(&mut context, "args[1]"), "\"x\"");
+    assert_eq!(forward(&mut context, "args[2]"), "1");
+    assert_eq!(forward(&mut context, "args[3] === proxy"), "true");
+    assert_eq!(forward(&mut context, "target.x"), "1");
+}
+
+#[test]
+fn has_trap() {
+    let mut context = Context::default();
+    let init = r#"
+        var log = [];
+        var proxy = new Proxy({}, {
+            has(target, key) {
+                log.push(typeof key === "symbol" ? key.toString() : key);
+                return true;
+            }
+        });
+        var child = Object.create(proxy);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "'anything' in proxy"), "true");
+    assert_eq!(forward(&mut context, "42 in proxy"), "true");
+    assert_eq!(forward(&mut context, "Symbol.iterator in proxy"), "true");
+    // `in` looks up the prototype chain through `[[HasProperty]]` of the prototype.
+    assert_eq!(forward(&mut context, "'inherited' in child"), "true");
+    assert_eq!(forward(&mut context, "Reflect.has(proxy, 'x')"), "true");
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"anything,42,Symbol(Symbol.iterator),inherited,x\""
+    );
+
+    // A trap cannot report a non-configurable property as missing.
+    let invariant = r#"
+        var target = Object.defineProperty({}, "x", { value: 1 });
+        "x" in new Proxy(target, { has() { return false; } });
+    "#;
+    check_output(&[TestAction::TestStartsWith(
+        invariant,
+        "Uncaught \"TypeError\": ",
+    )]);
+}
+
+#[test]
+fn for_in_traps() {
+    let mut context = Context::default();
+    let init = r#"
+        var log = [];
+        var proto = new Proxy({ inherited: 1 }, {
+            ownKeys(target) {
+                log.push("ownKeys");
+                return Reflect.ownKeys(target);
+            },
+            getOwnPropertyDescriptor(target, key) {
+                log.push(key);
+                return Reflect.getOwnPropertyDescriptor(target, key);
+            }
+        });
+        var object = Object.create(proto);
+        object.own = 1;
+        var keys = [];
+        for (var key in object) {
+            keys.push(key);
+        }
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "keys.join()"), "\"own,inherited\"");
+    assert_eq!(forward(&mut context, "log.join()"), "\"ownKeys,inherited\"");
+}
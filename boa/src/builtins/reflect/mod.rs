@@ -379,15 +379,20 @@ impl Reflect {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
+        // 1. If Type(target) is not Object, throw a TypeError exception.
         let target = args
             .get(0)
             .and_then(JsValue::as_object)
             .ok_or_else(|| context.construct_type_error("target must be an object"))?;
+        // 2. If Type(proto) is not Object and proto is not null, throw a TypeError exception.
         let proto = match args.get_or_undefined(1) {
             JsValue::Object(obj) => Some(obj.clone()),
             JsValue::Null => None,
             _ => return context.throw_type_error("proto must be an object or null"),
         };
+        // 3. Return ? target.[[SetPrototypeOf]](proto).
+        //
+        // Unlike `Object.setPrototypeOf`, a failure is reported by returning false.
         Ok(target.__set_prototype_of__(proto, context)?.into())
     }
 }
@@ -15,6 +15,62 @@ fn apply() {
     assert_eq!(forward(&mut context, "called.result"), "42");
 }
 
+#[test]
+fn construct_with_new_target() {
+    let mut context = Context::default();
+
+    let init = r#"
+        function F() { this.target = new.target; }
+        function G() {}
+        class A { constructor(x) { this.x = x; this.target = new.target; } }
+        class B extends A {}
+        var f = Reflect.construct(F, [], G);
+        var a = Reflect.construct(A, [1], B);
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(f) === G.prototype"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "f.target === G"), "true");
+    assert_eq!(forward(&mut context, "a instanceof B"), "true");
+    assert_eq!(forward(&mut context, "a.target === B && a.x === 1"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(Reflect.construct(Array, [], G)) === G.prototype"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn construct_type_errors() {
+    let mut context = Context::default();
+
+    let init = r#"
+        function f() {}
+        var errors = [];
+        for (const args of [[() => {}, []], [f, [], () => {}], [f, [], {}], [f, 1], [f]]) {
+            try {
+                Reflect.construct(...args);
+                errors.push(false);
+            } catch (e) {
+                errors.push(e instanceof TypeError);
+            }
+        }
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "errors.join()"),
+        "\"true,true,true,true,true\""
+    );
+}
+
 #[test]
 fn construct() {
     let mut context = Context::default();
@@ -189,3 +245,46 @@ fn set_prototype_of() {
 
     assert_eq!(forward(&mut context, "p.name"), "\"F\"");
 }
+
+#[test]
+fn set_prototype_of_failure() {
+    let mut context = Context::default();
+
+    let init = r#"
+        let frozen = Object.preventExtensions({});
+        let parent = {};
+        let child = Object.create(parent);
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Reflect.setPrototypeOf(frozen, {})"),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(frozen) === Object.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Reflect.setPrototypeOf(frozen, Object.prototype)"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Reflect.setPrototypeOf(parent, child)"),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Object.setPrototypeOf(frozen, {}); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
@@ -21,7 +21,7 @@ use crate::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
         JsObject, ObjectData,
     },
-    property::Attribute,
+    property::{Attribute, PropertyDescriptor},
     symbol::WellKnownSymbols,
     syntax::lexer::regex::RegExpFlags,
     value::{IntegerOrInfinity, JsValue},
@@ -57,6 +57,8 @@ impl BuiltIn for RegExp {
         .union(Attribute::NON_ENUMERABLE)
         .union(Attribute::CONFIGURABLE);
 
+    const LAZY: bool = true;
+
     fn init(context: &mut Context) -> JsValue {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
@@ -67,6 +69,10 @@ impl BuiltIn for RegExp {
 
         let flag_attributes = Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE;
 
+        let get_has_indices = FunctionBuilder::native(context, Self::get_has_indices)
+            .name("get hasIndices")
+            .constructor(false)
+            .build();
         let get_global = FunctionBuilder::native(context, Self::get_global)
             .name("get global")
             .constructor(false)
@@ -112,7 +118,6 @@ impl BuiltIn for RegExp {
             None,
             Attribute::CONFIGURABLE,
         )
-        .property("lastIndex", 0, Attribute::all())
         .method(Self::test, "test", 1)
         .method(Self::exec, "exec", 1)
         .method(Self::to_string, "toString", 0)
@@ -141,6 +146,7 @@ impl BuiltIn for RegExp {
             (WellKnownSymbols::split(), "[Symbol.split]"),
             2,
         )
+        .accessor("hasIndices", Some(get_has_indices), None, flag_attributes)
         .accessor("global", Some(get_global), None, flag_attributes)
         .accessor("ignoreCase", Some(get_ignore_case), None, flag_attributes)
         .accessor("multiline", Some(get_multiline), None, flag_attributes)
@@ -237,9 +243,27 @@ impl RegExp {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-regexpalloc
     fn alloc(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // `%RegExp.prototype%` is only set up once the `RegExp` builtin is initialized.
+        context.initialize_lazy_global(Self::NAME);
+
+        // 1. Let obj be ? OrdinaryCreateFromConstructor(newTarget, "%RegExp.prototype%", « [[RegExpMatcher]], [[OriginalSource]], [[OriginalFlags]] »).
         let proto = get_prototype_from_constructor(this, StandardObjects::regexp_object, context)?;
+        let obj = JsObject::from_proto_and_data(proto, ObjectData::ordinary());
+
+        // 2. Perform ! DefinePropertyOrThrow(obj, "lastIndex", PropertyDescriptor { [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: false }).
+        obj.define_property_or_throw(
+            "lastIndex",
+            PropertyDescriptor::builder()
+                .writable(true)
+                .enumerable(false)
+                .configurable(false)
+                .build(),
+            context,
+        )
+        .expect("this DefinePropertyOrThrow call must not fail");
 
-        Ok(JsObject::from_proto_and_data(proto, ObjectData::ordinary()).into())
+        // 3. Return obj.
+        Ok(obj.into())
     }
 
     /// `22.2.3.2.2 RegExpInitialize ( obj, pattern, flags )`
@@ -268,7 +292,7 @@ impl RegExp {
             flags.to_string(context)?
         };
 
-        // 5. If F contains any code unit other than "g", "i", "m", "s", "u", or "y"
+        // 5. If F contains any code unit other than "d", "g", "i", "m", "s", "u", or "y"
         //    or if it contains the same code unit more than once, throw a SyntaxError exception.
         let flags = match RegExpFlags::from_str(&f) {
             Err(msg) => return context.throw_syntax_error(msg),
@@ -295,6 +319,9 @@ impl RegExp {
 
         this.set_data(ObjectData::reg_exp(Box::new(regexp)));
 
+        // 15. Perform ? Set(obj, "lastIndex", +0𝔽, true).
+        this.set_field("lastIndex", 0, true, context)?;
+
         // 16. Return obj.
         Ok(this.clone())
     }
@@ -338,6 +365,7 @@ impl RegExp {
         if let Some(object) = this.as_object() {
             if let Some(regexp) = object.borrow().as_regexp() {
                 return Ok(JsValue::new(match flag {
+                    b'd' => regexp.flags.contains(RegExpFlags::HAS_INDICES),
                     b'g' => regexp.flags.contains(RegExpFlags::GLOBAL),
                     b'm' => regexp.flags.contains(RegExpFlags::MULTILINE),
                     b's' => regexp.flags.contains(RegExpFlags::DOT_ALL),
@@ -357,6 +385,7 @@ impl RegExp {
         }
 
         let name = match flag {
+            b'd' => "hasIndices",
             b'g' => "global",
             b'm' => "multiline",
             b's' => "dotAll",
@@ -371,6 +400,24 @@ impl RegExp {
         ))
     }
 
+    /// `get RegExp.prototype.hasIndices`
+    ///
+    /// The `hasIndices` property indicates whether or not the "`d`" flag is used with the regular expression.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.hasIndices
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/hasIndices
+    pub(crate) fn get_has_indices(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::regexp_has_flag(this, b'd', context)
+    }
+
     /// `get RegExp.prototype.global`
     ///
     /// The `global` property indicates whether or not the "`g`" flag is used with the regular expression.
@@ -502,41 +549,46 @@ impl RegExp {
         if let Some(object) = this.as_object() {
             // 3. Let result be the empty String.
             let mut result = String::new();
-            // 4. Let global be ! ToBoolean(? Get(R, "global")).
-            // 5. If global is true, append the code unit 0x0067 (LATIN SMALL LETTER G) as the last code unit of result.
+            // 4. Let hasIndices be ToBoolean(? Get(R, "hasIndices")).
+            // 5. If hasIndices is true, append the code unit 0x0064 (LATIN SMALL LETTER D) as the last code unit of result.
+            if object.get("hasIndices", context)?.to_boolean() {
+                result.push('d');
+            }
+            // 6. Let global be ! ToBoolean(? Get(R, "global")).
+            // 7. If global is true, append the code unit 0x0067 (LATIN SMALL LETTER G) as the last code unit of result.
             if object.get("global", context)?.to_boolean() {
                 result.push('g');
             }
-            // 6. Let ignoreCase be ! ToBoolean(? Get(R, "ignoreCase")).
-            // 7. If ignoreCase is true, append the code unit 0x0069 (LATIN SMALL LETTER I) as the last code unit of result.
+            // 8. Let ignoreCase be ! ToBoolean(? Get(R, "ignoreCase")).
+            // 9. If ignoreCase is true, append the code unit 0x0069 (LATIN SMALL LETTER I) as the last code unit of result.
             if object.get("ignoreCase", context)?.to_boolean() {
                 result.push('i');
             }
 
-            // 8. Let multiline be ! ToBoolean(? Get(R, "multiline")).
-            // 9. If multiline is true, append the code unit 0x006D (LATIN SMALL LETTER M) as the last code unit of result.
+            // 10. Let multiline be ! ToBoolean(? Get(R, "multiline")).
+            // 11. If multiline is true, append the code unit 0x006D (LATIN SMALL LETTER M) as the last code unit of result.
             if object.get("multiline", context)?.to_boolean() {
                 result.push('m');
             }
 
-            // 10. Let dotAll be ! ToBoolean(? Get(R, "dotAll")).
-            // 11. If dotAll is true, append the code unit 0x0073 (LATIN SMALL LETTER S) as the last code unit of result.
+            // 12. Let dotAll be ! ToBoolean(? Get(R, "dotAll")).
+            // 13. If dotAll is true, append the code unit 0x0073 (LATIN SMALL LETTER S) as the last code unit of result.
             if object.get("dotAll", context)?.to_boolean() {
                 result.push('s');
             }
-            // 12. Let unicode be ! ToBoolean(? Get(R, "unicode")).
-            // 13. If unicode is true, append the code unit 0x0075 (LATIN SMALL LETTER U) as the last code unit of result.
+            // 14. Let unicode be ! ToBoolean(? Get(R, "unicode")).
+            // 15. If unicode is true, append the code unit 0x0075 (LATIN SMALL LETTER U) as the last code unit of result.
             if object.get("unicode", context)?.to_boolean() {
                 result.push('u');
             }
 
-            // 14. Let sticky be ! ToBoolean(? Get(R, "sticky")).
-            // 15. If sticky is true, append the code unit 0x0079 (LATIN SMALL LETTER Y) as the last code unit of result.
+            // 16. Let sticky be ! ToBoolean(? Get(R, "sticky")).
+            // 17. If sticky is true, append the code unit 0x0079 (LATIN SMALL LETTER Y) as the last code unit of result.
             if object.get("sticky", context)?.to_boolean() {
                 result.push('y');
             }
 
-            // 16. Return result.
+            // 18. Return result.
             return Ok(result.into());
         }
 
@@ -789,6 +841,9 @@ impl RegExp {
         // 10. If flags contains "u", let fullUnicode be true; else let fullUnicode be false.
         let unicode = flags.contains('u');
 
+        // If flags contains "d", let hasIndices be true; else let hasIndices be false.
+        let has_indices = flags.contains('d');
+
         // 11. Let matchSucceeded be false.
         // 12. Repeat, while matchSucceeded is false,
         let match_value = loop {
@@ -916,18 +971,15 @@ impl RegExp {
             // i. Let s be the CapturingGroupName of the corresponding RegExpIdentifierName.
             // ii. Perform ! CreateDataPropertyOrThrow(groups, s, capturedValue).
             for (name, range) in named_groups {
-                if let Some(range) = range {
-                    let value = if let Some(s) = input.get(range.clone()) {
-                        s
-                    } else {
-                        ""
-                    };
-
-                    groups
-                        .to_object(context)?
-                        .create_data_property_or_throw(name, value, context)
-                        .expect("this CreateDataPropertyOrThrow call must not fail");
-                }
+                let value = match range {
+                    Some(range) => input.get(range).unwrap_or_default().into(),
+                    None => JsValue::undefined(),
+                };
+
+                groups
+                    .to_object(context)?
+                    .create_data_property_or_throw(name, value, context)
+                    .expect("this CreateDataPropertyOrThrow call must not fail");
             }
             groups
         } else {
@@ -963,10 +1015,87 @@ impl RegExp {
                 .expect("this CreateDataPropertyOrThrow call must not fail");
         }
 
+        // If hasIndices is true, then
+        if has_indices {
+            // a. Let indicesArray be MakeMatchIndicesIndexPairArray(S, indices, groupNames, hasGroups).
+            let indices_array =
+                Self::make_match_indices_index_pair_array(input, &match_value, context);
+
+            // b. Perform ! CreateDataPropertyOrThrow(A, "indices", indicesArray).
+            a.create_data_property_or_throw("indices", indices_array, context)
+                .expect("this CreateDataPropertyOrThrow call must not fail");
+        }
+
         // 28. Return A.
         Ok(Some(a))
     }
 
+    /// `MakeMatchIndicesIndexPairArray ( S, indices, groupNames, hasGroups )`
+    ///
+    /// Creates the `indices` array of a match result, holding the `[start, end]` pair of each
+    /// capture in UTF-16 code units, or `undefined` for captures that didn't participate.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-makematchindicesindexpairarray
+    fn make_match_indices_index_pair_array(
+        input: &JsString,
+        match_value: &regress::Match,
+        context: &mut Context,
+    ) -> JsObject {
+        // GetMatchIndexPair ( S, match )
+        let get_match_index_pair = |range: Option<regress::Range>, context: &mut Context| {
+            range.map_or_else(JsValue::undefined, |range| {
+                let start = input[..range.start].encode_utf16().count();
+                let end = start + input[range].encode_utf16().count();
+                Array::create_array_from_list([start.into(), end.into()], context).into()
+            })
+        };
+
+        // 1. Let n be the number of elements in indices.
+        let n = match_value.captures.len() + 1;
+
+        // 3. Let A be ! ArrayCreate(n).
+        // 6. For each integer i such that i ≥ 0 and i < n, in ascending order, do
+        //   a. Let matchIndices be indices[i].
+        //   b. If matchIndices is not undefined, let matchIndexPair be GetMatchIndexPair(S, matchIndices).
+        //   c. Else, let matchIndexPair be undefined.
+        //   d. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(i)), matchIndexPair).
+        let pairs: Vec<_> = (0..n)
+            .map(|i| get_match_index_pair(match_value.group(i), context))
+            .collect();
+        let a = Array::create_array_from_list(pairs, context);
+
+        // 4. If hasGroups is true, then
+        //   a. Let groups be OrdinaryObjectCreate(null).
+        // 5. Else,
+        //   a. Let groups be undefined.
+        let named_groups = match_value.named_groups();
+        let groups = if named_groups.clone().count() > 0 {
+            let groups = JsObject::empty();
+
+            //   e. If i > 0 and groupNames[i - 1] is not undefined, then
+            //     i. Perform ! CreateDataPropertyOrThrow(groups, groupNames[i - 1], matchIndexPair).
+            for (name, range) in named_groups {
+                let pair = get_match_index_pair(range, context);
+                groups
+                    .create_data_property_or_throw(name, pair, context)
+                    .expect("this CreateDataPropertyOrThrow call must not fail");
+            }
+            groups.into()
+        } else {
+            JsValue::undefined()
+        };
+
+        // 5. Perform ! CreateDataPropertyOrThrow(A, "groups", groups).
+        a.create_data_property_or_throw("groups", groups, context)
+            .expect("this CreateDataPropertyOrThrow call must not fail");
+
+        // 7. Return A.
+        a
+    }
+
     /// `RegExp.prototype[ @@match ]( string )`
     ///
     /// This method retrieves the matches when matching a string against a regular expression.
@@ -102,6 +102,40 @@ fn last_index() {
     assert_eq!(forward(&mut context, "regex.lastIndex"), "0");
 }
 
+#[test]
+fn last_index_own_property() {
+    let mut context = Context::default();
+    let init = r#"
+        var regex = /a/g;
+        var desc = Object.getOwnPropertyDescriptor(regex, "lastIndex");
+        "#;
+
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "desc.value"), "0");
+    assert_eq!(forward(&mut context, "desc.writable"), "true");
+    assert_eq!(forward(&mut context, "desc.enumerable"), "false");
+    assert_eq!(forward(&mut context, "desc.configurable"), "false");
+    assert_eq!(
+        forward(&mut context, "RegExp.prototype.hasOwnProperty('lastIndex')"),
+        "false"
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "regex.lastIndex = 2; regex.exec('aaaa').index"
+        ),
+        "2"
+    );
+    assert_eq!(forward(&mut context, "regex.lastIndex"), "3");
+    assert_eq!(
+        forward(&mut context, "regex.lastIndex = 10; regex.test('aaaa')"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "regex.lastIndex"), "0");
+    assert_eq!(forward(&mut context, "delete regex.lastIndex"), "false");
+}
+
 #[test]
 fn exec() {
     let mut context = Context::default();
@@ -237,3 +271,103 @@ fn search() {
     assert_eq!(forward(&mut context, "/u/[Symbol.search](null)"), "1");
     assert_eq!(forward(&mut context, "/d/[Symbol.search](undefined)"), "2");
 }
+
+#[test]
+fn character_class_escapes() {
+    let mut context = Context::default();
+
+    assert_eq!(forward(&mut context, "/\\d+/.exec('ab123c')[0]"), "\"123\"");
+    assert_eq!(forward(&mut context, "/\\D+/.exec('12ab3')[0]"), "\"ab\"");
+    assert_eq!(
+        forward(&mut context, "/\\w+/.exec('  foo_1! ')[0]"),
+        "\"foo_1\""
+    );
+    assert_eq!(forward(&mut context, "/\\W/.test('abc')"), "false");
+    assert_eq!(forward(&mut context, "/\\s/.test('a\\u00A0b')"), "true");
+    assert_eq!(
+        forward(&mut context, "/\\S+/.exec(' \\tfoo ')[0]"),
+        "\"foo\""
+    );
+    assert_eq!(forward(&mut context, "/\\bfoo\\b/.test('a foo b')"), "true");
+    assert_eq!(forward(&mut context, "/\\bfoo\\b/.test('afoob')"), "false");
+    assert_eq!(forward(&mut context, "/\\Boo\\B/.test('afoob')"), "true");
+}
+
+#[test]
+fn unicode_property_escapes() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(&mut context, "/\\p{Letter}+/u.exec('123abcé!')[0]"),
+        "\"abcé\""
+    );
+    assert_eq!(forward(&mut context, "/\\p{L}/u.test('1')"), "false");
+    assert_eq!(forward(&mut context, "/\\P{L}/u.test('1')"), "true");
+    assert_eq!(forward(&mut context, "/\\p{Lu}/u.test('a')"), "false");
+    assert_eq!(
+        forward(&mut context, "/\\p{Script=Greek}/u.test('α')"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "/^\\p{Emoji}$/u.test('😀')"), "true");
+    assert_eq!(
+        forward(&mut context, "/[\\p{N}_]+/u.exec('a1_2b')[0]"),
+        "\"1_2\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new RegExp('\\\\p{Foo}', 'u') } catch (e) { e instanceof SyntaxError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn named_capture_groups() {
+    let mut context = Context::default();
+    let init = r#"
+        var m = /(?<year>\d{4})-(?<month>\d{2})(?:-(?<day>\d{2}))?/.exec("on 2021-03");
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "m.groups.year"), "\"2021\"");
+    assert_eq!(forward(&mut context, "m.groups.month"), "\"03\"");
+    assert_eq!(forward(&mut context, "'day' in m.groups"), "true");
+    assert_eq!(forward(&mut context, "m.groups.day"), "undefined");
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(m.groups)"),
+        "null"
+    );
+    assert_eq!(forward(&mut context, "/(a)/.exec('a').groups"), "undefined");
+}
+
+#[test]
+fn has_indices() {
+    let mut context = Context::default();
+    let init = r#"
+        var re = /(?<year>\d{4})(-(?<month>\d{2}))?/d;
+        var m = re.exec("in 2021!");
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "re.hasIndices"), "true");
+    assert_eq!(forward(&mut context, "re.flags"), "\"d\"");
+    assert_eq!(forward(&mut context, "/a/g.hasIndices"), "false");
+    assert_eq!(forward(&mut context, "/a/.exec('a').indices"), "undefined");
+
+    assert_eq!(forward(&mut context, "m.groups.year"), "\"2021\"");
+    assert_eq!(forward(&mut context, "m.indices.length"), "4");
+    assert_eq!(forward(&mut context, "m.indices[0].join()"), "\"3,7\"");
+    assert_eq!(forward(&mut context, "m.indices[1].join()"), "\"3,7\"");
+    assert_eq!(forward(&mut context, "m.indices[2]"), "undefined");
+    assert_eq!(
+        forward(&mut context, "m.indices.groups.year.join()"),
+        "\"3,7\""
+    );
+    assert_eq!(forward(&mut context, "'month' in m.indices.groups"), "true");
+    assert_eq!(forward(&mut context, "m.indices.groups.month"), "undefined");
+    assert_eq!(
+        forward(&mut context, "/(a)/d.exec('a').indices.groups"),
+        "undefined"
+    );
+}
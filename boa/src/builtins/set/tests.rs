@@ -246,3 +246,17 @@ fn not_a_function() {
         "\"TypeError: calling a builtin Set constructor without new is forbidden\""
     );
 }
+
+#[test]
+fn same_value_zero_values() {
+    let mut context = Context::default();
+    let init = r#"
+        let set = new Set([0, NaN]);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "set.has(-0)"), "true");
+    assert_eq!(forward(&mut context, "set.has(0 / 0)"), "true");
+    assert_eq!(forward(&mut context, "set.add(-0).add(NaN).size"), "2");
+    assert_eq!(forward(&mut context, "set.delete(-0)"), "true");
+    assert_eq!(forward(&mut context, "set.size"), "1");
+}
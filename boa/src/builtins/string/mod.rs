@@ -118,6 +118,7 @@ impl BuiltIn for String {
         .method(Self::includes, "includes", 1)
         .method(Self::index_of, "indexOf", 1)
         .method(Self::last_index_of, "lastIndexOf", 1)
+        .method(Self::locale_compare, "localeCompare", 1)
         .method(Self::r#match, "match", 1)
         .method(Self::normalize, "normalize", 1)
         .method(Self::pad_end, "padEnd", 1)
@@ -271,7 +272,7 @@ impl String {
         Ok(result.into())
     }
 
-    /// `String.prototype.raw( template, ...substitutions )`
+    /// `String.raw( template, ...substitutions )`
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -1252,6 +1253,72 @@ impl String {
         Ok(JsValue::new(-1))
     }
 
+    /// `String.prototype.localeCompare( that [, locales [, options ] ] )`
+    ///
+    /// The `localeCompare()` method returns a number indicating whether a reference string comes before,
+    /// or after, or is the same as the given string in sort order.
+    ///
+    /// Boa does not implement `Intl`, so full locale-aware collation is not supported and the `locales`
+    /// argument is ignored. Strings are compared by their Unicode code points, which gives the same
+    /// ordering in every locale. The `sensitivity` property of `options` is honoured through a simple
+    /// folding of case and diacritics.
+    ///
+    /// Returns `-1`, `0` or `1`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.localecompare
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/localeCompare
+    pub(crate) fn locale_compare(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let this = this.require_object_coercible(context)?;
+        // 2. Let S be ? ToString(O).
+        let string = this.to_string(context)?;
+
+        // 3. Let thatValue be ? ToString(that).
+        let that = args.get_or_undefined(0).to_string(context)?;
+
+        let (ignore_case, ignore_accents) = match args.get_or_undefined(2).as_object() {
+            Some(options) => {
+                let sensitivity = options.get("sensitivity", context)?;
+                if sensitivity.is_undefined() {
+                    (false, false)
+                } else {
+                    match sensitivity.to_string(context)?.as_str() {
+                        "base" => (true, true),
+                        "accent" => (true, false),
+                        "case" => (false, true),
+                        "variant" => (false, false),
+                        _ => {
+                            return context.throw_range_error(
+                                "sensitivity must be one of base, accent, case or variant",
+                            )
+                        }
+                    }
+                }
+            }
+            None => (false, false),
+        };
+
+        let ordering = if ignore_case || ignore_accents {
+            let string = fold_for_collation(&string, ignore_case, ignore_accents);
+            let that = fold_for_collation(&that, ignore_case, ignore_accents);
+            string.cmp(&that)
+        } else {
+            // `chars` yields whole code points, so surrogate pairs compare as the code point they encode
+            // instead of by their individual code units.
+            string.chars().cmp(that.chars())
+        };
+
+        Ok(JsValue::new(ordering as i32))
+    }
+
     /// `String.prototype.match( regexp )`
     ///
     /// The `match()` method retrieves the result of matching a **string** against a [`regular expression`][regex].
@@ -1932,6 +1999,39 @@ impl String {
     }
 }
 
+/// Returns `true` if `c` belongs to one of the combining diacritical marks blocks.
+fn is_combining_diacritic(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+    )
+}
+
+/// Folds a string for the comparisons done by `String.prototype.localeCompare`.
+///
+/// Diacritics are removed by decomposing the string and dropping the combining marks, and case is
+/// removed by lowercasing every code point.
+fn fold_for_collation(string: &str, ignore_case: bool, ignore_accents: bool) -> StdString {
+    let folded: StdString = if ignore_accents {
+        string
+            .nfd()
+            .filter(|c| !is_combining_diacritic(*c))
+            .collect()
+    } else {
+        string.to_owned()
+    };
+
+    if ignore_case {
+        folded.to_lowercase()
+    } else {
+        folded
+    }
+}
+
 /// `22.1.3.17.1 GetSubstitution ( matched, str, position, captures, namedCaptures, replacement )`
 ///
 /// More information:
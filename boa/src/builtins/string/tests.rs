@@ -1,4 +1,4 @@
-use crate::{forward, forward_val, Context};
+use crate::{check_output, forward, forward_val, Context, TestAction};
 
 #[test]
 fn length() {
@@ -688,6 +688,18 @@ fn split() {
     );
 }
 
+#[test]
+fn split_empty_separator_splits_code_units() {
+    // Astral characters are split into their two surrogate code units.
+    check_output(&[
+        TestAction::TestEq("'😀'.split('').length", "2"),
+        TestAction::TestEq("'😀'.split('').every((s) => s.length === 1)", "true"),
+        TestAction::TestEq("'a😀b'.split('').length", "4"),
+        TestAction::TestEq("'a😀b'.split('', 2).length", "2"),
+        TestAction::TestEq("'a😀b'.split('')[3]", "\"b\""),
+    ]);
+}
+
 #[test]
 fn split_with_symbol_split_method() {
     assert_eq!(
@@ -1147,3 +1159,214 @@ fn search() {
     assert_eq!(forward(&mut context, "'aa'.search(/a/g)"), "0");
     assert_eq!(forward(&mut context, "'ba'.search(/a/)"), "1");
 }
+
+#[test]
+fn normalize() {
+    let mut context = Context::default();
+
+    // "Å" as a single precomposed code point vs "A" followed by a combining ring above.
+    forward(&mut context, "const composed = '\\u00C5'");
+    forward(&mut context, "const decomposed = 'A\\u030A'");
+
+    assert_eq!(
+        forward(&mut context, "composed.normalize('NFD') === decomposed"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "decomposed.normalize('NFC') === composed"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "decomposed.normalize() === composed"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "composed.normalize('NFD').length"),
+        "2"
+    );
+    assert_eq!(
+        forward(&mut context, "decomposed.normalize('NFC').length"),
+        "1"
+    );
+
+    // Compatibility decomposition of the "ﬁ" ligature.
+    assert_eq!(
+        forward(&mut context, "'\\uFB01'.normalize('NFKD')"),
+        "\"fi\""
+    );
+    assert_eq!(
+        forward(&mut context, "'\\uFB01'.normalize('NFKC')"),
+        "\"fi\""
+    );
+    assert_eq!(forward(&mut context, "'\\uFB01'.normalize('NFD')"), "\"ﬁ\"");
+
+    // Astral code points survive the round trip.
+    assert_eq!(
+        forward(&mut context, "'\\uD83D\\uDE00'.normalize('NFD') === '😀'"),
+        "true"
+    );
+}
+
+#[test]
+fn normalize_invalid_form() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { 'a'.normalize('nfc') } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { 'a'.normalize(null) } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn locale_compare() {
+    let mut context = Context::default();
+
+    assert_eq!(forward(&mut context, "'a'.localeCompare('b')"), "-1");
+    assert_eq!(forward(&mut context, "'b'.localeCompare('a')"), "1");
+    assert_eq!(forward(&mut context, "'a'.localeCompare('a')"), "0");
+    assert_eq!(forward(&mut context, "'a'.localeCompare('ab')"), "-1");
+    assert_eq!(forward(&mut context, "''.localeCompare('')"), "0");
+    assert_eq!(forward(&mut context, "'a'.localeCompare('A')"), "1");
+
+    // Astral code points sort after every code point of the Basic Multilingual Plane.
+    assert_eq!(
+        forward(&mut context, "'\\uD83D\\uDE00'.localeCompare('\\uFFFF')"),
+        "1"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "['c', 'b', 'a'].sort((x, y) => x.localeCompare(y)).join()"
+        ),
+        "\"a,b,c\""
+    );
+}
+
+#[test]
+fn locale_compare_base_sensitivity() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "'a'.localeCompare('A', undefined, { sensitivity: 'base' })"
+        ),
+        "0"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "'r\\u00E9sum\\u00E9'.localeCompare('Resume', undefined, { sensitivity: 'base' })"
+        ),
+        "0"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "'\\u00E9'.localeCompare('f', undefined, { sensitivity: 'base' })"
+        ),
+        "-1"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "'a'.localeCompare('b', undefined, { sensitivity: 'base' })"
+        ),
+        "-1"
+    );
+    assert_eq!(forward(&mut context, "'\\u00E9'.localeCompare('e')"), "1");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { 'a'.localeCompare('b', undefined, { sensitivity: 'none' }) } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn raw_as_template_tag() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(&mut context, "String.raw`a\\n${1 + 1}b${'x'}c`"),
+        "\"a\\n2bxc\""
+    );
+    assert_eq!(forward(&mut context, "String.raw``"), "\"\"");
+    assert_eq!(forward(&mut context, "String.raw`\\u{41}${0}`.length"), "7");
+}
+
+#[test]
+fn raw_with_array_like() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "String.raw({ raw: ['x', 'y', 'z'] }, 0, 1, 2, 3)"
+        ),
+        "\"x0y1z\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "String.raw({ raw: { length: 2, 0: 'a', 1: 'b' } }, { toString() { return '-' } })"
+        ),
+        "\"a-b\""
+    );
+    assert_eq!(
+        forward(&mut context, "String.raw({ raw: 'abc' }, 1, 2)"),
+        "\"a1b2c\""
+    );
+    assert_eq!(
+        forward(&mut context, "String.raw({ raw: ['a', 'b'] })"),
+        "\"ab\""
+    );
+    assert_eq!(forward(&mut context, "String.raw({ raw: [] }, 1)"), "\"\"");
+    assert_eq!(
+        forward(&mut context, "String.raw({ raw: { length: -1 } }, 1)"),
+        "\"\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { String.raw({}) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn pad_start_and_pad_end() {
+    check_output(&[
+        // The filler is repeated and truncated to fill the string exactly.
+        TestAction::TestEq("'abc'.padStart(10, '12345')", "\"1234512abc\""),
+        TestAction::TestEq("'abc'.padEnd(8, 'xy')", "\"abcxyxyx\""),
+        TestAction::TestEq("'abc'.padStart(6)", "\"   abc\""),
+        TestAction::TestEq("'abc'.padEnd(5, undefined)", "\"abc  \""),
+        TestAction::TestEq("'x'.padStart(3, 5)", "\"55x\""),
+        // Strings that are already long enough are returned as they are.
+        TestAction::TestEq("'abc'.padStart(2, 'z')", "\"abc\""),
+        TestAction::TestEq("'abc'.padEnd(-1, 'z')", "\"abc\""),
+        TestAction::TestEq("'abc'.padEnd(NaN, 'z')", "\"abc\""),
+        // An empty filler doesn't pad.
+        TestAction::TestEq("'abc'.padStart(6, '')", "\"abc\""),
+        // The length is counted in UTF-16 code units.
+        TestAction::TestEq("'😀'.padStart(4, '😀')", "\"😀😀\""),
+        TestAction::TestEq("'a'.padEnd(4, '😀').length", "4"),
+        TestAction::TestStartsWith(
+            "String.prototype.padStart.call(null, 3)",
+            "Uncaught \"TypeError\"",
+        ),
+    ]);
+}
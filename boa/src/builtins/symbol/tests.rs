@@ -38,3 +38,48 @@ fn symbol_access() {
         TestAction::TestEq("x['Symbol(Hello)']", "undefined"),
     ]);
 }
+
+#[test]
+fn primitive_symbol_methods() {
+    let init = r#"
+        var sym = Symbol("x");
+        var empty = Symbol();
+        "#;
+    check_output(&[
+        TestAction::Execute(init),
+        TestAction::TestEq("sym.toString()", "\"Symbol(x)\""),
+        TestAction::TestEq("empty.toString()", "\"Symbol()\""),
+        TestAction::TestEq("sym.description", "\"x\""),
+        TestAction::TestEq("empty.description", "undefined"),
+        TestAction::TestEq("sym.valueOf() === sym", "true"),
+        TestAction::TestEq("Symbol.prototype.toString.call(sym)", "\"Symbol(x)\""),
+        TestAction::TestEq("Object(sym).toString()", "\"Symbol(x)\""),
+    ]);
+}
+
+#[test]
+fn symbol_keyed_member_expressions() {
+    let init = r#"
+        var sym = Symbol("key");
+        var obj = {};
+        obj[sym] = 1;
+        obj[sym] += 2;
+        obj[sym]++;
+        obj[Symbol.iterator] = function* () { yield "a"; yield "b"; };
+        var array = [];
+        array[sym] = "array";
+        "#;
+    check_output(&[
+        TestAction::Execute(init),
+        TestAction::TestEq("obj[sym]", "4"),
+        TestAction::TestEq("[...obj].join()", "\"a,b\""),
+        TestAction::TestEq("Object.keys(obj).length", "0"),
+        TestAction::TestEq("Object.getOwnPropertySymbols(obj).length", "2"),
+        TestAction::TestEq("obj['Symbol(key)']", "undefined"),
+        TestAction::TestEq("array[sym]", "\"array\""),
+        TestAction::TestEq("array.length", "0"),
+        TestAction::TestEq("sym in obj", "true"),
+        TestAction::TestEq("delete obj[sym]", "true"),
+        TestAction::TestEq("sym in obj", "false"),
+    ]);
+}
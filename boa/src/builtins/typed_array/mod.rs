@@ -17,7 +17,7 @@ use crate::{
         array_buffer::{ArrayBuffer, SharedMemoryOrder},
         iterable::iterable_to_list,
         typed_array::integer_indexed_object::{ContentType, IntegerIndexed},
-        Array, ArrayIterator, BuiltIn, JsArgs,
+        ArrayIterator, BuiltIn, JsArgs,
     },
     context::{StandardConstructor, StandardObjects},
     gc::{empty_trace, Finalize, Trace},
@@ -35,6 +35,9 @@ use std::cmp::Ordering;
 
 pub mod integer_indexed_object;
 
+#[cfg(test)]
+mod tests;
+
 macro_rules! typed_array {
     ($ty:ident, $name:literal, $global_object_name:ident) => {
         #[doc = concat!("JavaScript `", $name, "` built-in implementation.")]
@@ -333,6 +336,8 @@ impl TypedArray {
         .method(Self::filter, "filter", 1)
         .method(Self::find, "find", 1)
         .method(Self::findindex, "findIndex", 1)
+        .method(Self::find_last, "findLast", 1)
+        .method(Self::find_last_index, "findLastIndex", 1)
         .method(Self::foreach, "forEach", 1)
         .method(Self::includes, "includes", 1)
         .method(Self::index_of, "indexOf", 1)
@@ -349,10 +354,6 @@ impl TypedArray {
         .method(Self::sort, "sort", 1)
         .method(Self::subarray, "subarray", 2)
         .method(Self::values, "values", 0)
-        // 23.2.3.29 %TypedArray%.prototype.toString ( )
-        // The initial value of the %TypedArray%.prototype.toString data property is the same
-        // built-in function object as the Array.prototype.toString method defined in 23.1.3.30.
-        .method(Array::to_string, "toString", 0)
         .build();
 
         object
@@ -1190,6 +1191,128 @@ impl TypedArray {
         Ok((-1).into())
     }
 
+    /// `%TypedArray%.prototype.findLast ( predicate [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.findlast
+    fn find_last(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let obj = this
+            .as_object()
+            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj_borrow = obj.borrow();
+        let o = obj_borrow
+            .as_typed_array()
+            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        if o.is_detached() {
+            return context.throw_type_error("Buffer of the typed array is detached");
+        }
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = o.array_length();
+        drop(obj_borrow);
+
+        // 4. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = match args.get_or_undefined(0).as_object() {
+            Some(obj) if obj.is_callable() => obj,
+            _ => {
+                return context.throw_type_error(
+                    "TypedArray.prototype.findLast called with non-callable predicate function",
+                )
+            }
+        };
+
+        // 5. Let k be len - 1.
+        // 6. Repeat, while k ≥ 0,
+        for k in (0..len).rev() {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Let kValue be ! Get(O, Pk).
+            let k_value = obj.get(k, context).expect("Get cannot fail here");
+
+            // c. Let testResult be ! ToBoolean(? Call(predicate, thisArg, « kValue, 𝔽(k), O »)).
+            // d. If testResult is true, return kValue.
+            if predicate
+                .call(
+                    args.get_or_undefined(1),
+                    &[k_value.clone(), k.into(), this.clone()],
+                    context,
+                )?
+                .to_boolean()
+            {
+                return Ok(k_value);
+            }
+            // e. Set k to k - 1.
+        }
+
+        // 7. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `%TypedArray%.prototype.findLastIndex ( predicate [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.findlastindex
+    fn find_last_index(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let obj = this
+            .as_object()
+            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj_borrow = obj.borrow();
+        let o = obj_borrow
+            .as_typed_array()
+            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        if o.is_detached() {
+            return context.throw_type_error("Buffer of the typed array is detached");
+        }
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = o.array_length();
+        drop(obj_borrow);
+
+        // 4. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = match args.get_or_undefined(0).as_object() {
+            Some(obj) if obj.is_callable() => obj,
+            _ => return context.throw_type_error(
+                "TypedArray.prototype.findLastIndex called with non-callable predicate function",
+            ),
+        };
+
+        // 5. Let k be len - 1.
+        // 6. Repeat, while k ≥ 0,
+        for k in (0..len).rev() {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Let kValue be ! Get(O, Pk).
+            let k_value = obj.get(k, context).expect("Get cannot fail here");
+
+            // c. Let testResult be ! ToBoolean(? Call(predicate, thisArg, « kValue, 𝔽(k), O »)).
+            // d. If testResult is true, return 𝔽(k).
+            if predicate
+                .call(
+                    args.get_or_undefined(1),
+                    &[k_value, k.into(), this.clone()],
+                    context,
+                )?
+                .to_boolean()
+            {
+                return Ok(k.into());
+            }
+            // e. Set k to k - 1.
+        }
+
+        // 7. Return -1𝔽.
+        Ok((-1).into())
+    }
+
     /// `23.2.3.13 %TypedArray%.prototype.forEach ( callbackfn [ , thisArg ] )`
     ///
     /// More information:
@@ -0,0 +1,290 @@
+use crate::{check_output, forward, Context, TestAction};
+
+#[test]
+fn construct_from_length_buffer_and_array() {
+    let mut context = Context::default();
+    let init = r#"
+        var from_length = new Int32Array(3);
+        var buffer = new ArrayBuffer(16);
+        var from_buffer = new Float64Array(buffer, 8);
+        var from_array = new Uint8Array([1, 257, -1]);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "from_length.length"), "3");
+    assert_eq!(forward(&mut context, "from_length.byteLength"), "12");
+    assert_eq!(forward(&mut context, "from_buffer.length"), "1");
+    assert_eq!(forward(&mut context, "from_buffer.byteOffset"), "8");
+    assert_eq!(
+        forward(&mut context, "from_buffer.buffer === buffer"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "from_array.join()"), "\"1,1,255\"");
+}
+
+#[test]
+fn element_round_trip() {
+    let mut context = Context::default();
+    let init = r#"
+        var floats = new Float64Array([1.5, -0, NaN, Infinity, -1e300]);
+        var bytes = new Uint8Array(floats.buffer);
+        var copy = new Float64Array(new Uint8Array(bytes).buffer);
+        var ints = new Int32Array([2147483647, -2147483648]);
+        var int_copy = new Int32Array(new Uint8Array(new Uint8Array(ints.buffer)).buffer);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "bytes.length"), "40");
+    assert_eq!(forward(&mut context, "copy[0]"), "1.5");
+    assert_eq!(forward(&mut context, "Object.is(copy[1], -0)"), "true");
+    assert_eq!(forward(&mut context, "Number.isNaN(copy[2])"), "true");
+    assert_eq!(forward(&mut context, "copy[3]"), "Infinity");
+    assert_eq!(forward(&mut context, "copy[4]"), "-1e+300");
+    assert_eq!(
+        forward(&mut context, "int_copy.join()"),
+        "\"2147483647,-2147483648\""
+    );
+}
+
+#[test]
+fn views_alias_buffer() {
+    let mut context = Context::default();
+    let init = r#"
+        var buffer = new ArrayBuffer(8);
+        var bytes = new Uint8Array(buffer);
+        var ints = new Int32Array(buffer);
+        ints[1] = -1;
+        bytes[0] = 7;
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "bytes.join()"),
+        "\"7,0,0,0,255,255,255,255\""
+    );
+    assert_eq!(forward(&mut context, "ints[0]"), "7");
+}
+
+#[test]
+fn bigint_elements() {
+    check_output(&[
+        TestAction::Execute("var signed = new BigInt64Array(2);"),
+        TestAction::Execute("var unsigned = new BigUint64Array(signed.buffer);"),
+        TestAction::TestStartsWith("signed[0] = 1", "Uncaught \"TypeError\": "),
+        TestAction::TestStartsWith("unsigned.fill(1)", "Uncaught \"TypeError\": "),
+        TestAction::TestStartsWith("new BigInt64Array([1])", "Uncaught \"TypeError\": "),
+        TestAction::TestEq("typeof signed[0]", "\"bigint\""),
+        TestAction::TestEq("signed[0] = -1n; signed[0] === -1n", "true"),
+        TestAction::TestEq("unsigned[0] === 2n ** 64n - 1n", "true"),
+        TestAction::TestEq("signed[1] = 2n ** 63n; signed[1] === -(2n ** 63n)", "true"),
+        TestAction::TestEq("unsigned[1] = 2n ** 64n + 5n; signed[1] === 5n", "true"),
+        TestAction::TestEq(
+            "new Uint8Array(signed.buffer).join()",
+            "\"255,255,255,255,255,255,255,255,5,0,0,0,0,0,0,0\"",
+        ),
+        TestAction::TestEq(
+            "new BigUint64Array(new Uint8Array([1, 0, 0, 0, 0, 0, 0, 128]).buffer)[0]
+                === 2n ** 63n + 1n",
+            "true",
+        ),
+    ]);
+}
+
+#[test]
+fn out_of_bounds_access() {
+    check_output(&[
+        TestAction::Execute("var ta = new Uint8Array(2);"),
+        TestAction::TestEq("ta[2]", "undefined"),
+        TestAction::TestEq("ta[-1]", "undefined"),
+        TestAction::TestEq("ta['1.5']", "undefined"),
+        TestAction::TestEq("ta[5] = 1; ta[5]", "undefined"),
+        TestAction::TestEq("5 in ta", "false"),
+        TestAction::TestEq("Object.keys(ta).length", "2"),
+    ]);
+}
+
+#[test]
+fn subarray_and_set() {
+    check_output(&[
+        TestAction::Execute("var ta = new Int32Array(6);"),
+        TestAction::Execute("var sub = ta.subarray(2, 5);"),
+        TestAction::TestEq("sub.length", "3"),
+        TestAction::TestEq("sub.byteOffset", "8"),
+        TestAction::TestEq("sub[0] = 42; ta[2]", "42"),
+        TestAction::TestEq("ta.set([1, 2], 0); ta[1]", "2"),
+        TestAction::TestEq("sub.set(new Int32Array([9]), 2); ta[4]", "9"),
+        TestAction::TestEq("ta.join()", "\"1,2,42,0,9,0\""),
+    ]);
+}
+
+#[test]
+fn set_from_overlapping_buffer() {
+    let mut context = Context::default();
+    let init = r#"
+        var buffer = new ArrayBuffer(8);
+        var bytes = new Uint8Array(buffer);
+        function reset() {
+            bytes.set([1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+        "#;
+    forward(&mut context, init);
+
+    // Same element type, copying forwards and backwards within the buffer.
+    assert_eq!(
+        forward(
+            &mut context,
+            "reset(); bytes.set(bytes.subarray(0, 6), 2); bytes.join()"
+        ),
+        "\"1,2,1,2,3,4,5,6\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "reset(); bytes.set(bytes.subarray(2)); bytes.join()"
+        ),
+        "\"3,4,5,6,7,8,7,8\""
+    );
+
+    // Different element types, where every element is converted while reading the source.
+    assert_eq!(
+        forward(
+            &mut context,
+            "reset(); new Uint8Array(buffer, 1, 4).set(new Uint16Array(buffer, 0, 2)); bytes.join()"
+        ),
+        "\"1,1,3,4,5,6,7,8\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "reset(); new Uint16Array(buffer, 2, 3).set(new Uint8Array(buffer, 0, 3)); bytes.join()"
+        ),
+        "\"1,2,1,0,2,0,3,0\""
+    );
+}
+
+#[test]
+fn sort_defaults_to_numeric_order() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(&mut context, "[10, 2, 1].sort().join()"),
+        "\"1,10,2\""
+    );
+    assert_eq!(
+        forward(&mut context, "Int32Array.of(10, 2, 1).sort().join()"),
+        "\"1,2,10\""
+    );
+    assert_eq!(
+        forward(&mut context, "BigInt64Array.of(10n, -2n, 1n).sort().join()"),
+        "\"-2,1,10\""
+    );
+
+    let init = r#"
+        var floats = new Float64Array([NaN, 1, 0, -Infinity, NaN, -0, 3]).sort();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "floats.join()"),
+        "\"-Infinity,0,0,1,3,NaN,NaN\""
+    );
+    assert_eq!(forward(&mut context, "Object.is(floats[1], -0)"), "true");
+    assert_eq!(forward(&mut context, "Object.is(floats[2], 0)"), "true");
+}
+
+#[test]
+fn search_methods() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(&mut context, "Float64Array.of(1, 2, 3).includes(2)"),
+        "true"
+    );
+    // `includes` uses SameValueZero, while `indexOf` and `lastIndexOf` use strict equality.
+    assert_eq!(
+        forward(&mut context, "Float64Array.of(1, NaN).includes(NaN)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Float64Array.of(1, NaN).indexOf(NaN)"),
+        "-1"
+    );
+    assert_eq!(
+        forward(&mut context, "Float32Array.of(-0).includes(0)"),
+        "true"
+    );
+    // The elements are numbers, so other types are never found.
+    assert_eq!(
+        forward(&mut context, "Uint8Array.of(1, 2).includes('2')"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "BigInt64Array.of(1n).indexOf(1)"),
+        "-1"
+    );
+    assert_eq!(
+        forward(&mut context, "Int8Array.of(1, 2, 3, 2).lastIndexOf(2)"),
+        "3"
+    );
+    assert_eq!(
+        forward(&mut context, "Int8Array.of(1, 2, 3, 2).lastIndexOf(2, -3)"),
+        "1"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Int8Array.of(1, 2, 3, 4).findLast(x => x % 2)"
+        ),
+        "3"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Int8Array.of(1, 2, 3, 4).findLastIndex(x => x < 3)"
+        ),
+        "1"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Int8Array.of(1, 2, 3, 4).findLastIndex(x => x > 4)"
+        ),
+        "-1"
+    );
+    // The predicate can modify the typed array while it is being searched.
+    assert_eq!(
+        forward(
+            &mut context,
+            "var ta = new Int8Array(2);
+            ta.findLast((v, i) => { ta[i] = 5; ta.foo = 1; return false; });
+            ta.findLastIndex((v, i) => { ta[i] = 6; ta.bar = 1; return false; });
+            ta.join()"
+        ),
+        "\"6,6\""
+    );
+}
+
+#[test]
+fn array_like_methods() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(&mut context, "Float64Array.of(1.5, 2).join('|')"),
+        "\"1.5|2\""
+    );
+    assert_eq!(
+        forward(&mut context, "Uint8Array.of(1, 2, 3).reverse().join()"),
+        "\"3,2,1\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Int16Array.of(1, 2, 3).reduceRight((acc, x) => acc + x, '')"
+        ),
+        "\"321\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Uint8Array.prototype.toString === Array.prototype.toString"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "String(Uint8Array.of(1, 2))"),
+        "\"1,2\""
+    );
+}
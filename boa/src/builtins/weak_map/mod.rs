@@ -0,0 +1,285 @@
+//! This module implements the global `WeakMap` object.
+//!
+//! A `WeakMap` is a collection of key/value pairs whose keys are objects, held weakly: an entry
+//! doesn't prevent its key from being garbage collected, and it disappears once the key is
+//! collected. For that reason the entries of a `WeakMap` can't be enumerated.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weakmap-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap
+
+use crate::{
+    builtins::{map::add_entries_from_iterable, BuiltIn, JsArgs},
+    context::StandardObjects,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+        WeakTable,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakMap;
+
+impl BuiltIn for WeakMap {
+    const NAME: &'static str = "WeakMap";
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE
+        .union(Attribute::NON_ENUMERABLE)
+        .union(Attribute::CONFIGURABLE);
+
+    fn init(context: &mut Context) -> JsValue {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let weak_map_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().weak_map_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .method(Self::delete, "delete", 1)
+        .method(Self::get, "get", 1)
+        .method(Self::has, "has", 1)
+        .method(Self::set, "set", 2)
+        .build();
+
+        weak_map_object.into()
+    }
+}
+
+impl WeakMap {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `WeakMap ( [ iterable ] )`
+    ///
+    /// Constructor for `WeakMap` objects.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap-iterable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/WeakMap
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin WeakMap constructor without new is forbidden",
+            );
+        }
+
+        // 2. Let map be ? OrdinaryCreateFromConstructor(NewTarget, "%WeakMap.prototype%", « [[WeakMapData]] »).
+        // 3. Set map.[[WeakMapData]] to a new empty List.
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardObjects::weak_map_object, context)?;
+        let map = JsObject::from_proto_and_data(prototype, ObjectData::weak_map(WeakTable::new()));
+
+        // 4. If iterable is either undefined or null, return map.
+        let iterable = match args.get_or_undefined(0) {
+            val if !val.is_null_or_undefined() => val,
+            _ => return Ok(map.into()),
+        };
+
+        // 5. Let adder be ? Get(map, "set").
+        let adder = map.get("set", context)?;
+
+        // 6. Return ? AddEntriesFromIterable(map, iterable, adder).
+        add_entries_from_iterable(&map, iterable, &adder, context)
+    }
+
+    /// `WeakMap.prototype.delete( key )`
+    ///
+    /// Removes the entry of the key, if it exists.
+    /// Returns true if there was an entry, and false otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/delete
+    pub(crate) fn delete(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let object = match this.as_object() {
+            Some(object) if object.borrow().as_weak_map_ref().is_some() => object,
+            _ => return context.throw_type_error("'this' is not a WeakMap"),
+        };
+
+        // 3. Let entries be the List that is M.[[WeakMapData]].
+        // 4. If Type(key) is not Object, return false.
+        let key = match args
+            .get_or_undefined(0)
+            .as_object()
+            .and_then(JsObject::weak_handle)
+        {
+            Some(key) => key,
+            None => return Ok(false.into()),
+        };
+
+        // 5. For each Record { [[Key]], [[Value]] } p of entries, do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, then
+        //         i. Set p.[[Key]] to empty.
+        //         ii. Set p.[[Value]] to empty.
+        //         iii. Return true.
+        // 6. Return false.
+        let mut object = object.borrow_mut();
+        let map = object
+            .as_weak_map_mut()
+            .expect("checked that `this` is a WeakMap");
+        Ok(map.remove(&key).into())
+    }
+
+    /// `WeakMap.prototype.get( key )`
+    ///
+    /// Returns the value associated with the key, or undefined if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.get
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/get
+    pub(crate) fn get(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let object = match this.as_object() {
+            Some(object) if object.borrow().as_weak_map_ref().is_some() => object,
+            _ => return context.throw_type_error("'this' is not a WeakMap"),
+        };
+
+        // 3. Let entries be the List that is M.[[WeakMapData]].
+        // 4. If Type(key) is not Object, return undefined.
+        let key = match args
+            .get_or_undefined(0)
+            .as_object()
+            .and_then(JsObject::weak_handle)
+        {
+            Some(key) => key,
+            None => return Ok(JsValue::undefined()),
+        };
+
+        // 5. For each Record { [[Key]], [[Value]] } p of entries, do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, return p.[[Value]].
+        // 6. Return undefined.
+        let object = object.borrow();
+        let map = object
+            .as_weak_map_ref()
+            .expect("checked that `this` is a WeakMap");
+        Ok(map.get(&key).cloned().unwrap_or_default())
+    }
+
+    /// `WeakMap.prototype.has( key )`
+    ///
+    /// Checks if the `WeakMap` has an entry for the key.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/has
+    pub(crate) fn has(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let object = match this.as_object() {
+            Some(object) if object.borrow().as_weak_map_ref().is_some() => object,
+            _ => return context.throw_type_error("'this' is not a WeakMap"),
+        };
+
+        // 3. Let entries be the List that is M.[[WeakMapData]].
+        // 4. If Type(key) is not Object, return false.
+        let key = match args
+            .get_or_undefined(0)
+            .as_object()
+            .and_then(JsObject::weak_handle)
+        {
+            Some(key) => key,
+            None => return Ok(false.into()),
+        };
+
+        // 5. For each Record { [[Key]], [[Value]] } p of entries, do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, return true.
+        // 6. Return false.
+        let object = object.borrow();
+        let map = object
+            .as_weak_map_ref()
+            .expect("checked that `this` is a WeakMap");
+        Ok(map.contains_key(&key).into())
+    }
+
+    /// `WeakMap.prototype.set( key, value )`
+    ///
+    /// Inserts a new entry in the `WeakMap`, or updates the value of an existing one.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.set
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/set
+    pub(crate) fn set(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let object = match this.as_object() {
+            Some(object) if object.borrow().as_weak_map_ref().is_some() => object,
+            _ => return context.throw_type_error("'this' is not a WeakMap"),
+        };
+
+        // 3. Let entries be the List that is M.[[WeakMapData]].
+        // 4. If Type(key) is not Object, throw a TypeError exception.
+        let key = match args.get_or_undefined(0).as_object() {
+            Some(key) => key.create_weak_handle(),
+            None => return context.throw_type_error("WeakMap keys must be objects"),
+        };
+
+        // 5. For each Record { [[Key]], [[Value]] } p of entries, do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, then
+        //         i. Set p.[[Value]] to value.
+        //         ii. Return M.
+        // 6. Let p be the Record { [[Key]]: key, [[Value]]: value }.
+        // 7. Append p as the last element of entries.
+        object
+            .borrow_mut()
+            .as_weak_map_mut()
+            .expect("checked that `this` is a WeakMap")
+            .insert(key, args.get_or_undefined(1).clone());
+
+        // 8. Return M.
+        Ok(this.clone())
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{forward, forward_val, gc::force_collect, Context};
+
+#[test]
+fn construct() {
+    let mut context = Context::default();
+    let init = r#"
+        const a = {};
+        const b = {};
+        const weak_map = new WeakMap([[a, 1], [b, 2], [a, 3]]);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "weak_map.get(a)"), "3");
+    assert_eq!(forward(&mut context, "weak_map.get(b)"), "2");
+    assert_eq!(forward(&mut context, "weak_map.get({})"), "undefined");
+    assert_eq!(
+        forward(&mut context, "WeakMap()"),
+        "Uncaught \"TypeError\": \"calling a builtin WeakMap constructor without new is forbidden\""
+    );
+}
+
+#[test]
+fn get_set_has_delete() {
+    let mut context = Context::default();
+    let init = r#"
+        const key = {};
+        const weak_map = new WeakMap();
+        const returned = weak_map.set(key, "value");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "returned === weak_map"), "true");
+    assert_eq!(forward(&mut context, "weak_map.has(key)"), "true");
+    assert_eq!(forward(&mut context, "weak_map.get(key)"), "\"value\"");
+    assert_eq!(forward(&mut context, "weak_map.delete(key)"), "true");
+    assert_eq!(forward(&mut context, "weak_map.delete(key)"), "false");
+    assert_eq!(forward(&mut context, "weak_map.has(key)"), "false");
+    assert_eq!(forward(&mut context, "weak_map.get(key)"), "undefined");
+    assert_eq!(
+        forward(&mut context, "weak_map.set(weak_map, 1).get(weak_map)"),
+        "1"
+    );
+}
+
+#[test]
+fn non_object_keys() {
+    let mut context = Context::default();
+    forward(&mut context, "const weak_map = new WeakMap();");
+    assert_eq!(
+        forward(&mut context, "weak_map.set(1, 2)"),
+        "Uncaught \"TypeError\": \"WeakMap keys must be objects\""
+    );
+    assert_eq!(
+        forward(&mut context, "weak_map.set(Symbol(), 2)"),
+        "Uncaught \"TypeError\": \"WeakMap keys must be objects\""
+    );
+    assert_eq!(forward(&mut context, "weak_map.get('a')"), "undefined");
+    assert_eq!(forward(&mut context, "weak_map.has(null)"), "false");
+    assert_eq!(forward(&mut context, "weak_map.delete(1)"), "false");
+    assert_eq!(
+        forward(&mut context, "WeakMap.prototype.has.call(new Map(), {})"),
+        "Uncaught \"TypeError\": \"'this' is not a WeakMap\""
+    );
+}
+
+#[test]
+fn not_iterable() {
+    let mut context = Context::default();
+    let init = r#"
+        const weak_map = new WeakMap([[{}, 1]]);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "Object.keys(weak_map).length"), "0");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyNames(WeakMap.prototype).sort().join()"
+        ),
+        "\"constructor,delete,get,has,set\""
+    );
+    assert_eq!(
+        forward(&mut context, "weak_map[Symbol.iterator]"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(weak_map)"),
+        "\"[object WeakMap]\""
+    );
+}
+
+#[test]
+fn entries_are_removed_when_keys_are_collected() {
+    let mut context = Context::default();
+    let init = r#"
+        var key = {};
+        var kept = {};
+        var weak_map = new WeakMap();
+        weak_map.set(key, "collected");
+        weak_map.set(kept, "kept");
+        weak_map
+        "#;
+    let weak_map = forward_val(&mut context, init).unwrap();
+    let weak_map = weak_map.as_object().unwrap();
+    assert_eq!(weak_map.borrow().as_weak_map_ref().unwrap().len(), 2);
+
+    forward(&mut context, "key = undefined;");
+    force_collect();
+
+    assert_eq!(weak_map.borrow().as_weak_map_ref().unwrap().len(), 1);
+    assert_eq!(forward(&mut context, "weak_map.get(kept)"), "\"kept\"");
+}
@@ -0,0 +1,260 @@
+//! This module implements the global `WeakSet` object.
+//!
+//! A `WeakSet` is a collection of objects, held weakly: being in the set doesn't prevent an
+//! object from being garbage collected, and it is removed from the set once it is collected.
+//! For that reason the objects of a `WeakSet` can't be enumerated.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weakset-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet
+
+use crate::{
+    builtins::{BuiltIn, JsArgs},
+    context::StandardObjects,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+        WeakTable,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    BoaProfiler, Context, JsResult, JsValue,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakSet;
+
+impl BuiltIn for WeakSet {
+    const NAME: &'static str = "WeakSet";
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE
+        .union(Attribute::NON_ENUMERABLE)
+        .union(Attribute::CONFIGURABLE);
+
+    fn init(context: &mut Context) -> JsValue {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let weak_set_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().weak_set_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .method(Self::add, "add", 1)
+        .method(Self::delete, "delete", 1)
+        .method(Self::has, "has", 1)
+        .build();
+
+        weak_set_object.into()
+    }
+}
+
+impl WeakSet {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `WeakSet ( [ iterable ] )`
+    ///
+    /// Constructor for `WeakSet` objects.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset-iterable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/WeakSet
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin WeakSet constructor without new is forbidden",
+            );
+        }
+
+        // 2. Let set be ? OrdinaryCreateFromConstructor(NewTarget, "%WeakSet.prototype%", « [[WeakSetData]] »).
+        // 3. Set set.[[WeakSetData]] to a new empty List.
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardObjects::weak_set_object, context)?;
+        let set = JsObject::from_proto_and_data(prototype, ObjectData::weak_set(WeakTable::new()));
+
+        // 4. If iterable is either undefined or null, return set.
+        let iterable = args.get_or_undefined(0);
+        if iterable.is_null_or_undefined() {
+            return Ok(set.into());
+        }
+
+        // 5. Let adder be ? Get(set, "add").
+        // 6. If IsCallable(adder) is false, throw a TypeError exception.
+        let adder = set.get("add", context)?;
+        let adder = adder.as_callable().ok_or_else(|| {
+            context.construct_type_error("property `add` of `NewTarget` is not callable")
+        })?;
+
+        // 7. Let iteratorRecord be ? GetIterator(iterable).
+        let iterator_record = iterable.get_iterator(context, None, None)?;
+
+        // 8. Repeat,
+        loop {
+            // a. Let next be ? IteratorStep(iteratorRecord).
+            let next = iterator_record.next(context)?;
+
+            // b. If next is false, return set.
+            if next.done {
+                return Ok(set.into());
+            }
+
+            // c. Let nextValue be ? IteratorValue(next).
+            // d. Let status be Completion(Call(adder, set, « nextValue »)).
+            // e. IfAbruptCloseIterator(status, iteratorRecord).
+            if let Err(status) = adder.call(&set.clone().into(), &[next.value], context) {
+                return iterator_record.close(Err(status), context);
+            }
+        }
+    }
+
+    /// `WeakSet.prototype.add( value )`
+    ///
+    /// Adds an object to the `WeakSet`, and returns the `WeakSet`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/add
+    pub(crate) fn add(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let S be the this value.
+        // 2. Perform ? RequireInternalSlot(S, [[WeakSetData]]).
+        let object = match this.as_object() {
+            Some(object) if object.borrow().as_weak_set_ref().is_some() => object,
+            _ => return context.throw_type_error("'this' is not a WeakSet"),
+        };
+
+        // 3. If Type(value) is not Object, throw a TypeError exception.
+        let value = match args.get_or_undefined(0).as_object() {
+            Some(value) => value.create_weak_handle(),
+            None => return context.throw_type_error("WeakSet values must be objects"),
+        };
+
+        // 4. Let entries be the List that is S.[[WeakSetData]].
+        // 5. For each element e of entries, do
+        //     a. If e is not empty and SameValue(e, value) is true, then
+        //         i. Return S.
+        // 6. Append value as the last element of entries.
+        object
+            .borrow_mut()
+            .as_weak_set_mut()
+            .expect("checked that `this` is a WeakSet")
+            .insert(value, ());
+
+        // 7. Return S.
+        Ok(this.clone())
+    }
+
+    /// `WeakSet.prototype.delete( value )`
+    ///
+    /// Removes an object from the `WeakSet`.
+    /// Returns true if the object was in the `WeakSet`, and false otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/delete
+    pub(crate) fn delete(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let S be the this value.
+        // 2. Perform ? RequireInternalSlot(S, [[WeakSetData]]).
+        let object = match this.as_object() {
+            Some(object) if object.borrow().as_weak_set_ref().is_some() => object,
+            _ => return context.throw_type_error("'this' is not a WeakSet"),
+        };
+
+        // 3. If Type(value) is not Object, return false.
+        let value = match args
+            .get_or_undefined(0)
+            .as_object()
+            .and_then(JsObject::weak_handle)
+        {
+            Some(value) => value,
+            None => return Ok(false.into()),
+        };
+
+        // 4. Let entries be the List that is S.[[WeakSetData]].
+        // 5. For each element e of entries, do
+        //     a. If e is not empty and SameValue(e, value) is true, then
+        //         i. Replace the element of entries whose value is e with an element whose value is empty.
+        //         ii. Return true.
+        // 6. Return false.
+        let mut object = object.borrow_mut();
+        let set = object
+            .as_weak_set_mut()
+            .expect("checked that `this` is a WeakSet");
+        Ok(set.remove(&value).into())
+    }
+
+    /// `WeakSet.prototype.has( value )`
+    ///
+    /// Checks if an object is in the `WeakSet`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/has
+    pub(crate) fn has(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let S be the this value.
+        // 2. Perform ? RequireInternalSlot(S, [[WeakSetData]]).
+        let object = match this.as_object() {
+            Some(object) if object.borrow().as_weak_set_ref().is_some() => object,
+            _ => return context.throw_type_error("'this' is not a WeakSet"),
+        };
+
+        // 3. Let entries be the List that is S.[[WeakSetData]].
+        // 4. If Type(value) is not Object, return false.
+        let value = match args
+            .get_or_undefined(0)
+            .as_object()
+            .and_then(JsObject::weak_handle)
+        {
+            Some(value) => value,
+            None => return Ok(false.into()),
+        };
+
+        // 5. For each element e of entries, do
+        //     a. If e is not empty and SameValue(e, value) is true, return true.
+        // 6. Return false.
+        let object = object.borrow();
+        let set = object
+            .as_weak_set_ref()
+            .expect("checked that `this` is a WeakSet");
+        Ok(set.contains_key(&value).into())
+    }
+}
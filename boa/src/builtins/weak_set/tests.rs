@@ -0,0 +1,59 @@
+use crate::{forward, forward_val, gc::force_collect, Context};
+
+#[test]
+fn add_has_delete() {
+    let mut context = Context::default();
+    let init = r#"
+        const a = {};
+        const b = {};
+        const weak_set = new WeakSet([a, a]);
+        const returned = weak_set.add(b);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "returned === weak_set"), "true");
+    assert_eq!(forward(&mut context, "weak_set.has(a)"), "true");
+    assert_eq!(forward(&mut context, "weak_set.has(b)"), "true");
+    assert_eq!(forward(&mut context, "weak_set.has({})"), "false");
+    assert_eq!(forward(&mut context, "weak_set.delete(a)"), "true");
+    assert_eq!(forward(&mut context, "weak_set.delete(a)"), "false");
+    assert_eq!(forward(&mut context, "weak_set.has(a)"), "false");
+    assert_eq!(forward(&mut context, "Object.keys(weak_set).length"), "0");
+    assert_eq!(
+        forward(&mut context, "weak_set[Symbol.iterator]"),
+        "undefined"
+    );
+}
+
+#[test]
+fn non_object_values() {
+    let mut context = Context::default();
+    forward(&mut context, "const weak_set = new WeakSet();");
+    assert_eq!(
+        forward(&mut context, "weak_set.add('a')"),
+        "Uncaught \"TypeError\": \"WeakSet values must be objects\""
+    );
+    assert_eq!(
+        forward(&mut context, "new WeakSet([1])"),
+        "Uncaught \"TypeError\": \"WeakSet values must be objects\""
+    );
+    assert_eq!(forward(&mut context, "weak_set.has(1)"), "false");
+    assert_eq!(forward(&mut context, "weak_set.delete(undefined)"), "false");
+}
+
+#[test]
+fn values_are_removed_when_collected() {
+    let mut context = Context::default();
+    let init = r#"
+        var value = {};
+        var weak_set = new WeakSet([value]);
+        weak_set
+        "#;
+    let weak_set = forward_val(&mut context, init).unwrap();
+    let weak_set = weak_set.as_object().unwrap();
+    assert_eq!(weak_set.borrow().as_weak_set_ref().unwrap().len(), 1);
+
+    forward(&mut context, "value = undefined;");
+    force_collect();
+
+    assert!(weak_set.borrow().as_weak_set_ref().unwrap().is_empty());
+}
@@ -1,5 +1,5 @@
 use crate::{
-    builtins::function::ThisMode,
+    builtins::function::{ConstructorKind, ThisMode},
     environments::BindingLocator,
     gc::Gc,
     syntax::ast::{
@@ -7,18 +7,19 @@ use crate::{
             declaration::{BindingPatternTypeArray, BindingPatternTypeObject, DeclarationPattern},
             iteration::IterableLoopInitializer,
             template::TemplateElement,
-            Declaration, GetConstField, GetField, MethodDefinitionKind, PropertyDefinition,
-            PropertyName, StatementList,
+            Class, ClassElement, Declaration, FormalParameter, FunctionExpr, GetConstField,
+            GetField, GetPrivateField, GetSuperField, Identifier, MethodDefinitionKind,
+            PropertyDefinition, PropertyName, Return, Spread, StatementList, SuperCall,
         },
         op::{AssignOp, BinOp, BitOp, CompOp, LogOp, NumOp, UnaryOp},
-        Const, Node,
+        ByteSpan, Const, Node, Position,
     },
-    vm::{BindingOpcode, CodeBlock, Opcode},
+    vm::{BindingOpcode, CodeBlock, CodeBlockFlags, Opcode},
     Context, JsBigInt, JsResult, JsString, JsValue,
 };
 use boa_interner::{Interner, Sym};
 use rustc_hash::FxHashMap;
-use std::mem::size_of;
+use std::{mem::size_of, rc::Rc};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum Literal {
@@ -52,11 +53,20 @@ enum JumpControlInfoKind {
     Try,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FunctionKind {
+    Declaration,
+    Expression,
+    Arrow,
+}
+
 #[derive(Debug, Clone, Copy)]
 enum Access<'a> {
     Variable { name: Sym },
     ByName { node: &'a GetConstField },
     ByValue { node: &'a GetField },
+    SuperField { node: &'a GetSuperField },
+    PrivateField { node: &'a GetPrivateField },
     This,
 }
 
@@ -67,9 +77,25 @@ pub struct ByteCompiler<'b> {
     names_map: FxHashMap<Sym, u32>,
     bindings_map: FxHashMap<BindingLocator, u32>,
     jump_info: Vec<JumpControlInfo>,
+    /// The source code the compiled functions were parsed from.
+    source: Option<Rc<str>>,
     context: &'b mut Context,
 }
 
+/// Gets the flags of the code of a method of the given kind.
+fn method_flags(kind: MethodDefinitionKind) -> CodeBlockFlags {
+    match kind {
+        MethodDefinitionKind::Generator => CodeBlockFlags::GENERATOR,
+        MethodDefinitionKind::Async => CodeBlockFlags::ASYNC,
+        MethodDefinitionKind::AsyncGenerator => {
+            unreachable!("async generator methods are not compiled yet")
+        }
+        MethodDefinitionKind::Get | MethodDefinitionKind::Set | MethodDefinitionKind::Ordinary => {
+            CodeBlockFlags::empty()
+        }
+    }
+}
+
 impl<'b> ByteCompiler<'b> {
     /// Represents a placeholder address that will be patched later.
     const DUMMY_ADDRESS: u32 = u32::MAX;
@@ -82,10 +108,24 @@ impl<'b> ByteCompiler<'b> {
             names_map: FxHashMap::default(),
             bindings_map: FxHashMap::default(),
             jump_info: Vec::new(),
+            source: None,
             context,
         }
     }
 
+    /// Sets the source code the compiled functions were parsed from, which their source text
+    /// refers to.
+    #[inline]
+    pub(crate) fn set_source(&mut self, source: Option<Rc<str>>) {
+        self.source = source;
+    }
+
+    /// Gets the source text of a function from its span in the source code, if both are known.
+    #[inline]
+    fn source_text(&self, span: Option<ByteSpan>) -> Option<(Rc<str>, ByteSpan)> {
+        Some((self.source.clone()?, span?))
+    }
+
     #[inline]
     fn interner(&self) -> &Interner {
         self.context.interner()
@@ -461,6 +501,8 @@ impl<'b> ByteCompiler<'b> {
             Node::Identifier(name) => Access::Variable { name: name.sym() },
             Node::GetConstField(node) => Access::ByName { node },
             Node::GetField(node) => Access::ByValue { node },
+            Node::GetSuperField(node) => Access::SuperField { node },
+            Node::GetPrivateField(node) => Access::PrivateField { node },
             Node::This => Access::This,
             _ => unreachable!(),
         }
@@ -469,21 +511,35 @@ impl<'b> ByteCompiler<'b> {
     #[inline]
     fn access_get(&mut self, access: Access<'_>, use_expr: bool) -> JsResult<()> {
         match access {
-            Access::Variable { name } => {
-                let binding = self.context.get_binding_value(name);
-                let index = self.get_or_insert_binding(binding);
-                self.emit(Opcode::GetName, &[index]);
-            }
+            Access::Variable { name } => self.emit_get_binding(name, Opcode::GetName),
             Access::ByName { node } => {
                 let index = self.get_or_insert_name(node.field());
                 self.compile_expr(node.obj(), true)?;
                 self.emit(Opcode::GetPropertyByName, &[index]);
             }
             Access::ByValue { node } => {
-                self.compile_expr(node.field(), true)?;
                 self.compile_expr(node.obj(), true)?;
+                self.compile_expr(node.field(), true)?;
+                self.emit(Opcode::Swap, &[]);
                 self.emit(Opcode::GetPropertyByValue, &[]);
             }
+            Access::SuperField { node } => match node {
+                GetSuperField::Const(field) => {
+                    let index = self.get_or_insert_name(*field);
+                    self.emit_opcode(Opcode::Super);
+                    self.emit(Opcode::GetPropertyByName, &[index]);
+                }
+                GetSuperField::Expr(expr) => {
+                    self.compile_expr(expr, true)?;
+                    self.emit_opcode(Opcode::Super);
+                    self.emit_opcode(Opcode::GetPropertyByValue);
+                }
+            },
+            Access::PrivateField { node } => {
+                self.compile_expr(node.obj(), true)?;
+                self.emit_private_name(node.field());
+                self.emit_opcode(Opcode::GetPrivateField);
+            }
             Access::This => {
                 self.emit(Opcode::This, &[]);
             }
@@ -495,45 +551,145 @@ impl<'b> ByteCompiler<'b> {
         Ok(())
     }
 
+    /// Evaluates the base object and the property key of the reference to `access`, leaving
+    /// them on the stack, and returns how many values were pushed.
+    ///
+    /// The key is converted with `ToPropertyKey` right away, so it is evaluated before the
+    /// right-hand side of an assignment.
     #[inline]
-    fn access_set(
-        &mut self,
-        access: Access<'_>,
-        expr: Option<&Node>,
-        use_expr: bool,
-    ) -> JsResult<()> {
-        if let Some(expr) = expr {
-            self.compile_expr(expr, true)?;
+    fn access_reference(&mut self, access: Access<'_>) -> JsResult<u8> {
+        match access {
+            Access::Variable { .. } => Ok(0),
+            Access::ByName { node } => {
+                self.compile_expr(node.obj(), true)?;
+                Ok(1)
+            }
+            Access::ByValue { node } => {
+                self.compile_expr(node.obj(), true)?;
+                self.compile_expr(node.field(), true)?;
+                self.emit_opcode(Opcode::ToPropertyKey);
+                Ok(2)
+            }
+            // Assigning to a super property sets the property on `this`.
+            Access::SuperField { node } => match node {
+                GetSuperField::Const(_) => {
+                    self.emit_opcode(Opcode::This);
+                    Ok(1)
+                }
+                GetSuperField::Expr(expr) => {
+                    self.emit_opcode(Opcode::This);
+                    self.compile_expr(expr, true)?;
+                    self.emit_opcode(Opcode::ToPropertyKey);
+                    Ok(2)
+                }
+            },
+            Access::PrivateField { node } => {
+                self.compile_expr(node.obj(), true)?;
+                Ok(1)
+            }
+            Access::This => todo!("access_reference 'this'"),
         }
+    }
 
-        if use_expr {
-            self.emit(Opcode::Dup, &[]);
+    /// Pushes the value of a reference evaluated by [`Self::access_reference`], keeping the
+    /// reference on the stack.
+    #[inline]
+    fn access_reference_get(&mut self, access: Access<'_>) {
+        match access {
+            Access::Variable { name } => self.emit_get_binding(name, Opcode::GetName),
+            Access::ByName { node } => {
+                self.emit_opcode(Opcode::Dup);
+                let index = self.get_or_insert_name(node.field());
+                self.emit(Opcode::GetPropertyByName, &[index]);
+            }
+            Access::ByValue { .. } => self.emit_opcode(Opcode::GetPropertyByValuePush),
+            Access::SuperField { node } => match node {
+                GetSuperField::Const(field) => {
+                    self.emit_opcode(Opcode::Super);
+                    let index = self.get_or_insert_name(*field);
+                    self.emit(Opcode::GetPropertyByName, &[index]);
+                }
+                GetSuperField::Expr(_) => {
+                    self.emit_opcode(Opcode::Dup);
+                    self.emit_opcode(Opcode::Super);
+                    self.emit_opcode(Opcode::GetPropertyByValue);
+                }
+            },
+            Access::PrivateField { node } => {
+                self.emit_opcode(Opcode::Dup);
+                self.emit_private_name(node.field());
+                self.emit_opcode(Opcode::GetPrivateField);
+            }
+            Access::This => todo!("access_reference_get 'this'"),
         }
+    }
 
+    /// Assigns the value on top of the stack to a reference evaluated by
+    /// [`Self::access_reference`], consuming the reference.
+    #[inline]
+    fn access_reference_set(&mut self, access: Access<'_>, use_expr: bool) {
         match access {
             Access::Variable { name } => {
-                let binding = self.context.set_mutable_binding(name);
+                if use_expr {
+                    self.emit_opcode(Opcode::Dup);
+                }
+                let mut binding = self.context.set_mutable_binding(name);
+                // Imported bindings are immutable.
+                if binding.is_global() && self.context.module_imports.contains_key(&name) {
+                    binding = BindingLocator::mutate_immutable(name);
+                }
                 let index = self.get_or_insert_binding(binding);
                 self.emit(Opcode::SetName, &[index]);
+                return;
             }
             Access::ByName { node } => {
-                self.compile_expr(node.obj(), true)?;
                 let index = self.get_or_insert_name(node.field());
                 self.emit(Opcode::SetPropertyByName, &[index]);
             }
-            Access::ByValue { node } => {
-                self.compile_expr(node.field(), true)?;
-                self.compile_expr(node.obj(), true)?;
-                self.emit(Opcode::SetPropertyByValue, &[]);
+            Access::ByValue { .. }
+            | Access::SuperField {
+                node: GetSuperField::Expr(_),
+            } => self.emit_opcode(Opcode::SetPropertyByValue),
+            Access::SuperField {
+                node: GetSuperField::Const(field),
+            } => {
+                let index = self.get_or_insert_name(*field);
+                self.emit(Opcode::SetPropertyByName, &[index]);
+            }
+            Access::PrivateField { node } => {
+                self.emit_private_name(node.field());
+                self.emit_opcode(Opcode::SetPrivateField);
             }
-            Access::This => todo!("access_set 'this'"),
+            Access::This => todo!("access_reference_set 'this'"),
+        }
+
+        if !use_expr {
+            self.emit_opcode(Opcode::Pop);
         }
+    }
+
+    /// Compiles the assignment of `expr` to `access`.
+    #[inline]
+    fn access_set(&mut self, access: Access<'_>, expr: &Node, use_expr: bool) -> JsResult<()> {
+        self.access_reference(access)?;
+        self.compile_expr(expr, true)?;
+        self.access_reference_set(access, use_expr);
         Ok(())
     }
 
+    /// Records that the next instruction starts a statement at `position` in the source code.
+    #[inline]
+    fn record_position(&mut self, position: Option<Position>) {
+        if let Some(position) = position {
+            let start = self.next_opcode_location();
+            self.code_block.positions.push((start, position));
+        }
+    }
+
     #[inline]
     pub fn compile_statement_list(&mut self, list: &StatementList, use_expr: bool) -> JsResult<()> {
         for (i, node) in list.items().iter().enumerate() {
+            self.record_position(list.position(i));
             if i + 1 == list.items().len() {
                 self.compile_stmt(node, use_expr)?;
                 break;
@@ -568,37 +724,43 @@ impl<'b> ByteCompiler<'b> {
             Node::UnaryOp(unary) => {
                 let opcode = match unary.op() {
                     UnaryOp::IncrementPre => {
-                        self.compile_expr(unary.target(), true)?;
-                        self.emit(Opcode::Inc, &[]);
-
                         let access = self.compile_access(unary.target());
-                        self.access_set(access, None, true)?;
+                        self.access_reference(access)?;
+                        self.access_reference_get(access);
+                        self.emit_opcode(Opcode::Inc);
+                        self.access_reference_set(access, true);
                         None
                     }
                     UnaryOp::DecrementPre => {
-                        self.compile_expr(unary.target(), true)?;
-                        self.emit(Opcode::Dec, &[]);
-
                         let access = self.compile_access(unary.target());
-                        self.access_set(access, None, true)?;
+                        self.access_reference(access)?;
+                        self.access_reference_get(access);
+                        self.emit_opcode(Opcode::Dec);
+                        self.access_reference_set(access, true);
                         None
                     }
                     UnaryOp::IncrementPost => {
-                        self.compile_expr(unary.target(), true)?;
-                        self.emit(Opcode::Dup, &[]);
-                        self.emit(Opcode::Inc, &[]);
                         let access = self.compile_access(unary.target());
-                        self.access_set(access, None, false)?;
-
+                        let level = self.access_reference(access)?;
+                        self.access_reference_get(access);
+                        // Keep the old value below the reference as the result.
+                        self.emit_opcode(Opcode::Dup);
+                        self.emit_opcode(Opcode::RotateRight);
+                        self.emit_u8(level + 2);
+                        self.emit_opcode(Opcode::Inc);
+                        self.access_reference_set(access, false);
                         None
                     }
                     UnaryOp::DecrementPost => {
-                        self.compile_expr(unary.target(), true)?;
-                        self.emit(Opcode::Dup, &[]);
-                        self.emit(Opcode::Dec, &[]);
                         let access = self.compile_access(unary.target());
-                        self.access_set(access, None, false)?;
-
+                        let level = self.access_reference(access)?;
+                        self.access_reference_get(access);
+                        // Keep the old value below the reference as the result.
+                        self.emit_opcode(Opcode::Dup);
+                        self.emit_opcode(Opcode::RotateRight);
+                        self.emit_u8(level + 2);
+                        self.emit_opcode(Opcode::Dec);
+                        self.access_reference_set(access, false);
                         None
                     }
                     UnaryOp::Delete => match unary.target() {
@@ -614,9 +776,10 @@ impl<'b> ByteCompiler<'b> {
                             self.emit(Opcode::DeletePropertyByValue, &[]);
                             None
                         }
-                        // TODO: implement delete on references.
-                        Node::Identifier(_) => {
-                            self.emit(Opcode::PushFalse, &[]);
+                        Node::Identifier(identifier) => {
+                            let binding = self.context.get_binding_value(identifier.sym());
+                            let index = self.get_or_insert_binding(binding);
+                            self.emit(Opcode::DeleteName, &[index]);
                             None
                         }
                         _ => {
@@ -631,9 +794,7 @@ impl<'b> ByteCompiler<'b> {
                     UnaryOp::TypeOf => {
                         match &unary.target() {
                             Node::Identifier(identifier) => {
-                                let binding = self.context.get_binding_value(identifier.sym());
-                                let index = self.get_or_insert_binding(binding);
-                                self.emit(Opcode::GetNameOrUndefined, &[index]);
+                                self.emit_get_binding(identifier.sym(), Opcode::GetNameOrUndefined);
                             }
                             expr => self.compile_expr(expr, true)?,
                         }
@@ -653,7 +814,10 @@ impl<'b> ByteCompiler<'b> {
                 }
             }
             Node::BinOp(binary) => {
-                self.compile_expr(binary.lhs(), true)?;
+                // The target of an assignment is compiled as a reference.
+                if !matches!(binary.op(), BinOp::Assign(_)) {
+                    self.compile_expr(binary.lhs(), true)?;
+                }
                 match binary.op() {
                     BinOp::Num(op) => {
                         self.compile_expr(binary.rhs(), true)?;
@@ -728,6 +892,10 @@ impl<'b> ByteCompiler<'b> {
                         }
                     }
                     BinOp::Assign(op) => {
+                        let access = self.compile_access(binary.lhs());
+                        let level = self.access_reference(access)?;
+                        self.access_reference_get(access);
+
                         let opcode = match op {
                             AssignOp::Add => Some(Opcode::Add),
                             AssignOp::Sub => Some(Opcode::Sub),
@@ -741,28 +909,30 @@ impl<'b> ByteCompiler<'b> {
                             AssignOp::Shl => Some(Opcode::ShiftLeft),
                             AssignOp::Shr => Some(Opcode::ShiftRight),
                             AssignOp::Ushr => Some(Opcode::UnsignedShiftRight),
-                            AssignOp::BoolAnd => {
-                                let exit = self.jump_with_custom_opcode(Opcode::LogicalAnd);
-                                self.compile_expr(binary.rhs(), true)?;
-                                let access = self.compile_access(binary.lhs());
-                                self.access_set(access, None, use_expr)?;
-                                self.patch_jump(exit);
-                                None
-                            }
-                            AssignOp::BoolOr => {
-                                let exit = self.jump_with_custom_opcode(Opcode::LogicalOr);
-                                self.compile_expr(binary.rhs(), true)?;
-                                let access = self.compile_access(binary.lhs());
-                                self.access_set(access, None, use_expr)?;
-                                self.patch_jump(exit);
-                                None
-                            }
-                            AssignOp::Coalesce => {
-                                let exit = self.jump_with_custom_opcode(Opcode::Coalesce);
+                            AssignOp::BoolAnd | AssignOp::BoolOr | AssignOp::Coalesce => {
+                                let exit = self.jump_with_custom_opcode(match op {
+                                    AssignOp::BoolAnd => Opcode::LogicalAnd,
+                                    AssignOp::BoolOr => Opcode::LogicalOr,
+                                    _ => Opcode::Coalesce,
+                                });
                                 self.compile_expr(binary.rhs(), true)?;
-                                let access = self.compile_access(binary.lhs());
-                                self.access_set(access, None, use_expr)?;
+                                self.access_reference_set(access, use_expr);
+                                let end = self.jump();
+
+                                // When short-circuiting, the reference is dropped and the
+                                // current value is the result.
                                 self.patch_jump(exit);
+                                if use_expr {
+                                    for _ in 0..level {
+                                        self.emit_opcode(Opcode::Swap);
+                                        self.emit_opcode(Opcode::Pop);
+                                    }
+                                } else {
+                                    for _ in 0..=level {
+                                        self.emit_opcode(Opcode::Pop);
+                                    }
+                                }
+                                self.patch_jump(end);
                                 None
                             }
                         };
@@ -770,8 +940,7 @@ impl<'b> ByteCompiler<'b> {
                         if let Some(opcode) = opcode {
                             self.compile_expr(binary.rhs(), true)?;
                             self.emit(opcode, &[]);
-                            let access = self.compile_access(binary.lhs());
-                            self.access_set(access, None, use_expr)?;
+                            self.access_reference_set(access, use_expr);
                         }
                     }
                     BinOp::Comma => {
@@ -847,26 +1016,52 @@ impl<'b> ByteCompiler<'b> {
                                         self.emit_opcode(Opcode::DefineOwnPropertyByValue);
                                     }
                                 },
-                                MethodDefinitionKind::Generator
-                                | MethodDefinitionKind::Async
-                                | MethodDefinitionKind::AsyncGenerator => {
-                                    // TODO: Implement generators
-                                    // TODO: Implement async
-                                    // TODO: Implement async generators
+                                MethodDefinitionKind::Generator | MethodDefinitionKind::Async => {
+                                    let flags = method_flags(*kind);
                                     match name {
                                         PropertyName::Literal(name) => {
-                                            self.emit_opcode(Opcode::PushUndefined);
+                                            self.compile_function(
+                                                FunctionKind::Expression,
+                                                func.name(),
+                                                func.parameters(),
+                                                func.body(),
+                                                flags,
+                                                func.source_span(),
+                                                true,
+                                            )?;
                                             self.emit_opcode(Opcode::Swap);
                                             let index = self.get_or_insert_name(*name);
                                             self.emit(Opcode::DefineOwnPropertyByName, &[index]);
                                         }
                                         PropertyName::Computed(name_node) => {
                                             self.compile_stmt(name_node, true)?;
-                                            self.emit_opcode(Opcode::PushUndefined);
+                                            self.compile_function(
+                                                FunctionKind::Expression,
+                                                func.name(),
+                                                func.parameters(),
+                                                func.body(),
+                                                flags,
+                                                func.source_span(),
+                                                true,
+                                            )?;
                                             self.emit_opcode(Opcode::DefineOwnPropertyByValue);
                                         }
                                     }
                                 }
+                                // TODO: Implement async generators
+                                MethodDefinitionKind::AsyncGenerator => match name {
+                                    PropertyName::Literal(name) => {
+                                        self.emit_opcode(Opcode::PushUndefined);
+                                        self.emit_opcode(Opcode::Swap);
+                                        let index = self.get_or_insert_name(*name);
+                                        self.emit(Opcode::DefineOwnPropertyByName, &[index]);
+                                    }
+                                    PropertyName::Computed(name_node) => {
+                                        self.compile_stmt(name_node, true)?;
+                                        self.emit_opcode(Opcode::PushUndefined);
+                                        self.emit_opcode(Opcode::DefineOwnPropertyByValue);
+                                    }
+                                },
                             }
                         }
                         PropertyDefinition::SpreadObject(expr) => {
@@ -892,7 +1087,7 @@ impl<'b> ByteCompiler<'b> {
                     self.emit_opcode(Opcode::PushUndefined);
                 } else {
                     let access = self.compile_access(assign.lhs());
-                    self.access_set(access, Some(assign.rhs()), use_expr)?;
+                    self.access_set(access, assign.rhs(), use_expr)?;
                 }
             }
             Node::GetConstField(node) => {
@@ -903,6 +1098,23 @@ impl<'b> ByteCompiler<'b> {
                 let access = Access::ByValue { node };
                 self.access_get(access, use_expr)?;
             }
+            Node::GetSuperField(node) => {
+                let access = Access::SuperField { node };
+                self.access_get(access, use_expr)?;
+            }
+            Node::GetPrivateField(node) => {
+                let access = Access::PrivateField { node };
+                self.access_get(access, use_expr)?;
+            }
+            Node::PrivateIn(private_in) => {
+                self.compile_expr(private_in.rhs(), true)?;
+                self.emit_private_name(private_in.name());
+                self.emit_opcode(Opcode::InPrivate);
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
             Node::ConditionalOp(op) => {
                 self.compile_expr(op.cond(), true)?;
                 let jelse = self.jump_if_false();
@@ -921,6 +1133,12 @@ impl<'b> ByteCompiler<'b> {
                 self.emit_opcode(Opcode::PopOnReturnAdd);
 
                 for element in array.as_ref() {
+                    // Elisions are parsed as `undefined` constants, and leave holes in the array.
+                    if let Node::Const(Const::Undefined) = element {
+                        self.emit_opcode(Opcode::PushElisionToArray);
+                        continue;
+                    }
+
                     self.compile_expr(element, true)?;
                     if let Node::Spread(_) = element {
                         self.emit_opcode(Opcode::InitIterator);
@@ -935,6 +1153,11 @@ impl<'b> ByteCompiler<'b> {
                     self.emit(Opcode::Pop, &[]);
                 }
             }
+            Node::NewTarget => {
+                if use_expr {
+                    self.emit_opcode(Opcode::PushNewTarget);
+                }
+            }
             Node::This => {
                 self.access_get(Access::This, use_expr)?;
             }
@@ -942,6 +1165,23 @@ impl<'b> ByteCompiler<'b> {
             Node::FunctionExpr(_function) => self.function(expr, use_expr)?,
             Node::ArrowFunctionDecl(_function) => self.function(expr, use_expr)?,
             Node::Call(_) | Node::New(_) => self.call(expr, use_expr)?,
+            Node::SuperCall(super_call) => {
+                if self.compile_arguments(super_call.args())? {
+                    self.emit_opcode(Opcode::SuperCallSpread);
+                } else {
+                    self.emit(Opcode::SuperCall, &[super_call.args().len() as u32]);
+                }
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
+            Node::ClassExpr(class) => {
+                self.class(class)?;
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
             Node::TemplateLit(template_literal) => {
                 for element in template_literal.elements() {
                     match element {
@@ -963,16 +1203,43 @@ impl<'b> ByteCompiler<'b> {
                     self.emit(Opcode::Pop, &[]);
                 }
             }
-            // TODO: implement AsyncFunctionExpr
-            // TODO: implement AwaitExpr
-            // TODO: implement GeneratorExpr
+            Node::GeneratorExpr(_) | Node::AsyncFunctionExpr(_) => {
+                self.function(expr, use_expr)?;
+            }
+            Node::Yield(r#yield) => {
+                if let Some(expr) = r#yield.expr() {
+                    self.compile_expr(expr, true)?;
+                } else {
+                    self.emit_opcode(Opcode::PushUndefined);
+                }
+
+                if r#yield.delegate() {
+                    self.emit_opcode(Opcode::InitIterator);
+                    self.emit_opcode(Opcode::PushUndefined);
+                    let start_address = self.next_opcode_location();
+                    let done = self.jump_with_custom_opcode(Opcode::GeneratorNextDelegate);
+                    self.emit(Opcode::Jump, &[start_address]);
+                    self.patch_jump(done);
+                } else {
+                    self.emit_opcode(Opcode::Yield);
+                    self.emit_opcode(Opcode::GeneratorNext);
+                }
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
+            Node::AwaitExpr(r#await) => {
+                self.compile_expr(r#await.expr(), true)?;
+                self.emit_opcode(Opcode::Await);
+                self.emit_opcode(Opcode::GeneratorNext);
+
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
             // TODO: implement AsyncGeneratorExpr
-            // TODO: implement Yield
-            Node::AsyncFunctionExpr(_)
-            | Node::AwaitExpr(_)
-            | Node::GeneratorExpr(_)
-            | Node::AsyncGeneratorExpr(_)
-            | Node::Yield(_) => {
+            Node::AsyncGeneratorExpr(_) => {
                 self.emit_opcode(Opcode::PushUndefined);
             }
             Node::TaggedTemplate(template) => {
@@ -1018,9 +1285,9 @@ impl<'b> ByteCompiler<'b> {
                     self.emit_opcode(Opcode::PushValueToArray);
                 }
 
-                self.emit_opcode(Opcode::Swap);
                 let index = self.get_or_insert_name(Sym::RAW);
                 self.emit(Opcode::SetPropertyByName, &[index]);
+                self.emit_opcode(Opcode::Pop);
 
                 for expr in template.exprs() {
                     self.compile_expr(expr, true)?;
@@ -1276,7 +1543,11 @@ impl<'b> ByteCompiler<'b> {
             }
             Node::ForOfLoop(for_of_loop) => {
                 self.compile_expr(for_of_loop.iterable(), true)?;
-                self.emit_opcode(Opcode::InitIterator);
+                if for_of_loop.r#await() {
+                    self.emit_opcode(Opcode::InitIteratorAsync);
+                } else {
+                    self.emit_opcode(Opcode::InitIterator);
+                }
 
                 self.emit_opcode(Opcode::LoopStart);
                 let start_address = self.next_opcode_location();
@@ -1285,7 +1556,14 @@ impl<'b> ByteCompiler<'b> {
 
                 self.context.push_compile_time_environment(false);
                 let push_env = self.jump_with_custom_opcode(Opcode::PushDeclarativeEnvironment);
-                let exit = self.jump_with_custom_opcode(Opcode::ForInLoopNext);
+                let exit = if for_of_loop.r#await() {
+                    self.emit_opcode(Opcode::ForAwaitOfLoopIterate);
+                    self.emit_opcode(Opcode::Await);
+                    self.emit_opcode(Opcode::GeneratorNext);
+                    self.jump_with_custom_opcode(Opcode::ForAwaitOfLoopNext)
+                } else {
+                    self.jump_with_custom_opcode(Opcode::ForInLoopNext)
+                };
 
                 match for_of_loop.init() {
                     IterableLoopInitializer::Identifier(ref ident) => {
@@ -1492,7 +1770,8 @@ impl<'b> ByteCompiler<'b> {
                 for node in block.items() {
                     self.create_declarations(node)?;
                 }
-                for node in block.items() {
+                for (i, node) in block.items().iter().enumerate() {
+                    self.record_position(block.statement_list().position(i));
                     self.compile_stmt(node, use_expr)?;
                 }
                 let num_bindings = self.context.pop_compile_time_environment().num_bindings();
@@ -1548,6 +1827,13 @@ impl<'b> ByteCompiler<'b> {
                 self.emit_opcode(Opcode::PopEnvironment);
             }
             Node::FunctionDecl(_function) => self.function(node, false)?,
+            Node::ClassDecl(class) => {
+                self.class(class)?;
+                self.emit_binding(
+                    BindingOpcode::InitLet,
+                    class.name().expect("class declaration must have a name"),
+                );
+            }
             Node::Return(ret) => {
                 if let Some(expr) = ret.expr() {
                     self.compile_expr(expr, true)?;
@@ -1565,7 +1851,8 @@ impl<'b> ByteCompiler<'b> {
                 for node in t.block().items() {
                     self.create_declarations(node)?;
                 }
-                for node in t.block().items() {
+                for (i, node) in t.block().items().iter().enumerate() {
+                    self.record_position(t.block().statement_list().position(i));
                     self.compile_stmt(node, false)?;
                 }
                 let num_bindings = self.context.pop_compile_time_environment().num_bindings();
@@ -1605,7 +1892,8 @@ impl<'b> ByteCompiler<'b> {
                     for node in catch.block().items() {
                         self.create_declarations(node)?;
                     }
-                    for node in catch.block().items() {
+                    for (i, node) in catch.block().items().iter().enumerate() {
+                        self.record_position(catch.block().statement_list().position(i));
                         self.compile_stmt(node, use_expr)?;
                     }
                     let num_bindings = self.context.pop_compile_time_environment().num_bindings();
@@ -1634,7 +1922,8 @@ impl<'b> ByteCompiler<'b> {
                         finally_start_address,
                     );
 
-                    for node in finally.items() {
+                    for (i, node) in finally.items().iter().enumerate() {
+                        self.record_position(finally.statement_list().position(i));
                         self.compile_stmt(node, false)?;
                     }
                     self.emit_opcode(Opcode::FinallyEnd);
@@ -1643,10 +1932,9 @@ impl<'b> ByteCompiler<'b> {
                     self.pop_try_control_info(None);
                 }
             }
-            // TODO: implement AsyncFunctionDecl
-            // TODO: implement GeneratorDecl
+            Node::GeneratorDecl(_) | Node::AsyncFunctionDecl(_) => self.function(node, false)?,
             // TODO: implement AsyncGeneratorDecl
-            Node::AsyncFunctionDecl(_) | Node::GeneratorDecl(_) | Node::AsyncGeneratorDecl(_) => {
+            Node::AsyncGeneratorDecl(_) => {
                 self.emit_opcode(Opcode::PushUndefined);
             }
             Node::Empty => {}
@@ -1656,35 +1944,163 @@ impl<'b> ByteCompiler<'b> {
     }
 
     pub(crate) fn function(&mut self, function: &Node, use_expr: bool) -> JsResult<()> {
-        #[derive(Debug, Clone, Copy, PartialEq)]
-        enum FunctionKind {
-            Declaration,
-            Expression,
-            Arrow,
-        }
-
-        let (kind, name, parameters, body) = match function {
+        let (kind, name, parameters, body, flags, source_span) = match function {
             Node::FunctionDecl(function) => (
                 FunctionKind::Declaration,
                 Some(function.name()),
                 function.parameters(),
                 function.body(),
+                CodeBlockFlags::empty(),
+                function.source_span(),
+            ),
+            Node::GeneratorDecl(generator) => (
+                FunctionKind::Declaration,
+                Some(generator.name()),
+                generator.parameters(),
+                generator.body(),
+                CodeBlockFlags::GENERATOR,
+                generator.source_span(),
+            ),
+            Node::AsyncFunctionDecl(function) => (
+                FunctionKind::Declaration,
+                Some(function.name()),
+                function.parameters(),
+                function.body(),
+                CodeBlockFlags::ASYNC,
+                function.source_span(),
             ),
             Node::FunctionExpr(function) => (
                 FunctionKind::Expression,
                 function.name(),
                 function.parameters(),
                 function.body(),
+                CodeBlockFlags::empty(),
+                function.source_span(),
+            ),
+            Node::GeneratorExpr(generator) => (
+                FunctionKind::Expression,
+                generator.name(),
+                generator.parameters(),
+                generator.body(),
+                CodeBlockFlags::GENERATOR,
+                generator.source_span(),
+            ),
+            Node::AsyncFunctionExpr(function) => (
+                FunctionKind::Expression,
+                function.name(),
+                function.parameters(),
+                function.body(),
+                CodeBlockFlags::ASYNC,
+                function.source_span(),
             ),
             Node::ArrowFunctionDecl(function) => (
                 FunctionKind::Arrow,
                 function.name(),
                 function.params(),
                 function.body(),
+                CodeBlockFlags::empty(),
+                function.source_span(),
             ),
             _ => unreachable!(),
         };
 
+        let mut code = self.function_code(kind, name, parameters, body, flags)?;
+        code.source = self.source_text(source_span);
+        self.emit_function(kind, name, code, use_expr);
+
+        Ok(())
+    }
+
+    fn compile_function(
+        &mut self,
+        kind: FunctionKind,
+        name: Option<Sym>,
+        parameters: &[FormalParameter],
+        body: &StatementList,
+        flags: CodeBlockFlags,
+        source_span: Option<ByteSpan>,
+        use_expr: bool,
+    ) -> JsResult<()> {
+        let mut code = self.function_code(kind, name, parameters, body, flags)?;
+        code.source = self.source_text(source_span);
+        self.emit_function(kind, name, code, use_expr);
+
+        Ok(())
+    }
+
+    /// Emits the instructions that create the function object of `code` and bind it to its
+    /// name, or leave it on the stack, depending on the kind of the function.
+    fn emit_function(
+        &mut self,
+        kind: FunctionKind,
+        name: Option<Sym>,
+        code: CodeBlock,
+        use_expr: bool,
+    ) {
+        self.emit_get_function(code);
+
+        match kind {
+            FunctionKind::Declaration => {
+                self.emit_binding(
+                    BindingOpcode::InitVar,
+                    name.expect("function declaration must have a name"),
+                );
+            }
+            FunctionKind::Expression | FunctionKind::Arrow => {
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
+        }
+    }
+
+    /// Adds the code of an inner function and emits the instruction that pushes a new
+    /// function object for it.
+    fn emit_get_function(&mut self, code: CodeBlock) {
+        let index = self.code_block.functions.len() as u32;
+        self.code_block.functions.push(Gc::new(code));
+        self.emit(Opcode::GetFunction, &[index]);
+    }
+
+    /// Pushes the value of the binding `name`, with the given opcode to get the value of the
+    /// binding from its environment.
+    ///
+    /// The imported bindings of a module are not part of its environments, so they are read
+    /// from the namespace object of the imported module instead.
+    fn emit_get_binding(&mut self, name: Sym, opcode: Opcode) {
+        let binding = self.context.get_binding_value(name);
+        if binding.is_global() {
+            if let Some(import) = self.context.module_imports.get(&name).copied() {
+                let namespace = self.context.get_binding_value(import.namespace);
+                let index = self.get_or_insert_binding(namespace);
+                self.emit(Opcode::GetName, &[index]);
+                if let Some(import_name) = import.import_name {
+                    let index = self.get_or_insert_name(import_name);
+                    self.emit(Opcode::GetPropertyByName, &[index]);
+                }
+                return;
+            }
+        }
+        let index = self.get_or_insert_binding(binding);
+        self.emit(opcode, &[index]);
+    }
+
+    /// Pushes the private name bound to `name` in the scope of the enclosing class bodies.
+    fn emit_private_name(&mut self, name: Sym) {
+        let binding = self.context.get_binding_value(name);
+        let index = self.get_or_insert_binding(binding);
+        self.emit(Opcode::GetName, &[index]);
+    }
+
+    /// Compiles the code of a function, without emitting any instruction in the current code.
+    fn function_code(
+        &mut self,
+        kind: FunctionKind,
+        name: Option<Sym>,
+        parameters: &[FormalParameter],
+        body: &StatementList,
+        flags: CodeBlockFlags,
+    ) -> JsResult<CodeBlock> {
         let strict = body.strict() || self.code_block.strict;
         let length = parameters.len() as u32;
         let mut code = CodeBlock::new(name.unwrap_or(Sym::EMPTY_STRING), length, strict, true);
@@ -1694,12 +2110,19 @@ impl<'b> ByteCompiler<'b> {
             code.this_mode = ThisMode::Lexical;
         }
 
+        // Generator and async functions are not constructors.
+        if flags.intersects(CodeBlockFlags::GENERATOR | CodeBlockFlags::ASYNC) {
+            code.constructor = false;
+        }
+        code.flags |= flags;
+
         let mut compiler = ByteCompiler {
             code_block: code,
             literals_map: FxHashMap::default(),
             names_map: FxHashMap::default(),
             bindings_map: FxHashMap::default(),
             jump_info: Vec::new(),
+            source: self.source.clone(),
             context: self.context,
         };
 
@@ -1779,6 +2202,15 @@ impl<'b> ByteCompiler<'b> {
             compiler.create_declarations(node)?;
         }
 
+        // A generator suspends once its parameters have been initialized, so the body only
+        // starts running on the first call to `next`.
+        if flags.contains(CodeBlockFlags::GENERATOR) {
+            compiler.emit_opcode(Opcode::PushUndefined);
+            compiler.emit_opcode(Opcode::Yield);
+            compiler.emit_opcode(Opcode::GeneratorNext);
+            compiler.emit_opcode(Opcode::Pop);
+        }
+
         compiler.compile_statement_list(body, false)?;
 
         if let Some(env_label) = env_label {
@@ -1801,28 +2233,7 @@ impl<'b> ByteCompiler<'b> {
         compiler.emit(Opcode::PushUndefined, &[]);
         compiler.emit(Opcode::Return, &[]);
 
-        let code = Gc::new(compiler.finish());
-
-        let index = self.code_block.functions.len() as u32;
-        self.code_block.functions.push(code);
-
-        self.emit(Opcode::GetFunction, &[index]);
-
-        match kind {
-            FunctionKind::Declaration => {
-                self.emit_binding(
-                    BindingOpcode::InitVar,
-                    name.expect("function declaration must have a name"),
-                );
-            }
-            FunctionKind::Expression | FunctionKind::Arrow => {
-                if !use_expr {
-                    self.emit(Opcode::Pop, &[]);
-                }
-            }
-        }
-
-        Ok(())
+        Ok(compiler.finish())
     }
 
     pub(crate) fn call(&mut self, node: &Node, use_expr: bool) -> JsResult<()> {
@@ -1852,34 +2263,379 @@ impl<'b> ByteCompiler<'b> {
                 self.emit(Opcode::Swap, &[]);
                 self.emit(Opcode::GetPropertyByValue, &[]);
             }
+            Node::GetSuperField(field) => {
+                self.emit_opcode(Opcode::This);
+                self.access_get(Access::SuperField { node: field }, true)?;
+            }
+            Node::GetPrivateField(field) => {
+                self.compile_expr(field.obj(), true)?;
+                self.emit(Opcode::Dup, &[]);
+                self.emit_private_name(field.field());
+                self.emit_opcode(Opcode::GetPrivateField);
+            }
             expr => {
                 self.compile_expr(expr, true)?;
                 if kind == CallKind::Call {
-                    self.emit_opcode(Opcode::This);
+                    self.emit_opcode(Opcode::PushUndefined);
                     self.emit_opcode(Opcode::Swap);
                 }
             }
         }
 
-        for arg in call.args().iter() {
+        let has_spread = self.compile_arguments(call.args())?;
+
+        match kind {
+            CallKind::Call if has_spread => self.emit_opcode(Opcode::CallSpread),
+            CallKind::Call => self.emit(Opcode::Call, &[call.args().len() as u32]),
+            CallKind::New if has_spread => self.emit_opcode(Opcode::NewSpread),
+            CallKind::New => self.emit(Opcode::New, &[call.args().len() as u32]),
+        }
+
+        if !use_expr {
+            self.emit(Opcode::Pop, &[]);
+        }
+        Ok(())
+    }
+
+    /// Compiles the arguments of a call, returning `true` if they contain a spread element.
+    ///
+    /// Without spread elements the arguments are pushed on the stack one by one. Otherwise they
+    /// are collected in an array, and every spread element is expanded with the iterator protocol.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-argumentlistevaluation
+    fn compile_arguments(&mut self, args: &[Node]) -> JsResult<bool> {
+        if !args.iter().any(|arg| matches!(arg, Node::Spread(_))) {
+            for arg in args {
+                self.compile_expr(arg, true)?;
+            }
+            return Ok(false);
+        }
+
+        self.emit_opcode(Opcode::PushNewArray);
+        self.emit_opcode(Opcode::PopOnReturnAdd);
+
+        for arg in args {
             self.compile_expr(arg, true)?;
+            if let Node::Spread(_) = arg {
+                self.emit_opcode(Opcode::InitIterator);
+                self.emit_opcode(Opcode::PushIteratorToArray);
+            } else {
+                self.emit_opcode(Opcode::PushValueToArray);
+            }
         }
 
-        let last_is_rest_parameter = matches!(call.args().last(), Some(Node::Spread(_)));
+        self.emit_opcode(Opcode::PopOnReturnSub);
+        Ok(true)
+    }
+
+    /// Compiles a class declaration or expression, leaving the class constructor on the stack.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-classdefinitionevaluation
+    fn class(&mut self, class: &Class) -> JsResult<()> {
+        // All parts of a class are strict mode code.
+        let strict = self.code_block.strict;
+        self.code_block.strict = true;
+
+        let mut private_names = Vec::new();
+        for element in class.elements() {
+            match element {
+                ClassElement::PrivateMethodDefinition(_, name, _)
+                | ClassElement::PrivateStaticMethodDefinition(_, name, _)
+                | ClassElement::PrivateFieldDefinition(name, _)
+                | ClassElement::PrivateStaticFieldDefinition(name, _)
+                    if !private_names.contains(name) =>
+                {
+                    private_names.push(*name);
+                }
+                _ => {}
+            }
+        }
+
+        // The name of the class is bound in the scope of the class body,
+        // so that the class can refer to itself in its methods and static fields.
+        // Each private name of the class is bound in the same scope, to a new unique name
+        // for every evaluation of the class.
+        let class_env = if class.name().is_some() || !private_names.is_empty() {
+            self.context.push_compile_time_environment(false);
+            if let Some(name) = class.name() {
+                self.context.create_immutable_binding(name)?;
+            }
+            for name in &private_names {
+                self.context.create_immutable_binding(*name)?;
+            }
+            let class_env = self.jump_with_custom_opcode(Opcode::PushDeclarativeEnvironment);
+            for name in &private_names {
+                let index = self.get_or_insert_name(*name);
+                self.emit(Opcode::PushPrivateName, &[index]);
+                self.emit_binding(BindingOpcode::InitConst, *name);
+            }
+            Some(class_env)
+        } else {
+            None
+        };
+
+        if let Some(super_ref) = class.super_ref() {
+            self.compile_expr(super_ref, true)?;
+        } else {
+            self.emit_opcode(Opcode::PushUndefined);
+        }
+        self.emit_opcode(Opcode::PushClassPrototype);
+
+        let mut code = if let Some(constructor) = class.constructor() {
+            self.function_code(
+                FunctionKind::Expression,
+                class.name(),
+                constructor.parameters(),
+                constructor.body(),
+                CodeBlockFlags::empty(),
+            )?
+        } else if class.super_ref().is_some() {
+            // The default constructor of a derived class passes all arguments to the parent.
+            let body = StatementList::from(vec![Node::from(SuperCall::new(vec![Node::from(
+                Spread::new(Identifier::new(Sym::ARGUMENTS)),
+            )]))]);
+            self.function_code(
+                FunctionKind::Expression,
+                class.name(),
+                &[],
+                &body,
+                CodeBlockFlags::empty(),
+            )?
+        } else {
+            let body = StatementList::from(Vec::new());
+            self.function_code(
+                FunctionKind::Expression,
+                class.name(),
+                &[],
+                &body,
+                CodeBlockFlags::empty(),
+            )?
+        };
+        code.flags |= CodeBlockFlags::CLASS_CONSTRUCTOR;
+        if class.super_ref().is_some() {
+            code.constructor_kind = ConstructorKind::Derived;
+        }
+        self.emit_get_function(code);
+        self.emit_opcode(Opcode::SetClassPrototype);
+
+        // Stack: constructor, prototype
+        for element in class.elements() {
+            match element {
+                ClassElement::MethodDefinition(kind, name, function) => {
+                    self.emit_opcode(Opcode::Dup);
+                    self.class_method(*kind, name, function)?;
+                }
+                ClassElement::StaticMethodDefinition(kind, name, function) => {
+                    self.emit_opcode(Opcode::Swap);
+                    self.emit_opcode(Opcode::Dup);
+                    self.class_method(*kind, name, function)?;
+                    self.emit_opcode(Opcode::Swap);
+                }
+                ClassElement::FieldDefinition(name, init) => {
+                    self.emit_opcode(Opcode::Swap);
+                    self.emit_opcode(Opcode::Dup);
+                    self.class_field(name, init.as_ref())?;
+                    self.emit_opcode(Opcode::PushClassField);
+                    self.emit_opcode(Opcode::Swap);
+                }
+                // Static fields are defined once the class is fully defined.
+                // TODO: Implement async generators
+                ClassElement::StaticFieldDefinition(_, _)
+                | ClassElement::PrivateStaticFieldDefinition(_, _)
+                | ClassElement::PrivateMethodDefinition(
+                    MethodDefinitionKind::AsyncGenerator,
+                    _,
+                    _,
+                )
+                | ClassElement::PrivateStaticMethodDefinition(
+                    MethodDefinitionKind::AsyncGenerator,
+                    _,
+                    _,
+                ) => {}
+                ClassElement::PrivateMethodDefinition(kind, name, function) => {
+                    self.emit_opcode(Opcode::Swap);
+                    self.emit_opcode(Opcode::Dup);
+                    self.class_private_method(*kind, *name, function, false)?;
+                    self.emit_opcode(Opcode::Swap);
+                }
+                ClassElement::PrivateStaticMethodDefinition(kind, name, function) => {
+                    self.emit_opcode(Opcode::Swap);
+                    self.emit_opcode(Opcode::Dup);
+                    self.class_private_method(*kind, *name, function, true)?;
+                    self.emit_opcode(Opcode::Swap);
+                }
+                ClassElement::PrivateFieldDefinition(name, init) => {
+                    self.emit_opcode(Opcode::Swap);
+                    self.emit_opcode(Opcode::Dup);
+                    self.emit_private_name(*name);
+                    self.class_field_initializer(init.as_ref())?;
+                    self.emit_opcode(Opcode::PushClassPrivateField);
+                    self.emit_opcode(Opcode::Swap);
+                }
+            }
+        }
+        self.emit_opcode(Opcode::Pop);
+
+        if let Some(name) = class.name() {
+            self.emit_opcode(Opcode::Dup);
+            self.emit_binding(BindingOpcode::InitConst, name);
+        }
+
+        // Static fields are evaluated once the class is fully defined.
+        for element in class.elements() {
+            match element {
+                ClassElement::StaticFieldDefinition(name, init) => {
+                    self.emit_opcode(Opcode::Dup);
+                    self.class_field(name, init.as_ref())?;
+                    self.emit_opcode(Opcode::DefineClassStaticField);
+                }
+                ClassElement::PrivateStaticFieldDefinition(name, init) => {
+                    self.emit_opcode(Opcode::Dup);
+                    self.emit_private_name(*name);
+                    self.class_field_initializer(init.as_ref())?;
+                    self.emit_opcode(Opcode::DefineClassStaticPrivateField);
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(class_env) = class_env {
+            let num_bindings = self.context.pop_compile_time_environment().num_bindings();
+            self.patch_jump_with_target(class_env, num_bindings as u32);
+            self.emit_opcode(Opcode::PopEnvironment);
+        }
+
+        self.code_block.strict = strict;
+        Ok(())
+    }
+
+    /// Compiles a method of a class and defines it on the object on top of the stack.
+    fn class_method(
+        &mut self,
+        kind: MethodDefinitionKind,
+        name: &PropertyName,
+        function: &FunctionExpr,
+    ) -> JsResult<()> {
+        let (by_name, by_value) = match kind {
+            MethodDefinitionKind::Get => (
+                Opcode::DefineClassGetterByName,
+                Opcode::DefineClassGetterByValue,
+            ),
+            MethodDefinitionKind::Set => (
+                Opcode::DefineClassSetterByName,
+                Opcode::DefineClassSetterByValue,
+            ),
+            MethodDefinitionKind::Ordinary
+            | MethodDefinitionKind::Generator
+            | MethodDefinitionKind::Async
+            | MethodDefinitionKind::AsyncGenerator => (
+                Opcode::DefineClassMethodByName,
+                Opcode::DefineClassMethodByValue,
+            ),
+        };
+
+        if let PropertyName::Computed(name_node) = name {
+            self.compile_expr(name_node, true)?;
+        }
 
         match kind {
-            CallKind::Call if last_is_rest_parameter => {
-                self.emit(Opcode::CallWithRest, &[call.args().len() as u32]);
+            // TODO: Implement async generators
+            MethodDefinitionKind::AsyncGenerator => {
+                self.emit_opcode(Opcode::PushUndefined);
             }
-            CallKind::Call => self.emit(Opcode::Call, &[call.args().len() as u32]),
-            CallKind::New if last_is_rest_parameter => {
-                self.emit(Opcode::NewWithRest, &[call.args().len() as u32]);
+            _ => {
+                let mut code = self.function_code(
+                    FunctionKind::Expression,
+                    function.name(),
+                    function.parameters(),
+                    function.body(),
+                    method_flags(kind),
+                )?;
+                code.constructor = false;
+                code.source = self.source_text(function.source_span());
+                self.emit_get_function(code);
             }
-            CallKind::New => self.emit(Opcode::New, &[call.args().len() as u32]),
         }
 
-        if !use_expr {
-            self.emit(Opcode::Pop, &[]);
+        match name {
+            PropertyName::Literal(name) => {
+                self.emit_opcode(Opcode::Swap);
+                let index = self.get_or_insert_name(*name);
+                self.emit(by_name, &[index]);
+            }
+            PropertyName::Computed(_) => self.emit_opcode(by_value),
+        }
+        Ok(())
+    }
+
+    /// Compiles a private method of a class and adds it to the class constructor on top of the
+    /// stack, either for each instance or for the constructor itself if it is static.
+    fn class_private_method(
+        &mut self,
+        kind: MethodDefinitionKind,
+        name: Sym,
+        function: &FunctionExpr,
+        is_static: bool,
+    ) -> JsResult<()> {
+        let opcode = match (kind, is_static) {
+            (MethodDefinitionKind::Get, false) => Opcode::PushClassPrivateGetter,
+            (MethodDefinitionKind::Set, false) => Opcode::PushClassPrivateSetter,
+            (_, false) => Opcode::PushClassPrivateMethod,
+            (MethodDefinitionKind::Get, true) => Opcode::DefineClassStaticPrivateGetter,
+            (MethodDefinitionKind::Set, true) => Opcode::DefineClassStaticPrivateSetter,
+            (_, true) => Opcode::DefineClassStaticPrivateMethod,
+        };
+
+        self.emit_private_name(name);
+        let mut code = self.function_code(
+            FunctionKind::Expression,
+            Some(name),
+            function.parameters(),
+            function.body(),
+            method_flags(kind),
+        )?;
+        code.constructor = false;
+        code.source = self.source_text(function.source_span());
+        self.emit_get_function(code);
+        self.emit_opcode(opcode);
+        Ok(())
+    }
+
+    /// Pushes the key and the initializer function of a class field.
+    ///
+    /// The initializer is `undefined` if the field has no initializer.
+    fn class_field(&mut self, name: &PropertyName, init: Option<&Node>) -> JsResult<()> {
+        match name {
+            PropertyName::Literal(name) => self.emit_push_literal(Literal::String(
+                self.interner().resolve_expect(*name).into(),
+            )),
+            PropertyName::Computed(name_node) => self.compile_expr(name_node, true)?,
+        }
+        self.class_field_initializer(init)
+    }
+
+    /// Pushes the initializer function of a class field, or `undefined` if the field has no
+    /// initializer.
+    fn class_field_initializer(&mut self, init: Option<&Node>) -> JsResult<()> {
+        if let Some(init) = init {
+            let body = StatementList::from(vec![Node::from(Return::new(init.clone(), None))]);
+            let mut code = self.function_code(
+                FunctionKind::Expression,
+                None,
+                &[],
+                &body,
+                CodeBlockFlags::empty(),
+            )?;
+            code.constructor = false;
+            self.emit_get_function(code);
+        } else {
+            self.emit_opcode(Opcode::PushUndefined);
         }
         Ok(())
     }
@@ -2143,6 +2899,13 @@ impl<'b> ByteCompiler<'b> {
                 }
                 self.context.create_mutable_binding(ident, true, true)?;
             }
+            Node::ClassDecl(decl) => {
+                let ident = decl.name().expect("class declaration must have a name");
+                if ident == Sym::ARGUMENTS {
+                    has_identifier_argument = true;
+                }
+                self.context.create_mutable_binding(ident, false, false)?;
+            }
             Node::DoWhileLoop(do_while_loop) => {
                 if !matches!(do_while_loop.body(), Node::Block(_)) {
                     self.create_declarations(do_while_loop.body())?;
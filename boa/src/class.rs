@@ -76,6 +76,13 @@ pub trait Class: NativeObject + Sized {
     const LENGTH: usize = 0;
     /// The attibutes the class will be binded with, default is `writable`, `enumerable`, `configurable`.
     const ATTRIBUTES: Attribute = Attribute::all();
+    /// The name of the native class this class extends, default is `None`.
+    ///
+    /// The parent class must already be registered as a global class. The class then inherits
+    /// the static members of the parent, and its prototype inherits from the prototype of the
+    /// parent. Instances of the class only hold the native data of the class itself, so methods
+    /// of the parent that downcast `this` to the parent type don't accept them.
+    const PARENT: Option<&'static str> = None;
 
     /// The constructor of the class.
     fn constructor(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<Self>;
@@ -167,6 +174,15 @@ impl<'context> ClassBuilder<'context> {
         Self { builder }
     }
 
+    /// Makes the class extend the class with the given constructor and prototype.
+    #[inline]
+    pub(crate) fn inherit_from(&mut self, constructor: JsObject, prototype: JsObject) -> &mut Self {
+        self.builder
+            .custom_prototype(constructor)
+            .inherit(prototype);
+        self
+    }
+
     #[inline]
     pub(crate) fn build(mut self) -> JsObject {
         self.builder.build()
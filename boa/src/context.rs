@@ -3,23 +3,31 @@
 use crate::{
     builtins::{
         self, function::NativeFunctionSignature, intrinsics::IntrinsicObjects,
-        iterable::IteratorPrototypes, typed_array::TypedArray,
+        iterable::IteratorPrototypes, promise::OperationType, proxy::Proxy,
+        typed_array::TypedArray,
     },
     bytecompiler::ByteCompiler,
     class::{Class, ClassBuilder},
     gc::Gc,
-    object::{FunctionBuilder, GlobalPropertyMap, JsObject, ObjectData},
+    job::{JobCallback, JobQueue, NativeJob, SimpleJobQueue},
+    module::{Module, ModuleImport, ModuleLoader},
+    object::{FunctionBuilder, GlobalPropertyMap, JsObject, ObjectData, PROTOTYPE},
     property::{Attribute, PropertyDescriptor, PropertyKey},
     realm::Realm,
     syntax::{ast::node::StatementList, parser::ParseError, Parser},
-    vm::{CallFrame, CodeBlock, FinallyReturn, Vm},
-    BoaProfiler, Interner, JsResult, JsValue,
+    vm::{CallFrame, CodeBlock, FinallyReturn, GeneratorResumeKind, StackTraceFrame, Vm},
+    BoaProfiler, Interner, JsResult, JsString, JsValue,
 };
 use boa_interner::Sym;
+use rustc_hash::FxHashMap;
+use std::{fmt, rc::Rc};
 
 #[cfg(feature = "console")]
 use crate::builtins::console::Console;
 
+#[cfg(feature = "fs-module-loader")]
+use crate::module::FsModuleLoader;
+
 /// Store a builtin constructor (such as `Object`) and its corresponding prototype.
 #[derive(Debug, Clone)]
 pub struct StandardConstructor {
@@ -62,7 +70,50 @@ impl StandardConstructor {
     }
 }
 
+/// A host hook that is called when a global binding can't be found.
+///
+/// See [`Context::set_global_not_found_hook`].
+#[derive(Clone)]
+struct GlobalNotFoundHook(Rc<dyn Fn(&str, &mut Context) -> JsResult<Option<JsValue>>>);
+
+impl fmt::Debug for GlobalNotFoundHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("GlobalNotFoundHook")
+    }
+}
+
+/// A handle to one of the realms of a [`Context`].
+///
+/// The handles of a context are only valid for that context. See [`Context::create_realm`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RealmHandle(usize);
+
+/// The state of a realm of a [`Context`] while it isn't the current realm.
+#[derive(Debug)]
+struct RealmState {
+    realm: Realm,
+    iterator_prototypes: IteratorPrototypes,
+    typed_array_constructor: StandardConstructor,
+    standard_objects: StandardObjects,
+    intrinsic_objects: IntrinsicObjects,
+}
+
+impl RealmState {
+    /// Creates the state of a realm whose intrinsics haven't been initialized yet.
+    fn new() -> Self {
+        Self {
+            realm: Realm::create(),
+            iterator_prototypes: IteratorPrototypes::default(),
+            typed_array_constructor: StandardConstructor::default(),
+            standard_objects: StandardObjects::default(),
+            intrinsic_objects: IntrinsicObjects::default(),
+        }
+    }
+}
+
 /// Cached core standard objects.
+///
+/// See [`Context::standard_objects`].
 #[derive(Debug, Clone)]
 pub struct StandardObjects {
     object: StandardConstructor,
@@ -82,8 +133,11 @@ pub struct StandardObjects {
     syntax_error: StandardConstructor,
     eval_error: StandardConstructor,
     uri_error: StandardConstructor,
+    aggregate_error: StandardConstructor,
     map: StandardConstructor,
     set: StandardConstructor,
+    weak_map: StandardConstructor,
+    weak_set: StandardConstructor,
     typed_array: StandardConstructor,
     typed_int8_array: StandardConstructor,
     typed_uint8_array: StandardConstructor,
@@ -98,6 +152,7 @@ pub struct StandardObjects {
     typed_float64_array: StandardConstructor,
     array_buffer: StandardConstructor,
     data_view: StandardConstructor,
+    promise: StandardConstructor,
 }
 
 impl Default for StandardObjects {
@@ -129,8 +184,11 @@ impl Default for StandardObjects {
             syntax_error: StandardConstructor::default(),
             eval_error: StandardConstructor::default(),
             uri_error: StandardConstructor::default(),
+            aggregate_error: StandardConstructor::default(),
             map: StandardConstructor::default(),
             set: StandardConstructor::default(),
+            weak_map: StandardConstructor::default(),
+            weak_set: StandardConstructor::default(),
             typed_array: StandardConstructor::default(),
             typed_int8_array: StandardConstructor::default(),
             typed_uint8_array: StandardConstructor::default(),
@@ -145,6 +203,7 @@ impl Default for StandardObjects {
             typed_float64_array: StandardConstructor::default(),
             array_buffer: StandardConstructor::default(),
             data_view: StandardConstructor::default(),
+            promise: StandardConstructor::default(),
         }
     }
 }
@@ -235,6 +294,11 @@ impl StandardObjects {
         &self.uri_error
     }
 
+    #[inline]
+    pub fn aggregate_error_object(&self) -> &StandardConstructor {
+        &self.aggregate_error
+    }
+
     #[inline]
     pub fn map_object(&self) -> &StandardConstructor {
         &self.map
@@ -245,6 +309,16 @@ impl StandardObjects {
         &self.set
     }
 
+    #[inline]
+    pub fn weak_map_object(&self) -> &StandardConstructor {
+        &self.weak_map
+    }
+
+    #[inline]
+    pub fn weak_set_object(&self) -> &StandardConstructor {
+        &self.weak_set
+    }
+
     #[inline]
     pub fn typed_array_object(&self) -> &StandardConstructor {
         &self.typed_array
@@ -314,8 +388,18 @@ impl StandardObjects {
     pub fn data_view_object(&self) -> &StandardConstructor {
         &self.data_view
     }
+
+    #[inline]
+    pub fn promise_object(&self) -> &StandardConstructor {
+        &self.promise
+    }
 }
 
+/// The maximum number of identifier strings cached by a [`Context`].
+///
+/// See [`Context::identifier_string`].
+pub(crate) const IDENTIFIER_STRING_CACHE_LIMIT: usize = 4096;
+
 /// Javascript context. It is the primary way to interact with the runtime.
 ///
 /// `Context`s constructed in a thread share the same runtime, therefore it
@@ -365,6 +449,11 @@ pub struct Context {
     /// String interner in the context.
     interner: Interner,
 
+    /// A cache of the strings of the interned identifiers that have been used as property keys
+    /// or global binding names at runtime, holding at most [`IDENTIFIER_STRING_CACHE_LIMIT`]
+    /// strings for the lifetime of the context.
+    identifier_string_cache: FxHashMap<Sym, JsString>,
+
     /// console object state.
     #[cfg(feature = "console")]
     console: Console,
@@ -384,7 +473,35 @@ pub struct Context {
     /// Whether or not global strict mode is active.
     strict: bool,
 
+    /// The value of `this` at the top level of scripts, if it isn't the global object.
+    script_this: Option<JsValue>,
+
     pub(crate) vm: Vm,
+
+    /// Queue of pending promise jobs and native jobs.
+    job_queue: Rc<dyn JobQueue>,
+
+    /// Host hook called when a global binding can't be found.
+    global_not_found_hook: Option<GlobalNotFoundHook>,
+
+    /// The promises that have been rejected without a handler, with their rejection reason.
+    unhandled_rejections: Vec<(JsObject, JsValue)>,
+
+    /// Host hook that resolves the modules imported by other modules.
+    module_loader: Option<Rc<dyn ModuleLoader>>,
+
+    /// The imported bindings of the module that is being compiled.
+    pub(crate) module_imports: FxHashMap<Sym, ModuleImport>,
+
+    /// The state of the realms of the context, indexed by their handle. The slot of the current
+    /// realm is empty, since its state is stored in the fields of the context.
+    realms: Vec<Option<RealmState>>,
+
+    /// The handle of the current realm.
+    current_realm: RealmHandle,
+
+    /// The last error thrown by [`Context::throw_conversion_error`].
+    conversion_error: Option<JsValue>,
 }
 
 impl Default for Context {
@@ -392,6 +509,7 @@ impl Default for Context {
         let mut context = Self {
             realm: Realm::create(),
             interner: Interner::default(),
+            identifier_string_cache: FxHashMap::default(),
             #[cfg(feature = "console")]
             console: Console::default(),
             iterator_prototypes: IteratorPrototypes::default(),
@@ -399,29 +517,34 @@ impl Default for Context {
             standard_objects: StandardObjects::default(),
             intrinsic_objects: IntrinsicObjects::default(),
             strict: false,
+            script_this: None,
             vm: Vm {
                 frame: None,
                 stack: Vec::with_capacity(1024),
                 trace: false,
                 stack_size_limit: 1024,
+                instruction_budget: None,
+                runtime_limit_exceeded: false,
+                error_trace: Vec::new(),
+                error_trace_value: None,
             },
+            job_queue: Rc::new(SimpleJobQueue::new()),
+            global_not_found_hook: None,
+            unhandled_rejections: Vec::new(),
+            #[cfg(feature = "fs-module-loader")]
+            module_loader: Some(Rc::new(FsModuleLoader::default())),
+            #[cfg(not(feature = "fs-module-loader"))]
+            module_loader: None,
+            module_imports: FxHashMap::default(),
+            realms: vec![None],
+            current_realm: RealmHandle(0),
+            conversion_error: None,
         };
 
         // Add new builtIns to Context Realm
         // At a later date this can be removed from here and called explicitly,
         // but for now we almost always want these default builtins
-        let typed_array_constructor_constructor = TypedArray::init(&mut context);
-        let typed_array_constructor_prototype = typed_array_constructor_constructor
-            .get("prototype", &mut context)
-            .expect("prototype must exist")
-            .as_object()
-            .expect("prototype must be object")
-            .clone();
-        context.typed_array_constructor.constructor = typed_array_constructor_constructor;
-        context.typed_array_constructor.prototype = typed_array_constructor_prototype;
         context.create_intrinsics();
-        context.iterator_prototypes = IteratorPrototypes::init(&mut context);
-        context.intrinsic_objects = IntrinsicObjects::init(&mut context);
         context
     }
 }
@@ -448,6 +571,27 @@ impl Context {
         &mut self.interner
     }
 
+    /// Gets the string of an interned identifier.
+    ///
+    /// This only avoids allocating a new string each time the VM uses an identifier as a
+    /// property key: the strings of the first [`IDENTIFIER_STRING_CACHE_LIMIT`] identifiers are
+    /// cached, so the keys created from the same identifier share their storage and are
+    /// compared by pointer. Property keys are still hashed and compared by content, so keys
+    /// computed at runtime are equal to them. Cached strings are kept until the context is
+    /// dropped, and the strings of the identifiers used once the cache is full are allocated
+    /// every time.
+    #[inline]
+    pub(crate) fn identifier_string(&mut self, sym: Sym) -> JsString {
+        if let Some(string) = self.identifier_string_cache.get(&sym) {
+            return string.clone();
+        }
+        let string: JsString = self.interner.resolve_expect(sym).into();
+        if self.identifier_string_cache.len() < IDENTIFIER_STRING_CACHE_LIMIT {
+            self.identifier_string_cache.insert(sym, string.clone());
+        }
+        string
+    }
+
     /// A helper function for getting an immutable reference to the `console` object.
     #[cfg(feature = "console")]
     pub(crate) fn console(&self) -> &Console {
@@ -473,12 +617,187 @@ impl Context {
         self.strict = strict;
     }
 
+    /// Sets the value of `this` at the top level of the scripts evaluated by the context.
+    ///
+    /// By default, it is the global object of the current realm. Hosts can use this to provide a
+    /// different top-level `this`, like the global scope of a web worker. This doesn't change the
+    /// global object itself, so `globalThis` and the `this` of sloppy functions are unaffected.
+    ///
+    /// # Examples
+    /// ```
+    /// use boa::{object::ObjectInitializer, property::Attribute, Context};
+    ///
+    /// let mut context = Context::default();
+    /// let this = ObjectInitializer::new(&mut context)
+    ///     .property("name", "worker", Attribute::all())
+    ///     .build();
+    /// context.set_script_this(this.into());
+    ///
+    /// let value = context.eval("this.name").unwrap();
+    /// assert_eq!(value.as_string().unwrap().as_str(), "worker");
+    /// ```
+    #[inline]
+    pub fn set_script_this(&mut self, this: JsValue) {
+        self.script_this = Some(this);
+    }
+
+    /// Sets a hook that is called when a global binding can't be found, before a
+    /// `ReferenceError` is thrown.
+    ///
+    /// The hook receives the name of the binding. If it returns `Ok(Some(value))`, `value` is
+    /// used as the value of the binding; if it returns `Ok(None)`, the usual `ReferenceError` is
+    /// thrown. This can be used to provide globals lazily, e.g. by registering them with
+    /// [`Context::register_global_property`] on first access.
+    ///
+    /// # Examples
+    /// ```
+    /// use boa::{property::Attribute, Context};
+    ///
+    /// let mut context = Context::default();
+    /// context.set_global_not_found_hook(|name, context| {
+    ///     if name != "answer" {
+    ///         return Ok(None);
+    ///     }
+    ///     context.register_global_property("answer", 42, Attribute::all());
+    ///     Ok(Some(42.into()))
+    /// });
+    ///
+    /// let value = context.eval("answer + 1").unwrap();
+    /// assert_eq!(value.as_number(), Some(43.0));
+    /// ```
+    #[inline]
+    pub fn set_global_not_found_hook<F>(&mut self, hook: F)
+    where
+        F: Fn(&str, &mut Context) -> JsResult<Option<JsValue>> + 'static,
+    {
+        self.global_not_found_hook = Some(GlobalNotFoundHook(Rc::new(hook)));
+    }
+
+    /// Sets the host hook that resolves the modules imported by other modules.
+    ///
+    /// With the `fs-module-loader` feature, the default loader reads the modules from the file
+    /// system. Otherwise a context has no loader, and only modules without imports can be
+    /// evaluated.
+    #[inline]
+    pub fn set_module_loader(&mut self, loader: Box<dyn ModuleLoader>) {
+        self.module_loader = Some(loader.into());
+    }
+
+    /// Gets the module loader of the context, if there is one.
+    #[inline]
+    pub(crate) fn module_loader(&self) -> Option<Rc<dyn ModuleLoader>> {
+        self.module_loader.clone()
+    }
+
+    /// Resolves a global binding that couldn't be found, consulting the global not found hook
+    /// before throwing a `ReferenceError`.
+    pub(crate) fn global_binding_not_found(&mut self, name: &str) -> JsResult<JsValue> {
+        if let Some(hook) = self.global_not_found_hook.clone() {
+            if let Some(value) = (hook.0)(name, self)? {
+                return Ok(value);
+            }
+        }
+        self.throw_reference_error(format!("{name} is not defined"))
+    }
+
     /// Sets up the default global objects within Global
     #[inline]
     fn create_intrinsics(&mut self) {
         let _timer = BoaProfiler::global().start_event("create_intrinsics", "interpreter");
+        let typed_array_constructor_constructor = TypedArray::init(self);
+        let typed_array_constructor_prototype = typed_array_constructor_constructor
+            .get("prototype", self)
+            .expect("prototype must exist")
+            .as_object()
+            .expect("prototype must be object")
+            .clone();
+        self.typed_array_constructor.constructor = typed_array_constructor_constructor;
+        self.typed_array_constructor.prototype = typed_array_constructor_prototype;
         // Create intrinsics, add global objects here
         builtins::init(self);
+        self.iterator_prototypes = IteratorPrototypes::init(self);
+        self.intrinsic_objects = IntrinsicObjects::init(self);
+    }
+
+    /// Creates a new realm, with its own global object and intrinsics.
+    ///
+    /// The new realm is isolated from the other realms of the context: globals defined in one
+    /// realm aren't visible in the others, and each realm has its own copy of the builtins, like
+    /// `Object`, `Array.prototype` or `%ThrowTypeError%`. The realms still share the garbage
+    /// collected heap, the string interner, the job queue, the well-known symbols and the
+    /// `Symbol.for` registry, the console, the strict mode flag and the global-not-found hook.
+    ///
+    /// Objects can be passed between realms, and keep their original prototype chain, so for
+    /// example an array created in one realm isn't an `instanceof` the `Array` of another.
+    /// Functions always run in the current realm though, so a function called from another realm
+    /// sees the global object of the realm it's called from.
+    ///
+    /// The current realm isn't changed. Use [`Context::eval_in_realm`] or
+    /// [`Context::enter_realm`] to run code in the new realm.
+    ///
+    /// # Examples
+    /// ```
+    /// use boa::Context;
+    ///
+    /// let mut context = Context::default();
+    /// let sandbox = context.create_realm();
+    ///
+    /// context.eval_in_realm(sandbox, "var answer = 42;").unwrap();
+    ///
+    /// assert_eq!(context.eval("typeof answer").unwrap(), "undefined".into());
+    /// assert_eq!(context.eval_in_realm(sandbox, "answer").unwrap(), 42.into());
+    /// ```
+    pub fn create_realm(&mut self) -> RealmHandle {
+        let realm = RealmHandle(self.realms.len());
+        self.realms.push(Some(RealmState::new()));
+
+        let previous = self.enter_realm(realm);
+        self.create_intrinsics();
+        self.enter_realm(previous);
+
+        realm
+    }
+
+    /// Returns the handle of the current realm.
+    #[inline]
+    pub fn current_realm(&self) -> RealmHandle {
+        self.current_realm
+    }
+
+    /// Makes `realm` the current realm, returning the handle of the previous one.
+    ///
+    /// All the evaluated code and the methods of the context, like [`Context::global_object`] or
+    /// [`Context::register_global_property`], use the current realm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `realm` wasn't created by this context.
+    pub fn enter_realm(&mut self, realm: RealmHandle) -> RealmHandle {
+        let previous = self.current_realm;
+        if realm == previous {
+            return previous;
+        }
+
+        let mut state = self
+            .realms
+            .get_mut(realm.0)
+            .and_then(Option::take)
+            .expect("the realm must have been created by this context");
+        std::mem::swap(&mut self.realm, &mut state.realm);
+        std::mem::swap(
+            &mut self.iterator_prototypes,
+            &mut state.iterator_prototypes,
+        );
+        std::mem::swap(
+            &mut self.typed_array_constructor,
+            &mut state.typed_array_constructor,
+        );
+        std::mem::swap(&mut self.standard_objects, &mut state.standard_objects);
+        std::mem::swap(&mut self.intrinsic_objects, &mut state.intrinsic_objects);
+
+        self.realms[previous.0] = Some(state);
+        self.current_realm = realm;
+        previous
     }
 
     /// Constructs an object with the `%Object.prototype%` prototype.
@@ -494,7 +813,18 @@ impl Context {
     where
         S: AsRef<[u8]>,
     {
-        Parser::new(src.as_ref(), self.strict).parse_all(&mut self.interner)
+        self.parse_script(src.as_ref(), self.strict)
+    }
+
+    /// Parses a script, keeping its source code for the source text of its functions.
+    fn parse_script(&mut self, src: &[u8], strict: bool) -> Result<StatementList, ParseError> {
+        let mut statement_list = Parser::new(src, strict).parse_all(&mut self.interner)?;
+
+        // The source text is sliced from the source code, which must be valid UTF-8 for it.
+        if let Ok(source) = std::str::from_utf8(src) {
+            statement_list.set_source(source.into());
+        }
+        Ok(statement_list)
     }
 
     /// <https://tc39.es/ecma262/#sec-call>
@@ -516,15 +846,133 @@ impl Context {
         self.realm.global_object()
     }
 
+    /// Returns the value of `this` at the top level of scripts.
+    ///
+    /// See [`Context::set_script_this`].
+    #[inline]
+    pub(crate) fn script_this(&self) -> JsValue {
+        self.script_this
+            .clone()
+            .unwrap_or_else(|| self.global_object().clone().into())
+    }
+
+    /// Wraps the global object in a proxy with the given `handler`, to observe the global
+    /// environment.
+    ///
+    /// After this call, reads and writes of global identifiers go through the `has`, `get` and
+    /// `set` traps of the proxy, whose target is the global object. Declarations of global
+    /// variables and functions, and accesses through `globalThis`, still use the global object
+    /// directly. Calling this again replaces the previous proxy.
+    ///
+    /// The traps shouldn't resolve global identifiers themselves, since doing so calls the traps
+    /// again.
+    ///
+    /// # Examples
+    /// ```
+    /// use boa::{object::JsObject, Context};
+    ///
+    /// let mut context = Context::default();
+    /// let handler = context
+    ///     .eval(
+    ///         "var log = [];
+    ///         (function (log, set) {
+    ///             return { set(t, k, v) { log.push(k); return set(t, k, v); } };
+    ///         })(log, Reflect.set)",
+    ///     )
+    ///     .unwrap();
+    /// context.wrap_global_in_proxy(handler.as_object().unwrap().clone());
+    ///
+    /// context.eval("answer = 42;").unwrap();
+    /// let log = context.eval("log.join()").unwrap();
+    /// assert_eq!(log.as_string().unwrap().as_str(), "answer");
+    /// ```
+    pub fn wrap_global_in_proxy(&mut self, handler: JsObject) {
+        let global_object = self.global_object().clone().into();
+        let proxy = Proxy::create(&global_object, &handler.into(), self)
+            .expect("the global object and the handler are objects");
+        self.realm.global_binding_object = proxy.as_object().cloned();
+    }
+
+    /// Returns the names of the global object's own string-keyed properties.
+    ///
+    /// This includes the builtins as well as any global defined by evaluated code, in property
+    /// creation order. Symbol-keyed properties are not included.
+    ///
+    /// # Examples
+    /// ```
+    /// use boa::Context;
+    ///
+    /// let mut context = Context::default();
+    /// context.eval("var answer = 42;").unwrap();
+    ///
+    /// assert!(context.global_bindings().contains(&"answer".to_string()));
+    /// ```
+    pub fn global_bindings(&mut self) -> Vec<String> {
+        let global_object = self.global_object().clone();
+        global_object
+            .__own_property_keys__(self)
+            .expect("the global object [[OwnPropertyKeys]] cannot fail")
+            .into_iter()
+            .filter_map(|key| match &key {
+                PropertyKey::String(name) => Some(name.to_string()),
+                PropertyKey::Index(index) => Some(index.to_string()),
+                PropertyKey::Symbol(_) => None,
+            })
+            .collect()
+    }
+
+    /// Deletes the property `key` of the global object.
+    ///
+    /// Returns `true` if the property was deleted or didn't exist, and `false` if it is
+    /// non-configurable, following the semantics of the `delete` operator.
+    ///
+    /// # Examples
+    /// ```
+    /// use boa::Context;
+    ///
+    /// let mut context = Context::default();
+    /// context.eval("var answer = 42;").unwrap();
+    ///
+    /// assert!(context.delete_global("answer"));
+    /// assert!(context.eval("answer").is_err());
+    /// ```
+    pub fn delete_global<K>(&mut self, key: K) -> bool
+    where
+        K: Into<PropertyKey>,
+    {
+        let global_object = self.global_object().clone();
+        global_object
+            .__delete__(&key.into(), self)
+            .expect("the global object [[Delete]] cannot fail")
+    }
+
+    /// Initializes the builtin bound to the global `name`, if its initialization was deferred.
+    ///
+    /// This must be called before reading or replacing the global binding `name`, since the
+    /// binding of a lazy builtin holds `undefined` until it is initialized.
+    #[inline]
+    pub(crate) fn initialize_lazy_global(&mut self, name: &str) {
+        if self.realm.lazy_globals.is_empty() {
+            return;
+        }
+
+        if let Some(init) = self.realm.lazy_globals.remove(name) {
+            let value = init(self);
+            if let Some(desc) = self.global_string_bindings_mut().get_mut(name) {
+                desc.fill_with(&PropertyDescriptor::builder().value(value).build());
+            }
+        }
+    }
+
     /// Return a reference to the global object string bindings.
     #[inline]
-    pub(crate) fn global_bindings(&self) -> &GlobalPropertyMap {
+    pub(crate) fn global_string_bindings(&self) -> &GlobalPropertyMap {
         self.realm.global_bindings()
     }
 
     /// Return a mutable reference to the global object string bindings.
     #[inline]
-    pub(crate) fn global_bindings_mut(&mut self) -> &mut GlobalPropertyMap {
+    pub(crate) fn global_string_bindings_mut(&mut self) -> &mut GlobalPropertyMap {
         self.realm.global_bindings_mut()
     }
 
@@ -605,6 +1053,32 @@ impl Context {
         Err(self.construct_type_error(message))
     }
 
+    /// Throws a `TypeError` with the specified message, for a value that can't be converted to
+    /// a Rust type with [`TryFromJs`](crate::value::TryFromJs).
+    ///
+    /// Unlike other errors, a conversion error isn't propagated by [`JsObject::get_as`], which
+    /// returns `None` instead.
+    #[inline]
+    pub fn throw_conversion_error<M, R>(&mut self, message: M) -> JsResult<R>
+    where
+        M: Into<Box<str>>,
+    {
+        let error = self.construct_type_error(message);
+        self.conversion_error = Some(error.clone());
+        Err(error)
+    }
+
+    /// Checks if `error` is the last error thrown by [`Context::throw_conversion_error`], and
+    /// forgets that error.
+    #[inline]
+    pub(crate) fn take_conversion_error(&mut self, error: &JsValue) -> bool {
+        self.conversion_error
+            .take()
+            .map_or(false, |conversion_error| {
+                conversion_error.strict_equals(error)
+            })
+    }
+
     /// Constructs a `ReferenceError` with the specified message.
     #[inline]
     pub fn construct_reference_error<M>(&mut self, message: M) -> JsValue
@@ -737,7 +1211,8 @@ impl Context {
             .constructor(true)
             .build();
 
-        self.global_bindings_mut().insert(
+        self.initialize_lazy_global(name);
+        self.global_string_bindings_mut().insert(
             name.into(),
             PropertyDescriptor::builder()
                 .value(function)
@@ -774,7 +1249,8 @@ impl Context {
             .constructor(false)
             .build();
 
-        self.global_bindings_mut().insert(
+        self.initialize_lazy_global(name);
+        self.global_string_bindings_mut().insert(
             name.into(),
             PropertyDescriptor::builder()
                 .value(function)
@@ -817,7 +1293,8 @@ impl Context {
             .constructor(true)
             .build();
 
-        self.global_bindings_mut().insert(
+        self.initialize_lazy_global(name);
+        self.global_string_bindings_mut().insert(
             name.into(),
             PropertyDescriptor::builder()
                 .value(function)
@@ -857,7 +1334,38 @@ impl Context {
     where
         T: Class,
     {
+        let parent = match T::PARENT {
+            Some(parent) => {
+                let constructor = self
+                    .global_object()
+                    .clone()
+                    .get(parent, self)?
+                    .as_constructor()
+                    .cloned()
+                    .ok_or_else(|| {
+                        self.construct_type_error(format!(
+                            "parent class `{parent}` of native class `{}` is not registered",
+                            T::NAME
+                        ))
+                    })?;
+                let prototype = constructor
+                    .get(PROTOTYPE, self)?
+                    .as_object()
+                    .cloned()
+                    .ok_or_else(|| {
+                        self.construct_type_error(format!(
+                            "invalid prototype for parent class `{parent}`"
+                        ))
+                    })?;
+                Some((constructor, prototype))
+            }
+            None => None,
+        };
+
         let mut class_builder = ClassBuilder::new::<T>(self);
+        if let Some((constructor, prototype)) = parent {
+            class_builder.inherit_from(constructor, prototype);
+        }
         T::init(&mut class_builder)?;
 
         let class = class_builder.build();
@@ -868,7 +1376,9 @@ impl Context {
             .configurable(T::ATTRIBUTES.configurable())
             .build();
 
-        self.global_bindings_mut().insert(T::NAME.into(), property);
+        self.initialize_lazy_global(T::NAME);
+        self.global_string_bindings_mut()
+            .insert(T::NAME.into(), property);
         Ok(())
     }
 
@@ -910,8 +1420,12 @@ impl Context {
         K: Into<PropertyKey>,
         V: Into<JsValue>,
     {
+        let key = key.into();
+        if let PropertyKey::String(ref name) = key {
+            self.initialize_lazy_global(name);
+        }
         self.realm.global_property_map.insert(
-            &key.into(),
+            &key,
             PropertyDescriptor::builder()
                 .value(value)
                 .writable(attribute.writable())
@@ -921,6 +1435,58 @@ impl Context {
         );
     }
 
+    /// Checks the syntax of the given code without compiling or evaluating it.
+    ///
+    /// The code is parsed exactly like [`Context::eval`] would, so early errors such as
+    /// duplicate lexical declarations or invalid assignment targets are reported as a
+    /// `SyntaxError`.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::default();
+    ///
+    /// assert!(context.check_syntax("let a = 1; a += 2;").is_ok());
+    /// assert!(context.check_syntax("let a; let a;").is_err());
+    /// ```
+    pub fn check_syntax<S>(&mut self, src: S) -> JsResult<()>
+    where
+        S: AsRef<[u8]>,
+    {
+        let _timer = BoaProfiler::global().start_event("Syntax check", "Main");
+
+        match Parser::new(src.as_ref(), false).parse_all(&mut self.interner) {
+            Ok(_) => Ok(()),
+            Err(e) => self.throw_syntax_error(e.to_string()),
+        }
+    }
+
+    /// Checks if the given code is incomplete, and could become valid if more code is appended.
+    ///
+    /// This is the case for code that ends inside of a block, a parenthesized expression or a
+    /// template literal, for example. A REPL can use it to read more lines instead of reporting a
+    /// syntax error. Complete code, and code with errors that more input can't fix, return
+    /// `false`.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::default();
+    ///
+    /// assert!(context.needs_more_input("function f() {"));
+    /// assert!(!context.needs_more_input("function f() {}"));
+    /// assert!(!context.needs_more_input("function f() }"));
+    /// ```
+    pub fn needs_more_input<S>(&mut self, src: S) -> bool
+    where
+        S: AsRef<[u8]>,
+    {
+        match self.parse(src) {
+            Ok(_) => false,
+            Err(e) => e.is_abrupt_end(),
+        }
+    }
+
     /// Evaluates the given code by compiling down to bytecode, then interpreting the bytecode into a value
     ///
     /// # Examples
@@ -933,15 +1499,117 @@ impl Context {
     /// assert!(value.is_number());
     /// assert_eq!(value.as_number().unwrap(), 4.0);
     /// ```
-    #[allow(clippy::unit_arg, clippy::drop_copy)]
+    #[inline]
     pub fn eval<S>(&mut self, src: S) -> JsResult<JsValue>
+    where
+        S: AsRef<[u8]>,
+    {
+        self.eval_with_scope(src, &[])
+    }
+
+    /// Evaluates an already parsed script, like [`Context::eval`] does after parsing the source.
+    ///
+    /// This allows tools to inspect or transform the AST returned by [`Context::parse`] before
+    /// running it, without having to turn it back into source code. The symbols of the AST must
+    /// come from the interner of this context.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{syntax::ast::{Const, Node}, Context, JsValue};
+    /// let mut context = Context::default();
+    ///
+    /// let statement_list = context.parse("1 + 3;").unwrap();
+    /// assert_eq!(context.eval_ast(&statement_list).unwrap(), JsValue::new(4));
+    ///
+    /// let statement_list = vec![Node::Const(Const::Int(10))].into();
+    /// assert_eq!(context.eval_ast(&statement_list).unwrap(), JsValue::new(10));
+    /// ```
+    pub fn eval_ast(&mut self, statement_list: &StatementList) -> JsResult<JsValue> {
+        let code_block = self.compile(statement_list)?;
+        let result = self.execute(code_block);
+        self.run_jobs_after_script(result)
+    }
+
+    /// Links and evaluates a module, and returns a promise that settles once the evaluation of
+    /// the module is done.
+    ///
+    /// The modules imported by the module are resolved with the module loader of the context.
+    /// The promise is fulfilled with `undefined` when the module and its dependencies have been
+    /// evaluated, including their top-level `await`s, and is rejected with the error thrown by
+    /// the evaluation otherwise. The pending jobs are run before returning, so the promise is
+    /// already settled unless the module awaits a promise that is settled by the host later.
+    ///
+    /// A module is only evaluated once, so evaluating it again returns the same promise. Errors
+    /// found while linking the module are thrown instead.
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa::{builtins::promise::PromiseState, module::Module, Context};
+    /// let mut context = Context::default();
+    ///
+    /// let module = Module::parse(
+    ///     "main.js",
+    ///     "export const answer = await Promise.resolve(6 * 7);",
+    ///     &mut context,
+    /// )
+    /// .unwrap();
+    /// let promise = context.eval_module(&module).unwrap();
+    /// let state = promise.borrow().as_promise().unwrap().state();
+    /// assert_eq!(state, PromiseState::Fulfilled);
+    ///
+    /// let namespace = module.namespace(&mut context);
+    /// let answer = namespace.get("answer", &mut context).unwrap();
+    /// assert_eq!(answer.as_number(), Some(42.0));
+    /// ```
+    pub fn eval_module(&mut self, module: &Module) -> JsResult<JsObject> {
+        let promise = module.link(self).and_then(|()| module.evaluate(self))?;
+        self.run_jobs_after_script(Ok(JsValue::undefined()))?;
+        Ok(promise)
+    }
+
+    /// Evaluates the given code in the given realm.
+    ///
+    /// The current realm is restored afterwards. See [`Context::create_realm`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `realm` wasn't created by this context.
+    pub fn eval_in_realm<S>(&mut self, realm: RealmHandle, src: S) -> JsResult<JsValue>
+    where
+        S: AsRef<[u8]>,
+    {
+        let previous = self.enter_realm(realm);
+        let result = self.eval(src);
+        self.enter_realm(previous);
+        result
+    }
+
+    /// Evaluates the given code in a new lexical scope that contains the given bindings.
+    ///
+    /// The bindings are mutable, like `let` declarations, and are only visible to the evaluated
+    /// code and the functions it creates, so they don't have to be defined as global properties.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::default();
+    ///
+    /// let value = context
+    ///     .eval_with_scope("a + b", &[("a", 1.into()), ("b", 2.into())])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(value.as_number(), Some(3.0));
+    /// assert_eq!(context.eval("typeof a").unwrap(), "undefined".into());
+    /// ```
+    #[allow(clippy::unit_arg, clippy::drop_copy)]
+    pub fn eval_with_scope<S>(&mut self, src: S, scope: &[(&str, JsValue)]) -> JsResult<JsValue>
     where
         S: AsRef<[u8]>,
     {
         let main_timer = BoaProfiler::global().start_event("Evaluation", "Main");
 
-        let parsing_result = Parser::new(src.as_ref(), false)
-            .parse_all(&mut self.interner)
+        let parsing_result = self
+            .parse_script(src.as_ref(), false)
             .map_err(|e| e.to_string());
 
         let statement_list = match parsing_result {
@@ -949,8 +1617,7 @@ impl Context {
             Err(e) => return self.throw_syntax_error(e),
         };
 
-        let code_block = self.compile(&statement_list)?;
-        let result = self.execute(code_block);
+        let result = self.eval_ast_with_scope(&statement_list, scope);
 
         // The main_timer needs to be dropped before the BoaProfiler is.
         drop(main_timer);
@@ -959,11 +1626,152 @@ impl Context {
         result
     }
 
+    /// Evaluates an already parsed script in a new lexical scope that contains the given
+    /// bindings. See [`Context::eval_with_scope`].
+    fn eval_ast_with_scope(
+        &mut self,
+        statement_list: &StatementList,
+        scope: &[(&str, JsValue)],
+    ) -> JsResult<JsValue> {
+        if scope.is_empty() {
+            return self.eval_ast(statement_list);
+        }
+
+        // The bindings of the scope are declared first, so their index is their position in
+        // `scope`.
+        self.push_compile_time_environment(false);
+        let code_block = scope
+            .iter()
+            .try_for_each(|(name, _)| {
+                let name = self.interner.get_or_intern(name);
+                self.create_mutable_binding(name, false, false)
+            })
+            .and_then(|()| self.compile(statement_list));
+        let num_bindings = self.pop_compile_time_environment().num_bindings();
+        let code_block = code_block?;
+
+        // The code is compiled as if the scope was the only environment above the global one, so
+        // it runs with an environment stack that matches it.
+        let mut environments = self.realm.environments.global_stack();
+        environments.push_declarative(num_bindings);
+        for (index, (_, value)) in scope.iter().enumerate() {
+            environments.put_value(1, index, value.clone());
+        }
+        std::mem::swap(&mut self.realm.environments, &mut environments);
+        let result = self.execute(code_block);
+        std::mem::swap(&mut self.realm.environments, &mut environments);
+        self.run_jobs_after_script(result)
+    }
+
+    /// Runs the pending jobs after a script has been evaluated.
+    ///
+    /// The job queue is only drained once the outermost script has finished running, since `eval`
+    /// can also be called from inside of a running script (e.g. by `JSON.parse`).
+    fn run_jobs_after_script(&mut self, result: JsResult<JsValue>) -> JsResult<JsValue> {
+        if self.vm.frame.is_none() {
+            let jobs = self.run_jobs();
+            result.and_then(|value| jobs.map(|()| value))
+        } else {
+            result
+        }
+    }
+
+    /// `HostEnqueuePromiseJob ( job, realm )`
+    ///
+    /// Schedules a promise job to be run after the currently running script has finished.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostenqueuepromisejob
+    #[inline]
+    pub fn host_enqueue_promise_job(&mut self, job: JobCallback) {
+        self.job_queue.clone().enqueue_promise_job(job, self);
+    }
+
+    /// `HostPromiseRejectionTracker ( promise, operation )`
+    ///
+    /// Keeps track of the promises that are rejected without a handler. A promise stops being
+    /// tracked when a handler is added to it later.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-promise-rejection-tracker
+    pub(crate) fn host_promise_rejection_tracker(
+        &mut self,
+        promise: &JsObject,
+        reason: &JsValue,
+        operation: OperationType,
+    ) {
+        match operation {
+            OperationType::Reject => self
+                .unhandled_rejections
+                .push((promise.clone(), reason.clone())),
+            OperationType::Handle => self
+                .unhandled_rejections
+                .retain(|(rejected, _)| !JsObject::equals(rejected, promise)),
+        }
+    }
+
+    /// Takes the reasons of the promises that have been rejected and still have no handler, in
+    /// the order they were rejected.
+    ///
+    /// A handler can still be added to a rejected promise by a later job, so this is usually
+    /// called after [`Context::run_jobs`], or after a top-level [`Context::eval`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa::Context;
+    /// let mut context = Context::default();
+    ///
+    /// context.eval("Promise.reject(1); Promise.reject(2).catch(() => {});").unwrap();
+    ///
+    /// let rejections = context.take_unhandled_rejections();
+    /// assert_eq!(rejections.len(), 1);
+    /// assert_eq!(rejections[0].as_number(), Some(1.0));
+    /// assert!(context.take_unhandled_rejections().is_empty());
+    /// ```
+    #[inline]
+    pub fn take_unhandled_rejections(&mut self) -> Vec<JsValue> {
+        std::mem::take(&mut self.unhandled_rejections)
+            .into_iter()
+            .map(|(_, reason)| reason)
+            .collect()
+    }
+
+    /// Schedules a job implemented in Rust on the job queue.
+    ///
+    /// The job runs with the next call to [`Context::run_jobs`], or after the current top-level
+    /// [`Context::eval`] has finished.
+    #[inline]
+    pub fn enqueue_job(&mut self, job: NativeJob) {
+        self.job_queue.clone().enqueue_job(job, self);
+    }
+
+    /// Runs all the pending jobs, including the ones enqueued while running them.
+    ///
+    /// With the default job queue this stops at the first job that throws and returns its error.
+    #[inline]
+    pub fn run_jobs(&mut self) -> JsResult<()> {
+        self.job_queue.clone().run_jobs(self)
+    }
+
+    /// Replaces the job queue of the context.
+    ///
+    /// This allows hosts to integrate the promise jobs with their own event loop. Jobs still
+    /// pending in the previous queue are dropped.
+    #[inline]
+    pub fn set_job_queue(&mut self, job_queue: Box<dyn JobQueue>) {
+        self.job_queue = job_queue.into();
+    }
+
     /// Compile the AST into a `CodeBlock` ready to be executed by the VM.
     #[inline]
     pub fn compile(&mut self, statement_list: &StatementList) -> JsResult<Gc<CodeBlock>> {
         let _timer = BoaProfiler::global().start_event("Compilation", "Main");
         let mut compiler = ByteCompiler::new(Sym::MAIN, statement_list.strict(), self);
+        compiler.set_source(statement_list.source().cloned());
         for node in statement_list.items() {
             compiler.create_declarations(node)?;
         }
@@ -980,12 +1788,14 @@ impl Context {
     #[inline]
     pub fn execute(&mut self, code_block: Gc<CodeBlock>) -> JsResult<JsValue> {
         let _timer = BoaProfiler::global().start_event("Execution", "Main");
-        let global_object = self.global_object().clone().into();
+        let this = self.script_this();
 
+        self.vm.error_trace.clear();
+        self.vm.error_trace_value = None;
         self.vm.push_frame(CallFrame {
             prev: None,
             code: code_block,
-            this: global_object,
+            this,
             pc: 0,
             catch: Vec::new(),
             finally_return: FinallyReturn::None,
@@ -998,12 +1808,40 @@ impl Context {
             }],
             param_count: 0,
             arg_count: 0,
+            generator_resume_kind: GeneratorResumeKind::Normal,
+            function_object: None,
+            new_target: None,
         });
 
         self.realm.set_global_binding_number();
         let result = self.run();
         self.vm.pop_frame();
-        result
+        let (result, _) = result?;
+        Ok(result)
+    }
+
+    /// Returns the stack trace of the last error that escaped the executed code.
+    ///
+    /// The first frame is the one where the error was thrown, and each following frame is its
+    /// caller, up to the top level of the script. Every frame has the position of the statement
+    /// that was executing in it, which lets embedders map the error back to the source. The
+    /// trace is empty if the last execution completed normally.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::default();
+    ///
+    /// assert!(context.eval("function f() {\n  throw 1;\n}\nf();").is_err());
+    ///
+    /// let trace = context.error_trace();
+    /// assert_eq!(trace[0].function_name(), "f");
+    /// assert_eq!(trace[0].position().unwrap().line_number(), 2);
+    /// assert_eq!(trace[1].position().unwrap().line_number(), 4);
+    /// ```
+    #[inline]
+    pub fn error_trace(&self) -> &[StackTraceFrame] {
+        &self.vm.error_trace
     }
 
     /// Return the cached iterator prototypes.
@@ -1019,12 +1857,40 @@ impl Context {
     }
 
     /// Return the core standard objects.
+    ///
+    /// These are the intrinsic constructors of the context and their prototypes, like `%Array%`
+    /// and `%Array.prototype%`. They are not affected by later changes to the global object, so
+    /// embedders can use them to give host objects a standard prototype.
+    ///
+    /// # Examples
+    /// ```
+    /// use boa::{
+    ///     object::{JsObject, ObjectData},
+    ///     property::Attribute,
+    ///     Context, JsValue,
+    /// };
+    ///
+    /// let mut context = Context::default();
+    /// let array_prototype = context.standard_objects().array_object().prototype();
+    ///
+    /// let host = JsObject::from_proto_and_data(array_prototype, ObjectData::ordinary());
+    /// context.register_global_property("host", host, Attribute::all());
+    ///
+    /// let value = context
+    ///     .eval("Object.getPrototypeOf(host) === Array.prototype && typeof host.push")
+    ///     .unwrap();
+    /// assert_eq!(value, JsValue::new("function"));
+    /// ```
     #[inline]
     pub fn standard_objects(&self) -> &StandardObjects {
         &self.standard_objects
     }
 
     /// Return the intrinsic objects.
+    ///
+    /// These are the intrinsics that are neither constructors nor prototypes, like
+    /// `%ThrowTypeError%`. The standard constructors and prototypes are available through
+    /// [`Context::standard_objects`].
     #[inline]
     pub fn intrinsics(&self) -> &IntrinsicObjects {
         &self.intrinsic_objects
@@ -1034,4 +1900,51 @@ impl Context {
     pub fn set_trace(&mut self, trace: bool) {
         self.vm.trace = trace;
     }
+
+    /// Limits the number of bytecode instructions that can still be executed, or removes the
+    /// limit if `budget` is `None`.
+    ///
+    /// Every executed instruction consumes one unit of the budget, so loops and recursion can't
+    /// run forever. Once the budget is exhausted, the script is aborted with a `RangeError` that
+    /// skips the `catch` and `finally` blocks of the script, and every further instruction
+    /// throws it again. A script can't recover from it: the error is always propagated to the
+    /// embedder, which can tell it apart from a regular exception with
+    /// [`Context::runtime_limit_exceeded`].
+    ///
+    /// # Examples
+    /// ```
+    /// use boa::Context;
+    ///
+    /// let mut context = Context::default();
+    /// context.set_instruction_budget(Some(10_000));
+    ///
+    /// assert!(context.eval("try { while (true) {} } catch {}").is_err());
+    /// assert!(context.runtime_limit_exceeded());
+    /// ```
+    #[inline]
+    pub fn set_instruction_budget(&mut self, budget: Option<u64>) {
+        self.vm.instruction_budget = budget;
+        self.vm.runtime_limit_exceeded = false;
+    }
+
+    /// Returns the number of bytecode instructions that can still be executed, if limited.
+    ///
+    /// See [`Context::set_instruction_budget`].
+    #[inline]
+    pub fn instruction_budget(&self) -> Option<u64> {
+        self.vm.instruction_budget
+    }
+
+    /// Returns `true` if a script was aborted because it ran out of the instruction budget set
+    /// with [`Context::set_instruction_budget`].
+    ///
+    /// This is only set once a script tries to run an instruction past its budget, so a script
+    /// that uses exactly its budget completes normally. When an evaluation fails and this
+    /// returns `true`, the error is the abort rather than an exception thrown by the script,
+    /// even if the script threw a `RangeError` of its own. It is reset by the next call to
+    /// [`Context::set_instruction_budget`].
+    #[inline]
+    pub fn runtime_limit_exceeded(&self) -> bool {
+        self.vm.runtime_limit_exceeded
+    }
 }
@@ -183,7 +183,7 @@ impl Context {
                         None => false,
                     };
                     if function_scope && desc.is_none() {
-                        self.global_bindings_mut().insert(
+                        self.global_string_bindings_mut().insert(
                             name_str,
                             PropertyDescriptor::builder()
                                 .value(JsValue::Undefined)
@@ -252,7 +252,7 @@ impl Context {
     pub(crate) fn create_immutable_binding(&mut self, name: Sym) -> JsResult<()> {
         let name_str = JsString::from(self.interner().resolve_expect(name));
         let exists_global = self.realm.compile_env.stack.len() == 1
-            && self.global_bindings().contains_key(&name_str);
+            && self.global_string_bindings().contains_key(&name_str);
 
         let env = self
             .realm
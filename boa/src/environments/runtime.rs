@@ -1,5 +1,6 @@
 use crate::{
     gc::{Finalize, Gc, Trace},
+    object::JsObject,
     Context, JsResult, JsValue,
 };
 use boa_interner::Sym;
@@ -12,10 +13,12 @@ use gc::GcCell;
 ///
 /// Optionally, an environment can hold a `this` value.
 /// The `this` value is present only if the environment is a function environment.
+/// Function environments also hold the `new.target` of the function, if it was constructed.
 #[derive(Debug, Trace, Finalize)]
 pub(crate) struct DeclarativeEnvironment {
     bindings: GcCell<Vec<Option<JsValue>>>,
-    this: Option<JsValue>,
+    this: Option<GcCell<JsValue>>,
+    new_target: Option<JsObject>,
 }
 
 impl DeclarativeEnvironment {
@@ -67,10 +70,23 @@ impl DeclarativeEnvironmentStack {
             stack: vec![Gc::new(DeclarativeEnvironment {
                 bindings: GcCell::new(Vec::new()),
                 this: None,
+                new_target: None,
             })],
         }
     }
 
+    /// Create a new environment stack that only contains the global environment of this stack.
+    #[inline]
+    pub(crate) fn global_stack(&self) -> Self {
+        Self {
+            stack: vec![self
+                .stack
+                .first()
+                .expect("global environment must always exist")
+                .clone()],
+        }
+    }
+
     /// Set the number of bindings on the global environment.
     ///
     /// # Panics
@@ -93,27 +109,57 @@ impl DeclarativeEnvironmentStack {
     pub(crate) fn get_last_this(&self) -> Option<JsValue> {
         for env in self.stack.iter().rev() {
             if let Some(this) = &env.this {
-                return Some(this.clone());
+                return Some(this.borrow().clone());
             }
         }
         None
     }
 
+    /// Get the `new.target` value of the most outer function environment.
+    #[inline]
+    pub(crate) fn get_last_new_target(&self) -> Option<JsObject> {
+        self.stack
+            .iter()
+            .rev()
+            .find(|env| env.this.is_some())
+            .and_then(|env| env.new_target.clone())
+    }
+
+    /// Set the `this` value of the most outer function environment.
+    ///
+    /// This is used to bind `this` in a derived constructor, once the `super` constructor returns.
+    #[inline]
+    pub(crate) fn bind_this(&mut self, value: JsValue) {
+        for env in self.stack.iter().rev() {
+            if let Some(this) = &env.this {
+                *this.borrow_mut() = value;
+                return;
+            }
+        }
+    }
+
     /// Push a declarative environment on the environments stack.
     #[inline]
     pub(crate) fn push_declarative(&mut self, num_bindings: usize) {
         self.stack.push(Gc::new(DeclarativeEnvironment {
             bindings: GcCell::new(vec![None; num_bindings]),
             this: None,
+            new_target: None,
         }));
     }
 
     /// Push a function environment on the environments stack.
     #[inline]
-    pub(crate) fn push_function(&mut self, num_bindings: usize, this: JsValue) {
+    pub(crate) fn push_function(
+        &mut self,
+        num_bindings: usize,
+        this: JsValue,
+        new_target: Option<JsObject>,
+    ) {
         self.stack.push(Gc::new(DeclarativeEnvironment {
             bindings: GcCell::new(vec![None; num_bindings]),
-            this: Some(this),
+            this: Some(GcCell::new(this)),
+            new_target,
         }));
     }
 
@@ -283,7 +329,7 @@ impl BindingLocator {
     /// Creates a binding locator that indicates that it was attempted to mutate an immutable binding.
     /// At runtime this should always produce a type error.
     #[inline]
-    pub(in crate::environments) fn mutate_immutable(name: Sym) -> Self {
+    pub(crate) fn mutate_immutable(name: Sym) -> Self {
         Self {
             name,
             environment_index: 0,
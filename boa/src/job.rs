@@ -0,0 +1,156 @@
+//! This module contains the types used to schedule and run ECMAScript jobs.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-jobs
+
+use crate::{
+    gc::{Finalize, Trace},
+    object::JsObject,
+    Context, JsResult, JsValue,
+};
+use std::{cell::RefCell, collections::VecDeque, fmt};
+
+/// The `JobCallback` Record.
+///
+/// Holds a function object that will be called when the job runs.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-jobcallback-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JobCallback {
+    callback: JsObject,
+}
+
+impl JobCallback {
+    /// `HostMakeJobCallback ( callback )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostmakejobcallback
+    #[inline]
+    pub fn make_job_callback(callback: JsObject) -> Self {
+        // 1. Return the JobCallback Record { [[Callback]]: callback, [[HostDefined]]: empty }.
+        Self { callback }
+    }
+
+    /// `HostCallJobCallback ( jobCallback, V, argumentsList )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostcalljobcallback
+    #[inline]
+    pub fn call_job_callback(
+        &self,
+        v: &JsValue,
+        arguments_list: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Assert: IsCallable(jobCallback.[[Callback]]) is true.
+        // 2. Return ? Call(jobCallback.[[Callback]], V, argumentsList).
+        self.callback.call(v, arguments_list, context)
+    }
+}
+
+/// The closure run by a [`NativeJob`].
+type NativeJobFn = dyn FnOnce(&mut Context) -> JsResult<JsValue>;
+
+/// A job implemented in Rust, scheduled with [`Context::enqueue_job`].
+///
+/// Promise jobs are turned into native jobs before being enqueued, so a [`JobQueue`] only has to
+/// store a single kind of job.
+pub struct NativeJob {
+    f: Box<NativeJobFn>,
+}
+
+impl fmt::Debug for NativeJob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("NativeJob")
+    }
+}
+
+impl NativeJob {
+    /// Creates a new job from a closure.
+    #[inline]
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnOnce(&mut Context) -> JsResult<JsValue> + 'static,
+    {
+        Self { f: Box::new(f) }
+    }
+
+    /// Runs the job.
+    #[inline]
+    pub fn call(self, context: &mut Context) -> JsResult<JsValue> {
+        (self.f)(context)
+    }
+}
+
+impl From<JobCallback> for NativeJob {
+    #[inline]
+    fn from(job: JobCallback) -> Self {
+        Self::new(move |context| job.call_job_callback(&JsValue::undefined(), &[], context))
+    }
+}
+
+/// A queue of pending jobs, used by the [`Context`] to schedule promise jobs and native jobs.
+///
+/// Hosts that have their own event loop can implement this trait and install it with
+/// [`Context::set_job_queue`]. The queue is shared with the context, so its methods take `&self`
+/// and implementations need interior mutability.
+pub trait JobQueue: fmt::Debug {
+    /// `HostEnqueuePromiseJob ( job, realm )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostenqueuepromisejob
+    fn enqueue_promise_job(&self, job: JobCallback, context: &mut Context);
+
+    /// Enqueues a job implemented in Rust.
+    fn enqueue_job(&self, job: NativeJob, context: &mut Context);
+
+    /// Runs the pending jobs, including the ones enqueued while running them.
+    fn run_jobs(&self, context: &mut Context) -> JsResult<()>;
+}
+
+/// The default [`JobQueue`], which runs the jobs in the order they were enqueued.
+#[derive(Debug, Default)]
+pub struct SimpleJobQueue(RefCell<VecDeque<NativeJob>>);
+
+impl SimpleJobQueue {
+    /// Creates an empty queue.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl JobQueue for SimpleJobQueue {
+    #[inline]
+    fn enqueue_promise_job(&self, job: JobCallback, _context: &mut Context) {
+        self.0.borrow_mut().push_back(job.into());
+    }
+
+    #[inline]
+    fn enqueue_job(&self, job: NativeJob, _context: &mut Context) {
+        self.0.borrow_mut().push_back(job);
+    }
+
+    /// Drains the queue, stopping at the first job that throws and returning its error.
+    fn run_jobs(&self, context: &mut Context) -> JsResult<()> {
+        // The queue must not be borrowed while a job runs, since the job can enqueue other jobs.
+        loop {
+            let job = self.0.borrow_mut().pop_front();
+            match job {
+                Some(job) => job.call(context)?,
+                None => return Ok(()),
+            };
+        }
+    }
+}
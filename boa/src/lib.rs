@@ -64,9 +64,14 @@
     clippy::missing_errors_doc,
     clippy::as_conversions,
     clippy::let_unit_value,
+    clippy::manual_let_else,
     rustdoc::missing_doc_code_examples
 )]
 
+// Allows the derive macros of `boa_macros` to refer to `::boa` from the tests of this crate.
+#[cfg(test)]
+extern crate self as boa;
+
 pub mod bigint;
 pub mod builtins;
 pub mod bytecompiler;
@@ -74,6 +79,8 @@ pub mod class;
 pub mod context;
 pub mod environments;
 pub mod gc;
+pub mod job;
+pub mod module;
 pub mod object;
 pub mod profiler;
 pub mod property;
@@ -0,0 +1,86 @@
+//! A module loader that reads the modules from the file system.
+
+use super::{Module, ModuleLoader};
+use crate::{Context, JsResult};
+use rustc_hash::FxHashMap;
+use std::{
+    cell::RefCell,
+    path::{Path, PathBuf},
+};
+
+/// A module loader that reads the modules from the file system.
+///
+/// Specifiers that start with `./` or `../` are resolved relative to the directory of the
+/// importing module, and other specifiers relative to the root directory of the loader. Every
+/// file is only loaded once, so all its imports share the same module.
+///
+/// The name of the modules loaded by this loader is their path, so modules created with
+/// [`Module::parse`] should also be named after their path for their relative imports to work.
+#[derive(Debug)]
+pub struct FsModuleLoader {
+    root: PathBuf,
+    modules: RefCell<FxHashMap<PathBuf, Module>>,
+}
+
+impl FsModuleLoader {
+    /// Creates a loader that resolves the bare specifiers relative to `root`.
+    #[inline]
+    pub fn new<P>(root: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self {
+            root: root.into(),
+            modules: RefCell::default(),
+        }
+    }
+}
+
+impl Default for FsModuleLoader {
+    /// Creates a loader that resolves the bare specifiers relative to the current directory.
+    #[inline]
+    fn default() -> Self {
+        Self::new(".")
+    }
+}
+
+impl ModuleLoader for FsModuleLoader {
+    fn resolve(
+        &self,
+        specifier: &str,
+        referrer: &Module,
+        context: &mut Context,
+    ) -> JsResult<Module> {
+        let path = if specifier.starts_with("./") || specifier.starts_with("../") {
+            let referrer = referrer.name();
+            Path::new(referrer.as_str())
+                .parent()
+                .unwrap_or_else(|| Path::new(""))
+                .join(specifier)
+        } else {
+            self.root.join(specifier)
+        };
+        let path = match path.canonicalize() {
+            Ok(path) => path,
+            Err(e) => {
+                return context
+                    .throw_type_error(format!("could not resolve module '{}': {}", specifier, e))
+            }
+        };
+
+        if let Some(module) = self.modules.borrow().get(&path) {
+            return Ok(module.clone());
+        }
+
+        let src = match std::fs::read(&path) {
+            Ok(src) => src,
+            Err(e) => {
+                return context
+                    .throw_type_error(format!("could not load module '{}': {}", specifier, e))
+            }
+        };
+        let module = Module::parse(&path.to_string_lossy(), src, context)?;
+        self.modules.borrow_mut().insert(path, module.clone());
+        Ok(module)
+    }
+}
@@ -0,0 +1,1008 @@
+//! This module implements ECMAScript modules.
+//!
+//! A [`Module`] is created by parsing the source code of a module. [`Context::eval_module`]
+//! resolves the modules it imports with the [`ModuleLoader`] of the context, links them together
+//! and evaluates them.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-modules
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Modules
+
+#[cfg(feature = "fs-module-loader")]
+mod fs;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "fs-module-loader")]
+pub use fs::FsModuleLoader;
+
+use crate::{
+    builtins::{
+        promise::{Promise, PromiseCapability, PromiseState},
+        Array, JsArgs,
+    },
+    gc::{empty_trace, Cell, Finalize, Gc, Trace},
+    object::{FunctionBuilder, JsObject, ObjectData},
+    property::PropertyDescriptor,
+    symbol::WellKnownSymbols,
+    syntax::{
+        ast::node::{ExportDeclaration, ImportKind, ModuleItem, ModuleItemList, StatementList},
+        Parser,
+    },
+    vm::GeneratorResumeKind,
+    Context, JsResult, JsString, JsValue,
+};
+use boa_interner::Sym;
+use std::{fmt, rc::Rc};
+
+/// A host hook that resolves the modules imported by other modules.
+///
+/// The loader of a context is set with [`Context::set_module_loader`]. It must return the same
+/// module every time it resolves the same specifier for the same referrer, so loaders usually
+/// cache the modules they create.
+pub trait ModuleLoader: fmt::Debug {
+    /// `HostResolveImportedModule ( referencingScriptOrModule, specifier )`
+    ///
+    /// Returns the module that `specifier` refers to when it is imported by `referrer`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostresolveimportedmodule
+    fn resolve(
+        &self,
+        specifier: &str,
+        referrer: &Module,
+        context: &mut Context,
+    ) -> JsResult<Module>;
+}
+
+/// An import of a module, which binds an export of a requested module.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#importentry-record
+#[derive(Debug, Clone, Copy, Finalize)]
+struct ImportEntry {
+    module_request: Sym,
+    /// The name of the imported export, or `None` if the namespace object is imported.
+    import_name: Option<Sym>,
+    local_name: Sym,
+}
+
+// SAFETY: The entry only holds interned strings, which are not garbage collected.
+unsafe impl Trace for ImportEntry {
+    empty_trace!();
+}
+
+/// An export of a binding of the module itself.
+#[derive(Debug, Clone, Copy, Finalize)]
+struct LocalExportEntry {
+    export_name: Sym,
+    local_name: Sym,
+}
+
+// SAFETY: The entry only holds interned strings, which are not garbage collected.
+unsafe impl Trace for LocalExportEntry {
+    empty_trace!();
+}
+
+/// An export of a binding of a requested module.
+#[derive(Debug, Clone, Copy, Finalize)]
+struct IndirectExportEntry {
+    export_name: Sym,
+    module_request: Sym,
+    /// The name of the re-exported export, or `None` if the namespace object is re-exported.
+    import_name: Option<Sym>,
+}
+
+// SAFETY: The entry only holds interned strings, which are not garbage collected.
+unsafe impl Trace for IndirectExportEntry {
+    empty_trace!();
+}
+
+/// The status of a module, in the order a module goes through them.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-cyclic-module-records
+#[derive(Debug, Clone, Trace, Finalize)]
+enum ModuleStatus {
+    Unlinked,
+    Linking,
+    Linked,
+    /// The requested modules of the module are being evaluated.
+    Evaluating,
+    /// The code of the module has started running. The contained promise settles once the
+    /// module is done, which may be later if the module has a top-level `await`.
+    Evaluated(JsObject),
+}
+
+/// The state of a module, shared by all the handles to the module.
+#[derive(Debug, Trace, Finalize)]
+struct ModuleRecord {
+    name: JsString,
+    items: ModuleItemList,
+    /// The source code of the module, which the source text of its functions refers to.
+    #[unsafe_ignore_trace]
+    source: Option<Rc<str>>,
+    requested_modules: Vec<Sym>,
+    import_entries: Vec<ImportEntry>,
+    local_export_entries: Vec<LocalExportEntry>,
+    indirect_export_entries: Vec<IndirectExportEntry>,
+    /// The requests of the modules whose exports are re-exported by `export * from "module"`.
+    star_export_entries: Vec<Sym>,
+    /// The resolved requested modules, in the order of `requested_modules`.
+    dependencies: Vec<Module>,
+    /// Holds a getter for each exported local binding, once the linking of the module has
+    /// created it.
+    bindings: JsObject,
+    /// The generator that runs the code of the module, once the linking of the module has
+    /// instantiated its functions and exported its bindings.
+    generator: Option<JsObject>,
+    namespace: Option<JsObject>,
+    status: ModuleStatus,
+}
+
+impl ModuleRecord {
+    /// Gets the resolved module of a module request of the module.
+    fn dependency(&self, module_request: Sym) -> Module {
+        let index = self
+            .requested_modules
+            .iter()
+            .position(|request| *request == module_request)
+            .expect("every module request must be in the requested modules");
+        self.dependencies[index].clone()
+    }
+}
+
+/// The binding that an export name of a module resolves to.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#resolvedbinding-record
+#[derive(Debug, Clone, PartialEq, Trace, Finalize)]
+enum ResolvedBinding {
+    /// A local binding of a module.
+    Binding { module: Module, local_name: Sym },
+    /// The namespace object of a module.
+    Namespace(Module),
+}
+
+impl ResolvedBinding {
+    /// Gets the current value of the binding.
+    fn value(&self, context: &mut Context) -> JsResult<JsValue> {
+        match self {
+            Self::Namespace(module) => Ok(module.namespace(context).into()),
+            Self::Binding { module, local_name } => {
+                let bindings = module.inner.borrow().bindings.clone();
+                let name = context.identifier_string(*local_name);
+                if let Some(getter) = bindings.get(name.clone(), context)?.as_object() {
+                    return getter.call(&JsValue::undefined(), &[], context);
+                }
+                context.throw_reference_error(format!(
+                    "Cannot access '{}' before initialization",
+                    name
+                ))
+            }
+        }
+    }
+}
+
+/// The result of resolving an export name of a module.
+#[derive(Debug)]
+enum ExportResolution {
+    Resolved(ResolvedBinding),
+    NotFound,
+    /// The name is exported by more than one `export * from "module"` declaration, with
+    /// different bindings.
+    Ambiguous,
+}
+
+impl ExportResolution {
+    /// Throws a `SyntaxError` if an import or a re-export of `name` from the requested module
+    /// didn't resolve to a binding.
+    fn check(&self, module_request: Sym, name: Sym, context: &mut Context) -> JsResult<()> {
+        let message = match self {
+            Self::Resolved(_) => return Ok(()),
+            Self::NotFound => "does not provide an export named",
+            Self::Ambiguous => "contains conflicting star exports for name",
+        };
+        context.throw_syntax_error(format!(
+            "The requested module '{}' {} '{}'",
+            context.interner().resolve_expect(module_request),
+            message,
+            context.interner().resolve_expect(name)
+        ))
+    }
+}
+
+/// The binding of a module that an imported identifier refers to, while the module is
+/// compiled.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ModuleImport {
+    /// The parameter of the module function that holds the namespace object of the imported
+    /// module.
+    pub(crate) namespace: Sym,
+    /// The name of the imported export, or `None` if the namespace object is imported.
+    pub(crate) import_name: Option<Sym>,
+}
+
+/// A source text module.
+///
+/// The module is a handle to the module record, so clones refer to the same module.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sourctextmodule-record
+#[derive(Clone, Trace, Finalize)]
+pub struct Module {
+    inner: Gc<Cell<ModuleRecord>>,
+}
+
+impl fmt::Debug for Module {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Module")
+            .field("name", &self.inner.borrow().name)
+            .finish()
+    }
+}
+
+impl PartialEq for Module {
+    fn eq(&self, other: &Self) -> bool {
+        Gc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Module {
+    /// `ParseModule ( sourceText, realm, hostDefined )`
+    ///
+    /// Parses the source code of a module. The name identifies the module in stack traces, and
+    /// loaders can use it to resolve the specifiers imported by the module, e.g. as a path.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-parsemodule
+    pub fn parse<S>(name: &str, src: S, context: &mut Context) -> JsResult<Self>
+    where
+        S: AsRef<[u8]>,
+    {
+        let items = match Parser::new(src.as_ref(), true).parse_module(context.interner_mut()) {
+            Ok(items) => items,
+            Err(e) => return context.throw_syntax_error(e.to_string()),
+        };
+
+        let mut import_entries = Vec::new();
+        for item in items.items() {
+            if let ModuleItem::ImportDeclaration(import) = item {
+                let module_request = import.specifier();
+                if let Some(local_name) = import.default() {
+                    import_entries.push(ImportEntry {
+                        module_request,
+                        import_name: Some(Sym::DEFAULT),
+                        local_name,
+                    });
+                }
+                match import.kind() {
+                    ImportKind::DefaultOrUnnamed => {}
+                    ImportKind::Namespace(local_name) => import_entries.push(ImportEntry {
+                        module_request,
+                        import_name: None,
+                        local_name: *local_name,
+                    }),
+                    ImportKind::Named(specifiers) => {
+                        import_entries.extend(specifiers.iter().map(|specifier| ImportEntry {
+                            module_request,
+                            import_name: Some(specifier.export_name()),
+                            local_name: specifier.binding(),
+                        }));
+                    }
+                }
+            }
+        }
+
+        let mut local_export_entries = Vec::new();
+        let mut indirect_export_entries = Vec::new();
+        let mut star_export_entries = Vec::new();
+        for item in items.items() {
+            let export = match item {
+                ModuleItem::ExportDeclaration(export) => export,
+                _ => continue,
+            };
+            match export {
+                ExportDeclaration::ReExportAll {
+                    alias: None,
+                    specifier,
+                } => star_export_entries.push(*specifier),
+                ExportDeclaration::ReExportAll {
+                    alias: Some(alias),
+                    specifier,
+                } => indirect_export_entries.push(IndirectExportEntry {
+                    export_name: *alias,
+                    module_request: *specifier,
+                    import_name: None,
+                }),
+                ExportDeclaration::ReExport {
+                    specifiers,
+                    specifier,
+                } => {
+                    indirect_export_entries.extend(specifiers.iter().map(|export| {
+                        IndirectExportEntry {
+                            export_name: export.alias(),
+                            module_request: *specifier,
+                            import_name: Some(export.private_name()),
+                        }
+                    }));
+                }
+                ExportDeclaration::List(specifiers) => {
+                    for export in specifiers.iter() {
+                        let import = import_entries
+                            .iter()
+                            .find(|import| import.local_name == export.private_name());
+                        match import {
+                            // Re-exporting an imported binding exports the binding of the
+                            // imported module.
+                            Some(ImportEntry {
+                                module_request,
+                                import_name: import_name @ Some(_),
+                                ..
+                            }) => indirect_export_entries.push(IndirectExportEntry {
+                                export_name: export.alias(),
+                                module_request: *module_request,
+                                import_name: *import_name,
+                            }),
+                            _ => local_export_entries.push(LocalExportEntry {
+                                export_name: export.alias(),
+                                local_name: export.private_name(),
+                            }),
+                        }
+                    }
+                }
+                _ => local_export_entries.extend(
+                    export
+                        .exported_names()
+                        .into_iter()
+                        .zip(export.local_names())
+                        .map(|(export_name, local_name)| LocalExportEntry {
+                            export_name,
+                            local_name,
+                        }),
+                ),
+            }
+        }
+
+        let record = ModuleRecord {
+            name: name.into(),
+            requested_modules: items.requested_modules(),
+            items,
+            // The source text is sliced from the source code, which must be valid UTF-8 for it.
+            source: std::str::from_utf8(src.as_ref()).ok().map(Rc::from),
+            import_entries,
+            local_export_entries,
+            indirect_export_entries,
+            star_export_entries,
+            dependencies: Vec::new(),
+            bindings: JsObject::from_proto_and_data(None, ObjectData::ordinary()),
+            generator: None,
+            namespace: None,
+            status: ModuleStatus::Unlinked,
+        };
+        Ok(Self {
+            inner: Gc::new(Cell::new(record)),
+        })
+    }
+
+    /// Gets the name of the module.
+    #[inline]
+    pub fn name(&self) -> JsString {
+        self.inner.borrow().name.clone()
+    }
+
+    /// `GetModuleNamespace ( module )`
+    ///
+    /// Gets the namespace object of the module, which has a property for each export of the
+    /// module that holds the current value of the exported binding.
+    ///
+    /// The exports are only resolved once the module is linked, by [`Context::eval_module`] or
+    /// by the linking of a module that imports it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getmodulenamespace
+    pub fn namespace(&self, context: &mut Context) -> JsObject {
+        if let Some(namespace) = &self.inner.borrow().namespace {
+            return namespace.clone();
+        }
+
+        let mut exports = Vec::new();
+        if !matches!(self.inner.borrow().status, ModuleStatus::Unlinked) {
+            for name in self.exported_names(&mut Vec::new()) {
+                if let ExportResolution::Resolved(binding) =
+                    self.resolve_export(name, &mut Vec::new())
+                {
+                    exports.push((context.identifier_string(name), binding));
+                }
+            }
+        }
+        exports.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        // The exports are accessor properties instead of the writable data properties of the
+        // spec, so reading them always gets the current value of the binding.
+        let namespace = JsObject::from_proto_and_data(None, ObjectData::ordinary());
+        for (name, binding) in exports {
+            let getter = FunctionBuilder::closure_with_captures(
+                context,
+                |_, _, binding, context| binding.value(context),
+                binding,
+            )
+            .name(name.as_str())
+            .build();
+            namespace.borrow_mut().insert(
+                name,
+                PropertyDescriptor::builder()
+                    .get(getter)
+                    .set(JsValue::undefined())
+                    .enumerable(true)
+                    .configurable(false),
+            );
+        }
+        namespace.borrow_mut().insert(
+            WellKnownSymbols::to_string_tag(),
+            PropertyDescriptor::builder()
+                .value("Module")
+                .writable(false)
+                .enumerable(false)
+                .configurable(false),
+        );
+        namespace
+            .__prevent_extensions__(context)
+            .expect("ordinary objects can always be made non-extensible");
+
+        self.inner.borrow_mut().namespace = Some(namespace.clone());
+        namespace
+    }
+
+    /// `GetExportedNames ( [ exportStarSet ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getexportednames
+    fn exported_names(&self, export_star_set: &mut Vec<Self>) -> Vec<Sym> {
+        // 2. If exportStarSet contains module, then
+        if export_star_set.contains(self) {
+            // b. Return a new empty List.
+            return Vec::new();
+        }
+        // 3. Append module to exportStarSet.
+        export_star_set.push(self.clone());
+
+        let record = self.inner.borrow();
+        let mut names: Vec<_> = record
+            .local_export_entries
+            .iter()
+            .map(|entry| entry.export_name)
+            .chain(
+                record
+                    .indirect_export_entries
+                    .iter()
+                    .map(|entry| entry.export_name),
+            )
+            .collect();
+        for module_request in &record.star_export_entries {
+            for name in record
+                .dependency(*module_request)
+                .exported_names(export_star_set)
+            {
+                if name != Sym::DEFAULT && !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+        names
+    }
+
+    /// `ResolveExport ( exportName [ , resolveSet ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-resolveexport
+    fn resolve_export(
+        &self,
+        export_name: Sym,
+        resolve_set: &mut Vec<(Self, Sym)>,
+    ) -> ExportResolution {
+        // 2. For each Record { [[Module]], [[ExportName]] } r of resolveSet, do
+        //     a. If module and r.[[Module]] are the same Module Record and
+        //        SameValue(exportName, r.[[ExportName]]) is true, then
+        //         i. Assert: This is a circular import request.
+        //         ii. Return null.
+        if resolve_set
+            .iter()
+            .any(|(module, name)| module == self && *name == export_name)
+        {
+            return ExportResolution::NotFound;
+        }
+        // 3. Append the Record { [[Module]]: module, [[ExportName]]: exportName } to resolveSet.
+        resolve_set.push((self.clone(), export_name));
+
+        let record = self.inner.borrow();
+        // 4. For each ExportEntry Record e of module.[[LocalExportEntries]], do
+        for entry in &record.local_export_entries {
+            if entry.export_name == export_name {
+                return ExportResolution::Resolved(ResolvedBinding::Binding {
+                    module: self.clone(),
+                    local_name: entry.local_name,
+                });
+            }
+        }
+
+        // 5. For each ExportEntry Record e of module.[[IndirectExportEntries]], do
+        for entry in &record.indirect_export_entries {
+            if entry.export_name == export_name {
+                let imported_module = record.dependency(entry.module_request);
+                return match entry.import_name {
+                    Some(import_name) => imported_module.resolve_export(import_name, resolve_set),
+                    None => ExportResolution::Resolved(ResolvedBinding::Namespace(imported_module)),
+                };
+            }
+        }
+
+        // 6. If SameValue(exportName, "default") is true, then
+        if export_name == Sym::DEFAULT {
+            // b. Return null.
+            return ExportResolution::NotFound;
+        }
+
+        // 7. Let starResolution be null.
+        let mut star_resolution = None;
+        // 8. For each ExportEntry Record e of module.[[StarExportEntries]], do
+        for module_request in &record.star_export_entries {
+            let imported_module = record.dependency(*module_request);
+            match imported_module.resolve_export(export_name, resolve_set) {
+                ExportResolution::Ambiguous => return ExportResolution::Ambiguous,
+                ExportResolution::NotFound => {}
+                ExportResolution::Resolved(resolution) => match &star_resolution {
+                    None => star_resolution = Some(resolution),
+                    Some(star_resolution) if *star_resolution != resolution => {
+                        return ExportResolution::Ambiguous;
+                    }
+                    Some(_) => {}
+                },
+            }
+        }
+
+        // 9. Return starResolution.
+        star_resolution.map_or(ExportResolution::NotFound, ExportResolution::Resolved)
+    }
+
+    /// `Link ( )`
+    ///
+    /// Resolves the modules requested by the module and by its dependencies, and then
+    /// initializes the environment of each of them. Cycles are allowed, since modules that are
+    /// already being linked are skipped.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-moduledeclarationlinking
+    pub(crate) fn link(&self, context: &mut Context) -> JsResult<()> {
+        if !matches!(self.inner.borrow().status, ModuleStatus::Unlinked) {
+            return Ok(());
+        }
+
+        // The environments are only initialized once every module of the graph is resolved,
+        // since resolving the imports of a module needs the exports of its dependencies.
+        let mut modules = Vec::new();
+        let result = self.load(&mut modules, context).and_then(|()| {
+            modules
+                .iter()
+                .rev()
+                .try_for_each(|module| module.initialize_environment(context))
+        });
+
+        for module in &modules {
+            let mut record = module.inner.borrow_mut();
+            if result.is_ok() {
+                record.status = ModuleStatus::Linked;
+            } else {
+                record.status = ModuleStatus::Unlinked;
+                record.dependencies.clear();
+                record.generator = None;
+                record.bindings = JsObject::from_proto_and_data(None, ObjectData::ordinary());
+            }
+        }
+        result
+    }
+
+    /// Resolves the requested modules with the module loader of the context, and then the
+    /// modules they request, depth first. Each module that is not linked yet is added to
+    /// `modules`.
+    fn load(&self, modules: &mut Vec<Self>, context: &mut Context) -> JsResult<()> {
+        self.inner.borrow_mut().status = ModuleStatus::Linking;
+        modules.push(self.clone());
+
+        let requested_modules = self.inner.borrow().requested_modules.clone();
+        if requested_modules.is_empty() {
+            return Ok(());
+        }
+
+        let loader = match context.module_loader() {
+            Some(loader) => loader,
+            None => {
+                return context.throw_type_error(format!(
+                    "cannot resolve the modules imported by `{}` without a module loader",
+                    self.name()
+                ))
+            }
+        };
+        let mut dependencies = Vec::with_capacity(requested_modules.len());
+        for module_request in requested_modules {
+            let specifier = context.identifier_string(module_request);
+            dependencies.push(loader.resolve(specifier.as_str(), self, context)?);
+        }
+        self.inner
+            .borrow_mut()
+            .dependencies
+            .clone_from(&dependencies);
+
+        for dependency in &dependencies {
+            if matches!(dependency.inner.borrow().status, ModuleStatus::Unlinked) {
+                dependency.load(modules, context)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// `InitializeEnvironment ( )`
+    ///
+    /// Checks that the re-exports and the imports of the module resolve to a binding, and then
+    /// instantiates the functions of the module and exports its bindings.
+    ///
+    /// The code of the module is compiled to a generator that receives the namespace objects of
+    /// the requested modules. Imported identifiers are compiled to reads of the properties of
+    /// these namespaces, which makes them live bindings. The generator registers a getter of
+    /// each of its exported bindings and then yields, so the exported functions can be called
+    /// by the other modules of a cycle before the module itself is evaluated.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-source-text-module-record-initialize-environment
+    fn initialize_environment(&self, context: &mut Context) -> JsResult<()> {
+        let (indirect_export_entries, import_entries) = {
+            let record = self.inner.borrow();
+            (
+                record.indirect_export_entries.clone(),
+                record.import_entries.clone(),
+            )
+        };
+
+        // 1. For each ExportEntry Record e of module.[[IndirectExportEntries]], do
+        for entry in indirect_export_entries {
+            //     a. Let resolution be module.ResolveExport(e.[[ExportName]]).
+            //     b. If resolution is null or ambiguous, throw a SyntaxError exception.
+            let resolution = self.resolve_export(entry.export_name, &mut Vec::new());
+            resolution.check(entry.module_request, entry.export_name, context)?;
+        }
+
+        // 7. For each ImportEntry Record in of module.[[ImportEntries]], do
+        for entry in import_entries {
+            if let Some(import_name) = entry.import_name {
+                // a. Let importedModule be ! HostResolveImportedModule(module, in.[[ModuleRequest]]).
+                let imported_module = self.inner.borrow().dependency(entry.module_request);
+                // d. Else,
+                //     i. Let resolution be importedModule.ResolveExport(in.[[ImportName]]).
+                //     ii. If resolution is null or ambiguous, throw a SyntaxError exception.
+                let resolution = imported_module.resolve_export(import_name, &mut Vec::new());
+                resolution.check(entry.module_request, import_name, context)?;
+            }
+        }
+
+        let dependencies = self.inner.borrow().dependencies.clone();
+        let bindings = self.inner.borrow().bindings.clone();
+        let export_function = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, bindings, context| {
+                let name = args.get(0).cloned().unwrap_or_default();
+                let getter = args.get(1).cloned().unwrap_or_default();
+                bindings.create_data_property_or_throw(
+                    name.to_property_key(context)?,
+                    getter,
+                    context,
+                )?;
+                Ok(JsValue::undefined())
+            },
+            bindings,
+        )
+        .build();
+
+        let mut arguments = vec![export_function.into()];
+        let mut parameters = Vec::with_capacity(dependencies.len());
+        for (i, dependency) in dependencies.iter().enumerate() {
+            let parameter = context
+                .interner_mut()
+                .get_or_intern(format!("*namespace{}*", i));
+            parameters.push(parameter);
+            arguments.push(dependency.namespace(context).into());
+        }
+
+        let (function, source) = {
+            let record = self.inner.borrow();
+            for entry in &record.import_entries {
+                let index = record
+                    .requested_modules
+                    .iter()
+                    .position(|request| *request == entry.module_request)
+                    .expect("every module request must be in the requested modules");
+                context.module_imports.insert(
+                    entry.local_name,
+                    ModuleImport {
+                        namespace: parameters[index],
+                        import_name: entry.import_name,
+                    },
+                );
+            }
+
+            let mut exported_locals: Vec<_> = record
+                .local_export_entries
+                .iter()
+                .map(|entry| entry.local_name)
+                .collect();
+            exported_locals.dedup();
+
+            let name = context.interner_mut().get_or_intern(record.name.as_str());
+            let export_function = context.interner_mut().get_or_intern_static("*export*");
+            let function =
+                record
+                    .items
+                    .to_generator(name, export_function, &parameters, &exported_locals);
+            (function, record.source.clone())
+        };
+
+        let mut statement_list = StatementList::from(vec![function]);
+        if let Some(source) = source {
+            statement_list.set_source(source);
+        }
+        let code = context.compile(&statement_list);
+        context.module_imports.clear();
+        let function = context.execute(code?)?;
+        let generator = function
+            .as_object()
+            .expect("the module code must compile to a function")
+            .call(&JsValue::undefined(), &arguments, context)?;
+        let generator = generator
+            .as_object()
+            .expect("the module function must return a generator")
+            .clone();
+        let next = generator.get("next", context)?;
+        context.call(&next, &generator.clone().into(), &[])?;
+        self.inner.borrow_mut().generator = Some(generator);
+        Ok(())
+    }
+
+    /// `Evaluate ( )`
+    ///
+    /// Evaluates the requested modules and then the module itself, unless it has already been
+    /// evaluated, and returns a promise that settles once the evaluation is done. The promise
+    /// is rejected if the module or one of its dependencies threw during its evaluation.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-moduleevaluation
+    pub(crate) fn evaluate(&self, context: &mut Context) -> JsResult<JsObject> {
+        let promise_constructor = context.standard_objects().promise_object().constructor();
+        let status = self.inner.borrow().status.clone();
+        match status {
+            ModuleStatus::Linked => {}
+            // The module is a dependency of itself, which is evaluated once its own dependencies
+            // are.
+            ModuleStatus::Evaluating => {
+                let promise =
+                    Promise::promise_resolve(promise_constructor, JsValue::undefined(), context)?;
+                return Ok(promise
+                    .as_object()
+                    .expect("PromiseResolve must return a promise")
+                    .clone());
+            }
+            ModuleStatus::Evaluated(ref promise) => return Ok(promise.clone()),
+            ModuleStatus::Unlinked | ModuleStatus::Linking => {
+                unreachable!("a module must be linked before it is evaluated")
+            }
+        }
+
+        self.inner.borrow_mut().status = ModuleStatus::Evaluating;
+        let capability = PromiseCapability::new(&promise_constructor.into(), context)?;
+        let result = self.execute(capability.clone(), context);
+        self.inner.borrow_mut().status = ModuleStatus::Evaluated(capability.promise().clone());
+        result?;
+        Ok(capability.promise().clone())
+    }
+
+    /// `ExecuteModule ( [ capability ] )`
+    ///
+    /// Evaluates the requested modules, and then runs the code of the module by resuming the
+    /// generator created when the module was linked. If a requested module is still waiting
+    /// for a top-level `await`, the code only runs once that module is done.
+    ///
+    /// `capability` settles once the code of the module is done.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-source-text-module-record-execute-module
+    fn execute(&self, capability: PromiseCapability, context: &mut Context) -> JsResult<()> {
+        let dependencies = self.inner.borrow().dependencies.clone();
+        let mut pending = Vec::new();
+        for dependency in &dependencies {
+            let promise = dependency.evaluate(context)?;
+            let (state, result) = {
+                let promise = promise.borrow();
+                let promise = promise
+                    .as_promise()
+                    .expect("the evaluation of a module must return a promise");
+                (promise.state(), promise.result().cloned())
+            };
+            match state {
+                PromiseState::Fulfilled => {}
+                PromiseState::Rejected => {
+                    capability.reject().call(
+                        &JsValue::undefined(),
+                        &[result.unwrap_or_default()],
+                        context,
+                    )?;
+                    return Ok(());
+                }
+                PromiseState::Pending => pending.push(promise.into()),
+            }
+        }
+
+        let generator = self
+            .inner
+            .borrow()
+            .generator
+            .clone()
+            .expect("a linked module must have a generator");
+        let execution = ModuleExecution {
+            generator,
+            capability,
+        };
+        if pending.is_empty() {
+            return execution.resume(JsValue::undefined(), GeneratorResumeKind::Normal, context);
+        }
+
+        // The code of the module waits for all of its pending dependencies, and the evaluation
+        // of the module is rejected as soon as one of them is.
+        let promise_constructor = context.standard_objects().promise_object().constructor();
+        let dependencies = Array::create_array_from_list(pending, context);
+        let all = Promise::all(&promise_constructor.into(), &[dependencies.into()], context)?;
+        let on_fulfilled = FunctionBuilder::closure_with_captures(
+            context,
+            |_this, _args, execution, context| {
+                execution.resume(JsValue::undefined(), GeneratorResumeKind::Normal, context)?;
+                Ok(JsValue::undefined())
+            },
+            execution.clone(),
+        )
+        .name("")
+        .length(1)
+        .build();
+        Promise::perform_promise_then(
+            all.as_object().expect("Promise.all must return a promise"),
+            &on_fulfilled.into(),
+            &execution.capability.reject().clone().into(),
+            None,
+            context,
+        );
+        Ok(())
+    }
+}
+
+/// The suspended code of a module, which is waiting for its dependencies or for a top-level
+/// `await`.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct ModuleExecution {
+    generator: JsObject,
+    capability: PromiseCapability,
+}
+
+impl ModuleExecution {
+    /// Resumes the code of the module with `value`, up to its next `await` or to its end.
+    ///
+    /// Each top-level `await` suspends the generator of the module with the awaited value.
+    /// The code is then resumed once that value is resolved, like the code of an async
+    /// function. The capability of the execution settles once the code is done.
+    fn resume(
+        &self,
+        value: JsValue,
+        resume_kind: GeneratorResumeKind,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let method = match resume_kind {
+            GeneratorResumeKind::Normal => "next",
+            GeneratorResumeKind::Throw => "throw",
+            GeneratorResumeKind::Return => "return",
+        };
+        let result = self
+            .generator
+            .get(method, context)
+            .and_then(|method| context.call(&method, &self.generator.clone().into(), &[value]));
+        let result = match result {
+            Ok(result) => result,
+            Err(error) => {
+                self.capability
+                    .reject()
+                    .call(&JsValue::undefined(), &[error], context)?;
+                return Ok(());
+            }
+        };
+
+        let done = result.get_field("done", context)?.to_boolean();
+        let value = result.get_field("value", context)?;
+        if done {
+            self.capability.resolve().call(
+                &JsValue::undefined(),
+                &[JsValue::undefined()],
+                context,
+            )?;
+            return Ok(());
+        }
+
+        let promise_constructor = context.standard_objects().promise_object().constructor();
+        let promise = match Promise::promise_resolve(promise_constructor, value, context) {
+            Ok(promise) => promise,
+            Err(error) => return self.resume(error, GeneratorResumeKind::Throw, context),
+        };
+        let on_fulfilled = FunctionBuilder::closure_with_captures(
+            context,
+            |_this, args, execution, context| {
+                execution.resume(
+                    args.get_or_undefined(0).clone(),
+                    GeneratorResumeKind::Normal,
+                    context,
+                )?;
+                Ok(JsValue::undefined())
+            },
+            self.clone(),
+        )
+        .name("")
+        .length(1)
+        .build();
+        let on_rejected = FunctionBuilder::closure_with_captures(
+            context,
+            |_this, args, execution, context| {
+                execution.resume(
+                    args.get_or_undefined(0).clone(),
+                    GeneratorResumeKind::Throw,
+                    context,
+                )?;
+                Ok(JsValue::undefined())
+            },
+            self.clone(),
+        )
+        .name("")
+        .length(1)
+        .build();
+        Promise::perform_promise_then(
+            promise
+                .as_object()
+                .expect("PromiseResolve must return a promise"),
+            &on_fulfilled.into(),
+            &on_rejected.into(),
+            None,
+            context,
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,304 @@
+use super::{Module, ModuleLoader};
+use crate::{builtins::promise::PromiseState, object::JsObject, Context, JsResult, JsValue};
+use rustc_hash::FxHashMap;
+
+/// A loader that resolves the specifiers to modules that are already parsed.
+#[derive(Debug, Default)]
+struct MemoryModuleLoader {
+    modules: FxHashMap<String, Module>,
+}
+
+impl ModuleLoader for MemoryModuleLoader {
+    fn resolve(&self, specifier: &str, _: &Module, context: &mut Context) -> JsResult<Module> {
+        match self.modules.get(specifier) {
+            Some(module) => Ok(module.clone()),
+            None => context.throw_type_error(format!("module '{}' not found", specifier)),
+        }
+    }
+}
+
+/// Creates a context whose loader resolves the given modules by name, and parses the main
+/// module, which the loader resolves as `main.js`.
+fn context_with_modules(main: &str, modules: &[(&str, &str)]) -> (Context, Module) {
+    let mut context = Context::default();
+    let mut loader = MemoryModuleLoader::default();
+    for (name, src) in modules {
+        let module = Module::parse(name, src, &mut context).expect("failed to parse module");
+        loader.modules.insert((*name).to_owned(), module);
+    }
+    let main = Module::parse("main.js", main, &mut context).expect("failed to parse module");
+    loader.modules.insert("main.js".to_owned(), main.clone());
+    context.set_module_loader(Box::new(loader));
+    (context, main)
+}
+
+/// Evaluates a module, and returns its namespace object once its evaluation is fulfilled, or
+/// the error that the evaluation threw or was rejected with.
+fn eval_module(context: &mut Context, module: &Module) -> JsResult<JsObject> {
+    let promise = context.eval_module(module)?;
+    let (state, result) = {
+        let promise = promise.borrow();
+        let promise = promise
+            .as_promise()
+            .expect("the evaluation must return a promise");
+        (promise.state(), promise.result().cloned())
+    };
+    match state {
+        PromiseState::Fulfilled => Ok(module.namespace(context)),
+        PromiseState::Rejected => Err(result.expect("a rejected promise must have a result")),
+        PromiseState::Pending => panic!("the evaluation of the module must be settled"),
+    }
+}
+
+/// Gets the message of a thrown error.
+fn error_message(error: &JsValue, context: &mut Context) -> String {
+    error
+        .as_object()
+        .expect("the error must be an object")
+        .get("message", context)
+        .expect("failed to get the message")
+        .as_string()
+        .expect("the message must be a string")
+        .to_string()
+}
+
+#[test]
+fn named_import() {
+    let (mut context, main) = context_with_modules(
+        r#"
+        import { greeting as hello, name } from "lib.js";
+        export const message = `${hello}, ${name}!`;
+        "#,
+        &[(
+            "lib.js",
+            r#"
+            const greeting = "Hello";
+            export { greeting };
+            export let name = "world";
+            "#,
+        )],
+    );
+
+    let namespace = eval_module(&mut context, &main).unwrap();
+    let message = namespace.get("message", &mut context).unwrap();
+    assert_eq!(message.as_string().unwrap().as_str(), "Hello, world!");
+}
+
+#[test]
+fn live_binding_update() {
+    let (mut context, main) = context_with_modules(
+        r#"
+        import { count, increment } from "counter.js";
+        import * as counter from "counter.js";
+        export const before = count;
+        increment();
+        increment();
+        export const after = count;
+        export const fromNamespace = counter.count;
+        "#,
+        &[(
+            "counter.js",
+            r#"
+            export let count = 0;
+            export function increment() {
+                count++;
+            }
+            "#,
+        )],
+    );
+
+    let namespace = eval_module(&mut context, &main).unwrap();
+    let before = namespace.get("before", &mut context).unwrap();
+    let after = namespace.get("after", &mut context).unwrap();
+    let from_namespace = namespace.get("fromNamespace", &mut context).unwrap();
+    assert_eq!(before.as_number(), Some(0.0));
+    assert_eq!(after.as_number(), Some(2.0));
+    assert_eq!(from_namespace.as_number(), Some(2.0));
+}
+
+#[test]
+fn default_and_re_exports() {
+    let (mut context, main) = context_with_modules(
+        r#"
+        import square, { double, ns } from "reexport.js";
+        export const result = square(double(3)) + ns.offset;
+        "#,
+        &[
+            (
+                "math.js",
+                r#"
+                export default function (x) { return x * x; }
+                export const double = (x) => x * 2;
+                export const offset = 1;
+                "#,
+            ),
+            (
+                "reexport.js",
+                r#"
+                export { default } from "math.js";
+                export * from "math.js";
+                export * as ns from "math.js";
+                "#,
+            ),
+        ],
+    );
+
+    let namespace = eval_module(&mut context, &main).unwrap();
+    let result = namespace.get("result", &mut context).unwrap();
+    assert_eq!(result.as_number(), Some(37.0));
+}
+
+#[test]
+fn modules_are_evaluated_once() {
+    let (mut context, main) = context_with_modules(
+        r#"
+        import { runs as a } from "a.js";
+        import { runs as b } from "b.js";
+        export const runs = [a, b];
+        "#,
+        &[
+            ("a.js", r#"export { runs } from "shared.js";"#),
+            ("b.js", r#"export { runs } from "shared.js";"#),
+            (
+                "shared.js",
+                r#"
+                globalThis.runs = (globalThis.runs ?? 0) + 1;
+                export const runs = globalThis.runs;
+                "#,
+            ),
+        ],
+    );
+
+    eval_module(&mut context, &main).unwrap();
+    eval_module(&mut context, &main).unwrap();
+    let runs = context.eval("globalThis.runs").unwrap();
+    assert_eq!(runs.as_number(), Some(1.0));
+}
+
+#[test]
+fn missing_export() {
+    let (mut context, main) = context_with_modules(
+        r#"import { missing } from "lib.js";"#,
+        &[("lib.js", "export const present = 1;")],
+    );
+
+    let error = eval_module(&mut context, &main).unwrap_err();
+    assert_eq!(
+        error_message(&error, &mut context),
+        "The requested module 'lib.js' does not provide an export named 'missing'"
+    );
+}
+
+#[test]
+fn imports_are_immutable() {
+    let (mut context, main) = context_with_modules(
+        r#"
+        import { value } from "lib.js";
+        value = 2;
+        "#,
+        &[("lib.js", "export let value = 1;")],
+    );
+
+    let error = eval_module(&mut context, &main).unwrap_err();
+    let name = error
+        .as_object()
+        .unwrap()
+        .get("name", &mut context)
+        .unwrap();
+    assert_eq!(name.as_string().unwrap().as_str(), "TypeError");
+}
+
+#[test]
+fn module_code_is_strict() {
+    let (mut context, main) = context_with_modules(
+        r#"
+        function f() { return this; }
+        export const declaration = typeof f();
+        export const expression = typeof (function () { return this; })();
+        export const nested = typeof (() => function () { return this; })()();
+        "#,
+        &[],
+    );
+
+    let namespace = eval_module(&mut context, &main).unwrap();
+    for name in ["declaration", "expression", "nested"] {
+        let value = namespace.get(name, &mut context).unwrap();
+        assert_eq!(value.display().to_string(), "\"undefined\"", "{name}");
+    }
+}
+
+#[test]
+fn hoisted_function_exports_in_cycles() {
+    let (mut context, main) = context_with_modules(
+        r#"
+        import { b } from "b.js";
+        export function a() { return 1; }
+        export const result = b;
+        "#,
+        &[(
+            "b.js",
+            r#"
+            import { a } from "main.js";
+            export const b = a();
+            "#,
+        )],
+    );
+
+    let namespace = eval_module(&mut context, &main).unwrap();
+    let result = namespace.get("result", &mut context).unwrap();
+    assert_eq!(result.as_number(), Some(1.0));
+}
+
+#[test]
+fn top_level_await() {
+    let (mut context, main) = context_with_modules(
+        r#"
+        import { value } from "lib.js";
+        export const out = await 1;
+        export const sum = value + out;
+        "#,
+        &[(
+            "lib.js",
+            r#"
+            export let value = 0;
+            value = await Promise.resolve(41);
+            "#,
+        )],
+    );
+
+    let namespace = eval_module(&mut context, &main).unwrap();
+    let out = namespace.get("out", &mut context).unwrap();
+    let sum = namespace.get("sum", &mut context).unwrap();
+    assert_eq!(out.as_number(), Some(1.0));
+    assert_eq!(sum.as_number(), Some(42.0));
+}
+
+#[test]
+fn top_level_await_rejection() {
+    let (mut context, main) = context_with_modules(
+        r#"
+        import { caught } from "lib.js";
+        export const message = caught;
+        await Promise.reject(new Error("rejected"));
+        "#,
+        &[(
+            "lib.js",
+            r#"
+            export let caught;
+            try {
+                await Promise.reject(new Error("caught"));
+            } catch (e) {
+                caught = e.message;
+            }
+            "#,
+        )],
+    );
+
+    let error = eval_module(&mut context, &main).unwrap_err();
+    assert_eq!(error_message(&error, &mut context), "rejected");
+    let message = main
+        .namespace(&mut context)
+        .get("message", &mut context)
+        .unwrap();
+    assert_eq!(message.as_string().unwrap().as_str(), "caught");
+}
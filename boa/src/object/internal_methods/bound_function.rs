@@ -36,26 +36,32 @@ fn bound_function_exotic_call(
     arguments_list: &[JsValue],
     context: &mut Context,
 ) -> JsResult<JsValue> {
-    let obj = obj.borrow();
-    let bound_function = obj
-        .as_bound_function()
-        .expect("bound function exotic method should only be callable from bound function objects");
+    // The bound function must not stay borrowed while the target runs, since the
+    // target can observe and mutate the bound function object.
+    let (target, bound_this, args) = {
+        let obj = obj.borrow();
+        let bound_function = obj.as_bound_function().expect(
+            "bound function exotic method should only be callable from bound function objects",
+        );
 
-    // 1. Let target be F.[[BoundTargetFunction]].
-    let target = bound_function.target_function();
+        // 1. Let target be F.[[BoundTargetFunction]].
+        let target = bound_function.target_function().clone();
 
-    // 2. Let boundThis be F.[[BoundThis]].
-    let bound_this = bound_function.this();
+        // 2. Let boundThis be F.[[BoundThis]].
+        let bound_this = bound_function.this().clone();
 
-    // 3. Let boundArgs be F.[[BoundArguments]].
-    let bound_args = bound_function.args();
+        // 3. Let boundArgs be F.[[BoundArguments]].
+        let bound_args = bound_function.args();
 
-    // 4. Let args be the list-concatenation of boundArgs and argumentsList.
-    let mut args = bound_args.to_vec();
-    args.extend_from_slice(arguments_list);
+        // 4. Let args be the list-concatenation of boundArgs and argumentsList.
+        let mut args = bound_args.to_vec();
+        args.extend_from_slice(arguments_list);
+
+        (target, bound_this, args)
+    };
 
     // 5. Return ? Call(target, boundThis, args).
-    target.call(bound_this, &args, context)
+    target.call(&bound_this, &args, context)
 }
 
 /// Internal method `[[Construct]]` for Bound Function Exotic Objects
@@ -72,22 +78,26 @@ fn bound_function_exotic_construct(
     new_target: &JsValue,
     context: &mut Context,
 ) -> JsResult<JsValue> {
-    let object = obj.borrow();
-    let bound_function = object
-        .as_bound_function()
-        .expect("bound function exotic method should only be callable from bound function objects");
+    let (target, args) = {
+        let object = obj.borrow();
+        let bound_function = object.as_bound_function().expect(
+            "bound function exotic method should only be callable from bound function objects",
+        );
 
-    // 1. Let target be F.[[BoundTargetFunction]].
-    let target = bound_function.target_function();
+        // 1. Let target be F.[[BoundTargetFunction]].
+        let target = bound_function.target_function().clone();
 
-    // 2. Assert: IsConstructor(target) is true.
+        // 2. Assert: IsConstructor(target) is true.
 
-    // 3. Let boundArgs be F.[[BoundArguments]].
-    let bound_args = bound_function.args();
+        // 3. Let boundArgs be F.[[BoundArguments]].
+        let bound_args = bound_function.args();
 
-    // 4. Let args be the list-concatenation of boundArgs and argumentsList.
-    let mut args = bound_args.to_vec();
-    args.extend_from_slice(arguments_list);
+        // 4. Let args be the list-concatenation of boundArgs and argumentsList.
+        let mut args = bound_args.to_vec();
+        args.extend_from_slice(arguments_list);
+
+        (target, args)
+    };
 
     // 5. If SameValue(F, newTarget) is true, set newTarget to target.
     let new_target = match new_target {
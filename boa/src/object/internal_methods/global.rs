@@ -20,6 +20,7 @@ pub(crate) static GLOBAL_INTERNAL_METHODS: InternalObjectMethods = InternalObjec
     __get__: global_get,
     __set__: global_set,
     __delete__: global_delete,
+    __own_property_keys__: global_own_property_keys,
     ..ORDINARY_INTERNAL_METHODS
 };
 
@@ -51,6 +52,9 @@ pub(crate) fn global_get_own_property(
     // 7. Set D.[[Enumerable]] to the value of X's [[Enumerable]] attribute.
     // 8. Set D.[[Configurable]] to the value of X's [[Configurable]] attribute.
     // 9. Return D.
+    if let PropertyKey::String(name) = key {
+        context.initialize_lazy_global(name);
+    }
     Ok(context.realm.global_property_map.get(key).cloned())
 }
 
@@ -201,6 +205,9 @@ pub(crate) fn global_set_no_receiver(
     // https://tc39.es/ecma262/multipage/ordinary-and-exotic-objects-behaviours.html#sec-ordinarysetwithowndescriptor
 
     // 1. Assert: IsPropertyKey(P) is true.
+    if let PropertyKey::String(name) = key {
+        context.initialize_lazy_global(name);
+    }
     let own_desc = if let Some(desc) = context.realm.global_property_map.get(key).cloned() {
         desc
     }
@@ -293,6 +300,9 @@ pub(crate) fn global_delete(
     let _timer = BoaProfiler::global().start_event("Object::global_delete", "object");
     // 1. Assert: IsPropertyKey(P) is true.
     // 2. Let desc be ? O.[[GetOwnProperty]](P).
+    if let PropertyKey::String(name) = key {
+        context.initialize_lazy_global(name);
+    }
     match context.realm.global_property_map.get(key) {
         // 4. If desc.[[Configurable]] is true, then
         Some(desc) if desc.expect_configurable() => {
@@ -308,6 +318,63 @@ pub(crate) fn global_delete(
     }
 }
 
+/// Abstract operation `OrdinaryOwnPropertyKeys`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinaryownpropertykeys
+#[inline]
+#[allow(clippy::unnecessary_wraps)]
+pub(crate) fn global_own_property_keys(
+    _obj: &JsObject,
+    context: &mut Context,
+) -> JsResult<Vec<PropertyKey>> {
+    let _timer = BoaProfiler::global().start_event("Object::global_own_property_keys", "object");
+    // 1. Let keys be a new empty List.
+    let mut keys = Vec::new();
+
+    let ordered_indexes = {
+        let mut indexes: Vec<_> = context
+            .realm
+            .global_property_map
+            .index_property_keys()
+            .copied()
+            .collect();
+        indexes.sort_unstable();
+        indexes
+    };
+
+    // 2. For each own property key P of O such that P is an array index, in ascending numeric index order, do
+    // a. Add P as the last element of keys.
+    keys.extend(ordered_indexes.into_iter().map(Into::into));
+
+    // 3. For each own property key P of O such that Type(P) is String and P is not an array index, in ascending chronological order of property creation, do
+    // a. Add P as the last element of keys.
+    keys.extend(
+        context
+            .realm
+            .global_property_map
+            .string_property_keys()
+            .cloned()
+            .map(Into::into),
+    );
+
+    // 4. For each own property key P of O such that Type(P) is Symbol, in ascending chronological order of property creation, do
+    // a. Add P as the last element of keys.
+    keys.extend(
+        context
+            .realm
+            .global_property_map
+            .symbol_property_keys()
+            .cloned()
+            .map(Into::into),
+    );
+
+    // 5. Return keys.
+    Ok(keys)
+}
+
 /// Abstract operation `ValidateAndApplyPropertyDescriptor`
 ///
 /// More information:
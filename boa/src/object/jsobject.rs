@@ -5,7 +5,10 @@
 use super::{JsPrototype, NativeObject, Object, PropertyMap};
 use crate::{
     gc::{self, Finalize, Gc, Trace},
-    object::{ObjectData, ObjectKind},
+    object::{
+        weak_table::{WeakHandle, WeakToken},
+        ObjectData, ObjectKind,
+    },
     property::{PropertyDescriptor, PropertyKey},
     value::PreferredType,
     Context, JsResult, JsValue,
@@ -52,6 +55,8 @@ impl JsObject {
             prototype: prototype.into(),
             extensible: true,
             properties: PropertyMap::default(),
+            private_elements: Vec::new(),
+            weak_token: None,
         })
     }
 
@@ -110,6 +115,24 @@ impl JsObject {
         std::ptr::eq(lhs.as_ref(), rhs.as_ref())
     }
 
+    /// Gets a weak handle to this object, if one has been created before.
+    ///
+    /// An object without a weak handle can't be a key of any weak collection.
+    #[inline]
+    pub(crate) fn weak_handle(&self) -> Option<WeakHandle> {
+        self.borrow().weak_token.as_ref().map(WeakHandle::new)
+    }
+
+    /// Gets a weak handle to this object, creating it if this is the first one.
+    #[inline]
+    pub(crate) fn create_weak_handle(&self) -> WeakHandle {
+        WeakHandle::new(
+            self.borrow_mut()
+                .weak_token
+                .get_or_insert_with(WeakToken::default),
+        )
+    }
+
     /// Converts an object to a primitive.
     ///
     /// Diverges from the spec to prevent a stack overflow when the object is recursive.
@@ -439,6 +462,17 @@ impl JsObject {
         self.borrow().is_native_object()
     }
 
+    /// Checks if it is a `Promise` object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn is_promise(&self) -> bool {
+        self.borrow().is_promise()
+    }
+
     pub fn to_property_descriptor(&self, context: &mut Context) -> JsResult<PropertyDescriptor> {
         // 1 is implemented on the method `to_property_descriptor` of value
 
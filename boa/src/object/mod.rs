@@ -6,9 +6,12 @@ use crate::{
         array_buffer::ArrayBuffer,
         function::arguments::{Arguments, MappedArguments},
         function::{BoundFunction, Captures, Function, NativeFunctionSignature},
+        generator::Generator,
+        iterable::AsyncFromSyncIterator,
         map::map_iterator::MapIterator,
         map::ordered_map::OrderedMap,
         object::for_in_iterator::ForInIterator,
+        promise::Promise,
         proxy::Proxy,
         regexp::regexp_string_iterator::RegExpStringIterator,
         set::ordered_set::OrderedSet,
@@ -20,6 +23,7 @@ use crate::{
     context::StandardConstructor,
     gc::{Finalize, Trace},
     property::{Attribute, PropertyDescriptor, PropertyKey},
+    vm::StackTraceFrame,
     Context, JsBigInt, JsResult, JsString, JsSymbol, JsValue,
 };
 use std::{
@@ -31,6 +35,7 @@ use std::{
 pub use jsobject::{JsObject, RecursionLimiter, Ref, RefMut};
 pub use operations::IntegrityLevel;
 pub use property_map::*;
+pub use weak_table::WeakTable;
 
 use self::internal_methods::{
     arguments::ARGUMENTS_EXOTIC_INTERNAL_METHODS,
@@ -48,6 +53,7 @@ use self::internal_methods::{
     string::STRING_EXOTIC_INTERNAL_METHODS,
     InternalObjectMethods, ORDINARY_INTERNAL_METHODS,
 };
+use self::weak_table::WeakToken;
 
 #[cfg(test)]
 mod tests;
@@ -56,6 +62,7 @@ pub(crate) mod internal_methods;
 mod jsobject;
 mod operations;
 mod property_map;
+pub(crate) mod weak_table;
 
 /// Static `prototype`, usually set on constructors as a key to point to their respective prototype object.
 pub static PROTOTYPE: &str = "prototype";
@@ -96,6 +103,65 @@ pub struct Object {
     prototype: JsPrototype,
     /// Whether it can have new properties added to it.
     extensible: bool,
+    /// The [[PrivateElements]] of the object, the private fields and methods added by classes.
+    private_elements: Vec<(JsSymbol, PrivateElement)>,
+    /// The token that identifies the object in the weak collections, created on first use.
+    #[unsafe_ignore_trace]
+    weak_token: Option<WeakToken>,
+}
+
+/// A private field, method or accessor of an object.
+///
+/// Private elements are keyed by a unique symbol for each private name of a class, and can only
+/// be accessed by the code of that class.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-privateelement-specification-type
+#[derive(Clone, Debug, Trace, Finalize)]
+pub enum PrivateElement {
+    /// A private field, with its current value.
+    Field(JsValue),
+
+    /// A private method.
+    Method(JsObject),
+
+    /// A private accessor, with at least one of a getter or a setter.
+    Accessor {
+        getter: Option<JsObject>,
+        setter: Option<JsObject>,
+    },
+}
+
+/// Appends a private method or accessor to a list of private elements.
+///
+/// A getter and a setter with the same name are merged into a single accessor.
+pub(crate) fn push_private_method(
+    elements: &mut Vec<(JsSymbol, PrivateElement)>,
+    name: JsSymbol,
+    method: PrivateElement,
+) {
+    if let PrivateElement::Accessor { getter, setter } = &method {
+        let existing = elements.iter_mut().find(|(key, _)| key == &name);
+        if let Some((
+            _,
+            PrivateElement::Accessor {
+                getter: existing_getter,
+                setter: existing_setter,
+            },
+        )) = existing
+        {
+            if getter.is_some() {
+                existing_getter.clone_from(getter);
+            }
+            if setter.is_some() {
+                existing_setter.clone_from(setter);
+            }
+            return;
+        }
+    }
+    elements.push((name, method));
 }
 
 /// Defines the kind of an object and its internal methods
@@ -119,15 +185,19 @@ pub enum ObjectKind {
     Boolean(bool),
     DataView(DataView),
     ForInIterator(ForInIterator),
+    AsyncFromSyncIterator(AsyncFromSyncIterator),
     Function(Function),
+    Generator(Generator),
     BoundFunction(BoundFunction),
     Set(OrderedSet<JsValue>),
     SetIterator(SetIterator),
+    WeakMap(WeakTable<JsValue>),
+    WeakSet(WeakTable<()>),
     String(JsString),
     StringIterator(StringIterator),
     Number(f64),
     Symbol(JsSymbol),
-    Error,
+    Error(Vec<StackTraceFrame>),
     Ordinary,
     Proxy(Proxy),
     Date(Date),
@@ -135,6 +205,7 @@ pub enum ObjectKind {
     Arguments(Arguments),
     NativeObject(Box<dyn NativeObject>),
     IntegerIndexed(IntegerIndexed),
+    Promise(Promise),
 }
 
 impl ObjectData {
@@ -178,6 +249,14 @@ impl ObjectData {
         }
     }
 
+    /// Create the `Promise` object data
+    pub fn promise(promise: Promise) -> Self {
+        Self {
+            kind: ObjectKind::Promise(promise),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `RegExp` object data
     pub fn reg_exp(reg_exp: Box<RegExp>) -> Self {
         Self {
@@ -226,6 +305,22 @@ impl ObjectData {
         }
     }
 
+    /// Create the `AsyncFromSyncIterator` object data
+    pub fn async_from_sync_iterator(async_from_sync_iterator: AsyncFromSyncIterator) -> Self {
+        Self {
+            kind: ObjectKind::AsyncFromSyncIterator(async_from_sync_iterator),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Generator` object data
+    pub fn generator(generator: Generator) -> Self {
+        Self {
+            kind: ObjectKind::Generator(generator),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `Function` object data
     pub fn function(function: Function) -> Self {
         Self {
@@ -266,6 +361,22 @@ impl ObjectData {
         }
     }
 
+    /// Create the `WeakMap` object data
+    pub fn weak_map(weak_map: WeakTable<JsValue>) -> Self {
+        Self {
+            kind: ObjectKind::WeakMap(weak_map),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `WeakSet` object data
+    pub fn weak_set(weak_set: WeakTable<()>) -> Self {
+        Self {
+            kind: ObjectKind::WeakSet(weak_set),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `String` object data and reference its exclusive internal methods
     pub fn string(string: JsString) -> Self {
         Self {
@@ -298,10 +409,10 @@ impl ObjectData {
         }
     }
 
-    /// Create the `Error` object data
-    pub fn error() -> Self {
+    /// Create the `Error` object data, with the stack trace captured when it was created.
+    pub fn error(stack_trace: Vec<StackTraceFrame>) -> Self {
         Self {
-            kind: ObjectKind::Error,
+            kind: ObjectKind::Error(stack_trace),
             internal_methods: &ORDINARY_INTERNAL_METHODS,
         }
     }
@@ -380,7 +491,9 @@ impl Display for ObjectKind {
             Self::ArrayIterator(_) => "ArrayIterator",
             Self::ArrayBuffer(_) => "ArrayBuffer",
             Self::ForInIterator(_) => "ForInIterator",
+            Self::AsyncFromSyncIterator(_) => "AsyncFromSyncIterator",
             Self::Function(_) => "Function",
+            Self::Generator(_) => "Generator",
             Self::BoundFunction(_) => "BoundFunction",
             Self::RegExp(_) => "RegExp",
             Self::RegExpStringIterator(_) => "RegExpStringIterator",
@@ -388,10 +501,12 @@ impl Display for ObjectKind {
             Self::MapIterator(_) => "MapIterator",
             Self::Set(_) => "Set",
             Self::SetIterator(_) => "SetIterator",
+            Self::WeakMap(_) => "WeakMap",
+            Self::WeakSet(_) => "WeakSet",
             Self::String(_) => "String",
             Self::StringIterator(_) => "StringIterator",
             Self::Symbol(_) => "Symbol",
-            Self::Error => "Error",
+            Self::Error(_) => "Error",
             Self::Ordinary => "Ordinary",
             Self::Proxy(_) => "Proxy",
             Self::Boolean(_) => "Boolean",
@@ -403,6 +518,7 @@ impl Display for ObjectKind {
             Self::NativeObject(_) => "NativeObject",
             Self::IntegerIndexed(_) => "TypedArray",
             Self::DataView(_) => "DataView",
+            Self::Promise(_) => "Promise",
         })
     }
 }
@@ -425,6 +541,8 @@ impl Default for Object {
             properties: PropertyMap::default(),
             prototype: None,
             extensible: true,
+            private_elements: Vec::new(),
+            weak_token: None,
         }
     }
 }
@@ -570,6 +688,53 @@ impl Object {
         }
     }
 
+    #[inline]
+    pub fn as_async_from_sync_iterator(&self) -> Option<&AsyncFromSyncIterator> {
+        match &self.data {
+            ObjectData {
+                kind: ObjectKind::AsyncFromSyncIterator(iter),
+                ..
+            } => Some(iter),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `Generator` object.
+    #[inline]
+    pub fn is_generator(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::Generator(_),
+                ..
+            }
+        )
+    }
+
+    /// Returns a reference to the generator data on the object.
+    #[inline]
+    pub fn as_generator(&self) -> Option<&Generator> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Generator(ref generator),
+                ..
+            } => Some(generator),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the generator data on the object.
+    #[inline]
+    pub fn as_generator_mut(&mut self) -> Option<&mut Generator> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Generator(ref mut generator),
+                ..
+            } => Some(generator),
+            _ => None,
+        }
+    }
+
     /// Checks if it is a `Map` object.pub
     #[inline]
     pub fn is_map(&self) -> bool {
@@ -670,6 +835,50 @@ impl Object {
         }
     }
 
+    #[inline]
+    pub fn as_weak_map_ref(&self) -> Option<&WeakTable<JsValue>> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::WeakMap(ref weak_map),
+                ..
+            } => Some(weak_map),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_weak_map_mut(&mut self) -> Option<&mut WeakTable<JsValue>> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::WeakMap(weak_map),
+                ..
+            } => Some(weak_map),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_weak_set_ref(&self) -> Option<&WeakTable<()>> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::WeakSet(ref weak_set),
+                ..
+            } => Some(weak_set),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_weak_set_mut(&mut self) -> Option<&mut WeakTable<()>> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::WeakSet(weak_set),
+                ..
+            } => Some(weak_set),
+            _ => None,
+        }
+    }
+
     #[inline]
     pub fn as_set_iterator_mut(&mut self) -> Option<&mut SetIterator> {
         match &mut self.data {
@@ -778,19 +987,20 @@ impl Object {
         matches!(
             self.data,
             ObjectData {
-                kind: ObjectKind::Error,
+                kind: ObjectKind::Error(_),
                 ..
             }
         )
     }
 
+    /// Gets the stack trace of the error object, if it is one.
     #[inline]
-    pub fn as_error(&self) -> Option<()> {
+    pub fn as_error(&self) -> Option<&[StackTraceFrame]> {
         match self.data {
             ObjectData {
-                kind: ObjectKind::Error,
+                kind: ObjectKind::Error(ref stack_trace),
                 ..
-            } => Some(()),
+            } => Some(stack_trace),
             _ => None,
         }
     }
@@ -1086,6 +1296,40 @@ impl Object {
         }
     }
 
+    /// Checks if it is a `Promise` object.
+    #[inline]
+    pub fn is_promise(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::Promise(_),
+                ..
+            }
+        )
+    }
+
+    #[inline]
+    pub fn as_promise(&self) -> Option<&Promise> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Promise(ref promise),
+                ..
+            } => Some(promise),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_promise_mut(&mut self) -> Option<&mut Promise> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Promise(ref mut promise),
+                ..
+            } => Some(promise),
+            _ => None,
+        }
+    }
+
     /// Return `true` if it is a native object and the native type is `T`.
     #[inline]
     pub fn is<T>(&self) -> bool
@@ -1166,6 +1410,47 @@ impl Object {
     {
         self.insert(key, property)
     }
+
+    /// Abstract operation `PrivateElementFind ( O, P )`
+    ///
+    /// Returns the private element of the object with the given private name, if there is one.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-privateelementfind
+    #[inline]
+    pub(crate) fn private_element_find(&self, name: &JsSymbol) -> Option<&PrivateElement> {
+        self.private_elements
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, element)| element)
+    }
+
+    /// Mutable version of [`Object::private_element_find`].
+    #[inline]
+    pub(crate) fn private_element_find_mut(
+        &mut self,
+        name: &JsSymbol,
+    ) -> Option<&mut PrivateElement> {
+        self.private_elements
+            .iter_mut()
+            .find(|(key, _)| key == name)
+            .map(|(_, element)| element)
+    }
+
+    /// Appends a private element to the object, without checking for duplicates.
+    #[inline]
+    pub(crate) fn append_private_element(&mut self, name: JsSymbol, element: PrivateElement) {
+        self.private_elements.push((name, element));
+    }
+
+    /// Appends a private method or accessor to the object, merging a getter and a setter with the
+    /// same name into a single accessor.
+    #[inline]
+    pub(crate) fn append_private_method(&mut self, name: JsSymbol, method: PrivateElement) {
+        push_private_method(&mut self.private_elements, name, method);
+    }
 }
 
 /// The functions binding.
@@ -1,11 +1,11 @@
 use crate::{
-    builtins::Array,
+    builtins::{function::ClassFieldDefinition, Array},
     context::{StandardConstructor, StandardObjects},
-    object::JsObject,
+    object::{JsObject, PrivateElement},
     property::{PropertyDescriptor, PropertyKey, PropertyNameKind},
     symbol::WellKnownSymbols,
-    value::Type,
-    Context, JsResult, JsValue,
+    value::{TryFromJs, Type},
+    Context, JsResult, JsSymbol, JsValue,
 };
 
 /// Object integrity level.
@@ -71,6 +71,37 @@ impl JsObject {
         self.__get__(&key.into(), self.clone().into(), context)
     }
 
+    /// Gets a property of the object and converts it to a Rust type with [`TryFromJs`].
+    ///
+    /// Returns `Ok(None)` if the property is missing or its value can't be converted to `T`.
+    /// Exceptions thrown while getting the property or converting its value, e.g. from a
+    /// getter, are returned as errors.
+    ///
+    /// # Examples
+    /// ```
+    /// use boa::Context;
+    ///
+    /// let mut context = Context::default();
+    /// let value = context.eval("({ size: 3, name: 'box' })").unwrap();
+    /// let object = value.as_object().unwrap();
+    ///
+    /// assert_eq!(object.get_as::<u32, _>("size", &mut context).unwrap(), Some(3));
+    /// assert_eq!(object.get_as::<u32, _>("name", &mut context).unwrap(), None);
+    /// ```
+    #[inline]
+    pub fn get_as<T, K>(&self, key: K, context: &mut Context) -> JsResult<Option<T>>
+    where
+        T: TryFromJs,
+        K: Into<PropertyKey>,
+    {
+        let value = self.get(key, context)?;
+        match T::try_from_js(&value, context) {
+            Ok(value) => Ok(Some(value)),
+            Err(error) if context.take_conversion_error(&error) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
     /// set property of object or throw if bool flag is passed.
     ///
     /// More information:
@@ -606,9 +637,254 @@ impl JsObject {
 
     // todo: PrivateSet
 
-    // todo: DefineField
+    /// Abstract operation `DefineField ( receiver, fieldRecord )`
+    ///
+    /// Evaluates the initializer of a class field and defines the field on `self`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-definefield
+    pub(crate) fn define_field(
+        &self,
+        field: &ClassFieldDefinition,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. Let fieldName be fieldRecord.[[Name]].
+        // 2. Let initializer be fieldRecord.[[Initializer]].
+        let initializer = match field {
+            ClassFieldDefinition::Public(_, initializer)
+            | ClassFieldDefinition::Private(_, initializer) => initializer,
+        };
 
-    // todo: InitializeInstanceElements
+        // 3. If initializer is not empty, then
+        let init_value = if let Some(initializer) = initializer {
+            // a. Let initValue be ? Call(initializer, receiver).
+            initializer.call(&self.clone().into(), &[], context)?
+        } else {
+            // 4. Else, let initValue be undefined.
+            JsValue::undefined()
+        };
+
+        match field {
+            // 5. If fieldName is a Private Name, then
+            ClassFieldDefinition::Private(name, _) => {
+                // a. Perform ? PrivateFieldAdd(receiver, fieldName, initValue).
+                self.private_field_add(name, init_value, context)?;
+            }
+            // 6. Else,
+            ClassFieldDefinition::Public(name, _) => {
+                // a. Assert: IsPropertyKey(fieldName) is true.
+                // b. Perform ? CreateDataPropertyOrThrow(receiver, fieldName, initValue).
+                self.create_data_property_or_throw(name.clone(), init_value, context)?;
+            }
+        }
+
+        // 7. Return unused.
+        Ok(())
+    }
+
+    /// Abstract operation `InitializeInstanceElements ( O, constructor )`
+    ///
+    /// Adds the private methods of the class `constructor` to `self`, then defines its fields in
+    /// the order in which they appear in the class body.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-initializeinstanceelements
+    pub(crate) fn initialize_instance_elements(
+        &self,
+        constructor: &Self,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. Let methods be the value of constructor.[[PrivateMethods]].
+        // 3. Let fields be the value of constructor.[[Fields]].
+        let (methods, fields) = constructor
+            .borrow()
+            .as_function()
+            .map(|function| {
+                (
+                    function.private_methods().to_vec(),
+                    function.fields().to_vec(),
+                )
+            })
+            .unwrap_or_default();
+
+        // 2. For each PrivateElement method of methods, do
+        for (name, method) in methods {
+            // a. Perform ? PrivateMethodOrAccessorAdd(O, method).
+            self.private_method_or_accessor_add(name, method, context)?;
+        }
+
+        // 4. For each element fieldRecord of fields, do
+        for field in &fields {
+            // a. Perform ? DefineField(O, fieldRecord).
+            self.define_field(field, context)?;
+        }
+
+        // 5. Return unused.
+        Ok(())
+    }
+
+    /// Abstract operation `PrivateFieldAdd ( O, P, value )`
+    ///
+    /// Adds a private field to `self`, throwing if it already has an element with that name.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-privatefieldadd
+    pub(crate) fn private_field_add(
+        &self,
+        name: &JsSymbol,
+        value: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. If the host is a web browser, then
+        //     a. Perform ? HostEnsureCanAddPrivateElement(O).
+        // 2. Let entry be PrivateElementFind(O, P).
+        // 3. If entry is not empty, throw a TypeError exception.
+        if self.borrow().private_element_find(name).is_some() {
+            return context.throw_type_error("cannot initialize a private field twice");
+        }
+
+        // 4. Append PrivateElement { [[Key]]: P, [[Kind]]: field, [[Value]]: value } to O.[[PrivateElements]].
+        self.borrow_mut()
+            .append_private_element(name.clone(), PrivateElement::Field(value));
+
+        // 5. Return unused.
+        Ok(())
+    }
+
+    /// Abstract operation `PrivateMethodOrAccessorAdd ( O, method )`
+    ///
+    /// Adds a private method or accessor to `self`, throwing if it already has an element with
+    /// that name.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-privatemethodoraccessoradd
+    pub(crate) fn private_method_or_accessor_add(
+        &self,
+        name: JsSymbol,
+        method: PrivateElement,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. Assert: method.[[Kind]] is either method or accessor.
+        // 2. If the host is a web browser, then
+        //     a. Perform ? HostEnsureCanAddPrivateElement(O).
+        // 3. Let entry be PrivateElementFind(O, method.[[Key]]).
+        // 4. If entry is not empty, throw a TypeError exception.
+        if self.borrow().private_element_find(&name).is_some() {
+            return context.throw_type_error("cannot initialize a private method twice");
+        }
+
+        // 5. Append method to O.[[PrivateElements]].
+        self.borrow_mut().append_private_element(name, method);
+
+        // 6. Return unused.
+        Ok(())
+    }
+
+    /// Abstract operation `PrivateGet ( O, P )`
+    ///
+    /// Gets the value of a private field, private method or private getter of `self`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-privateget
+    pub(crate) fn private_get(&self, name: &JsSymbol, context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let entry be PrivateElementFind(O, P).
+        let entry = self.borrow().private_element_find(name).cloned();
+        match &entry {
+            // 2. If entry is empty, throw a TypeError exception.
+            None => context.throw_type_error(format!(
+                "cannot read private member {} from an object whose class did not declare it",
+                name.description().unwrap_or_default()
+            )),
+            // 3. If entry.[[Kind]] is either field or method, then
+            //     a. Return entry.[[Value]].
+            Some(PrivateElement::Field(value)) => Ok(value.clone()),
+            Some(PrivateElement::Method(method)) => Ok(method.clone().into()),
+            // 4. Assert: entry.[[Kind]] is accessor.
+            // 5. If entry.[[Get]] is undefined, throw a TypeError exception.
+            Some(PrivateElement::Accessor { getter: None, .. }) => {
+                context.throw_type_error(format!(
+                    "private accessor {} was defined without a getter",
+                    name.description().unwrap_or_default()
+                ))
+            }
+            // 6. Let getter be entry.[[Get]].
+            // 7. Return ? Call(getter, O).
+            Some(PrivateElement::Accessor {
+                getter: Some(getter),
+                ..
+            }) => getter.call(&self.clone().into(), &[], context),
+        }
+    }
+
+    /// Abstract operation `PrivateSet ( O, P, value )`
+    ///
+    /// Sets the value of a private field of `self`, or calls its private setter.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-privateset
+    pub(crate) fn private_set(
+        &self,
+        name: &JsSymbol,
+        value: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. Let entry be PrivateElementFind(O, P).
+        let setter = match self.borrow_mut().private_element_find_mut(name) {
+            // 2. If entry is empty, throw a TypeError exception.
+            None => {
+                return context.throw_type_error(format!(
+                    "cannot write private member {} to an object whose class did not declare it",
+                    name.description().unwrap_or_default()
+                ))
+            }
+            // 3. If entry.[[Kind]] is field, then
+            Some(PrivateElement::Field(field)) => {
+                // a. Set entry.[[Value]] to value.
+                *field = value;
+                return Ok(());
+            }
+            // 4. Else if entry.[[Kind]] is method, then
+            //     a. Throw a TypeError exception.
+            Some(PrivateElement::Method(_)) => {
+                return context.throw_type_error(format!(
+                    "private method {} is not writable",
+                    name.description().unwrap_or_default()
+                ))
+            }
+            // 5. Else,
+            //     a. Assert: entry.[[Kind]] is accessor.
+            //     b. If entry.[[Set]] is undefined, throw a TypeError exception.
+            Some(PrivateElement::Accessor { setter: None, .. }) => {
+                return context.throw_type_error(format!(
+                    "private accessor {} was defined without a setter",
+                    name.description().unwrap_or_default()
+                ))
+            }
+            //     c. Let setter be entry.[[Set]].
+            Some(PrivateElement::Accessor {
+                setter: Some(setter),
+                ..
+            }) => setter.clone(),
+        };
+
+        // d. Perform ? Call(setter, O, « value »).
+        setter.call(&self.clone().into(), &[value], context)?;
+
+        // 6. Return unused.
+        Ok(())
+    }
 }
 
 impl JsValue {
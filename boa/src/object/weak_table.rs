@@ -0,0 +1,134 @@
+//! This module implements the storage of the weak collections, `WeakMap` and `WeakSet`.
+//!
+//! The garbage collector has no support for weak pointers, so the keys of a [`WeakTable`] are
+//! [`WeakHandle`]s instead of the objects themselves. Every object can lazily create a token
+//! that is only owned by the object, and a handle is a weak reference to that token: once the
+//! object is collected the token is dropped with it, and the entries of the handle are dead.
+
+use crate::gc::{custom_trace, Finalize, Trace};
+use rustc_hash::FxHashMap;
+use std::{
+    hash::{Hash, Hasher},
+    rc::{Rc, Weak},
+};
+
+/// The token that is owned by an object and identifies it in the weak collections.
+pub(crate) type WeakToken = Rc<()>;
+
+/// A weak reference to the identity of an object.
+///
+/// The handle doesn't keep the object alive, and is no longer alive once the object has been
+/// collected. Two handles are equal if they refer to the same object.
+#[derive(Debug, Clone)]
+pub(crate) struct WeakHandle(Weak<()>);
+
+impl WeakHandle {
+    /// Creates a new handle to the object that owns `token`.
+    pub(crate) fn new(token: &WeakToken) -> Self {
+        Self(Rc::downgrade(token))
+    }
+
+    /// Checks if the object of this handle has not been collected yet.
+    pub(crate) fn is_alive(&self) -> bool {
+        self.0.strong_count() > 0
+    }
+}
+
+impl PartialEq for WeakHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Weak::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for WeakHandle {}
+
+impl Hash for WeakHandle {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // The allocation of the token is kept by the weak reference, so its address can't be
+        // reused by another object while this handle exists.
+        self.0.as_ptr().hash(state);
+    }
+}
+
+/// A table of values keyed weakly by objects.
+///
+/// The values are kept alive by the table, but the keys are not. Entries whose key has been
+/// collected are never observable, and are removed once the table has grown to twice its size
+/// after the last removal, so that inserting stays amortized constant time.
+///
+/// Values that reference their own key keep the key alive for as long as the table is alive,
+/// since the garbage collector doesn't support ephemerons.
+#[derive(Debug, Finalize)]
+pub struct WeakTable<V: Trace> {
+    entries: FxHashMap<WeakHandle, V>,
+    prune_threshold: usize,
+}
+
+unsafe impl<V: Trace> Trace for WeakTable<V> {
+    custom_trace!(this, {
+        for value in this.entries.values() {
+            mark(value);
+        }
+    });
+}
+
+impl<V: Trace> Default for WeakTable<V> {
+    fn default() -> Self {
+        Self {
+            entries: FxHashMap::default(),
+            prune_threshold: Self::MIN_PRUNE_THRESHOLD,
+        }
+    }
+}
+
+impl<V: Trace> WeakTable<V> {
+    /// The number of entries below which dead entries are never removed.
+    const MIN_PRUNE_THRESHOLD: usize = 16;
+
+    /// Creates a new empty `WeakTable`.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the value associated with `key`, if any.
+    pub(crate) fn get(&self, key: &WeakHandle) -> Option<&V> {
+        self.entries.get(key)
+    }
+
+    /// Checks if there is an entry for `key`.
+    pub(crate) fn contains_key(&self, key: &WeakHandle) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// Inserts an entry for `key`, replacing its previous value if it has one.
+    pub(crate) fn insert(&mut self, key: WeakHandle, value: V) {
+        if self.entries.len() >= self.prune_threshold {
+            self.remove_dead_entries();
+        }
+        self.entries.insert(key, value);
+    }
+
+    /// Removes the entry of `key`, returning `true` if there was one.
+    pub(crate) fn remove(&mut self, key: &WeakHandle) -> bool {
+        self.entries.remove(key).is_some()
+    }
+
+    /// Gets the number of entries whose key is still alive.
+    pub fn len(&self) -> usize {
+        self.entries.keys().filter(|key| key.is_alive()).count()
+    }
+
+    /// Checks if there are no entries whose key is still alive.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes the entries whose key has been collected.
+    ///
+    /// The next removal happens once the live entries have doubled, so the cost of a removal is
+    /// spread over the insertions that preceded it.
+    fn remove_dead_entries(&mut self) {
+        self.entries.retain(|key, _| key.is_alive());
+        self.prune_threshold = (self.entries.len() * 2).max(Self::MIN_PRUNE_THRESHOLD);
+    }
+}
@@ -477,13 +477,55 @@ impl From<PropertyDescriptorBuilder> for PropertyDescriptor {
 pub enum PropertyKey {
     String(JsString),
     Symbol(JsSymbol),
+    /// An array index, which is an integer in the range `0..=2^32 - 2`.
+    ///
+    /// Keys that look like integers but are not array indices, such as `"01"` or
+    /// `"4294967295"`, are stored as [`PropertyKey::String`].
     Index(u32),
 }
 
+impl PropertyKey {
+    /// The largest valid array index, `2^32 - 2`.
+    pub(crate) const MAX_ARRAY_INDEX: u32 = u32::MAX - 1;
+
+    /// Parses `string` as an array index, if it is the canonical string representation of one.
+    ///
+    /// More information:
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#array-index
+    fn parse_array_index(string: &str) -> Option<u32> {
+        // Only the canonical form is an array index: no sign, no leading zeros and no
+        // whitespace, which `str::parse` would otherwise accept in part.
+        if string.is_empty()
+            || !string.bytes().all(|b| b.is_ascii_digit())
+            || (string.len() > 1 && string.starts_with('0'))
+        {
+            return None;
+        }
+        string
+            .parse()
+            .ok()
+            .filter(|index| *index <= Self::MAX_ARRAY_INDEX)
+    }
+
+    /// Creates a key from an integer, which is an array index only if it is in range.
+    fn from_integer<T>(value: T) -> Self
+    where
+        T: Copy + ToString,
+        u32: TryFrom<T>,
+    {
+        match u32::try_from(value) {
+            Ok(index) if index <= Self::MAX_ARRAY_INDEX => Self::Index(index),
+            _ => Self::String(JsString::from(value.to_string())),
+        }
+    }
+}
+
 impl From<JsString> for PropertyKey {
     #[inline]
     fn from(string: JsString) -> Self {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = Self::parse_array_index(&string) {
             Self::Index(index)
         } else {
             Self::String(string)
@@ -494,7 +536,7 @@ impl From<JsString> for PropertyKey {
 impl From<&str> for PropertyKey {
     #[inline]
     fn from(string: &str) -> Self {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = Self::parse_array_index(string) {
             Self::Index(index)
         } else {
             Self::String(string.into())
@@ -505,7 +547,7 @@ impl From<&str> for PropertyKey {
 impl From<String> for PropertyKey {
     #[inline]
     fn from(string: String) -> Self {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = Self::parse_array_index(&string) {
             Self::Index(index)
         } else {
             Self::String(string.into())
@@ -516,7 +558,7 @@ impl From<String> for PropertyKey {
 impl From<Box<str>> for PropertyKey {
     #[inline]
     fn from(string: Box<str>) -> Self {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = Self::parse_array_index(&string) {
             Self::Index(index)
         } else {
             Self::String(string.into())
@@ -590,65 +632,45 @@ impl From<u16> for PropertyKey {
 
 impl From<u32> for PropertyKey {
     fn from(value: u32) -> Self {
-        Self::Index(value)
+        Self::from_integer(value)
     }
 }
 
 impl From<usize> for PropertyKey {
     fn from(value: usize) -> Self {
-        if let Ok(index) = u32::try_from(value) {
-            Self::Index(index)
-        } else {
-            Self::String(JsString::from(value.to_string()))
-        }
+        Self::from_integer(value)
     }
 }
 
 impl From<i64> for PropertyKey {
     fn from(value: i64) -> Self {
-        if let Ok(index) = u32::try_from(value) {
-            Self::Index(index)
-        } else {
-            Self::String(JsString::from(value.to_string()))
-        }
+        Self::from_integer(value)
     }
 }
 
 impl From<u64> for PropertyKey {
     fn from(value: u64) -> Self {
-        if let Ok(index) = u32::try_from(value) {
-            Self::Index(index)
-        } else {
-            Self::String(JsString::from(value.to_string()))
-        }
+        Self::from_integer(value)
     }
 }
 
 impl From<isize> for PropertyKey {
     fn from(value: isize) -> Self {
-        if let Ok(index) = u32::try_from(value) {
-            Self::Index(index)
-        } else {
-            Self::String(JsString::from(value.to_string()))
-        }
+        Self::from_integer(value)
     }
 }
 
 impl From<i32> for PropertyKey {
     fn from(value: i32) -> Self {
-        if let Ok(index) = u32::try_from(value) {
-            Self::Index(index)
-        } else {
-            Self::String(JsString::from(value.to_string()))
-        }
+        Self::from_integer(value)
     }
 }
 
 impl From<f64> for PropertyKey {
     fn from(value: f64) -> Self {
-        use num_traits::cast::FromPrimitive;
-        if let Some(index) = u32::from_f64(value) {
-            return Self::Index(index);
+        // `-0` is also converted to the index `0`, as `ToString(-0)` is `"0"`.
+        if value.fract() == 0.0 && (0.0..=f64::from(Self::MAX_ARRAY_INDEX)).contains(&value) {
+            return Self::Index(value as u32);
         }
 
         Self::String(ryu_js::Buffer::new().format(value).into())
@@ -7,8 +7,9 @@
 use crate::{
     environments::{CompileTimeEnvironmentStack, DeclarativeEnvironmentStack},
     object::{GlobalPropertyMap, JsObject, ObjectData, PropertyMap},
-    BoaProfiler,
+    BoaProfiler, Context, JsValue,
 };
+use rustc_hash::FxHashMap;
 
 /// Representation of a Realm.
 ///
@@ -16,8 +17,13 @@ use crate::{
 #[derive(Debug)]
 pub struct Realm {
     global_object: JsObject,
+    /// The binding object of the global environment, if it isn't the global object itself.
+    pub(crate) global_binding_object: Option<JsObject>,
     pub(crate) global_extensible: bool,
     pub(crate) global_property_map: PropertyMap,
+    /// The builtins whose initialization is deferred until their global binding is first used,
+    /// indexed by the name of the binding.
+    pub(crate) lazy_globals: FxHashMap<&'static str, fn(&mut Context) -> JsValue>,
     pub(crate) environments: DeclarativeEnvironmentStack,
     pub(crate) compile_env: CompileTimeEnvironmentStack,
 }
@@ -33,8 +39,10 @@ impl Realm {
 
         Self {
             global_object,
+            global_binding_object: None,
             global_extensible: true,
             global_property_map: PropertyMap::default(),
+            lazy_globals: FxHashMap::default(),
             environments: DeclarativeEnvironmentStack::new(),
             compile_env: CompileTimeEnvironmentStack::new(),
         }
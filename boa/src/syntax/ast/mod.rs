@@ -11,6 +11,6 @@ pub use self::{
     constant::Const,
     keyword::Keyword,
     node::Node,
-    position::{Position, Span},
+    position::{ByteSpan, Position, Span},
     punctuator::Punctuator,
 };
@@ -40,12 +40,13 @@ impl Block {
         self.statements.items()
     }
 
-    pub(crate) fn lexically_declared_names(&self, interner: &Interner) -> FxHashSet<Sym> {
-        self.statements.lexically_declared_names(interner)
+    /// Gets the statement list of this block.
+    pub(crate) fn statement_list(&self) -> &StatementList {
+        &self.statements
     }
 
-    pub(crate) fn var_declared_named(&self) -> FxHashSet<Sym> {
-        self.statements.var_declared_names()
+    pub(crate) fn lexically_declared_names(&self, interner: &Interner) -> FxHashSet<Sym> {
+        self.statements.lexically_declared_names(interner)
     }
 
     /// Implements the display formatting with indentation.
@@ -1,6 +1,9 @@
 use crate::{
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
+    syntax::ast::{
+        node::{join_nodes, FormalParameter, Node, SourceText, StatementList},
+        ByteSpan,
+    },
 };
 use boa_interner::{Interner, Sym, ToInternedString};
 
@@ -26,6 +29,7 @@ pub struct ArrowFunctionDecl {
     name: Option<Sym>,
     params: Box<[FormalParameter]>,
     body: StatementList,
+    source_text: SourceText,
 }
 
 impl ArrowFunctionDecl {
@@ -40,6 +44,7 @@ impl ArrowFunctionDecl {
             name: name.into(),
             params: params.into(),
             body: body.into(),
+            source_text: SourceText::default(),
         }
     }
 
@@ -63,6 +68,18 @@ impl ArrowFunctionDecl {
         &self.body
     }
 
+    /// Gets the span of the source text of the arrow function in the source code it was parsed
+    /// from, if it was parsed from source code.
+    pub fn source_span(&self) -> Option<ByteSpan> {
+        self.source_text.span()
+    }
+
+    /// Sets the span of the source text of the arrow function.
+    pub(in crate::syntax) fn with_source_span(mut self, span: ByteSpan) -> Self {
+        self.source_text = SourceText::new(span);
+        self
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn to_indented_string(
         &self,
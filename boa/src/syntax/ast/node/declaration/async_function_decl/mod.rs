@@ -2,7 +2,10 @@
 
 use crate::{
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
+    syntax::ast::{
+        node::{join_nodes, FormalParameter, Node, SourceText, StatementList},
+        ByteSpan,
+    },
 };
 use boa_interner::{Interner, Sym, ToInternedString};
 
@@ -23,6 +26,7 @@ pub struct AsyncFunctionDecl {
     name: Sym,
     parameters: Box<[FormalParameter]>,
     body: StatementList,
+    source_text: SourceText,
 }
 
 impl AsyncFunctionDecl {
@@ -36,6 +40,7 @@ impl AsyncFunctionDecl {
             name,
             parameters: parameters.into(),
             body: body.into(),
+            source_text: SourceText::default(),
         }
     }
 
@@ -50,8 +55,20 @@ impl AsyncFunctionDecl {
     }
 
     /// Gets the body of the async function declaration.
-    pub fn body(&self) -> &[Node] {
-        self.body.items()
+    pub fn body(&self) -> &StatementList {
+        &self.body
+    }
+
+    /// Gets the span of the source text of the async function declaration in the source code it was parsed
+    /// from, if it was parsed from source code.
+    pub fn source_span(&self) -> Option<ByteSpan> {
+        self.source_text.span()
+    }
+
+    /// Sets the span of the source text of the async function declaration.
+    pub(in crate::syntax) fn with_source_span(mut self, span: ByteSpan) -> Self {
+        self.source_text = SourceText::new(span);
+        self
     }
 
     /// Implements the display formatting with indentation.
@@ -66,7 +83,7 @@ impl AsyncFunctionDecl {
             join_nodes(interner, &self.parameters)
         );
 
-        if self.body().is_empty() {
+        if self.body.items().is_empty() {
             buf.push_str(") {}");
         } else {
             buf.push_str(&format!(
@@ -2,7 +2,10 @@
 
 use crate::{
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
+    syntax::ast::{
+        node::{join_nodes, FormalParameter, Node, SourceText, StatementList},
+        ByteSpan,
+    },
 };
 use boa_interner::{Interner, Sym, ToInternedString};
 
@@ -24,6 +27,7 @@ pub struct AsyncFunctionExpr {
     name: Option<Sym>,
     parameters: Box<[FormalParameter]>,
     body: StatementList,
+    source_text: SourceText,
 }
 
 impl AsyncFunctionExpr {
@@ -38,6 +42,7 @@ impl AsyncFunctionExpr {
             name: name.into(),
             parameters: parameters.into(),
             body: body.into(),
+            source_text: SourceText::default(),
         }
     }
 
@@ -52,8 +57,20 @@ impl AsyncFunctionExpr {
     }
 
     /// Gets the body of the function declaration.
-    pub fn body(&self) -> &[Node] {
-        self.body.items()
+    pub fn body(&self) -> &StatementList {
+        &self.body
+    }
+
+    /// Gets the span of the source text of the async function expression in the source code it was parsed
+    /// from, if it was parsed from source code.
+    pub fn source_span(&self) -> Option<ByteSpan> {
+        self.source_text.span()
+    }
+
+    /// Sets the span of the source text of the async function expression.
+    pub(in crate::syntax) fn with_source_span(mut self, span: ByteSpan) -> Self {
+        self.source_text = SourceText::new(span);
+        self
     }
 
     /// Implements the display formatting with indentation.
@@ -67,7 +84,7 @@ impl AsyncFunctionExpr {
             buf.push_str(&format!(" {}", interner.resolve_expect(name)));
         }
         buf.push_str(&format!("({}", join_nodes(interner, &self.parameters)));
-        if self.body().is_empty() {
+        if self.body.items().is_empty() {
             buf.push_str(") {}");
         } else {
             buf.push_str(&format!(
@@ -0,0 +1,260 @@
+use crate::{
+    gc::{Finalize, Trace},
+    syntax::ast::node::{
+        declaration::block_to_string, join_nodes, FunctionExpr, MethodDefinitionKind, Node,
+        PropertyName,
+    },
+};
+use boa_interner::{Interner, Sym, ToInternedString};
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+/// A class is a template for creating objects, with a constructor, methods and fields.
+///
+/// The same node is used for class declarations and class expressions. The name of a class
+/// declaration is always present, while it is optional for a class expression.
+///
+/// Class bodies are always strict mode code.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-class-definitions
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Class {
+    name: Option<Sym>,
+    super_ref: Option<Box<Node>>,
+    constructor: Option<FunctionExpr>,
+    elements: Box<[ClassElement]>,
+}
+
+impl Class {
+    /// Creates a new class.
+    pub(in crate::syntax) fn new<N, S, C, E>(
+        name: N,
+        super_ref: S,
+        constructor: C,
+        elements: E,
+    ) -> Self
+    where
+        N: Into<Option<Sym>>,
+        S: Into<Option<Node>>,
+        C: Into<Option<FunctionExpr>>,
+        E: Into<Box<[ClassElement]>>,
+    {
+        Self {
+            name: name.into(),
+            super_ref: super_ref.into().map(Box::new),
+            constructor: constructor.into(),
+            elements: elements.into(),
+        }
+    }
+
+    /// Gets the name of the class.
+    pub fn name(&self) -> Option<Sym> {
+        self.name
+    }
+
+    /// Gets the expression of the `extends` clause, if the class has one.
+    pub fn super_ref(&self) -> Option<&Node> {
+        self.super_ref.as_deref()
+    }
+
+    /// Gets the explicit constructor of the class.
+    pub fn constructor(&self) -> Option<&FunctionExpr> {
+        self.constructor.as_ref()
+    }
+
+    /// Gets the methods and fields of the class, in source order.
+    pub fn elements(&self) -> &[ClassElement] {
+        &self.elements
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn to_indented_string(
+        &self,
+        interner: &Interner,
+        indent_n: usize,
+    ) -> String {
+        let mut buf = "class".to_owned();
+        if let Some(name) = self.name {
+            buf.push_str(&format!(" {}", interner.resolve_expect(name)));
+        }
+        if let Some(super_ref) = &self.super_ref {
+            buf.push_str(&format!(
+                " extends {}",
+                super_ref.to_interned_string(interner)
+            ));
+        }
+        if self.constructor.is_none() && self.elements.is_empty() {
+            buf.push_str(" {}");
+            return buf;
+        }
+
+        let indentation = "    ".repeat(indent_n + 1);
+        buf.push_str(" {\n");
+        if let Some(constructor) = &self.constructor {
+            buf.push_str(&format!(
+                "{indentation}constructor({}) {}\n",
+                join_nodes(interner, constructor.parameters()),
+                block_to_string(constructor.body(), interner, indent_n + 1)
+            ));
+        }
+        for element in self.elements.iter() {
+            buf.push_str(&match element {
+                ClassElement::MethodDefinition(kind, name, function) => {
+                    format!(
+                        "{indentation}{}\n",
+                        method_to_string(*kind, name, function, interner, indent_n + 1)
+                    )
+                }
+                ClassElement::StaticMethodDefinition(kind, name, function) => {
+                    format!(
+                        "{indentation}static {}\n",
+                        method_to_string(*kind, name, function, interner, indent_n + 1)
+                    )
+                }
+                ClassElement::PrivateMethodDefinition(kind, name, function) => {
+                    format!(
+                        "{indentation}{}\n",
+                        method_to_string(*kind, &(*name).into(), function, interner, indent_n + 1)
+                    )
+                }
+                ClassElement::PrivateStaticMethodDefinition(kind, name, function) => {
+                    format!(
+                        "{indentation}static {}\n",
+                        method_to_string(*kind, &(*name).into(), function, interner, indent_n + 1)
+                    )
+                }
+                ClassElement::FieldDefinition(name, init) => {
+                    format!(
+                        "{indentation}{};\n",
+                        field_to_string(name, init.as_ref(), interner)
+                    )
+                }
+                ClassElement::StaticFieldDefinition(name, init) => {
+                    format!(
+                        "{indentation}static {};\n",
+                        field_to_string(name, init.as_ref(), interner)
+                    )
+                }
+                ClassElement::PrivateFieldDefinition(name, init) => {
+                    format!(
+                        "{indentation}{};\n",
+                        field_to_string(&(*name).into(), init.as_ref(), interner)
+                    )
+                }
+                ClassElement::PrivateStaticFieldDefinition(name, init) => {
+                    format!(
+                        "{indentation}static {};\n",
+                        field_to_string(&(*name).into(), init.as_ref(), interner)
+                    )
+                }
+            });
+        }
+        buf.push_str(&format!("{}}}", "    ".repeat(indent_n)));
+
+        buf
+    }
+}
+
+/// Formats a method definition of a class body, without the `static` prefix.
+fn method_to_string(
+    kind: MethodDefinitionKind,
+    name: &PropertyName,
+    function: &FunctionExpr,
+    interner: &Interner,
+    indentation: usize,
+) -> String {
+    let prefix = match kind {
+        MethodDefinitionKind::Get => "get ",
+        MethodDefinitionKind::Set => "set ",
+        MethodDefinitionKind::Ordinary => "",
+        MethodDefinitionKind::Generator => "*",
+        MethodDefinitionKind::Async => "async ",
+        MethodDefinitionKind::AsyncGenerator => "async *",
+    };
+    let name = match name {
+        PropertyName::Literal(name) => interner.resolve_expect(*name).to_owned(),
+        PropertyName::Computed(node) => format!("[{}]", node.to_interned_string(interner)),
+    };
+    format!(
+        "{prefix}{name}({}) {}",
+        join_nodes(interner, function.parameters()),
+        block_to_string(function.body(), interner, indentation)
+    )
+}
+
+/// Formats a field definition of a class body, without the `static` prefix.
+fn field_to_string(name: &PropertyName, init: Option<&Node>, interner: &Interner) -> String {
+    let mut buf = match name {
+        PropertyName::Literal(name) => interner.resolve_expect(*name).to_owned(),
+        PropertyName::Computed(node) => format!("[{}]", node.to_interned_string(interner)),
+    };
+    if let Some(init) = init {
+        buf.push_str(&format!(" = {}", init.to_interned_string(interner)));
+    }
+    buf
+}
+
+impl ToInternedString for Class {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        self.to_indented_string(interner, 0)
+    }
+}
+
+/// An element of a class body.
+///
+/// The constructor of a class is not a class element, it is stored separately in the [`Class`].
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassElement
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum ClassElement {
+    /// A method defined on the prototype of the class.
+    MethodDefinition(MethodDefinitionKind, PropertyName, FunctionExpr),
+
+    /// A method defined on the class constructor itself.
+    StaticMethodDefinition(MethodDefinitionKind, PropertyName, FunctionExpr),
+
+    /// A field defined on every instance of the class, with an optional initializer.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-FieldDefinition
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes/Public_class_fields
+    FieldDefinition(PropertyName, Option<Node>),
+
+    /// A field defined on the class constructor itself, with an optional initializer.
+    StaticFieldDefinition(PropertyName, Option<Node>),
+
+    /// A private method available on every instance of the class.
+    ///
+    /// The interned name of private elements includes the leading `#`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-PrivateIdentifier
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes/Private_class_fields
+    PrivateMethodDefinition(MethodDefinitionKind, Sym, FunctionExpr),
+
+    /// A private method available on the class constructor itself.
+    PrivateStaticMethodDefinition(MethodDefinitionKind, Sym, FunctionExpr),
+
+    /// A private field defined on every instance of the class, with an optional initializer.
+    PrivateFieldDefinition(Sym, Option<Node>),
+
+    /// A private field defined on the class constructor itself, with an optional initializer.
+    PrivateStaticFieldDefinition(Sym, Option<Node>),
+}
@@ -1,6 +1,9 @@
 use crate::{
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
+    syntax::ast::{
+        node::{join_nodes, FormalParameter, Node, SourceText, StatementList},
+        ByteSpan,
+    },
 };
 use boa_interner::{Interner, Sym, ToInternedString};
 
@@ -31,6 +34,7 @@ pub struct FunctionExpr {
     name: Option<Sym>,
     parameters: Box<[FormalParameter]>,
     body: StatementList,
+    source_text: SourceText,
 }
 
 impl FunctionExpr {
@@ -45,6 +49,7 @@ impl FunctionExpr {
             name: name.into(),
             parameters: parameters.into(),
             body: body.into(),
+            source_text: SourceText::default(),
         }
     }
 
@@ -63,6 +68,18 @@ impl FunctionExpr {
         &self.body
     }
 
+    /// Gets the span of the source text of the function expression in the source code it was parsed
+    /// from, if it was parsed from source code.
+    pub fn source_span(&self) -> Option<ByteSpan> {
+        self.source_text.span()
+    }
+
+    /// Sets the span of the source text of the function expression.
+    pub(in crate::syntax) fn with_source_span(mut self, span: ByteSpan) -> Self {
+        self.source_text = SourceText::new(span);
+        self
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn to_indented_string(
         &self,
@@ -1,6 +1,9 @@
 use crate::{
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
+    syntax::ast::{
+        node::{join_nodes, FormalParameter, Node, SourceText, StatementList},
+        ByteSpan,
+    },
 };
 use boa_interner::{Interner, Sym, ToInternedString};
 
@@ -22,6 +25,7 @@ pub struct GeneratorDecl {
     name: Sym,
     parameters: Box<[FormalParameter]>,
     body: StatementList,
+    source_text: SourceText,
 }
 
 impl GeneratorDecl {
@@ -35,6 +39,7 @@ impl GeneratorDecl {
             name,
             parameters: parameters.into(),
             body: body.into(),
+            source_text: SourceText::default(),
         }
     }
 
@@ -49,8 +54,20 @@ impl GeneratorDecl {
     }
 
     /// Gets the body of the generator declaration.
-    pub fn body(&self) -> &[Node] {
-        self.body.items()
+    pub fn body(&self) -> &StatementList {
+        &self.body
+    }
+
+    /// Gets the span of the source text of the generator declaration in the source code it was parsed
+    /// from, if it was parsed from source code.
+    pub fn source_span(&self) -> Option<ByteSpan> {
+        self.source_text.span()
+    }
+
+    /// Sets the span of the source text of the generator declaration.
+    pub(in crate::syntax) fn with_source_span(mut self, span: ByteSpan) -> Self {
+        self.source_text = SourceText::new(span);
+        self
     }
 
     /// Implements the display formatting with indentation.
@@ -64,7 +81,7 @@ impl GeneratorDecl {
             interner.resolve_expect(self.name),
             join_nodes(interner, &self.parameters)
         );
-        if self.body().is_empty() {
+        if self.body.items().is_empty() {
             buf.push_str(") {}");
         } else {
             buf.push_str(&format!(
@@ -1,6 +1,9 @@
 use crate::{
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
+    syntax::ast::{
+        node::{join_nodes, FormalParameter, Node, SourceText, StatementList},
+        ByteSpan,
+    },
 };
 use boa_interner::{Interner, Sym, ToInternedString};
 
@@ -23,6 +26,7 @@ pub struct GeneratorExpr {
     name: Option<Sym>,
     parameters: Box<[FormalParameter]>,
     body: StatementList,
+    source_text: SourceText,
 }
 
 impl GeneratorExpr {
@@ -37,6 +41,7 @@ impl GeneratorExpr {
             name: name.into(),
             parameters: parameters.into(),
             body: body.into(),
+            source_text: SourceText::default(),
         }
     }
 
@@ -55,6 +60,18 @@ impl GeneratorExpr {
         &self.body
     }
 
+    /// Gets the span of the source text of the generator expression in the source code it was parsed
+    /// from, if it was parsed from source code.
+    pub fn source_span(&self) -> Option<ByteSpan> {
+        self.source_text.span()
+    }
+
+    /// Sets the span of the source text of the generator expression.
+    pub(in crate::syntax) fn with_source_span(mut self, span: ByteSpan) -> Self {
+        self.source_text = SourceText::new(span);
+        self
+    }
+
     /// Converts the generator expresion node to a string with indentation.
     pub(in crate::syntax::ast::node) fn to_indented_string(
         &self,
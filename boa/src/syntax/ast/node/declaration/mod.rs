@@ -1,7 +1,10 @@
 //! Declaration nodes
 use crate::{
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, Identifier, Node},
+    syntax::ast::{
+        node::{join_nodes, Identifier, Node},
+        ByteSpan,
+    },
 };
 use boa_interner::{Interner, Sym, ToInternedString};
 
@@ -13,15 +16,20 @@ pub mod async_function_decl;
 pub mod async_function_expr;
 pub mod async_generator_decl;
 pub mod async_generator_expr;
+pub mod class_decl;
 pub mod function_decl;
 pub mod function_expr;
 pub mod generator_decl;
 pub mod generator_expr;
 
 pub use self::{
-    arrow_function_decl::ArrowFunctionDecl, async_function_decl::AsyncFunctionDecl,
-    async_function_expr::AsyncFunctionExpr, async_generator_decl::AsyncGeneratorDecl,
-    async_generator_expr::AsyncGeneratorExpr, function_decl::FunctionDecl,
+    arrow_function_decl::ArrowFunctionDecl,
+    async_function_decl::AsyncFunctionDecl,
+    async_function_expr::AsyncFunctionExpr,
+    async_generator_decl::AsyncGeneratorDecl,
+    async_generator_expr::AsyncGeneratorExpr,
+    class_decl::{Class, ClassElement},
+    function_decl::FunctionDecl,
     function_expr::FunctionExpr,
 };
 
@@ -682,3 +690,35 @@ pub(in crate::syntax::ast::node) fn block_to_string(
         )
     }
 }
+
+/// The location of the source text of a function in the source code it was parsed from.
+///
+/// This is the text returned by `Function.prototype.toString`. Only its span is stored, since the
+/// source code is shared by all the functions parsed from it. It is not part of the structure of
+/// the function, so it is ignored when comparing nodes.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-function.prototype.tostring
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, Trace, Finalize)]
+pub struct SourceText(#[unsafe_ignore_trace] Option<ByteSpan>);
+
+impl SourceText {
+    /// Creates a new source text from its span in the source code.
+    pub(in crate::syntax) fn new(span: ByteSpan) -> Self {
+        Self(Some(span))
+    }
+
+    /// Gets the span of the source text, if there is one.
+    pub fn span(&self) -> Option<ByteSpan> {
+        self.0
+    }
+}
+
+impl PartialEq for SourceText {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
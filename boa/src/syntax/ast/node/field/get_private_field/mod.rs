@@ -0,0 +1,67 @@
+use crate::{
+    gc::{Finalize, Trace},
+    syntax::ast::node::Node,
+};
+use boa_interner::{Interner, Sym, ToInternedString};
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+/// This property accessor provides access to a private field or method of a class instance,
+/// using the dot notation with a private name: `object.#name`.
+///
+/// Private names can only be referenced inside the body of the class that declares them.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-MemberExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes/Private_class_fields
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct GetPrivateField {
+    obj: Box<Node>,
+    field: Sym,
+}
+
+impl GetPrivateField {
+    /// Creates a `GetPrivateField` AST node.
+    ///
+    /// The interned name of the field includes the leading `#`.
+    pub fn new<V>(value: V, field: Sym) -> Self
+    where
+        V: Into<Node>,
+    {
+        Self {
+            obj: Box::new(value.into()),
+            field,
+        }
+    }
+
+    /// Gets the original object from where to get the field from.
+    pub fn obj(&self) -> &Node {
+        &self.obj
+    }
+
+    /// Gets the private name of the field to retrieve, including the leading `#`.
+    pub fn field(&self) -> Sym {
+        self.field
+    }
+}
+
+impl ToInternedString for GetPrivateField {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        format!(
+            "{}.{}",
+            self.obj.to_interned_string(interner),
+            interner.resolve_expect(self.field)
+        )
+    }
+}
+
+impl From<GetPrivateField> for Node {
+    fn from(get_private_field: GetPrivateField) -> Self {
+        Self::GetPrivateField(get_private_field)
+    }
+}
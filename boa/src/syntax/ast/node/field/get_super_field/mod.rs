@@ -0,0 +1,54 @@
+use crate::{
+    gc::{Finalize, Trace},
+    syntax::ast::node::Node,
+};
+use boa_interner::{Interner, Sym, ToInternedString};
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+/// This property accessor looks up a property on the prototype of the object a method was
+/// defined on, using either the dot or the bracket notation.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-SuperProperty
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/super
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum GetSuperField {
+    /// A `super.name` access.
+    Const(Sym),
+
+    /// A `super[expression]` access.
+    Expr(Box<Node>),
+}
+
+impl From<Sym> for GetSuperField {
+    fn from(field: Sym) -> Self {
+        Self::Const(field)
+    }
+}
+
+impl From<Node> for GetSuperField {
+    fn from(field: Node) -> Self {
+        Self::Expr(Box::new(field))
+    }
+}
+
+impl ToInternedString for GetSuperField {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        match self {
+            GetSuperField::Const(field) => format!("super.{}", interner.resolve_expect(*field)),
+            GetSuperField::Expr(field) => format!("super[{}]", field.to_interned_string(interner)),
+        }
+    }
+}
+
+impl From<GetSuperField> for Node {
+    fn from(get_super_field: GetSuperField) -> Self {
+        Self::GetSuperField(get_super_field)
+    }
+}
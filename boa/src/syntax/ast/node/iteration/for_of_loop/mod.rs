@@ -14,11 +14,13 @@ pub struct ForOfLoop {
     iterable: Box<Node>,
     body: Box<Node>,
     label: Option<Sym>,
+    r#await: bool,
 }
 
 impl ForOfLoop {
-    /// Creates a new "for of" loop AST node.
-    pub fn new<I, B>(init: IterableLoopInitializer, iterable: I, body: B) -> Self
+    /// Creates a new "for of" loop AST node, which is a "for await of" loop if `r#await` is
+    /// `true`.
+    pub fn new<I, B>(init: IterableLoopInitializer, iterable: I, body: B, r#await: bool) -> Self
     where
         I: Into<Node>,
         B: Into<Node>,
@@ -28,6 +30,7 @@ impl ForOfLoop {
             iterable: Box::new(iterable.into()),
             body: Box::new(body.into()),
             label: None,
+            r#await,
         }
     }
 
@@ -51,6 +54,11 @@ impl ForOfLoop {
         self.label = Some(label);
     }
 
+    /// Returns `true` if this is a "for await of" loop.
+    pub fn r#await(&self) -> bool {
+        self.r#await
+    }
+
     /// Converts the "for of" loop to a string with the given indentation.
     pub(in crate::syntax::ast::node) fn to_indented_string(
         &self,
@@ -63,7 +71,8 @@ impl ForOfLoop {
             String::new()
         };
         buf.push_str(&format!(
-            "for ({} of {}) {}",
+            "for {}({} of {}) {}",
+            if self.r#await { "await " } else { "" },
             self.init.to_interned_string(interner),
             self.iterable.to_interned_string(interner),
             self.body().to_indented_string(interner, indentation)
@@ -484,6 +484,39 @@ fn for_in_continue_label() {
     assert_eq!(&exec(scenario), "\"00\"");
 }
 
+#[test]
+fn for_in_skips_deleted_keys() {
+    let scenario = r#"
+        var obj = { a: 1, b: 2, c: 3 };
+        var visited = [];
+        for (var key in obj) {
+            visited.push(key);
+            if (key === "a") {
+                delete obj.b;
+            }
+        }
+        visited.join()
+    "#;
+    assert_eq!(&exec(scenario), "\"a,c\"");
+}
+
+#[test]
+fn for_in_skips_non_enumerable_and_shadowed_keys() {
+    let scenario = r#"
+        var proto = { x: 1, y: 2, z: 3 };
+        var obj = Object.create(proto);
+        obj.x = 4;
+        Object.defineProperty(obj, "y", { value: 5, enumerable: false });
+        Object.defineProperty(obj, "hidden", { value: 6, enumerable: false });
+        var visited = [];
+        for (var key in obj) {
+            visited.push(key);
+        }
+        visited.join()
+    "#;
+    assert_eq!(&exec(scenario), "\"x,z\"");
+}
+
 #[test]
 fn fmt() {
     // Labeled and unlabeled for in loops
@@ -531,6 +564,11 @@ fn fmt() {
                 break label;
             }
         };
+        async function f() {
+            for await (i of [1, 2, 3]) {
+                break;
+            };
+        };
         "#,
     );
     // Labeled and unlabeled do while loops
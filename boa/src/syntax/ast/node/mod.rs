@@ -9,18 +9,21 @@ pub mod declaration;
 pub mod field;
 pub mod identifier;
 pub mod iteration;
+pub mod module;
 pub mod new;
 pub mod object;
 pub mod operator;
 pub mod return_smt;
 pub mod spread;
 pub mod statement_list;
+pub mod super_call;
 pub mod switch;
 pub mod template;
 pub mod throw;
 pub mod try_node;
 pub mod r#yield;
 
+use self::iteration::IterableLoopInitializer;
 pub use self::{
     array::ArrayDecl,
     await_expr::AwaitExpr,
@@ -30,19 +33,24 @@ pub use self::{
     declaration::{
         async_generator_decl::AsyncGeneratorDecl, async_generator_expr::AsyncGeneratorExpr,
         generator_decl::GeneratorDecl, generator_expr::GeneratorExpr, ArrowFunctionDecl,
-        AsyncFunctionDecl, AsyncFunctionExpr, Declaration, DeclarationList, DeclarationPattern,
-        FunctionDecl, FunctionExpr,
+        AsyncFunctionDecl, AsyncFunctionExpr, Class, ClassElement, Declaration, DeclarationList,
+        DeclarationPattern, FunctionDecl, FunctionExpr, SourceText,
     },
-    field::{GetConstField, GetField},
+    field::{GetConstField, GetField, GetPrivateField, GetSuperField},
     identifier::Identifier,
     iteration::{Break, Continue, DoWhileLoop, ForInLoop, ForLoop, ForOfLoop, WhileLoop},
+    module::{
+        ExportDeclaration, ExportSpecifier, ImportDeclaration, ImportKind, ImportSpecifier,
+        ModuleItem, ModuleItemList,
+    },
     new::New,
     object::Object,
-    operator::{Assign, BinOp, UnaryOp},
+    operator::{Assign, BinOp, PrivateIn, UnaryOp},
     r#yield::Yield,
     return_smt::Return,
     spread::Spread,
     statement_list::{RcStatementList, StatementList},
+    super_call::SuperCall,
     switch::{Case, Switch},
     template::{TaggedTemplate, TemplateLit},
     throw::Throw,
@@ -96,6 +104,12 @@ pub enum Node {
     /// A function call. [More information](./expression/struct.Call.html).
     Call(Call),
 
+    /// A class declaration node. [More information](./declaration/struct.Class.html).
+    ClassDecl(Class),
+
+    /// A class expression node. [More information](./declaration/struct.Class.html).
+    ClassExpr(Class),
+
     /// A javascript conditional operand ( x ? y : z ). [More information](./conditional/struct.ConditionalOp.html).
     ConditionalOp(ConditionalOp),
 
@@ -132,6 +146,12 @@ pub enum Node {
     /// Provides access to object fields. [More information](./declaration/struct.GetField.html).
     GetField(GetField),
 
+    /// Provides access to the private fields and methods of a class instance. [More information](./field/struct.GetPrivateField.html).
+    GetPrivateField(GetPrivateField),
+
+    /// Provides access to the fields of a method's parent object. [More information](./field/enum.GetSuperField.html).
+    GetSuperField(GetSuperField),
+
     /// A `for` statement. [More information](./iteration/struct.ForLoop.html).
     ForLoop(ForLoop),
 
@@ -156,9 +176,15 @@ pub enum Node {
     /// An object. [More information](./object/struct.Object.html).
     Object(Object),
 
+    /// A `#name in object` check. [More information](./operator/struct.PrivateIn.html).
+    PrivateIn(PrivateIn),
+
     /// A return statement. [More information](./object/struct.Return.html).
     Return(Return),
 
+    /// A call to the parent class constructor. [More information](./super_call/struct.SuperCall.html).
+    SuperCall(SuperCall),
+
     /// A switch {case} statement. [More information](./switch/struct.Switch.html).
     Switch(Switch),
 
@@ -191,6 +217,20 @@ pub enum Node {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/this
     This,
 
+    /// The `new.target` meta property.
+    ///
+    /// It is the constructor that `new` was initially applied to, or `undefined` if the
+    /// running function was called without `new`. Arrow functions use the `new.target`
+    /// of their enclosing function.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-NewTarget
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/new.target
+    NewTarget,
+
     /// Unary operation node. [More information](./operator/struct.UnaryOp.html)
     UnaryOp(UnaryOp),
 
@@ -233,14 +273,93 @@ impl Node {
     #[allow(clippy::match_same_arms)]
     pub(crate) fn hoistable_order(a: &Self, b: &Self) -> Ordering {
         match (a, b) {
-            (Node::FunctionDecl(_), Node::FunctionDecl(_)) => Ordering::Equal,
-            (_, Node::FunctionDecl(_)) => Ordering::Greater,
-            (Node::FunctionDecl(_), _) => Ordering::Less,
+            (
+                Node::FunctionDecl(_) | Node::GeneratorDecl(_),
+                Node::FunctionDecl(_) | Node::GeneratorDecl(_),
+            ) => Ordering::Equal,
+            (_, Node::FunctionDecl(_) | Node::GeneratorDecl(_)) => Ordering::Greater,
+            (Node::FunctionDecl(_) | Node::GeneratorDecl(_), _) => Ordering::Less,
 
             (_, _) => Ordering::Equal,
         }
     }
 
+    /// Calls `f` with each `var` declaration of the statement, and whether it declares the
+    /// binding of a `for (var ... of ...)` statement.
+    ///
+    /// These are the declarations of the `VarDeclaredNames` of the statement, so they are searched
+    /// in the nested statements, but not in nested functions. Labelled statements don't have a
+    /// node of their own: the label is stored on the labelled loop, or dropped for other
+    /// statements, so they are searched like the statement they label.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-vardeclarednames
+    pub(crate) fn visit_var_declarations<F>(&self, f: &mut F)
+    where
+        F: FnMut(&Declaration, bool),
+    {
+        match self {
+            Self::VarDeclList(list) => {
+                for decl in list.as_ref() {
+                    f(decl, false);
+                }
+            }
+            Self::Block(block) => {
+                for node in block.items() {
+                    node.visit_var_declarations(f);
+                }
+            }
+            Self::If(if_node) => {
+                if_node.body().visit_var_declarations(f);
+                if let Some(else_node) = if_node.else_node() {
+                    else_node.visit_var_declarations(f);
+                }
+            }
+            Self::WhileLoop(while_loop) => while_loop.body().visit_var_declarations(f),
+            Self::DoWhileLoop(do_while_loop) => do_while_loop.body().visit_var_declarations(f),
+            Self::ForLoop(for_loop) => {
+                if let Some(init) = for_loop.init() {
+                    init.visit_var_declarations(f);
+                }
+                for_loop.body().visit_var_declarations(f);
+            }
+            Self::ForInLoop(for_in_loop) => {
+                if let IterableLoopInitializer::Var(decl) = for_in_loop.init() {
+                    f(decl, false);
+                }
+                for_in_loop.body().visit_var_declarations(f);
+            }
+            Self::ForOfLoop(for_of_loop) => {
+                if let IterableLoopInitializer::Var(decl) = for_of_loop.init() {
+                    f(decl, true);
+                }
+                for_of_loop.body().visit_var_declarations(f);
+            }
+            Self::Try(try_node) => {
+                let blocks = Some(try_node.block())
+                    .into_iter()
+                    .chain(try_node.catch().map(Catch::block))
+                    .chain(try_node.finally());
+                for block in blocks {
+                    for node in block.items() {
+                        node.visit_var_declarations(f);
+                    }
+                }
+            }
+            Self::Switch(switch) => {
+                let cases = switch.cases().iter().map(|case| case.body().items());
+                for items in cases.chain(switch.default()) {
+                    for node in items {
+                        node.visit_var_declarations(f);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
     /// Creates a `This` AST node.
     pub fn this() -> Self {
         Self::This
@@ -280,6 +399,7 @@ impl Node {
             Self::ForOfLoop(ref for_of) => for_of.to_indented_string(interner, indentation),
             Self::ForInLoop(ref for_in) => for_in.to_indented_string(interner, indentation),
             Self::This => "this".to_owned(),
+            Self::NewTarget => "new.target".to_owned(),
             Self::Try(ref try_catch) => try_catch.to_indented_string(interner, indentation),
             Self::Break(ref break_smt) => break_smt.to_interned_string(interner),
             Self::Continue(ref cont) => cont.to_interned_string(interner),
@@ -291,6 +411,13 @@ impl Node {
                 get_const_field.to_interned_string(interner)
             }
             Self::GetField(ref get_field) => get_field.to_interned_string(interner),
+            Self::GetPrivateField(ref get_private_field) => {
+                get_private_field.to_interned_string(interner)
+            }
+            Self::GetSuperField(ref get_super_field) => {
+                get_super_field.to_interned_string(interner)
+            }
+            Self::SuperCall(ref call) => call.to_interned_string(interner),
             Self::WhileLoop(ref while_loop) => while_loop.to_indented_string(interner, indentation),
             Self::DoWhileLoop(ref do_while) => do_while.to_indented_string(interner, indentation),
             Self::If(ref if_smt) => if_smt.to_indented_string(interner, indentation),
@@ -302,6 +429,7 @@ impl Node {
             Self::FunctionExpr(ref expr) => expr.to_indented_string(interner, indentation),
             Self::ArrowFunctionDecl(ref decl) => decl.to_indented_string(interner, indentation),
             Self::BinOp(ref op) => op.to_interned_string(interner),
+            Self::PrivateIn(ref op) => op.to_interned_string(interner),
             Self::UnaryOp(ref op) => op.to_interned_string(interner),
             Self::Return(ref ret) => ret.to_interned_string(interner),
             Self::TaggedTemplate(ref template) => template.to_interned_string(interner),
@@ -320,6 +448,9 @@ impl Node {
             Self::GeneratorExpr(ref expr) => expr.to_indented_string(interner, indentation),
             Self::AsyncGeneratorExpr(ref expr) => expr.to_indented_string(interner, indentation),
             Self::AsyncGeneratorDecl(ref decl) => decl.to_indented_string(interner, indentation),
+            Self::ClassDecl(ref class) | Self::ClassExpr(ref class) => {
+                class.to_indented_string(interner, indentation)
+            }
         }
     }
 }
@@ -0,0 +1,589 @@
+//! Module item nodes, the top level items of the source code of a module.
+
+use crate::{
+    gc::{empty_trace, Finalize, Trace},
+    syntax::ast::{
+        node::{
+            ArrowFunctionDecl, Call, Declaration, DeclarationList, FormalParameter, GeneratorExpr,
+            Identifier, Node, Return, StatementList, Yield,
+        },
+        Const, Position,
+    },
+};
+use boa_interner::{Interner, Sym, ToInternedString};
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+/// List of the items of a module.
+///
+/// A module item is either a statement list item, or an `import` or `export` declaration, which
+/// can only appear at the top level of a module.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItemList
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize)]
+pub struct ModuleItemList {
+    items: Box<[ModuleItem]>,
+    /// The start position of each item in the source code, if the list was parsed from source.
+    #[unsafe_ignore_trace]
+    #[cfg_attr(feature = "deser", serde(skip))]
+    positions: Box<[Position]>,
+}
+
+impl ModuleItemList {
+    /// Gets the list of items.
+    #[inline]
+    pub fn items(&self) -> &[ModuleItem] {
+        &self.items
+    }
+
+    /// Gets the start position in the source code of the item at `index`, if it is known.
+    #[inline]
+    pub fn position(&self, index: usize) -> Option<Position> {
+        self.positions.get(index).copied()
+    }
+
+    /// Sets the start positions in the source code of the items of the list.
+    #[inline]
+    pub(crate) fn set_positions(&mut self, positions: Box<[Position]>) {
+        debug_assert_eq!(positions.len(), self.items.len());
+        self.positions = positions;
+    }
+
+    /// Gets the specifiers of the modules requested by the `import` and `export` declarations
+    /// of the list, without duplicates and in source order.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-modulerequests
+    pub fn requested_modules(&self) -> Vec<Sym> {
+        let mut requests = Vec::new();
+        for item in self.items.iter() {
+            let specifier = match item {
+                ModuleItem::ImportDeclaration(import) => import.specifier(),
+                ModuleItem::ExportDeclaration(
+                    ExportDeclaration::ReExportAll { specifier, .. }
+                    | ExportDeclaration::ReExport { specifier, .. },
+                ) => *specifier,
+                _ => continue,
+            };
+            if !requests.contains(&specifier) {
+                requests.push(specifier);
+            }
+        }
+        requests
+    }
+
+    /// Creates the generator expression that runs the code of the module.
+    ///
+    /// The parameters of the generator are a function that exports a local binding, followed by
+    /// `parameters`. Its body instantiates the function declarations of the module and calls the
+    /// export function with the name of each binding of `exported_locals` and a getter of its
+    /// value, which lets other modules see the updates of the binding. It then yields, and runs
+    /// the items of the module without their `import` and `export` declarations once it is
+    /// resumed.
+    pub(crate) fn to_generator(
+        &self,
+        name: Sym,
+        export_function: Sym,
+        parameters: &[Sym],
+        exported_locals: &[Sym],
+    ) -> Node {
+        let mut exports = Vec::new();
+        for &local in exported_locals {
+            let getter = ArrowFunctionDecl::new(
+                None,
+                Vec::new(),
+                vec![Return::new(Identifier::new(local), None).into()],
+            );
+            let export = Call::new(
+                Identifier::new(export_function),
+                vec![Const::String(local).into(), getter.into()],
+            );
+            exports.push((export.into(), Position::new(1, 1)));
+        }
+
+        let mut items = Vec::new();
+        for (i, item) in self.items.iter().enumerate() {
+            let node = match item {
+                ModuleItem::ImportDeclaration(_)
+                | ModuleItem::ExportDeclaration(
+                    ExportDeclaration::ReExportAll { .. }
+                    | ExportDeclaration::ReExport { .. }
+                    | ExportDeclaration::List(_),
+                ) => continue,
+                ModuleItem::StatementListItem(node)
+                | ModuleItem::ExportDeclaration(
+                    ExportDeclaration::Declaration(node)
+                    | ExportDeclaration::DefaultDeclaration(node),
+                ) => node.clone(),
+                ModuleItem::ExportDeclaration(ExportDeclaration::DefaultAssignmentExpression(
+                    expr,
+                )) => DeclarationList::Const(
+                    vec![Declaration::new_with_identifier(Sym::DEFAULT, expr.clone())].into(),
+                )
+                .into(),
+            };
+            let position = self.position(i).unwrap_or_else(|| Position::new(1, 1));
+            items.push((node, position));
+        }
+
+        items.sort_by(|(a, _), (b, _)| Node::hoistable_order(a, b));
+        let hoisted = items
+            .iter()
+            .take_while(|(node, _)| matches!(node, Node::FunctionDecl(_) | Node::GeneratorDecl(_)))
+            .count();
+        let rest = items.split_off(hoisted);
+        items.extend(exports);
+        items.push((
+            Yield::new::<Node, _>(None, false).into(),
+            Position::new(1, 1),
+        ));
+        items.extend(rest);
+        let (items, positions): (Vec<_>, Vec<_>) = items.into_iter().unzip();
+        let mut body = StatementList::from(items);
+        body.set_strict(true);
+        body.set_positions(positions.into_boxed_slice());
+
+        let parameters: Vec<_> = std::iter::once(export_function)
+            .chain(parameters.iter().copied())
+            .map(|parameter| {
+                FormalParameter::new(Declaration::new_with_identifier(parameter, None), false)
+            })
+            .collect();
+        GeneratorExpr::new(name, parameters, body).into()
+    }
+}
+
+impl<T> From<T> for ModuleItemList
+where
+    T: Into<Box<[ModuleItem]>>,
+{
+    fn from(items: T) -> Self {
+        Self {
+            items: items.into(),
+            positions: Box::default(),
+        }
+    }
+}
+
+// The positions are only used to report errors, so they don't take part in comparisons.
+impl PartialEq for ModuleItemList {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+    }
+}
+
+impl ToInternedString for ModuleItemList {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        let mut buf = String::new();
+        for item in self.items.iter() {
+            buf.push_str(&item.to_interned_string(interner));
+            match item {
+                ModuleItem::StatementListItem(
+                    Node::Block(_) | Node::If(_) | Node::Switch(_) | Node::WhileLoop(_),
+                ) => {}
+                _ => buf.push(';'),
+            }
+            buf.push('\n');
+        }
+        buf
+    }
+}
+
+/// An item of a module.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItem
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum ModuleItem {
+    /// An `import` declaration.
+    ImportDeclaration(ImportDeclaration),
+
+    /// An `export` declaration.
+    ExportDeclaration(ExportDeclaration),
+
+    /// A statement or declaration that is neither imported nor exported.
+    StatementListItem(Node),
+}
+
+impl ToInternedString for ModuleItem {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        match self {
+            Self::ImportDeclaration(import) => import.to_interned_string(interner),
+            Self::ExportDeclaration(export) => export.to_interned_string(interner),
+            Self::StatementListItem(node) => node.to_indented_string(interner, 0),
+        }
+    }
+}
+
+impl From<ImportDeclaration> for ModuleItem {
+    fn from(import: ImportDeclaration) -> Self {
+        Self::ImportDeclaration(import)
+    }
+}
+
+impl From<ExportDeclaration> for ModuleItem {
+    fn from(export: ExportDeclaration) -> Self {
+        Self::ExportDeclaration(export)
+    }
+}
+
+impl From<Node> for ModuleItem {
+    fn from(node: Node) -> Self {
+        Self::StatementListItem(node)
+    }
+}
+
+/// An `import` declaration, which binds exports of another module in the current module.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ImportDeclaration {
+    default: Option<Sym>,
+    kind: ImportKind,
+    specifier: Sym,
+}
+
+impl ImportDeclaration {
+    /// Creates a new `import` declaration.
+    pub fn new<D>(default: D, kind: ImportKind, specifier: Sym) -> Self
+    where
+        D: Into<Option<Sym>>,
+    {
+        Self {
+            default: default.into(),
+            kind,
+            specifier,
+        }
+    }
+
+    /// Gets the binding of the default export of the module, if it is imported.
+    pub fn default(&self) -> Option<Sym> {
+        self.default
+    }
+
+    /// Gets the kind of the imports that follow the default binding.
+    pub fn kind(&self) -> &ImportKind {
+        &self.kind
+    }
+
+    /// Gets the specifier of the imported module.
+    pub fn specifier(&self) -> Sym {
+        self.specifier
+    }
+
+    /// Gets the names bound in the module by the declaration.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-boundnames
+    pub fn bound_names(&self) -> Vec<Sym> {
+        let mut names: Vec<_> = self.default.into_iter().collect();
+        match &self.kind {
+            ImportKind::DefaultOrUnnamed => {}
+            ImportKind::Namespace(binding) => names.push(*binding),
+            ImportKind::Named(specifiers) => {
+                names.extend(specifiers.iter().map(ImportSpecifier::binding));
+            }
+        }
+        names
+    }
+}
+
+impl ToInternedString for ImportDeclaration {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        let mut buf = "import ".to_owned();
+        if let Some(default) = self.default {
+            buf.push_str(interner.resolve_expect(default));
+            if !matches!(self.kind, ImportKind::DefaultOrUnnamed) {
+                buf.push_str(", ");
+            }
+        }
+        match &self.kind {
+            ImportKind::DefaultOrUnnamed => {}
+            ImportKind::Namespace(binding) => {
+                buf.push_str(&format!("* as {}", interner.resolve_expect(*binding)));
+            }
+            ImportKind::Named(specifiers) => {
+                let specifiers: Vec<_> = specifiers
+                    .iter()
+                    .map(|specifier| specifier.to_interned_string(interner))
+                    .collect();
+                buf.push_str(&format!("{{ {} }}", specifiers.join(", ")));
+            }
+        }
+        if self.default.is_some() || !matches!(self.kind, ImportKind::DefaultOrUnnamed) {
+            buf.push_str(" from ");
+        }
+        buf.push_str(&format!("\"{}\"", interner.resolve_expect(self.specifier)));
+        buf
+    }
+}
+
+/// The imports of an `import` declaration that follow its default binding, if any.
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum ImportKind {
+    /// Only the default export is imported, or the module is imported only for its side
+    /// effects, e.g. `import "module"`.
+    DefaultOrUnnamed,
+
+    /// The namespace object of the module is imported, e.g. `import * as ns from "module"`.
+    Namespace(Sym),
+
+    /// A list of named exports is imported, e.g. `import { a, b as c } from "module"`.
+    Named(Box<[ImportSpecifier]>),
+}
+
+/// A named import of an `import` declaration.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportSpecifier
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Finalize, PartialEq)]
+pub struct ImportSpecifier {
+    binding: Sym,
+    export_name: Sym,
+}
+
+impl ImportSpecifier {
+    /// Creates a new import specifier, which binds the export `export_name` to `binding`.
+    pub fn new(binding: Sym, export_name: Sym) -> Self {
+        Self {
+            binding,
+            export_name,
+        }
+    }
+
+    /// Gets the name of the binding created in the importing module.
+    pub fn binding(&self) -> Sym {
+        self.binding
+    }
+
+    /// Gets the name of the imported export.
+    pub fn export_name(&self) -> Sym {
+        self.export_name
+    }
+}
+
+unsafe impl Trace for ImportSpecifier {
+    empty_trace!();
+}
+
+impl ToInternedString for ImportSpecifier {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        if self.binding == self.export_name {
+            interner.resolve_expect(self.binding).to_owned()
+        } else {
+            format!(
+                "{} as {}",
+                interner.resolve_expect(self.export_name),
+                interner.resolve_expect(self.binding)
+            )
+        }
+    }
+}
+
+/// An `export` declaration, which makes bindings of the current module available to other
+/// modules.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/export
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum ExportDeclaration {
+    /// Re-exports all the exports of another module, e.g. `export * from "module"`, or its
+    /// namespace object if it has an alias, e.g. `export * as ns from "module"`.
+    ReExportAll { alias: Option<Sym>, specifier: Sym },
+
+    /// Re-exports a list of named exports of another module, e.g.
+    /// `export { a, b as c } from "module"`.
+    ReExport {
+        specifiers: Box<[ExportSpecifier]>,
+        specifier: Sym,
+    },
+
+    /// Exports a list of local bindings, e.g. `export { a, b as c }`.
+    List(Box<[ExportSpecifier]>),
+
+    /// Exports the bindings of a declaration, e.g. `export let a = 1`.
+    Declaration(Node),
+
+    /// Exports a function or class declaration as the default export, e.g.
+    /// `export default function f() {}`.
+    ///
+    /// An anonymous declaration is named `default`.
+    DefaultDeclaration(Node),
+
+    /// Exports the value of an expression as the default export, e.g. `export default 1 + 2`.
+    DefaultAssignmentExpression(Node),
+}
+
+impl ExportDeclaration {
+    /// Gets the names of the exports of the declaration, not including the exports of
+    /// `export * from "module"`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-exportednames
+    pub fn exported_names(&self) -> Vec<Sym> {
+        match self {
+            Self::ReExportAll { alias, .. } => alias.iter().copied().collect(),
+            Self::ReExport { specifiers, .. } | Self::List(specifiers) => {
+                specifiers.iter().map(ExportSpecifier::alias).collect()
+            }
+            Self::Declaration(node) => declaration_bound_names(node),
+            Self::DefaultDeclaration(_) | Self::DefaultAssignmentExpression(_) => {
+                vec![Sym::DEFAULT]
+            }
+        }
+    }
+
+    /// Gets the names of the local bindings of the module that the declaration exports.
+    pub(crate) fn local_names(&self) -> Vec<Sym> {
+        match self {
+            Self::ReExportAll { .. } | Self::ReExport { .. } => Vec::new(),
+            Self::List(specifiers) => specifiers
+                .iter()
+                .map(ExportSpecifier::private_name)
+                .collect(),
+            Self::Declaration(node) | Self::DefaultDeclaration(node) => {
+                declaration_bound_names(node)
+            }
+            Self::DefaultAssignmentExpression(_) => vec![Sym::DEFAULT],
+        }
+    }
+}
+
+/// Gets the names bound by a declaration that can be exported.
+fn declaration_bound_names(node: &Node) -> Vec<Sym> {
+    match node {
+        Node::VarDeclList(list) | Node::LetDeclList(list) | Node::ConstDeclList(list) => list
+            .as_ref()
+            .iter()
+            .flat_map(|decl| match decl {
+                Declaration::Identifier { ident, .. } => vec![ident.sym()],
+                Declaration::Pattern(pattern) => pattern.idents(),
+            })
+            .collect(),
+        Node::FunctionDecl(decl) => vec![decl.name()],
+        Node::GeneratorDecl(decl) => vec![decl.name()],
+        Node::AsyncFunctionDecl(decl) => vec![decl.name()],
+        Node::AsyncGeneratorDecl(decl) => vec![decl.name()],
+        Node::ClassDecl(class) => class.name().into_iter().collect(),
+        _ => Vec::new(),
+    }
+}
+
+impl ToInternedString for ExportDeclaration {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        let specifiers_to_string = |specifiers: &[ExportSpecifier]| {
+            let specifiers: Vec<_> = specifiers
+                .iter()
+                .map(|specifier| specifier.to_interned_string(interner))
+                .collect();
+            format!("{{ {} }}", specifiers.join(", "))
+        };
+
+        match self {
+            Self::ReExportAll { alias, specifier } => match alias {
+                Some(alias) => format!(
+                    "export * as {} from \"{}\"",
+                    interner.resolve_expect(*alias),
+                    interner.resolve_expect(*specifier)
+                ),
+                None => format!("export * from \"{}\"", interner.resolve_expect(*specifier)),
+            },
+            Self::ReExport {
+                specifiers,
+                specifier,
+            } => format!(
+                "export {} from \"{}\"",
+                specifiers_to_string(specifiers),
+                interner.resolve_expect(*specifier)
+            ),
+            Self::List(specifiers) => format!("export {}", specifiers_to_string(specifiers)),
+            Self::Declaration(node) => {
+                format!("export {}", node.to_indented_string(interner, 0))
+            }
+            Self::DefaultDeclaration(node) | Self::DefaultAssignmentExpression(node) => {
+                format!("export default {}", node.to_indented_string(interner, 0))
+            }
+        }
+    }
+}
+
+/// A named export of an `export` declaration.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportSpecifier
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Finalize, PartialEq)]
+pub struct ExportSpecifier {
+    alias: Sym,
+    private_name: Sym,
+}
+
+impl ExportSpecifier {
+    /// Creates a new export specifier, which exports `private_name` as `alias`.
+    pub fn new(alias: Sym, private_name: Sym) -> Self {
+        Self {
+            alias,
+            private_name,
+        }
+    }
+
+    /// Gets the name under which the binding is exported.
+    pub fn alias(&self) -> Sym {
+        self.alias
+    }
+
+    /// Gets the name of the exported binding, in the current module or in the module of a
+    /// re-export.
+    pub fn private_name(&self) -> Sym {
+        self.private_name
+    }
+}
+
+unsafe impl Trace for ExportSpecifier {
+    empty_trace!();
+}
+
+impl ToInternedString for ExportSpecifier {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        if self.alias == self.private_name {
+            interner.resolve_expect(self.private_name).to_owned()
+        } else {
+            format!(
+                "{} as {}",
+                interner.resolve_expect(self.private_name),
+                interner.resolve_expect(self.alias)
+            )
+        }
+    }
+}
@@ -73,6 +73,41 @@ fn spread_null_and_undefined_ignored() {
     assert_eq!(&exec(scenario), "true");
 }
 
+#[test]
+fn spread_overridden_by_later_properties() {
+    let scenario = r#"
+        var a = { x: 1, y: 2 };
+        var b = { ...a, x: 3 };
+
+        (b.x === 3) && (b.y === 2) && (a.x === 1)
+    "#;
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn spread_symbols_and_getters() {
+    let scenario = r#"
+        var s = Symbol("s");
+        var a = { [s]: 1, get x() { return 2; } };
+        Object.defineProperty(a, "hidden", { value: 3, enumerable: false });
+        var b = { ...a };
+        var desc = Object.getOwnPropertyDescriptor(b, "x");
+
+        (b[s] === 1) && (desc.value === 2) && !("hidden" in b)
+    "#;
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn spread_null_is_no_op() {
+    let scenario = r#"
+        var a = { x: 1, ...null, y: 2 };
+
+        Object.keys(a).join()
+    "#;
+    assert_eq!(&exec(scenario), "\"x,y\"");
+}
+
 #[test]
 fn fmt() {
     super::super::test_formatting(
@@ -2,9 +2,10 @@
 
 pub mod assign;
 pub mod bin_op;
+pub mod private_in;
 pub mod unary_op;
 
-pub use self::{assign::Assign, bin_op::BinOp, unary_op::UnaryOp};
+pub use self::{assign::Assign, bin_op::BinOp, private_in::PrivateIn, unary_op::UnaryOp};
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,63 @@
+use crate::{
+    gc::{Finalize, Trace},
+    syntax::ast::node::Node,
+};
+use boa_interner::{Interner, Sym, ToInternedString};
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+/// The `#name in object` expression checks if an object has a private field or method of the
+/// class that declares `#name`, without throwing for objects that do not.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-RelationalExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/in
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct PrivateIn {
+    name: Sym,
+    rhs: Box<Node>,
+}
+
+impl PrivateIn {
+    /// Creates a `PrivateIn` AST node.
+    pub(in crate::syntax) fn new<R>(name: Sym, rhs: R) -> Self
+    where
+        R: Into<Node>,
+    {
+        Self {
+            name,
+            rhs: Box::new(rhs.into()),
+        }
+    }
+
+    /// Gets the private name being checked, including the leading `#`.
+    pub fn name(&self) -> Sym {
+        self.name
+    }
+
+    /// Gets the object being checked.
+    pub fn rhs(&self) -> &Node {
+        &self.rhs
+    }
+}
+
+impl ToInternedString for PrivateIn {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        format!(
+            "{} in {}",
+            interner.resolve_expect(self.name),
+            self.rhs.to_interned_string(interner)
+        )
+    }
+}
+
+impl From<PrivateIn> for Node {
+    fn from(private_in: PrivateIn) -> Self {
+        Self::PrivateIn(private_in)
+    }
+}
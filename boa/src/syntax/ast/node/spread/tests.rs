@@ -30,6 +30,56 @@ fn spread_with_call() {
     assert_eq!(&exec(scenario), r#""message""#);
 }
 
+#[test]
+fn spread_in_any_argument_position() {
+    let scenario = r#"
+    function* g() {
+        yield 1;
+        yield 2;
+    }
+    function f(...args) {
+        return args.join();
+    }
+    f(0, ...g(), 3, ...[4, 5], ...new Set([6]));
+    "#;
+    assert_eq!(&exec(scenario), r#""0,1,2,3,4,5,6""#);
+}
+
+#[test]
+fn spread_generator_with_new_and_super() {
+    let scenario = r#"
+    function* g() {
+        yield 1;
+        yield 2;
+    }
+    class A {
+        constructor(...args) {
+            this.args = args;
+        }
+    }
+    class B extends A {
+        constructor() {
+            super(0, ...g(), 3);
+        }
+    }
+    new A(...g(), 3).args.join() + " " + new B().args.join();
+    "#;
+    assert_eq!(&exec(scenario), r#""1,2,3 0,1,2,3""#);
+}
+
+#[test]
+fn spread_with_method_call() {
+    let scenario = r#"
+    const o = {
+        m(...args) {
+            return this === o && args.join();
+        }
+    };
+    o.m(...new Set(["a", "b"]), "c");
+    "#;
+    assert_eq!(&exec(scenario), r#""a,b,c""#);
+}
+
 #[test]
 fn fmt() {
     super::super::test_formatting(
@@ -2,7 +2,10 @@
 
 use crate::{
     gc::{empty_trace, Finalize, Trace},
-    syntax::ast::node::{Declaration, Node},
+    syntax::ast::{
+        node::{Declaration, Node},
+        Position,
+    },
 };
 use boa_interner::{Interner, Sym, ToInternedString};
 use std::{ops::Deref, rc::Rc};
@@ -23,11 +26,20 @@ mod tests;
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-StatementList
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+#[derive(Clone, Debug, Trace, Finalize)]
 pub struct StatementList {
     #[cfg_attr(feature = "deser", serde(flatten))]
     items: Box<[Node]>,
     strict: bool,
+    /// The start position of each item in the source code, if the list was parsed from source.
+    #[unsafe_ignore_trace]
+    #[cfg_attr(feature = "deser", serde(skip))]
+    positions: Box<[Position]>,
+    /// The source code the list was parsed from, which the source text of its functions refers
+    /// to. It is only set on the top level list.
+    #[unsafe_ignore_trace]
+    #[cfg_attr(feature = "deser", serde(skip))]
+    source: Option<Rc<str>>,
 }
 
 impl StatementList {
@@ -37,6 +49,31 @@ impl StatementList {
         &self.items
     }
 
+    /// Gets the start position in the source code of the item at `index`, if it is known.
+    #[inline]
+    pub fn position(&self, index: usize) -> Option<Position> {
+        self.positions.get(index).copied()
+    }
+
+    /// Sets the start positions in the source code of the items of the list.
+    #[inline]
+    pub(crate) fn set_positions(&mut self, positions: Box<[Position]>) {
+        debug_assert_eq!(positions.len(), self.items.len());
+        self.positions = positions;
+    }
+
+    /// Gets the source code the list was parsed from, if it is known.
+    #[inline]
+    pub fn source(&self) -> Option<&Rc<str>> {
+        self.source.as_ref()
+    }
+
+    /// Sets the source code the list was parsed from.
+    #[inline]
+    pub(crate) fn set_source(&mut self, source: Rc<str>) {
+        self.source = Some(source);
+    }
+
     /// Get the strict mode.
     #[inline]
     pub fn strict(&self) -> bool {
@@ -117,16 +154,12 @@ impl StatementList {
     pub fn var_declared_names(&self) -> FxHashSet<Sym> {
         let mut set = FxHashSet::default();
         for stmt in self.items() {
-            if let Node::VarDeclList(decl_list) = stmt {
-                for decl in decl_list.as_ref() {
-                    match decl {
-                        Declaration::Identifier { ident, .. } => {
-                            set.insert(ident.sym());
-                        }
-                        Declaration::Pattern(p) => set.extend(p.idents().into_iter()),
-                    }
+            stmt.visit_var_declarations(&mut |decl, _| match decl {
+                Declaration::Identifier { ident, .. } => {
+                    set.insert(ident.sym());
                 }
-            }
+                Declaration::Pattern(p) => set.extend(p.idents()),
+            });
         }
         set
     }
@@ -140,10 +173,20 @@ where
         Self {
             items: stm.into(),
             strict: false,
+            positions: Box::default(),
+            source: None,
         }
     }
 }
 
+// The positions and the source are not part of the structure of the list, so they don't take
+// part in comparisons.
+impl PartialEq for StatementList {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items && self.strict == other.strict
+    }
+}
+
 impl ToInternedString for StatementList {
     fn to_interned_string(&self, interner: &Interner) -> String {
         self.to_indented_string(interner, 0)
@@ -1,4 +1,4 @@
-use crate::exec;
+use crate::{exec, forward, Context};
 
 #[test]
 fn template_literal() {
@@ -10,6 +10,22 @@ fn template_literal() {
     assert_eq!(&exec(scenario), "\"result: 10 and 20\"");
 }
 
+#[test]
+fn template_literal_cooking() {
+    let scenario = r#"
+        let n = 5;
+        let o = { toString() { return "obj"; } };
+        `first \u{41}\x42\u0043\0 ${n * 2}\
+ continued
+second ${o}|${[1, 2]}`;
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"first ABC\u{0} 10 continued\nsecond obj|1,2\""
+    );
+}
+
 #[test]
 fn tagged_template() {
     let scenario = r#"
@@ -30,6 +46,35 @@ fn tagged_template() {
     );
 }
 
+#[test]
+fn tagged_template_invalid_escape() {
+    let scenario = r#"
+        function tag(t) {
+            return [t[0] === undefined, t.raw[0], t[1], t.raw[1]];
+        }
+        tag`\unicode and \xg${0}\u{41}`;
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        r#"[ true, "\unicode and \xg", "A", "\u{41}" ]"#
+    );
+}
+
+#[test]
+fn untagged_template_invalid_escape() {
+    let mut context = Context::default();
+
+    assert!(forward(&mut context, "`\\unicode`").starts_with("Uncaught \"SyntaxError\": "));
+    assert!(forward(&mut context, "`\\01`").starts_with("Uncaught \"SyntaxError\": "));
+    assert!(forward(&mut context, "`\\8`").starts_with("Uncaught \"SyntaxError\": "));
+    assert_eq!(
+        forward(&mut context, "`\\x4`"),
+        "Uncaught \"SyntaxError\": \"Syntax Error: invalid escape sequence in template literal at position: 1:1\""
+    );
+    assert!(forward(&mut context, "`${1}\\u{41`").starts_with("Uncaught \"SyntaxError\": "));
+}
+
 #[test]
 fn fmt() {
     super::super::test_formatting(
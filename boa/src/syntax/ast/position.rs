@@ -126,6 +126,40 @@ impl fmt::Display for Span {
     }
 }
 
+/// A span of bytes in the JavaScript source code.
+///
+/// Unlike [`Span`], it stores the byte offsets of its start and end, which can be used to slice
+/// the source code.
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ByteSpan {
+    start: usize,
+    end: usize,
+}
+
+impl ByteSpan {
+    /// Creates a new `ByteSpan`.
+    #[inline]
+    #[track_caller]
+    pub fn new(start: usize, end: usize) -> Self {
+        assert!(start <= end, "a span cannot start after its end");
+
+        Self { start, end }
+    }
+
+    /// Gets the byte offset of the start of the span.
+    #[inline]
+    pub fn start(self) -> usize {
+        self.start
+    }
+
+    /// Gets the byte offset of the end of the span.
+    #[inline]
+    pub fn end(self) -> usize {
+        self.end
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::similar_names)]
 mod tests {
@@ -10,7 +10,7 @@ use crate::{
     Interner,
 };
 use core::convert::TryFrom;
-use std::io::Read;
+use std::io::{self, ErrorKind, Read};
 
 /// Lexes a single line comment.
 ///
@@ -96,10 +96,10 @@ impl<R> Tokenizer<R> for MultiLineComment {
             };
         }
 
-        Err(Error::syntax(
+        Err(Error::from(io::Error::new(
+            ErrorKind::UnexpectedEof,
             "unterminated multiline comment",
-            cursor.pos(),
-        ))
+        )))
     }
 }
 
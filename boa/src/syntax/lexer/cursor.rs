@@ -16,9 +16,16 @@ impl<R> Cursor<R> {
     pub(super) fn pos(&self) -> Position {
         self.pos
     }
+
+    /// Gets the number of bytes consumed from the source code so far.
+    #[inline]
+    pub(super) fn byte_pos(&self) -> usize {
+        self.iter.num_consumed_bytes
+    }
+
     /// Advances the position to the next column.
     #[inline]
-    pub(super) fn next_column(&mut self) {
+    fn next_column(&mut self) {
         let current_line = self.pos.line_number();
         let next_column = self.pos.column_number() + 1;
         self.pos = Position::new(current_line, next_column);
@@ -224,7 +231,15 @@ where
     pub(super) fn fill_bytes(&mut self, buf: &mut [u8]) -> io::Result<()> {
         let _timer = BoaProfiler::global().start_event("cursor::fill_bytes()", "Lexing");
 
-        self.iter.fill_bytes(buf)
+        for byte in buf.iter_mut() {
+            *byte = self.next_byte()?.ok_or_else(|| {
+                Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "unexpected EOF when filling buffer",
+                )
+            })?;
+        }
+        Ok(())
     }
 
     /// Retrieves the next byte.
@@ -295,6 +310,7 @@ struct InnerIter<R> {
     num_peeked_bytes: u8,
     peeked_bytes: u32,
     peeked_char: Option<Option<u32>>,
+    num_consumed_bytes: usize,
 }
 
 impl<R> InnerIter<R> {
@@ -306,6 +322,7 @@ impl<R> InnerIter<R> {
             num_peeked_bytes: 0,
             peeked_bytes: 0,
             peeked_char: None,
+            num_consumed_bytes: 0,
         }
     }
 }
@@ -314,23 +331,6 @@ impl<R> InnerIter<R>
 where
     R: Read,
 {
-    /// It will fill the buffer with checked ascii bytes.
-    ///
-    /// This expects for the buffer to be fully filled. If it's not, it will fail with an
-    /// `UnexpectedEof` I/O error.
-    #[inline]
-    fn fill_bytes(&mut self, buf: &mut [u8]) -> io::Result<()> {
-        for byte in buf.iter_mut() {
-            *byte = self.next_byte()?.ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::UnexpectedEof,
-                    "unexpected EOF when filling buffer",
-                )
-            })?;
-        }
-        Ok(())
-    }
-
     /// Increments the iter by n bytes.
     #[inline]
     fn increment(&mut self, n: u32) -> Result<(), Error> {
@@ -430,14 +430,18 @@ where
     #[inline]
     fn next_byte(&mut self) -> io::Result<Option<u8>> {
         self.peeked_char = None;
-        if self.num_peeked_bytes > 0 {
+        let byte = if self.num_peeked_bytes > 0 {
             let byte = (self.peeked_bytes & 0xFF) as u8;
             self.num_peeked_bytes -= 1;
             self.peeked_bytes >>= 8;
-            Ok(Some(byte))
+            Some(byte)
         } else {
-            self.iter.next().transpose()
+            self.iter.next().transpose()?
+        };
+        if byte.is_some() {
+            self.num_consumed_bytes += 1;
         }
+        Ok(byte)
     }
 
     /// Retrieves the next unchecked char in u32 code point.
@@ -14,9 +14,7 @@ use core::convert::TryFrom;
 use std::io::Read;
 use std::str;
 
-const STRICT_FORBIDDEN_IDENTIFIERS: [&str; 11] = [
-    "eval",
-    "arguments",
+const STRICT_FORBIDDEN_IDENTIFIERS: [&str; 9] = [
     "implements",
     "interface",
     "let",
@@ -137,7 +135,7 @@ impl<R> Tokenizer<R> for Identifier {
 
 impl Identifier {
     #[inline]
-    fn take_identifier_name<R>(
+    pub(super) fn take_identifier_name<R>(
         cursor: &mut Cursor<R>,
         start_pos: Position,
         init: char,
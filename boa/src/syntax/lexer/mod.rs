@@ -20,6 +20,7 @@ pub mod error;
 mod identifier;
 mod number;
 mod operator;
+mod private_identifier;
 pub mod regex;
 mod spread;
 mod string;
@@ -35,6 +36,7 @@ use self::{
     identifier::Identifier,
     number::NumberLiteral,
     operator::Operator,
+    private_identifier::PrivateIdentifier,
     regex::RegexLiteral,
     spread::SpreadLiteral,
     string::StringLiteral,
@@ -42,12 +44,12 @@ use self::{
 };
 pub use crate::{profiler::BoaProfiler, syntax::ast::Position};
 use crate::{
-    syntax::ast::{Punctuator, Span},
+    syntax::ast::{ByteSpan, Punctuator, Span},
     Interner,
 };
 use core::convert::TryFrom;
 pub use error::Error;
-use std::io::Read;
+use std::{io::Read, ops::Range};
 pub use token::{Token, TokenKind};
 
 trait Tokenizer<R> {
@@ -67,6 +69,7 @@ trait Tokenizer<R> {
 pub struct Lexer<R> {
     cursor: Cursor<R>,
     goal_symbol: InputElement,
+    token_start: usize,
 }
 
 impl<R> Lexer<R> {
@@ -89,13 +92,13 @@ impl<R> Lexer<R> {
 
     /// Sets the goal symbol for the lexer.
     #[inline]
-    pub(crate) fn set_goal(&mut self, elm: InputElement) {
+    pub fn set_goal(&mut self, elm: InputElement) {
         self.goal_symbol = elm;
     }
 
     /// Gets the goal symbol the lexer is currently using.
     #[inline]
-    pub(crate) fn get_goal(&self) -> InputElement {
+    pub fn get_goal(&self) -> InputElement {
         self.goal_symbol
     }
 
@@ -104,6 +107,15 @@ impl<R> Lexer<R> {
         self.cursor.strict_mode()
     }
 
+    /// Gets the span of bytes from the start of the last token to the current position.
+    ///
+    /// Regular expressions and the middle parts of template literals are lexed after the `/` or
+    /// `}` token they start with, so that token is still the last one.
+    #[inline]
+    fn token_byte_span(&self) -> ByteSpan {
+        ByteSpan::new(self.token_start, self.cursor.byte_pos())
+    }
+
     #[inline]
     pub(super) fn set_strict_mode(&mut self, strict_mode: bool) {
         self.cursor.set_strict_mode(strict_mode);
@@ -118,6 +130,7 @@ impl<R> Lexer<R> {
         Self {
             cursor: Cursor::new(reader),
             goal_symbol: InputElement::default(),
+            token_start: 0,
         }
     }
 
@@ -138,7 +151,7 @@ impl<R> Lexer<R> {
     {
         let _timer = BoaProfiler::global().start_event("lex_slash_token", "Lexing");
 
-        if let Some(c) = self.cursor.peek()? {
+        let token = if let Some(c) = self.cursor.peek()? {
             match c {
                 b'/' => {
                     self.cursor.next_byte()?.expect("/ token vanished"); // Consume the '/'
@@ -179,7 +192,8 @@ impl<R> Lexer<R> {
                 "Abrupt end: Expecting Token /,*,= or regex",
                 start,
             ))
-        }
+        };
+        token.map(|token| token.with_byte_span(self.token_byte_span()))
     }
 
     /// Retrieves the next token from the lexer.
@@ -193,6 +207,7 @@ impl<R> Lexer<R> {
 
         let (start, next_ch) = loop {
             let start = self.cursor.pos();
+            self.token_start = self.cursor.byte_pos();
             if let Some(next_ch) = self.cursor.next_char()? {
                 // Ignore whitespace
                 if !Self::is_whitespace(next_ch) {
@@ -266,6 +281,7 @@ impl<R> Lexer<R> {
                     Span::new(start, self.cursor.pos()),
                 )),
                 '/' => self.lex_slash_token(start, interner),
+                '#' => PrivateIdentifier.lex(&mut self.cursor, start, interner),
                 '=' | '*' | '+' | '-' | '%' | '|' | '&' | '^' | '<' | '>' | '!' | '~' | '?' => {
                     Operator::new(next_ch as u8).lex(&mut self.cursor, start, interner)
                 }
@@ -292,7 +308,7 @@ impl<R> Lexer<R> {
                 // Skip comment
                 self.next(interner)
             } else {
-                Ok(Some(token))
+                Ok(Some(token.with_byte_span(self.token_byte_span())))
             }
         } else {
             Err(Error::syntax(
@@ -306,6 +322,39 @@ impl<R> Lexer<R> {
         }
     }
 
+    /// Returns an iterator over the remaining tokens of the source code.
+    ///
+    /// Unlike [`Lexer::next`], the iterator does not stop at the first lexical error. Errors are
+    /// yielded in place of the malformed token, and lexing continues after it. Every item comes
+    /// with the byte range of the source code it was lexed from.
+    ///
+    /// The lexer cannot tell a regular expression from a division on its own, so the goal symbol
+    /// of the next token can be changed with [`Tokens::set_goal`].
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{syntax::{lexer::InputElement, Lexer}, Interner};
+    /// let mut interner = Interner::default();
+    /// let mut lexer = Lexer::new(&b"let a = 1;"[..]);
+    /// let mut tokens = lexer.tokens(&mut interner);
+    /// tokens.set_goal(InputElement::Div);
+    ///
+    /// let (token, range) = tokens.next().unwrap();
+    /// assert!(token.is_ok());
+    /// assert_eq!(range, 0..3);
+    /// assert_eq!(tokens.count(), 4);
+    /// ```
+    pub fn tokens<'a>(&'a mut self, interner: &'a mut Interner) -> Tokens<'a, R>
+    where
+        R: Read,
+    {
+        Tokens {
+            lexer: self,
+            interner,
+            done: false,
+        }
+    }
+
     /// Performs the lexing of a template literal.
     pub(crate) fn lex_template(
         &mut self,
@@ -315,17 +364,82 @@ impl<R> Lexer<R> {
     where
         R: Read,
     {
-        TemplateLiteral.lex(&mut self.cursor, start, interner)
+        TemplateLiteral
+            .lex(&mut self.cursor, start, interner)
+            .map(|token| token.with_byte_span(self.token_byte_span()))
+    }
+}
+
+/// Iterator over the tokens of a [`Lexer`], created by [`Lexer::tokens`].
+///
+/// Each item is either a token or the lexical error found in its place, together with the byte
+/// range of the source code it covers.
+#[derive(Debug)]
+pub struct Tokens<'a, R> {
+    lexer: &'a mut Lexer<R>,
+    interner: &'a mut Interner,
+    done: bool,
+}
+
+impl<R> Tokens<'_, R> {
+    /// Sets the goal symbol used to lex the next token.
+    #[inline]
+    pub fn set_goal(&mut self, elm: InputElement) {
+        self.lexer.set_goal(elm);
+    }
+}
+
+impl<R> Iterator for Tokens<'_, R>
+where
+    R: Read,
+{
+    type Item = (Result<Token, Error>, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let start = self.lexer.cursor.byte_pos();
+        let result = self.lexer.next(self.interner);
+        let range = self.lexer.token_start..self.lexer.cursor.byte_pos();
+
+        match result {
+            Ok(Some(token)) => Some((Ok(token), range)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(Error::IO(e)) => {
+                self.done = true;
+                Some((Err(Error::IO(e)), range))
+            }
+            Err(e) => {
+                // Make sure that lexing continues after the error.
+                if self.lexer.cursor.byte_pos() == start {
+                    match self.lexer.cursor.next_char() {
+                        Ok(Some(_)) => {}
+                        _ => self.done = true,
+                    }
+                }
+                Some((Err(e), self.lexer.token_start..self.lexer.cursor.byte_pos()))
+            }
+        }
     }
 }
 
 /// ECMAScript goal symbols.
 ///
+/// The goal symbol decides how the lexer reads a token that starts with `/`.
+///
 /// <https://tc39.es/ecma262/#sec-ecmascript-language-lexical-grammar>
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) enum InputElement {
+pub enum InputElement {
+    /// A `/` is a division operator.
     Div,
+    /// A `/` starts a regular expression literal.
     RegExp,
+    /// A `/` is a division operator, and a `}` can continue a template literal.
     TemplateTail,
 }
 
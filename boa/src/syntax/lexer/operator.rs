@@ -18,26 +18,21 @@ use std::io::Read;
 macro_rules! vop {
     ($cursor:ident, $assign_op:expr, $op:expr) => ({
         match $cursor.peek()? {
-            None => Err(Error::syntax("abrupt end - could not preview next value as part of the operator", $cursor.pos())),
             Some(b'=') => {
                 $cursor.next_byte()?.expect("= token vanished");
-                $cursor.next_column();
                 $assign_op
             }
-            Some(_) => $op,
+            _ => $op,
         }
     });
     ($cursor:ident, $assign_op:expr, $op:expr, {$($case:pat => $block:expr), +}) => ({
         match $cursor.peek()? {
-            None => Err(Error::syntax("abrupt end - could not preview next value as part of the operator", $cursor.pos())),
             Some(b'=') => {
                 $cursor.next_byte()?.expect("= token vanished");
-                $cursor.next_column();
                 $assign_op
             },
             $($case => {
                 $cursor.next_byte()?.expect("Token vanished");
-                $cursor.next_column();
                 $block
             })+,
             _ => $op,
@@ -47,7 +42,6 @@ macro_rules! vop {
         match $cursor.peek().ok_or_else(|| Error::syntax("could not preview next value", $cursor.pos()))? {
             $($case => {
                 $cursor.next_byte()?;
-                $cursor.next_column();
                 $block
             })+,
             _ => $op
@@ -58,13 +52,15 @@ macro_rules! vop {
 /// The `op` macro handles binary operations or assignment operations and converts them into tokens.
 macro_rules! op {
     ($cursor:ident, $start_pos:expr, $assign_op:expr, $op:expr) => ({
+        let punc: Result<Punctuator, Error> = vop!($cursor, $assign_op, $op);
         Ok(Token::new(
-            vop!($cursor, $assign_op, $op)?.into(),
+            punc?.into(),
             Span::new($start_pos, $cursor.pos()),
         ))
     });
     ($cursor:ident, $start_pos:expr, $assign_op:expr, $op:expr, {$($case:pat => $block:expr),+}) => ({
-        let punc: Punctuator = vop!($cursor, $assign_op, $op, {$($case => $block),+})?;
+        let punc: Result<Punctuator, Error> = vop!($cursor, $assign_op, $op, {$($case => $block),+});
+        let punc = punc?;
         Ok(Token::new(
             punc.into(),
             Span::new($start_pos, $cursor.pos()),
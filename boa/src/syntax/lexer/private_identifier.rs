@@ -0,0 +1,66 @@
+//! This module implements lexing for private identifiers (`#name`) used in the JavaScript programing language.
+
+use super::{identifier::Identifier, Cursor, Error, Tokenizer};
+use crate::{
+    profiler::BoaProfiler,
+    syntax::{
+        ast::{Position, Span},
+        lexer::{Token, TokenKind},
+    },
+    Interner,
+};
+use core::convert::TryFrom;
+use std::io::Read;
+
+/// Private identifier lexing.
+///
+/// Note: expects for the initial `#` to already be consumed from the cursor.
+///
+/// The interned name of the token keeps the leading `#`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-PrivateIdentifier
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes/Private_class_fields
+#[derive(Debug, Clone, Copy)]
+pub(super) struct PrivateIdentifier;
+
+impl<R> Tokenizer<R> for PrivateIdentifier {
+    fn lex(
+        &mut self,
+        cursor: &mut Cursor<R>,
+        start_pos: Position,
+        interner: &mut Interner,
+    ) -> Result<Token, Error>
+    where
+        R: Read,
+    {
+        let _timer = BoaProfiler::global().start_event("PrivateIdentifier", "Lexing");
+
+        let init = match cursor.peek_char()? {
+            Some(0x005C /* \ */) if cursor.peek_n(2)? >> 8 == 0x0075 /* u */ => {
+                let _ = cursor.next_char()?;
+                '\\'
+            }
+            Some(ch) if Identifier::is_identifier_start(ch) => {
+                let _ = cursor.next_char()?;
+                char::try_from(ch).expect("checked character value")
+            }
+            _ => {
+                return Err(Error::syntax(
+                    "expected an identifier name after '#'",
+                    start_pos,
+                ))
+            }
+        };
+
+        let (identifier_name, _) = Identifier::take_identifier_name(cursor, start_pos, init)?;
+
+        Ok(Token::new(
+            TokenKind::private_identifier(interner.get_or_intern(format!("#{identifier_name}"))),
+            Span::new(start_pos, cursor.pos()),
+        ))
+    }
+}
@@ -140,6 +140,7 @@ bitflags! {
         const DOT_ALL = 0b0000_1000;
         const UNICODE = 0b0001_0000;
         const STICKY = 0b0010_0000;
+        const HAS_INDICES = 0b0100_0000;
     }
 }
 
@@ -150,6 +151,7 @@ impl FromStr for RegExpFlags {
         let mut flags = Self::default();
         for c in s.bytes() {
             let new_flag = match c {
+                b'd' => Self::HAS_INDICES,
                 b'g' => Self::GLOBAL,
                 b'i' => Self::IGNORE_CASE,
                 b'm' => Self::MULTILINE,
@@ -182,6 +184,9 @@ fn parse_regex_flags(s: &str, start: Position, interner: &mut Interner) -> Resul
 impl ToString for RegExpFlags {
     fn to_string(&self) -> String {
         let mut s = String::new();
+        if self.contains(Self::HAS_INDICES) {
+            s.push('d');
+        }
         if self.contains(Self::GLOBAL) {
             s.push('g');
         }
@@ -150,12 +150,9 @@ impl StringLiteral {
                 Some(ch) if !Self::is_line_terminator(ch) => {
                     buf.push_code_point(ch);
                 }
-                _ => {
-                    return Err(Error::from(io::Error::new(
-                        ErrorKind::UnexpectedEof,
-                        "unterminated string literal",
-                    )));
-                }
+                // A string literal can't span several lines, so reaching a line terminator or the
+                // end of the input is an error even if more input follows.
+                _ => return Err(Error::syntax("unterminated string literal", start_pos)),
             }
         }
 
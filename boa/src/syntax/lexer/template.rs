@@ -64,7 +64,16 @@ impl TemplateString {
                         ch_start_pos,
                         true,
                         true,
-                    )?;
+                    )
+                    // The raw string ends where the template does, so an escape sequence that is
+                    // cut short by the end of the template is malformed, e.g. `\x4`.
+                    .map_err(|err| match err {
+                        Error::IO(_) => Error::syntax(
+                            "invalid escape sequence in template literal",
+                            ch_start_pos,
+                        ),
+                        err @ Error::Syntax(..) => err,
+                    })?;
 
                     if let Some(escape_value) = escape_value {
                         buf.push_code_point(escape_value);
@@ -7,7 +7,7 @@ use boa_interner::Sym;
 use super::token::Numeric;
 use super::*;
 use super::{Error, Position};
-use crate::syntax::ast::Keyword;
+use crate::syntax::ast::{ByteSpan, Keyword};
 use crate::syntax::lexer::template::TemplateString;
 use std::str;
 
@@ -131,6 +131,28 @@ fn check_invalid_identifier_part() {
     }
 }
 
+#[test]
+fn check_private_identifier() {
+    let s = "#x this.#y #\\u0078 #";
+    let mut lexer = Lexer::new(s.as_bytes());
+    let mut interner = Interner::default();
+
+    let expected = [
+        TokenKind::private_identifier(interner.get_or_intern_static("#x")),
+        TokenKind::Keyword(Keyword::This),
+        TokenKind::Punctuator(Punctuator::Dot),
+        TokenKind::private_identifier(interner.get_or_intern_static("#y")),
+        TokenKind::private_identifier(interner.get_or_intern_static("#x")),
+    ];
+
+    for expect in &expected {
+        assert_eq!(lexer.next(&mut interner).unwrap().unwrap().kind(), expect);
+    }
+    lexer
+        .next(&mut interner)
+        .expect_err("'#' without a name not rejected as expected");
+}
+
 #[test]
 fn check_string() {
     let s = "'aaa' \"bbb\"";
@@ -172,6 +194,27 @@ fn check_template_literal_unterminated() {
         .expect_err("Lexer did not handle unterminated literal with error");
 }
 
+#[test]
+fn check_punctuators_at_end_of_input() {
+    for (s, punctuator) in [
+        ("+", Punctuator::Add),
+        ("=", Punctuator::Assign),
+        ("!=", Punctuator::NotEq),
+        ("**", Punctuator::Exp),
+        (">>>", Punctuator::URightSh),
+        ("&&", Punctuator::BoolAnd),
+    ] {
+        let mut lexer = Lexer::new(s.as_bytes());
+        let mut interner = Interner::default();
+
+        expect_tokens(
+            &mut lexer,
+            &[TokenKind::Punctuator(punctuator)],
+            &mut interner,
+        );
+    }
+}
+
 #[test]
 fn check_punctuators() {
     // https://tc39.es/ecma262/#sec-punctuators
@@ -359,6 +402,39 @@ fn check_positions() {
     );
 }
 
+#[test]
+fn check_positions_compound_operators() {
+    let s = r#"a >>= "\u0041" => b"#;
+    let mut lexer = Lexer::new(s.as_bytes());
+    let mut interner = Interner::default();
+
+    let expected = [
+        span((1, 1), (1, 2)),
+        span((1, 3), (1, 6)),
+        span((1, 7), (1, 15)),
+        span((1, 16), (1, 18)),
+        span((1, 19), (1, 20)),
+    ];
+    for expected in expected {
+        assert_eq!(lexer.next(&mut interner).unwrap().unwrap().span(), expected);
+    }
+}
+
+#[test]
+fn check_byte_spans() {
+    let s = "\"é\"\nü = 1";
+    let mut lexer = Lexer::new(s.as_bytes());
+    let mut interner = Interner::default();
+
+    let expected = [(0, 4), (4, 5), (5, 7), (8, 9), (10, 11)];
+    for (start, end) in expected {
+        assert_eq!(
+            lexer.next(&mut interner).unwrap().unwrap().byte_span(),
+            ByteSpan::new(start, end)
+        );
+    }
+}
+
 #[test]
 fn check_positions_codepoint() {
     let s = r#"console.log("hello world\u{2764}"); // Test"#;
@@ -505,7 +581,9 @@ fn numbers() {
 #[test]
 fn numbers_with_separators() {
     let mut lexer = Lexer::new(
-        "1_0 2_0 0x3_4 056 7.8_9 4_2. 5_0e2 5_0e+2 5_0e-4 0b1_0 1_0.0_0e2 1.0E-0_1 -3_2".as_bytes(),
+        "1_0 2_0 0x3_4 056 7.8_9 4_2. 5_0e2 5_0e+2 5_0e-4 0b1_0 1_0.0_0e2 1.0E-0_1 -3_2 0xFF_FF \
+         0o7_7 .5_5 0b1010_0101"
+            .as_bytes(),
     );
     let mut interner = Interner::default();
 
@@ -524,6 +602,10 @@ fn numbers_with_separators() {
         TokenKind::numeric_literal(0.1),
         TokenKind::Punctuator(Punctuator::Sub),
         TokenKind::numeric_literal(32),
+        TokenKind::numeric_literal(0xFFFF),
+        TokenKind::numeric_literal(0o77),
+        TokenKind::numeric_literal(0.55),
+        TokenKind::numeric_literal(0b1010_0101),
     ];
 
     expect_tokens(&mut lexer, &expected, &mut interner);
@@ -532,7 +614,8 @@ fn numbers_with_separators() {
 #[test]
 fn numbers_with_bad_separators() {
     let numbers = [
-        "0b_10", "0x_10", "10_", "1._10", "1e+_10", "1E_10", "10__00",
+        "0b_10", "0x_10", "0o_10", "10_", "1._10", "1_.5", "1.0_e1", "1_e1", "1e+_10", "1E_10",
+        "10__00", "0x1__2", "0_1", "08_1", "1_n",
     ];
 
     for n in &numbers {
@@ -1130,3 +1213,68 @@ mod carriage_return {
         expect_tokens_with_lines(3, "-\r\n\n\r3");
     }
 }
+
+#[test]
+fn tokens_iterator_byte_ranges() {
+    let src = "let é = \"ü\"; // comment\nfoo(é);";
+    let mut lexer = Lexer::new(src.as_bytes());
+    let mut interner = Interner::default();
+
+    let tokens: Vec<_> = lexer
+        .tokens(&mut interner)
+        .map(|(token, range)| (token.unwrap(), range))
+        .collect();
+
+    let texts: Vec<_> = tokens
+        .iter()
+        .map(|(_, range)| &src[range.clone()])
+        .collect();
+    assert_eq!(
+        texts,
+        ["let", "é", "=", "\"ü\"", ";", "\n", "foo", "(", "é", ")", ";"]
+    );
+    assert_eq!(tokens[1].1, 4..6);
+    assert_eq!(tokens[3].0.span(), span((1, 9), (1, 12)));
+    assert_eq!(tokens[6].0.span(), span((2, 1), (2, 4)));
+}
+
+#[test]
+fn tokens_iterator_recovers_from_errors() {
+    let src = "a @ b \"unterminated";
+    let mut lexer = Lexer::new(src.as_bytes());
+    let mut interner = Interner::default();
+
+    let items: Vec<_> = lexer.tokens(&mut interner).collect();
+
+    assert_eq!(items.len(), 4);
+    assert!(items[0].0.is_ok());
+    assert!(matches!(items[1].0, Err(Error::Syntax(_, _))));
+    assert_eq!(items[1].1, 2..3);
+    assert_eq!(
+        items[2].0.as_ref().unwrap().kind(),
+        &TokenKind::identifier(interner.get_or_intern_static("b"))
+    );
+    assert!(items[3].0.is_err());
+    assert_eq!(items[3].1, 6..src.len());
+}
+
+#[test]
+fn tokens_iterator_goal_symbol() {
+    let src = "a / b / c";
+    let mut interner = Interner::default();
+
+    let mut lexer = Lexer::new(src.as_bytes());
+    let mut tokens = lexer.tokens(&mut interner);
+    tokens.set_goal(InputElement::Div);
+    assert_eq!(tokens.count(), 5);
+
+    let mut lexer = Lexer::new(src.as_bytes());
+    let mut tokens = lexer.tokens(&mut interner);
+    assert!(tokens.next().unwrap().0.is_ok());
+    let (regex, range) = tokens.next().unwrap();
+    assert!(matches!(
+        regex.unwrap().kind(),
+        TokenKind::RegularExpressionLiteral(_, _)
+    ));
+    assert_eq!(range, 2..7);
+}
@@ -6,7 +6,7 @@
 //! [spec]: https://tc39.es/ecma262/#sec-tokens
 
 use crate::{
-    syntax::ast::{Keyword, Punctuator, Span},
+    syntax::ast::{ByteSpan, Keyword, Punctuator, Span},
     syntax::lexer::template::TemplateString,
     Interner, JsBigInt, Sym,
 };
@@ -27,13 +27,19 @@ pub struct Token {
     kind: TokenKind,
     /// The token position in the original source code.
     span: Span,
+    /// The bytes of the original source code the token was lexed from.
+    byte_span: ByteSpan,
 }
 
 impl Token {
     /// Create a new detailed token from the token data, line number and column number
     #[inline]
     pub fn new(kind: TokenKind, span: Span) -> Self {
-        Self { kind, span }
+        Self {
+            kind,
+            span,
+            byte_span: ByteSpan::default(),
+        }
     }
 
     /// Gets the kind of the token.
@@ -48,6 +54,19 @@ impl Token {
         self.span
     }
 
+    /// Gets the span of bytes of the token in the original source code.
+    #[inline]
+    pub fn byte_span(&self) -> ByteSpan {
+        self.byte_span
+    }
+
+    /// Sets the span of bytes of the token in the original source code.
+    #[inline]
+    pub(crate) fn with_byte_span(mut self, byte_span: ByteSpan) -> Self {
+        self.byte_span = byte_span;
+        self
+    }
+
     /// Converts the token to a `String`.
     pub(crate) fn to_string(&self, interner: &Interner) -> String {
         self.kind.to_string(interner)
@@ -116,6 +135,11 @@ pub enum TokenKind {
     /// A numeric literal.
     NumericLiteral(Numeric),
 
+    /// A private identifier, such as `#name`.
+    ///
+    /// The interned name includes the leading `#`.
+    PrivateIdentifier(Sym),
+
     /// A piece of punctuation
     Punctuator(Punctuator),
 
@@ -191,6 +215,11 @@ impl TokenKind {
         Self::NumericLiteral(lit.into())
     }
 
+    /// Creates a `PrivateIdentifier` token type.
+    pub fn private_identifier(ident: Sym) -> Self {
+        Self::PrivateIdentifier(ident)
+    }
+
     /// Creates a `Punctuator` token type.
     pub fn punctuator(punc: Punctuator) -> Self {
         Self::Punctuator(punc)
@@ -229,7 +258,9 @@ impl TokenKind {
         match *self {
             Self::BooleanLiteral(val) => val.to_string(),
             Self::EOF => "end of file".to_owned(),
-            Self::Identifier(ident) => interner.resolve_expect(ident).to_owned(),
+            Self::Identifier(ident) | Self::PrivateIdentifier(ident) => {
+                interner.resolve_expect(ident).to_owned()
+            }
             Self::Keyword(word) => word.to_string(),
             Self::NullLiteral => "null".to_owned(),
             Self::NumericLiteral(Numeric::Rational(num)) => num.to_string(),
@@ -263,7 +294,7 @@ impl TokenKind {
                 interner.get_or_intern(&self.to_string(interner))
             }
             Self::EOF => interner.get_or_intern_static("end of file"),
-            Self::Identifier(sym) | Self::StringLiteral(sym) => sym,
+            Self::Identifier(sym) | Self::PrivateIdentifier(sym) | Self::StringLiteral(sym) => sym,
             Self::Keyword(word) => interner.get_or_intern_static(word.as_str()),
             Self::NullLiteral => Sym::NULL,
             Self::Punctuator(punc) => interner.get_or_intern_static(punc.as_str()),
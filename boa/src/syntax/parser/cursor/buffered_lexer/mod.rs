@@ -1,6 +1,7 @@
 use crate::{
     profiler::BoaProfiler,
     syntax::{
+        ast::ByteSpan,
         lexer::{InputElement, Lexer, Position, Token, TokenKind},
         parser::error::ParseError,
     },
@@ -30,6 +31,9 @@ pub(super) struct BufferedLexer<R> {
     peeked: [Option<Token>; PEEK_BUF_SIZE],
     read_index: usize,
     write_index: usize,
+    /// The byte offset of the end of the last token returned to the parser, ignoring line
+    /// terminators.
+    last_token_end: usize,
 }
 
 impl<R> From<Lexer<R>> for BufferedLexer<R>
@@ -53,6 +57,7 @@ where
             ],
             read_index: 0,
             write_index: 0,
+            last_token_end: 0,
         }
     }
 }
@@ -87,9 +92,9 @@ where
     ) -> Result<Token, ParseError> {
         let _timer = BoaProfiler::global().start_event("cursor::lex_regex()", "Parsing");
         self.set_goal(InputElement::RegExp);
-        self.lexer
-            .lex_slash_token(start, interner)
-            .map_err(Into::into)
+        let token = self.lexer.lex_slash_token(start, interner)?;
+        self.last_token_end = token.byte_span().end();
+        Ok(token)
     }
 
     /// Lexes the next tokens as template middle or template tail assuming that the starting
@@ -99,9 +104,15 @@ where
         start: Position,
         interner: &mut Interner,
     ) -> Result<Token, ParseError> {
-        self.lexer
-            .lex_template(start, interner)
-            .map_err(ParseError::from)
+        let token = self.lexer.lex_template(start, interner)?;
+        self.last_token_end = token.byte_span().end();
+        Ok(token)
+    }
+
+    /// Gets the span of bytes from `start` to the end of the last token returned to the parser.
+    #[inline]
+    pub(super) fn byte_span_from(&self, start: usize) -> ByteSpan {
+        ByteSpan::new(start, self.last_token_end.max(start))
     }
 
     #[inline]
@@ -189,6 +200,12 @@ where
             let tok = self.peeked[self.read_index].take();
             self.read_index = (self.read_index + 1) % PEEK_BUF_SIZE;
 
+            if let Some(ref token) = tok {
+                if token.kind() != &TokenKind::LineTerminator {
+                    self.last_token_end = token.byte_span().end();
+                }
+            }
+
             Ok(tok)
         } else {
             // We do not update the read index, since we should always return `None` from now on.
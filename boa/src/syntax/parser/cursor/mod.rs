@@ -4,12 +4,14 @@ mod buffered_lexer;
 use super::ParseError;
 use crate::{
     syntax::{
-        ast::Punctuator,
+        ast::{ByteSpan, Punctuator},
         lexer::{InputElement, Lexer, Position, Token, TokenKind},
     },
     Interner,
 };
+use boa_interner::Sym;
 use buffered_lexer::BufferedLexer;
+use rustc_hash::FxHashMap;
 use std::io::Read;
 
 /// The result of a peek for a semicolon.
@@ -25,6 +27,14 @@ pub(super) enum SemicolonResult<'s> {
 #[derive(Debug)]
 pub(super) struct Cursor<R> {
     buffered_lexer: BufferedLexer<R>,
+
+    /// The private names referenced in each class body being parsed, with the position of
+    /// their first reference, to check that every reference has a declaration.
+    private_environments_stack: Vec<FxHashMap<Sym, Position>>,
+
+    /// Whether the code being parsed is inside a non-arrow function, where `new.target` is
+    /// allowed.
+    allow_new_target: bool,
 }
 
 impl<R> Cursor<R>
@@ -36,6 +46,63 @@ where
     pub(super) fn new(reader: R) -> Self {
         Self {
             buffered_lexer: Lexer::new(reader).into(),
+            private_environments_stack: Vec::new(),
+            allow_new_target: false,
+        }
+    }
+
+    /// Pushes a new private environment, when starting to parse a class body.
+    #[inline]
+    pub(super) fn push_private_environment(&mut self) {
+        self.private_environments_stack.push(FxHashMap::default());
+    }
+
+    /// Pops the private environment of a class body, given the private names it declares.
+    ///
+    /// Private names that are referenced but not declared by this class must be declared by an
+    /// enclosing class, otherwise it is a syntax error.
+    pub(super) fn pop_private_environment<T>(
+        &mut self,
+        declared_names: &FxHashMap<Sym, T>,
+    ) -> Result<(), ParseError> {
+        let environment = self
+            .private_environments_stack
+            .pop()
+            .expect("private environment stack must not be empty");
+
+        for (name, position) in environment {
+            if !declared_names.contains_key(&name) {
+                if let Some(outer) = self.private_environments_stack.last_mut() {
+                    outer.entry(name).or_insert(position);
+                } else {
+                    return Err(ParseError::general(
+                        "reference to an undeclared private name",
+                        position,
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Records a reference to a private name in the innermost class body.
+    ///
+    /// It is a syntax error to reference a private name outside of a class body.
+    #[inline]
+    pub(super) fn push_used_private_identifier(
+        &mut self,
+        name: Sym,
+        position: Position,
+    ) -> Result<(), ParseError> {
+        if let Some(environment) = self.private_environments_stack.last_mut() {
+            environment.entry(name).or_insert(position);
+            Ok(())
+        } else {
+            Err(ParseError::general(
+                "private names may only be referenced inside a class body",
+                position,
+            ))
         }
     }
 
@@ -81,11 +148,30 @@ where
         self.buffered_lexer.strict_mode()
     }
 
+    /// Gets the span of bytes from `start` to the end of the last consumed token.
+    #[inline]
+    pub(super) fn byte_span_from(&self, start: usize) -> ByteSpan {
+        self.buffered_lexer.byte_span_from(start)
+    }
+
     #[inline]
     pub(super) fn set_strict_mode(&mut self, strict_mode: bool) {
         self.buffered_lexer.set_strict_mode(strict_mode);
     }
 
+    /// Returns whether `new.target` is allowed in the code being parsed.
+    #[inline]
+    pub(super) fn allow_new_target(&self) -> bool {
+        self.allow_new_target
+    }
+
+    /// Sets whether `new.target` is allowed, and returns the previous value so it can be
+    /// restored once the function or class field has been parsed.
+    #[inline]
+    pub(super) fn set_allow_new_target(&mut self, allow_new_target: bool) -> bool {
+        std::mem::replace(&mut self.allow_new_target, allow_new_target)
+    }
+
     /// Returns an error if the next token is not of kind `kind`.
     #[inline]
     pub(super) fn expect<K>(
@@ -3,7 +3,7 @@
 use crate::syntax::ast::Span;
 use crate::syntax::ast::{position::Position, Node};
 use crate::syntax::lexer::Error as LexError;
-use std::fmt;
+use std::{fmt, io::ErrorKind};
 
 /// Result of a parsing operation.
 pub type ParseResult = Result<Node, ParseError>;
@@ -120,6 +120,112 @@ impl ParseError {
     pub(super) fn unimplemented(message: &'static str, position: Position) -> Self {
         Self::Unimplemented { message, position }
     }
+
+    /// Gets the span of source code that caused the error, if known.
+    ///
+    /// Errors that only know a single position get an empty span starting at that position.
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            Self::Expected { span, .. } | Self::Unexpected { span, .. } => Some(*span),
+            Self::General { position, .. }
+            | Self::Unimplemented { position, .. }
+            | Self::Lex {
+                err: LexError::Syntax(_, position),
+            } => Some(Span::new(*position, *position)),
+            Self::AbruptEnd | Self::Lex { .. } => None,
+        }
+    }
+
+    /// Checks if the error was caused by the source code ending too early.
+    ///
+    /// Such source code can become valid if more code is appended to it, like an unclosed block or
+    /// an unterminated template literal, so a REPL can ask for more input instead of reporting the
+    /// error.
+    pub fn is_abrupt_end(&self) -> bool {
+        match self {
+            Self::AbruptEnd => true,
+            Self::Lex {
+                err: LexError::IO(err),
+            } => err.kind() == ErrorKind::UnexpectedEof,
+            _ => false,
+        }
+    }
+
+    /// Renders the error as a multi-line diagnostic that shows the offending line of the given
+    /// source code, with carets under the span of the error.
+    ///
+    /// `source` must be the source code that was parsed. Columns are counted in characters, so
+    /// lines containing multi-byte UTF-8 characters are underlined correctly.
+    ///
+    /// ```text
+    /// error: unexpected token ';', primary expression at line 2, col 9
+    ///  --> 2:9
+    ///   |
+    /// 2 | let a = ;
+    ///   |         ^
+    /// ```
+    pub fn render(&self, source: &str) -> String {
+        let mut buf = format!("error: {self}");
+        let span = match self.span() {
+            Some(span) => span,
+            None => return buf,
+        };
+        let start = span.start();
+        let line = match source_lines(source).nth(start.line_number() as usize - 1) {
+            Some(line) => line,
+            None => return buf,
+        };
+
+        let column = start.column_number() as usize - 1;
+        let width = if span.end().line_number() == start.line_number() {
+            (span.end().column_number() - start.column_number()) as usize
+        } else {
+            line.chars().count().saturating_sub(column)
+        };
+
+        // Tabs are kept in the padding, so that the carets line up with the source line.
+        let padding: String = line
+            .chars()
+            .take(column)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let line_number = start.line_number().to_string();
+        let gutter = " ".repeat(line_number.len());
+
+        buf.push_str(&format!(
+            "\n{gutter}--> {start}\n{gutter} |\n{line_number} | {line}\n{gutter} | {padding}{}",
+            "^".repeat(width.max(1))
+        ));
+        buf
+    }
+}
+
+/// Splits the source code on every ECMAScript line terminator.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-line-terminators
+fn source_lines(source: &str) -> impl Iterator<Item = &str> {
+    let mut rest = Some(source);
+    std::iter::from_fn(move || {
+        let current = rest?;
+        match current.find(['\n', '\r', '\u{2028}', '\u{2029}']) {
+            Some(index) => {
+                let terminator = if current[index..].starts_with("\r\n") {
+                    2
+                } else {
+                    current[index..].chars().next().map_or(1, char::len_utf8)
+                };
+                rest = Some(&current[index + terminator..]);
+                Some(&current[..index])
+            }
+            None => {
+                rest = None;
+                Some(current)
+            }
+        }
+    })
 }
 
 impl fmt::Display for ParseError {
@@ -14,10 +14,12 @@ use crate::{
     syntax::{
         ast::{
             node::{
-                declaration::Declaration, ArrowFunctionDecl, FormalParameter, Node, Return,
-                StatementList,
+                declaration::{BindingPatternTypeArray, BindingPatternTypeObject, Declaration},
+                ArrayDecl, ArrowFunctionDecl, FormalParameter, Node, Object, PropertyDefinition,
+                PropertyName, Return, StatementList,
             },
-            Punctuator,
+            op::BinOp,
+            Const, Punctuator,
         },
         lexer::{Error as LexError, Position, TokenKind},
         parser::{
@@ -30,6 +32,7 @@ use crate::{
     BoaProfiler, Interner,
 };
 
+use rustc_hash::FxHashSet;
 use std::io::Read;
 
 /// Arrow function parsing.
@@ -84,6 +87,7 @@ where
     ) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("ArrowFunction", "Parsing");
         let next_token = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+        let start = next_token.byte_span().start();
 
         let (params, params_start_position) =
             if let TokenKind::Punctuator(Punctuator::OpenParen) = &next_token.kind() {
@@ -115,6 +119,27 @@ where
                 )
             };
 
+        self.parse_body(params, start, params_start_position, cursor, interner)
+    }
+}
+
+impl ArrowFunction {
+    /// Parses the `=>` token and the body of an arrow function with already parsed parameters.
+    ///
+    /// This is used when the parameters were first parsed as a parenthesized expression and
+    /// then converted with [`cover_formal_parameters`]. The `start` offset is the byte offset of
+    /// the start of the parameters in the source code.
+    pub(super) fn parse_body<R>(
+        self,
+        params: FormalParameterList,
+        start: usize,
+        params_start_position: Position,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<ArrowFunctionDecl, ParseError>
+    where
+        R: Read,
+    {
         cursor.peek_expect_no_lineterminator(0, "arrow function", interner)?;
 
         cursor.expect(
@@ -141,6 +166,10 @@ where
             )));
         }
 
+        // Early Error: It is a Syntax Error if FunctionBodyContainsUseStrict of the body is true
+        // and the BoundNames of the parameters contain `eval` or `arguments`.
+        params.check_strict_bindings(body.strict(), None, params_start_position)?;
+
         // It is a Syntax Error if any element of the BoundNames of ArrowParameters
         // also occurs in the LexicallyDeclaredNames of ConciseBody.
         // https://tc39.es/ecma262/#sec-arrow-function-definitions-static-semantics-early-errors
@@ -165,8 +194,186 @@ where
             }
         }
 
-        Ok(ArrowFunctionDecl::new(self.name, params.parameters, body))
+        Ok(ArrowFunctionDecl::new(self.name, params.parameters, body)
+            .with_source_span(cursor.byte_span_from(start)))
+    }
+}
+
+/// Converts an expression parsed as a `CoverParenthesizedExpressionAndArrowParameterList` into
+/// the parameters of an arrow function.
+///
+/// Returns `None` if the expression does not cover an `ArrowFormalParameters` production.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-arrow-function-definitions-static-semantics-early-errors
+pub(super) fn cover_formal_parameters(node: &Node) -> Option<FormalParameterList> {
+    let mut elements = Vec::new();
+    let mut node = node;
+    loop {
+        match node {
+            Node::BinOp(bin_op) if bin_op.op() == BinOp::Comma => {
+                elements.push(bin_op.rhs());
+                node = bin_op.lhs();
+            }
+            _ => {
+                elements.push(node);
+                break;
+            }
+        }
+    }
+    elements.reverse();
+
+    let mut parameters = Vec::with_capacity(elements.len());
+    let mut parameter_names = FxHashSet::default();
+    let mut is_simple = true;
+    let mut has_duplicates = false;
+    let last = elements.len() - 1;
+    for (i, element) in elements.into_iter().enumerate() {
+        let parameter = match element {
+            Node::Spread(spread) if i == last => {
+                FormalParameter::new(cover_binding(spread.val(), None)?, true)
+            }
+            Node::Assign(assign) => FormalParameter::new(
+                cover_binding(assign.lhs(), Some(assign.rhs().clone()))?,
+                false,
+            ),
+            node => FormalParameter::new(cover_binding(node, None)?, false),
+        };
+
+        if parameter.is_rest_param() || parameter.init().is_some() || !parameter.is_identifier() {
+            is_simple = false;
+        }
+        for name in parameter.names() {
+            if !parameter_names.insert(name) {
+                has_duplicates = true;
+            }
+        }
+        parameters.push(parameter);
+    }
+
+    Some(FormalParameterList {
+        parameters: parameters.into_boxed_slice(),
+        is_simple,
+        has_duplicates,
+    })
+}
+
+/// Converts an expression into the binding of a formal parameter with the given initializer.
+fn cover_binding(node: &Node, init: Option<Node>) -> Option<Declaration> {
+    match node {
+        Node::Identifier(ident) => Some(Declaration::new_with_identifier(*ident, init)),
+        Node::Object(object) => Some(Declaration::new_with_object_pattern(
+            cover_object_pattern(object)?,
+            init,
+        )),
+        Node::ArrayDecl(array) => Some(Declaration::new_with_array_pattern(
+            cover_array_pattern(array)?,
+            init,
+        )),
+        _ => None,
+    }
+}
+
+/// Converts an object literal into the bindings of an object binding pattern.
+fn cover_object_pattern(object: &Object) -> Option<Vec<BindingPatternTypeObject>> {
+    let mut bindings = Vec::new();
+    let mut property_names = Vec::new();
+    let properties = object.properties();
+    for (i, property) in properties.iter().enumerate() {
+        let binding = match property {
+            PropertyDefinition::IdentifierReference(ident) => {
+                BindingPatternTypeObject::SingleName {
+                    ident: *ident,
+                    property_name: *ident,
+                    default_init: None,
+                }
+            }
+            PropertyDefinition::Property(PropertyName::Literal(property_name), value) => {
+                let (value, default_init) = match value {
+                    Node::Assign(assign) => (assign.lhs(), Some(assign.rhs().clone())),
+                    value => (value, None),
+                };
+                match &cover_binding(value, None)? {
+                    Declaration::Identifier { ident, .. } => BindingPatternTypeObject::SingleName {
+                        ident: ident.sym(),
+                        property_name: *property_name,
+                        default_init,
+                    },
+                    Declaration::Pattern(pattern) => BindingPatternTypeObject::BindingPattern {
+                        ident: *property_name,
+                        pattern: pattern.clone(),
+                        default_init,
+                    },
+                }
+            }
+            PropertyDefinition::SpreadObject(Node::Identifier(ident))
+                if i == properties.len() - 1 =>
+            {
+                BindingPatternTypeObject::RestProperty {
+                    ident: ident.sym(),
+                    excluded_keys: property_names.clone(),
+                }
+            }
+            _ => return None,
+        };
+        if let BindingPatternTypeObject::SingleName { property_name, .. }
+        | BindingPatternTypeObject::BindingPattern {
+            ident: property_name,
+            ..
+        } = binding
+        {
+            property_names.push(property_name);
+        }
+        bindings.push(binding);
+    }
+
+    if bindings.is_empty() {
+        bindings.push(BindingPatternTypeObject::Empty);
+    }
+    Some(bindings)
+}
+
+/// Converts an array literal into the bindings of an array binding pattern.
+fn cover_array_pattern(array: &ArrayDecl) -> Option<Vec<BindingPatternTypeArray>> {
+    let elements = array.as_ref();
+    let mut bindings = Vec::with_capacity(elements.len());
+    for (i, element) in elements.iter().enumerate() {
+        let binding = match element {
+            // Elisions are parsed as `undefined` constants.
+            Node::Const(Const::Undefined) => BindingPatternTypeArray::Elision,
+            Node::Spread(spread) if i == elements.len() - 1 => {
+                match &cover_binding(spread.val(), None)? {
+                    Declaration::Identifier { ident, .. } => {
+                        BindingPatternTypeArray::SingleNameRest { ident: ident.sym() }
+                    }
+                    Declaration::Pattern(pattern) => BindingPatternTypeArray::BindingPatternRest {
+                        pattern: pattern.clone(),
+                    },
+                }
+            }
+            element => {
+                let (element, default_init) = match element {
+                    Node::Assign(assign) => (assign.lhs(), Some(assign.rhs().clone())),
+                    element => (element, None),
+                };
+                match &cover_binding(element, default_init)? {
+                    Declaration::Identifier { ident, init } => {
+                        BindingPatternTypeArray::SingleName {
+                            ident: ident.sym(),
+                            default_init: init.clone(),
+                        }
+                    }
+                    Declaration::Pattern(pattern) => BindingPatternTypeArray::BindingPattern {
+                        pattern: pattern.clone(),
+                    },
+                }
+            }
+        };
+        bindings.push(binding);
     }
+    Some(bindings)
 }
 
 /// <https://tc39.es/ecma262/#prod-ConciseBody>
@@ -58,19 +58,21 @@ impl ExponentiationExpression {
 /// Checks by looking at the next token to see whether it's a unary operator or not.
 fn is_unary_expression<R>(
     cursor: &mut Cursor<R>,
+    allow_await: AllowAwait,
     interner: &mut Interner,
 ) -> Result<bool, ParseError>
 where
     R: Read,
 {
     Ok(if let Some(tok) = cursor.peek(0, interner)? {
-        matches!(
-            tok.kind(),
+        match tok.kind() {
             TokenKind::Keyword(Keyword::Delete | Keyword::Void | Keyword::TypeOf)
-                | TokenKind::Punctuator(
-                    Punctuator::Add | Punctuator::Sub | Punctuator::Not | Punctuator::Neg
-                )
-        )
+            | TokenKind::Punctuator(
+                Punctuator::Add | Punctuator::Sub | Punctuator::Not | Punctuator::Neg,
+            ) => true,
+            TokenKind::Keyword(Keyword::Await) => allow_await.0,
+            _ => false,
+        }
     } else {
         false
     })
@@ -85,9 +87,22 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("ExponentiationExpression", "Parsing");
 
-        if is_unary_expression(cursor, interner)? {
-            return UnaryExpression::new(self.allow_yield, self.allow_await)
-                .parse(cursor, interner);
+        if is_unary_expression(cursor, self.allow_await, interner)? {
+            let expr =
+                UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
+
+            // A unary expression can't be the base of an exponentiation without parentheses,
+            // since `-2 ** 2` would be ambiguous.
+            if let Some(tok) = cursor.peek(0, interner)? {
+                if let TokenKind::Punctuator(Punctuator::Exp) = tok.kind() {
+                    return Err(ParseError::general(
+                        "unparenthesized unary expression can't appear on the left-hand side of '**'",
+                        tok.span().start(),
+                    ));
+                }
+            }
+
+            return Ok(expr);
         }
 
         let lhs =
@@ -13,13 +13,16 @@ mod exponentiation;
 mod r#yield;
 
 use self::r#yield::YieldExpression;
-use self::{arrow_function::ArrowFunction, conditional::ConditionalExpression};
+use self::{
+    arrow_function::{cover_formal_parameters, ArrowFunction},
+    conditional::ConditionalExpression,
+};
 use crate::syntax::lexer::{Error as LexError, InputElement, TokenKind};
 use crate::Interner;
 use crate::{
     syntax::{
         ast::{
-            node::{Assign, BinOp, Node},
+            node::{Assign, BinOp, Node, PropertyDefinition},
             Keyword, Punctuator,
         },
         parser::{AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser},
@@ -149,37 +152,22 @@ where
                         }
                         TokenKind::Identifier(_) => {
                             if let Some(t) = cursor.peek(2, interner)? {
-                                match *t.kind() {
-                                    TokenKind::Punctuator(Punctuator::Comma) => {
-                                        // This must be an argument list and therefore (a, b) => {}
-                                        return ArrowFunction::new(
-                                            self.name,
-                                            self.allow_in,
-                                            self.allow_yield,
-                                            self.allow_await,
-                                        )
-                                        .parse(cursor, interner)
-                                        .map(Node::ArrowFunctionDecl);
-                                    }
-                                    TokenKind::Punctuator(Punctuator::CloseParen) => {
-                                        // Need to check if the token after the close paren is an
-                                        // arrow, if so then this is an ArrowFunction otherwise it
-                                        // is an expression of the form (b).
-                                        if let Some(t) = cursor.peek(3, interner)? {
-                                            if t.kind() == &TokenKind::Punctuator(Punctuator::Arrow)
-                                            {
-                                                return ArrowFunction::new(
-                                                    self.name,
-                                                    self.allow_in,
-                                                    self.allow_yield,
-                                                    self.allow_await,
-                                                )
-                                                .parse(cursor, interner)
-                                                .map(Node::ArrowFunctionDecl);
-                                            }
+                                if t.kind() == &TokenKind::Punctuator(Punctuator::CloseParen) {
+                                    // Need to check if the token after the close paren is an
+                                    // arrow, if so then this is an ArrowFunction otherwise it
+                                    // is an expression of the form (b).
+                                    if let Some(t) = cursor.peek(3, interner)? {
+                                        if t.kind() == &TokenKind::Punctuator(Punctuator::Arrow) {
+                                            return ArrowFunction::new(
+                                                self.name,
+                                                self.allow_in,
+                                                self.allow_yield,
+                                                self.allow_await,
+                                            )
+                                            .parse(cursor, interner)
+                                            .map(Node::ArrowFunctionDecl);
                                         }
                                     }
-                                    _ => {}
                                 }
                             }
                         }
@@ -193,16 +181,44 @@ where
 
         cursor.set_goal(InputElement::Div);
 
+        let start_token = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+        let start_position = start_token.span().start();
+        let start = start_token.byte_span().start();
+        let is_parenthesized = start_token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen);
         let mut lhs = ConditionalExpression::new(self.allow_in, self.allow_yield, self.allow_await)
             .parse(cursor, interner)?;
 
+        // A parenthesized expression followed by `=>` covers the parameters of an arrow function,
+        // for example `(a, b) => {}`. Any other expression can't be followed by `=>`.
+        if let Some(tok) = cursor.peek(0, interner)? {
+            if tok.kind() == &TokenKind::Punctuator(Punctuator::Arrow) {
+                let params = is_parenthesized
+                    .then(|| cover_formal_parameters(&lhs))
+                    .flatten()
+                    .ok_or_else(|| {
+                        ParseError::lex(LexError::Syntax(
+                            "Invalid arrow function parameters".into(),
+                            start_position,
+                        ))
+                    })?;
+                return ArrowFunction::new(
+                    self.name,
+                    self.allow_in,
+                    self.allow_yield,
+                    self.allow_await,
+                )
+                .parse_body(params, start, start_position, cursor, interner)
+                .map(Node::ArrowFunctionDecl);
+            }
+        }
+
         // Review if we are trying to assign to an invalid left hand side expression.
         // TODO: can we avoid cloning?
         if let Some(tok) = cursor.peek(0, interner)?.cloned() {
             match tok.kind() {
                 TokenKind::Punctuator(Punctuator::Assign) => {
                     cursor.next(interner)?.expect("= token vanished"); // Consume the token.
-                    if is_assignable(&lhs) {
+                    if is_assignable(&lhs, cursor.strict_mode()) {
                         let expr = self.parse(cursor, interner)?;
                         lhs = Assign::new(lhs, expr).into();
                     } else {
@@ -214,7 +230,7 @@ where
                 }
                 TokenKind::Punctuator(p) if p.as_binop().is_some() && p != &Punctuator::Comma => {
                     cursor.next(interner)?.expect("token vanished"); // Consume the token.
-                    if is_assignable(&lhs) {
+                    if is_assignable(&lhs, cursor.strict_mode()) {
                         let binop = p.as_binop().expect("binop disappeared");
                         let expr = self.parse(cursor, interner)?;
 
@@ -238,14 +254,40 @@ where
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-assignment-operators-static-semantics-early-errors
 #[inline]
-pub(crate) fn is_assignable(node: &Node) -> bool {
+pub(crate) fn is_assignable(node: &Node, strict: bool) -> bool {
     matches!(
         node,
         Node::GetConstField(_)
             | Node::GetField(_)
+            | Node::GetPrivateField(_)
+            | Node::GetSuperField(_)
             | Node::Assign(_)
             | Node::Call(_)
             | Node::Identifier(_)
             | Node::Object(_)
-    )
+    ) && !(strict && assigns_eval_or_arguments(node))
+}
+
+/// Returns true if the assignment target assigns to `eval` or `arguments`, including in the
+/// properties of an object destructuring pattern, which is an early error in strict mode code.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-identifiers-static-semantics-assignmenttargettype
+pub(crate) fn assigns_eval_or_arguments(node: &Node) -> bool {
+    match node {
+        Node::Identifier(ident) => [Sym::EVAL, Sym::ARGUMENTS].contains(&ident.sym()),
+        Node::Assign(assign) => assigns_eval_or_arguments(assign.lhs()),
+        Node::Object(object) => object.properties().iter().any(|property| match property {
+            PropertyDefinition::IdentifierReference(ident) => {
+                [Sym::EVAL, Sym::ARGUMENTS].contains(ident)
+            }
+            PropertyDefinition::Property(_, value) | PropertyDefinition::SpreadObject(value) => {
+                assigns_eval_or_arguments(value)
+            }
+            PropertyDefinition::MethodDefinition(..) => false,
+        }),
+        _ => false,
+    }
 }
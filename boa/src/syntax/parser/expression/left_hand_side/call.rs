@@ -12,7 +12,7 @@ use crate::{
     syntax::{
         ast::{
             node::{
-                field::{GetConstField, GetField},
+                field::{GetConstField, GetField, GetPrivateField},
                 Call, Node,
             },
             Punctuator,
@@ -92,13 +92,19 @@ where
                 TokenKind::Punctuator(Punctuator::Dot) => {
                     cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?; // We move the parser forward.
 
-                    match cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?.kind() {
+                    let name_token = cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?;
+                    match name_token.kind() {
                         TokenKind::Identifier(name) => {
                             lhs = GetConstField::new(lhs, *name).into();
                         }
                         TokenKind::Keyword(kw) => {
                             lhs = GetConstField::new(lhs, kw.to_sym(interner)).into();
                         }
+                        TokenKind::PrivateIdentifier(name) => {
+                            cursor
+                                .push_used_private_identifier(*name, name_token.span().start())?;
+                            lhs = GetPrivateField::new(lhs, *name).into();
+                        }
                         _ => {
                             return Err(ParseError::expected(
                                 ["identifier".to_owned()],
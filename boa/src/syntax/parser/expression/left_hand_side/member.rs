@@ -10,7 +10,7 @@ use crate::{
     syntax::{
         ast::{
             node::{
-                field::{GetConstField, GetField},
+                field::{GetConstField, GetField, GetPrivateField, GetSuperField},
                 Call, New, Node,
             },
             Keyword, Punctuator,
@@ -71,16 +71,81 @@ where
             == &TokenKind::Keyword(Keyword::New)
         {
             let _next = cursor.next(interner).expect("new keyword disappeared");
-            let lhs = self.parse(cursor, interner)?;
-            let args = match cursor.peek(0, interner)? {
-                Some(next) if next.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) => {
-                    Arguments::new(self.allow_yield, self.allow_await).parse(cursor, interner)?
+
+            // MetaProperty : NewTarget
+            if cursor.next_if(Punctuator::Dot, interner)?.is_some() {
+                let token = cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?;
+                match token.kind() {
+                    TokenKind::Identifier(name) if interner.resolve_expect(*name) == "target" => {
+                        // Early Error: `new.target` is only allowed in non-arrow functions.
+                        if !cursor.allow_new_target() {
+                            return Err(ParseError::general(
+                                "new.target expression is not allowed here",
+                                token.span().start(),
+                            ));
+                        }
+                        Node::NewTarget
+                    }
+                    _ => {
+                        return Err(ParseError::unexpected(
+                            token.to_string(interner),
+                            token.span(),
+                            "expected 'target' after 'new.'",
+                        ));
+                    }
                 }
-                _ => Box::new([]),
-            };
-            let call_node = Call::new(lhs, args);
+            } else {
+                let lhs = self.parse(cursor, interner)?;
+                let args = match cursor.peek(0, interner)? {
+                    Some(next) if next.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) => {
+                        Arguments::new(self.allow_yield, self.allow_await)
+                            .parse(cursor, interner)?
+                    }
+                    _ => Box::new([]),
+                };
+                let call_node = Call::new(lhs, args);
 
-            Node::from(New::from(call_node))
+                Node::from(New::from(call_node))
+            }
+        } else if cursor
+            .peek(0, interner)?
+            .ok_or(ParseError::AbruptEnd)?
+            .kind()
+            == &TokenKind::Keyword(Keyword::Super)
+        {
+            // SuperProperty : super [ Expression ] | super . IdentifierName
+            let super_token = cursor.next(interner)?.expect("super keyword disappeared");
+            let token = cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?;
+            match token.kind() {
+                TokenKind::Punctuator(Punctuator::Dot) => {
+                    let token = cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?;
+                    match token.kind() {
+                        TokenKind::Identifier(name) => GetSuperField::from(*name).into(),
+                        TokenKind::Keyword(kw) => GetSuperField::from(kw.to_sym(interner)).into(),
+                        _ => {
+                            return Err(ParseError::expected(
+                                ["identifier".to_owned()],
+                                token.to_string(interner),
+                                token.span(),
+                                "super property",
+                            ));
+                        }
+                    }
+                }
+                TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    let field = Expression::new(None, true, self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)?;
+                    cursor.expect(Punctuator::CloseBracket, "super property", interner)?;
+                    GetSuperField::from(field).into()
+                }
+                _ => {
+                    return Err(ParseError::unexpected(
+                        super_token.to_string(interner),
+                        super_token.span(),
+                        "expected '.' or '[' after super",
+                    ));
+                }
+            }
         } else {
             PrimaryExpression::new(self.allow_yield, self.allow_await).parse(cursor, interner)?
         };
@@ -98,6 +163,10 @@ where
                         TokenKind::Keyword(kw) => {
                             lhs = GetConstField::new(lhs, kw.to_sym(interner)).into();
                         }
+                        TokenKind::PrivateIdentifier(name) => {
+                            cursor.push_used_private_identifier(*name, token.span().start())?;
+                            lhs = GetPrivateField::new(lhs, *name).into();
+                        }
                         _ => {
                             return Err(ParseError::expected(
                                 ["identifier".to_owned()],
@@ -12,12 +12,12 @@ mod call;
 mod member;
 mod template;
 
-use self::{call::CallExpression, member::MemberExpression};
+use self::{arguments::Arguments, call::CallExpression, member::MemberExpression};
 use crate::{
     profiler::BoaProfiler,
     syntax::{
-        ast::{Node, Punctuator},
-        lexer::{InputElement, TokenKind},
+        ast::{node::SuperCall, Keyword, Node, Punctuator},
+        lexer::{InputElement, Token, TokenKind},
         parser::{AllowAwait, AllowYield, Cursor, ParseResult, TokenParser},
     },
     Interner,
@@ -34,14 +34,14 @@ use std::io::Read;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Expressions_and_Operators#Left-hand-side_expressions
 /// [spec]: https://tc39.es/ecma262/#prod-LeftHandSideExpression
 #[derive(Debug, Clone, Copy)]
-pub(super) struct LeftHandSideExpression {
+pub(in crate::syntax::parser) struct LeftHandSideExpression {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
 impl LeftHandSideExpression {
     /// Creates a new `LeftHandSideExpression` parser.
-    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -64,6 +64,17 @@ where
 
         cursor.set_goal(InputElement::TemplateTail);
 
+        // SuperCall : super Arguments
+        if cursor.peek(0, interner)?.map(Token::kind) == Some(&TokenKind::Keyword(Keyword::Super))
+            && cursor.peek(1, interner)?.map(Token::kind)
+                == Some(&TokenKind::Punctuator(Punctuator::OpenParen))
+        {
+            cursor.next(interner)?.expect("super keyword disappeared");
+            let args =
+                Arguments::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
+            return Ok(SuperCall::new(args).into());
+        }
+
         // TODO: Implement NewExpression: new MemberExpression
         let lhs =
             MemberExpression::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
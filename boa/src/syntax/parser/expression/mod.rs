@@ -20,7 +20,11 @@ pub(in crate::syntax::parser) mod await_expr;
 use boa_interner::Sym;
 
 use self::assignment::ExponentiationExpression;
-pub(super) use self::{assignment::AssignmentExpression, primary::Initializer};
+pub(super) use self::{
+    assignment::AssignmentExpression,
+    left_hand_side::LeftHandSideExpression,
+    primary::{Initializer, PropertyDefinition, PropertyName},
+};
 use super::{AllowAwait, AllowIn, AllowYield, Cursor, ParseResult, TokenParser};
 
 use crate::{
@@ -28,7 +32,7 @@ use crate::{
     syntax::{
         ast::op::LogOp,
         ast::{
-            node::{BinOp, Node},
+            node::{BinOp, Node, PrivateIn},
             Keyword, Punctuator,
         },
         lexer::{InputElement, TokenKind},
@@ -489,8 +493,27 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("Relation Expression", "Parsing");
 
-        let mut lhs =
-            ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
+        // RelationalExpression : [+In] PrivateIdentifier in ShiftExpression
+        let token = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+        let mut lhs = match *token.kind() {
+            TokenKind::PrivateIdentifier(name) if self.allow_in == AllowIn(true) => {
+                let position = token.span().start();
+                cursor
+                    .next(interner)?
+                    .expect("private identifier disappeared");
+                cursor.expect(Keyword::In, "private name check", interner)?;
+                cursor.push_used_private_identifier(name, position)?;
+                PrivateIn::new(
+                    name,
+                    ShiftExpression::new(self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)?,
+                )
+                .into()
+            }
+            _ => {
+                ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor, interner)?
+            }
+        };
         while let Some(tok) = cursor.peek(0, interner)? {
             match *tok.kind() {
                 TokenKind::Punctuator(op)
@@ -88,6 +88,7 @@ where
             .span()
             .end();
 
+        let allow_new_target = cursor.set_allow_new_target(true);
         let params = FormalParameters::new(false, true).parse(cursor, interner)?;
 
         cursor.expect(
@@ -98,6 +99,7 @@ where
         cursor.expect(Punctuator::OpenBlock, "async function expression", interner)?;
 
         let body = FunctionBody::new(false, true).parse(cursor, interner)?;
+        cursor.set_allow_new_target(allow_new_target);
 
         cursor.expect(
             Punctuator::CloseBlock,
@@ -123,6 +125,10 @@ where
             )));
         }
 
+        // Early Error: It is a Syntax Error if FunctionBodyContainsUseStrict of the body is true
+        // and the BindingIdentifier or the BoundNames of the parameters are `eval` or `arguments`.
+        params.check_strict_bindings(body.strict(), name, params_start_position)?;
+
         // It is a Syntax Error if any element of the BoundNames of FormalParameters
         // also occurs in the LexicallyDeclaredNames of FunctionBody.
         // https://tc39.es/ecma262/#sec-function-definitions-static-semantics-early-errors
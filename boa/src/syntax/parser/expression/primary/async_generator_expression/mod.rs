@@ -90,6 +90,7 @@ where
             .span()
             .end();
 
+        let allow_new_target = cursor.set_allow_new_target(true);
         let params = FormalParameters::new(true, true).parse(cursor, interner)?;
 
         cursor.expect(
@@ -104,6 +105,7 @@ where
         )?;
 
         let body = FunctionBody::new(true, true).parse(cursor, interner)?;
+        cursor.set_allow_new_target(allow_new_target);
 
         cursor.expect(
             Punctuator::CloseBlock,
@@ -129,6 +131,10 @@ where
             )));
         }
 
+        // Early Error: It is a Syntax Error if FunctionBodyContainsUseStrict of the body is true
+        // and the BindingIdentifier or the BoundNames of the parameters are `eval` or `arguments`.
+        params.check_strict_bindings(body.strict(), name, params_start_position)?;
+
         // It is a Syntax Error if any element of the BoundNames of FormalParameters
         // also occurs in the LexicallyDeclaredNames of FunctionBody.
         {
@@ -0,0 +1,84 @@
+//! Class expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/class
+//! [spec]: https://tc39.es/ecma262/#prod-ClassExpression
+
+use crate::{
+    syntax::{
+        ast::{Keyword, Node, Punctuator},
+        lexer::TokenKind,
+        parser::{
+            statement::{BindingIdentifier, ClassTail},
+            AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
+        },
+    },
+    BoaProfiler, Interner,
+};
+use std::io::Read;
+
+/// Class expression parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/class
+/// [spec]: https://tc39.es/ecma262/#prod-ClassExpression
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ClassExpression {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassExpression {
+    /// Creates a new `ClassExpression` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("ClassExpression", "Parsing");
+
+        let name = match cursor
+            .peek(0, interner)?
+            .ok_or(ParseError::AbruptEnd)?
+            .kind()
+        {
+            TokenKind::Keyword(Keyword::Extends) | TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                None
+            }
+            _ => {
+                // All parts of a class expression are strict mode code.
+                let strict = cursor.strict_mode();
+                cursor.set_strict_mode(true);
+                let name = BindingIdentifier::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner);
+                cursor.set_strict_mode(strict);
+                Some(name?)
+            }
+        };
+
+        let class =
+            ClassTail::new(name, self.allow_yield, self.allow_await).parse(cursor, interner)?;
+
+        Ok(Node::ClassExpr(class))
+    }
+}
@@ -81,12 +81,14 @@ where
             .span()
             .end();
 
+        let allow_new_target = cursor.set_allow_new_target(true);
         let params = FormalParameters::new(false, false).parse(cursor, interner)?;
 
         cursor.expect(Punctuator::CloseParen, "function expression", interner)?;
         cursor.expect(Punctuator::OpenBlock, "function expression", interner)?;
 
         let body = FunctionBody::new(false, false).parse(cursor, interner)?;
+        cursor.set_allow_new_target(allow_new_target);
 
         cursor.expect(Punctuator::CloseBlock, "function expression", interner)?;
 
@@ -108,6 +110,10 @@ where
             )));
         }
 
+        // Early Error: It is a Syntax Error if FunctionBodyContainsUseStrict of the body is true
+        // and the BindingIdentifier or the BoundNames of the parameters are `eval` or `arguments`.
+        params.check_strict_bindings(body.strict(), name, params_start_position)?;
+
         // It is a Syntax Error if any element of the BoundNames of FormalParameters
         // also occurs in the LexicallyDeclaredNames of FunctionBody.
         // https://tc39.es/ecma262/#sec-function-definitions-static-semantics-early-errors
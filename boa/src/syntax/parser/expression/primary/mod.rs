@@ -10,6 +10,7 @@
 mod array_initializer;
 mod async_function_expression;
 mod async_generator_expression;
+mod class_expression;
 mod function_expression;
 mod generator_expression;
 mod object_initializer;
@@ -19,16 +20,17 @@ mod tests;
 
 use self::{
     array_initializer::ArrayLiteral, async_function_expression::AsyncFunctionExpression,
-    async_generator_expression::AsyncGeneratorExpression, function_expression::FunctionExpression,
-    generator_expression::GeneratorExpression, object_initializer::ObjectLiteral,
+    async_generator_expression::AsyncGeneratorExpression, class_expression::ClassExpression,
+    function_expression::FunctionExpression, generator_expression::GeneratorExpression,
+    object_initializer::ObjectLiteral,
 };
-use super::Expression;
+use super::AssignmentExpression;
 use crate::{
     profiler::BoaProfiler,
     syntax::{
         ast::{
-            node::{Call, Identifier, New, Node},
-            Const, Keyword, Punctuator,
+            node::{BinOp, Call, Identifier, New, Node, Spread},
+            op, Const, Keyword, Punctuator,
         },
         lexer::{token::Numeric, InputElement, TokenKind},
         parser::{
@@ -39,7 +41,9 @@ use crate::{
     Interner,
 };
 use boa_interner::Sym;
-pub(in crate::syntax::parser) use object_initializer::Initializer;
+pub(in crate::syntax::parser) use object_initializer::{
+    Initializer, PropertyDefinition, PropertyName,
+};
 
 use std::io::Read;
 
@@ -87,13 +91,23 @@ where
         match tok.kind() {
             TokenKind::Keyword(Keyword::This) => Ok(Node::This),
             TokenKind::Keyword(Keyword::Function) => {
+                let start = tok.byte_span().start();
                 let next_token = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
                 if next_token.kind() == &TokenKind::Punctuator(Punctuator::Mul) {
-                    GeneratorExpression.parse(cursor, interner).map(Node::from)
+                    let generator = GeneratorExpression.parse(cursor, interner)?;
+                    Ok(generator
+                        .with_source_span(cursor.byte_span_from(start))
+                        .into())
                 } else {
-                    FunctionExpression.parse(cursor, interner).map(Node::from)
+                    let function = FunctionExpression.parse(cursor, interner)?;
+                    Ok(function
+                        .with_source_span(cursor.byte_span_from(start))
+                        .into())
                 }
             }
+            TokenKind::Keyword(Keyword::Class) => {
+                ClassExpression::new(self.allow_yield, self.allow_await).parse(cursor, interner)
+            }
             TokenKind::Keyword(Keyword::Async) => {
                 let mul_peek = cursor.peek(1, interner)?.ok_or(ParseError::AbruptEnd)?;
                 if mul_peek.kind() == &TokenKind::Punctuator(Punctuator::Mul) {
@@ -101,16 +115,90 @@ where
                         .parse(cursor, interner)
                         .map(Node::from)
                 } else {
-                    AsyncFunctionExpression::new(self.allow_yield)
-                        .parse(cursor, interner)
-                        .map(Node::from)
+                    let function =
+                        AsyncFunctionExpression::new(self.allow_yield).parse(cursor, interner)?;
+                    Ok(function
+                        .with_source_span(cursor.byte_span_from(tok.byte_span().start()))
+                        .into())
                 }
             }
             TokenKind::Punctuator(Punctuator::OpenParen) => {
+                // CoverParenthesizedExpressionAndArrowParameterList
                 cursor.set_goal(InputElement::RegExp);
-                let expr = Expression::new(None, true, self.allow_yield, self.allow_await)
-                    .parse(cursor, interner)?;
+                // A parenthesized element can't be converted to an arrow function parameter, as
+                // in `((a)) => {}` or `(a, (b)) => {}`.
+                let mut has_parenthesized_element = next_is_open_paren(cursor, interner)?;
+                let mut expr =
+                    AssignmentExpression::new(None, true, self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)?;
+                while cursor.next_if(Punctuator::Comma, interner)?.is_some() {
+                    // A trailing comma or a rest element can only appear in the parameters of an
+                    // arrow function, which are converted from this expression afterwards.
+                    let rest = match cursor
+                        .peek(0, interner)?
+                        .ok_or(ParseError::AbruptEnd)?
+                        .kind()
+                    {
+                        TokenKind::Punctuator(Punctuator::CloseParen) => None,
+                        TokenKind::Punctuator(Punctuator::Spread) => {
+                            let _next = cursor.next(interner)?.expect("spread token vanished");
+                            has_parenthesized_element |= next_is_open_paren(cursor, interner)?;
+                            Some(
+                                AssignmentExpression::new(
+                                    None,
+                                    true,
+                                    self.allow_yield,
+                                    self.allow_await,
+                                )
+                                .parse(cursor, interner)?,
+                            )
+                        }
+                        _ => {
+                            has_parenthesized_element |= next_is_open_paren(cursor, interner)?;
+                            let rhs = AssignmentExpression::new(
+                                None,
+                                true,
+                                self.allow_yield,
+                                self.allow_await,
+                            )
+                            .parse(cursor, interner)?;
+                            expr = BinOp::new(op::BinOp::Comma, expr, rhs).into();
+                            continue;
+                        }
+                    };
+
+                    cursor.expect(Punctuator::CloseParen, "arrow function", interner)?;
+                    let next_token = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+                    if next_token.kind() != &TokenKind::Punctuator(Punctuator::Arrow) {
+                        return Err(ParseError::expected(
+                            ["=>".to_owned()],
+                            next_token.to_string(interner),
+                            next_token.span(),
+                            "arrow function",
+                        ));
+                    }
+                    if has_parenthesized_element {
+                        return Err(ParseError::general(
+                            "Invalid arrow function parameters",
+                            tok.span().start(),
+                        ));
+                    }
+                    if let Some(rest) = rest {
+                        expr = BinOp::new(op::BinOp::Comma, expr, Spread::new(rest)).into();
+                    }
+                    return Ok(expr);
+                }
                 cursor.expect(Punctuator::CloseParen, "primary expression", interner)?;
+                if has_parenthesized_element {
+                    if let Some(next_token) = cursor.peek(0, interner)? {
+                        if next_token.kind() == &TokenKind::Punctuator(Punctuator::Arrow) {
+                            return Err(ParseError::general(
+                                "Invalid arrow function parameters",
+                                tok.span().start(),
+                            ));
+                        }
+                    }
+                }
                 Ok(expr)
             }
             TokenKind::Punctuator(Punctuator::OpenBracket) => {
@@ -211,3 +299,13 @@ where
         }
     }
 }
+
+/// Returns `true` if the next token is an opening parenthesis.
+fn next_is_open_paren<R: Read>(
+    cursor: &mut Cursor<R>,
+    interner: &mut Interner,
+) -> Result<bool, ParseError> {
+    Ok(cursor.peek(0, interner)?.map_or(false, |tok| {
+        tok.kind() == &TokenKind::Punctuator(Punctuator::OpenParen)
+    }))
+}
@@ -105,14 +105,14 @@ where
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-PropertyDefinition
 #[derive(Debug, Clone, Copy)]
-struct PropertyDefinition {
+pub(in crate::syntax::parser) struct PropertyDefinition {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
 impl PropertyDefinition {
     /// Creates a new `PropertyDefinition` parser.
-    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -199,6 +199,14 @@ where
             return Ok(node::PropertyDefinition::SpreadObject(node));
         }
 
+        // The source text of a method starts at its first token, including `async`, `*`, `get`
+        // and `set`.
+        let start = cursor
+            .peek(0, interner)?
+            .ok_or(ParseError::AbruptEnd)?
+            .byte_span()
+            .start();
+
         //Async [AsyncMethod, AsyncGeneratorMethod] object methods
         if cursor.next_if(Keyword::Async, interner)?.is_some() {
             cursor.peek_expect_no_lineterminator(0, "Async object methods", interner)?;
@@ -218,6 +226,7 @@ where
                     )?
                     .span()
                     .start();
+                let allow_new_target = cursor.set_allow_new_target(true);
                 let params = FormalParameters::new(true, true).parse(cursor, interner)?;
                 cursor.expect(
                     Punctuator::CloseParen,
@@ -240,6 +249,7 @@ where
                     interner,
                 )?;
                 let body = FunctionBody::new(true, true).parse(cursor, interner)?;
+                cursor.set_allow_new_target(allow_new_target);
                 cursor.expect(
                     TokenKind::Punctuator(Punctuator::CloseBlock),
                     "async generator method definition",
@@ -256,6 +266,10 @@ where
                     )));
                 }
 
+                // Early Error: It is a Syntax Error if FunctionBodyContainsUseStrict of the body is true
+                // and the BoundNames of the parameters contain `eval` or `arguments`.
+                params.check_strict_bindings(body.strict(), None, params_start_position)?;
+
                 // Early Error: It is a Syntax Error if any element of the BoundNames of UniqueFormalParameters also
                 // occurs in the LexicallyDeclaredNames of GeneratorBody.
                 {
@@ -282,7 +296,8 @@ where
                 return Ok(node::PropertyDefinition::method_definition(
                     MethodDefinitionKind::AsyncGenerator,
                     property_name,
-                    FunctionExpr::new(None, params.parameters, body),
+                    FunctionExpr::new(None, params.parameters, body)
+                        .with_source_span(cursor.byte_span_from(start)),
                 ));
             }
             // MethodDefinition[?Yield, ?Await] -> AsyncMethod[?Yield, ?Await]
@@ -291,6 +306,7 @@ where
                 .expect(Punctuator::OpenParen, "async method definition", interner)?
                 .span()
                 .start();
+            let allow_new_target = cursor.set_allow_new_target(true);
             let params = FormalParameters::new(false, true).parse(cursor, interner)?;
             cursor.expect(Punctuator::CloseParen, "async method definition", interner)?;
 
@@ -309,6 +325,7 @@ where
                 interner,
             )?;
             let body = FunctionBody::new(true, true).parse(cursor, interner)?;
+            cursor.set_allow_new_target(allow_new_target);
             cursor.expect(
                 TokenKind::Punctuator(Punctuator::CloseBlock),
                 "async method definition",
@@ -325,6 +342,10 @@ where
                 )));
             }
 
+            // Early Error: It is a Syntax Error if FunctionBodyContainsUseStrict of the body is true
+            // and the BoundNames of the parameters contain `eval` or `arguments`.
+            params.check_strict_bindings(body.strict(), None, params_start_position)?;
+
             // Early Error: It is a Syntax Error if any element of the BoundNames of UniqueFormalParameters also
             // occurs in the LexicallyDeclaredNames of GeneratorBody.
             {
@@ -350,7 +371,8 @@ where
             return Ok(node::PropertyDefinition::method_definition(
                 MethodDefinitionKind::Async,
                 property_name,
-                FunctionExpr::new(None, params.parameters, body),
+                FunctionExpr::new(None, params.parameters, body)
+                    .with_source_span(cursor.byte_span_from(start)),
             ));
         }
 
@@ -367,6 +389,7 @@ where
                 )?
                 .span()
                 .start();
+            let allow_new_target = cursor.set_allow_new_target(true);
             let params = FormalParameters::new(false, false).parse(cursor, interner)?;
             cursor.expect(
                 Punctuator::CloseParen,
@@ -389,6 +412,7 @@ where
                 interner,
             )?;
             let body = FunctionBody::new(true, false).parse(cursor, interner)?;
+            cursor.set_allow_new_target(allow_new_target);
             cursor.expect(
                 TokenKind::Punctuator(Punctuator::CloseBlock),
                 "generator method definition",
@@ -405,6 +429,10 @@ where
                 )));
             }
 
+            // Early Error: It is a Syntax Error if FunctionBodyContainsUseStrict of the body is true
+            // and the BoundNames of the parameters contain `eval` or `arguments`.
+            params.check_strict_bindings(body.strict(), None, params_start_position)?;
+
             // Early Error: It is a Syntax Error if any element of the BoundNames of UniqueFormalParameters also
             // occurs in the LexicallyDeclaredNames of GeneratorBody.
             {
@@ -431,7 +459,8 @@ where
             return Ok(node::PropertyDefinition::method_definition(
                 MethodDefinitionKind::Generator,
                 property_name,
-                FunctionExpr::new(None, params.parameters, body),
+                FunctionExpr::new(None, params.parameters, body)
+                    .with_source_span(cursor.byte_span_from(start)),
             ));
         }
 
@@ -473,7 +502,9 @@ where
                     "get method definition",
                     interner,
                 )?;
+                let allow_new_target = cursor.set_allow_new_target(true);
                 let body = FunctionBody::new(false, false).parse(cursor, interner)?;
+                cursor.set_allow_new_target(allow_new_target);
                 cursor.expect(
                     TokenKind::Punctuator(Punctuator::CloseBlock),
                     "get method definition",
@@ -483,7 +514,8 @@ where
                 Ok(node::PropertyDefinition::method_definition(
                     MethodDefinitionKind::Get,
                     property_name,
-                    FunctionExpr::new(None, [], body),
+                    FunctionExpr::new(None, [], body)
+                        .with_source_span(cursor.byte_span_from(start)),
                 ))
             }
             // MethodDefinition[?Yield, ?Await] -> set ClassElementName[?Yield, ?Await] ( PropertySetParameterList ) { FunctionBody[~Yield, ~Await] }
@@ -499,6 +531,7 @@ where
                     )?
                     .span()
                     .end();
+                let allow_new_target = cursor.set_allow_new_target(true);
                 let params = FormalParameters::new(false, false).parse(cursor, interner)?;
                 cursor.expect(
                     TokenKind::Punctuator(Punctuator::CloseParen),
@@ -518,6 +551,7 @@ where
                     interner,
                 )?;
                 let body = FunctionBody::new(false, false).parse(cursor, interner)?;
+                cursor.set_allow_new_target(allow_new_target);
                 cursor.expect(
                     TokenKind::Punctuator(Punctuator::CloseBlock),
                     "set method definition",
@@ -534,10 +568,15 @@ where
                     )));
                 }
 
+                // Early Error: It is a Syntax Error if FunctionBodyContainsUseStrict of the body is true
+                // and the BoundNames of the parameters contain `eval` or `arguments`.
+                params.check_strict_bindings(body.strict(), None, params_start_position)?;
+
                 Ok(node::PropertyDefinition::method_definition(
                     MethodDefinitionKind::Set,
                     property_name,
-                    FunctionExpr::new(None, params.parameters, body),
+                    FunctionExpr::new(None, params.parameters, body)
+                        .with_source_span(cursor.byte_span_from(start)),
                 ))
             }
             // MethodDefinition[?Yield, ?Await] -> ClassElementName[?Yield, ?Await] ( UniqueFormalParameters[~Yield, ~Await] ) { FunctionBody[~Yield, ~Await] }
@@ -550,6 +589,7 @@ where
                     )?
                     .span()
                     .end();
+                let allow_new_target = cursor.set_allow_new_target(true);
                 let params = FormalParameters::new(false, false).parse(cursor, interner)?;
                 cursor.expect(
                     TokenKind::Punctuator(Punctuator::CloseParen),
@@ -571,6 +611,7 @@ where
                     interner,
                 )?;
                 let body = FunctionBody::new(false, false).parse(cursor, interner)?;
+                cursor.set_allow_new_target(allow_new_target);
                 cursor.expect(
                     TokenKind::Punctuator(Punctuator::CloseBlock),
                     "method definition",
@@ -587,10 +628,15 @@ where
                     )));
                 }
 
+                // Early Error: It is a Syntax Error if FunctionBodyContainsUseStrict of the body is true
+                // and the BoundNames of the parameters contain `eval` or `arguments`.
+                params.check_strict_bindings(body.strict(), None, params_start_position)?;
+
                 Ok(node::PropertyDefinition::method_definition(
                     MethodDefinitionKind::Ordinary,
                     property_name,
-                    FunctionExpr::new(None, params.parameters, body),
+                    FunctionExpr::new(None, params.parameters, body)
+                        .with_source_span(cursor.byte_span_from(start)),
                 ))
             }
         }
@@ -604,14 +650,14 @@ where
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-PropertyName
 #[derive(Debug, Clone)]
-struct PropertyName {
+pub(in crate::syntax::parser) struct PropertyName {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
 impl PropertyName {
     /// Creates a new `PropertyName` parser.
-    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
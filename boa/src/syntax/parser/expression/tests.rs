@@ -1,8 +1,8 @@
 use crate::{
     syntax::{
-        ast::op::{AssignOp, BitOp, CompOp, LogOp, NumOp},
+        ast::op::{self, AssignOp, BitOp, CompOp, LogOp, NumOp},
         ast::{
-            node::{BinOp, Identifier},
+            node::{field::GetConstField, BinOp, FunctionDecl, Identifier, Node},
             Const,
         },
         parser::tests::{check_invalid, check_parser},
@@ -133,6 +133,22 @@ fn check_numeric_operations() {
         &mut interner,
     );
 
+    let mut interner = Interner::default();
+    check_parser(
+        "a ** b ** c",
+        vec![BinOp::new(
+            NumOp::Exp,
+            Identifier::new(interner.get_or_intern_static("a")),
+            BinOp::new(
+                NumOp::Exp,
+                Identifier::new(interner.get_or_intern_static("b")),
+                Identifier::new(interner.get_or_intern_static("c")),
+            ),
+        )
+        .into()],
+        &mut interner,
+    );
+
     let mut interner = Interner::default();
     check_parser(
         "a % b",
@@ -592,3 +608,56 @@ fn check_logical_expressions() {
     check_invalid("a ?? b || c");
     check_invalid("a || b ?? c");
 }
+
+#[test]
+fn check_exp_unary_operand() {
+    check_invalid("-2 ** 2");
+    check_invalid("+a ** 2");
+    check_invalid("delete a.b ** 2");
+    check_invalid("void 0 ** 2");
+}
+
+#[test]
+fn check_new_target() {
+    let mut interner = Interner::default();
+    check_parser(
+        "function f() { new.target.name }",
+        vec![FunctionDecl::new(
+            interner.get_or_intern_static("f"),
+            vec![],
+            vec![GetConstField::new(Node::NewTarget, interner.get_or_intern_static("name")).into()],
+        )
+        .into()],
+        &mut interner,
+    );
+
+    check_invalid("function f() { new.foo }");
+    check_invalid("function f() { new. }");
+
+    // `new.target` is only allowed inside of non-arrow functions.
+    check_invalid("new.target");
+    check_invalid("() => new.target");
+    check_invalid("function f() {} new.target");
+    check_invalid("({ a: new.target })");
+}
+
+/// Checks parsing of parenthesized comma expressions.
+#[test]
+fn check_comma_operator() {
+    let mut interner = Interner::default();
+    let a = interner.get_or_intern_static("a");
+    let b = interner.get_or_intern_static("b");
+    check_parser(
+        "(a, b) + 1",
+        vec![BinOp::new(
+            NumOp::Add,
+            BinOp::new(op::BinOp::Comma, Identifier::new(a), Identifier::new(b)),
+            Const::from(1),
+        )
+        .into()],
+        &mut interner,
+    );
+
+    check_invalid("(a, ...b)");
+    check_invalid("(a, b,)");
+}
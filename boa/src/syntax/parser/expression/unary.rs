@@ -17,8 +17,8 @@ use crate::{
         },
         lexer::{Error as LexError, TokenKind},
         parser::{
-            expression::update::UpdateExpression, AllowAwait, AllowYield, Cursor, ParseError,
-            ParseResult, TokenParser,
+            expression::{await_expr::AwaitExpression, update::UpdateExpression},
+            AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
         },
     },
     Interner,
@@ -78,6 +78,15 @@ where
                     }
                 }
 
+                // Early Error: It is a Syntax Error if the UnaryExpression is contained in strict mode code
+                // and the derived UnaryExpression is MemberExpression . PrivateIdentifier.
+                if let Node::GetPrivateField(_) = val {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        "private fields can not be deleted".into(),
+                        token_start,
+                    )));
+                }
+
                 Ok(node::UnaryOp::new(UnaryOp::Delete, val).into())
             }
             TokenKind::Keyword(Keyword::Void) => {
@@ -104,6 +113,11 @@ where
                 cursor.next(interner)?.expect("! token vanished"); // Consume the token.
                 Ok(node::UnaryOp::new(UnaryOp::Not, self.parse(cursor, interner)?).into())
             }
+            TokenKind::Keyword(Keyword::Await) if self.allow_await.0 => {
+                AwaitExpression::new(self.allow_yield)
+                    .parse(cursor, interner)
+                    .map(Node::from)
+            }
             _ => UpdateExpression::new(self.allow_yield, self.allow_await).parse(cursor, interner),
         }
     }
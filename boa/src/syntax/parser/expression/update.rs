@@ -64,23 +64,41 @@ where
                 cursor
                     .next(interner)?
                     .expect("Punctuator::Inc token disappeared");
-                return Ok(node::UnaryOp::new(
-                    UnaryOp::IncrementPre,
-                    UnaryExpression::new(self.allow_yield, self.allow_await)
-                        .parse(cursor, interner)?,
-                )
-                .into());
+                let position = cursor
+                    .peek(0, interner)?
+                    .ok_or(ParseError::AbruptEnd)?
+                    .span()
+                    .start();
+                let target = UnaryExpression::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?;
+                // https://tc39.es/ecma262/#sec-update-expressions-static-semantics-early-errors
+                if !is_simple_assignment_target(&target, cursor.strict_mode()) {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        "Invalid left-hand side in assignment".into(),
+                        position,
+                    )));
+                }
+                return Ok(node::UnaryOp::new(UnaryOp::IncrementPre, target).into());
             }
             TokenKind::Punctuator(Punctuator::Dec) => {
                 cursor
                     .next(interner)?
                     .expect("Punctuator::Dec token disappeared");
-                return Ok(node::UnaryOp::new(
-                    UnaryOp::DecrementPre,
-                    UnaryExpression::new(self.allow_yield, self.allow_await)
-                        .parse(cursor, interner)?,
-                )
-                .into());
+                let position = cursor
+                    .peek(0, interner)?
+                    .ok_or(ParseError::AbruptEnd)?
+                    .span()
+                    .start();
+                let target = UnaryExpression::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?;
+                // https://tc39.es/ecma262/#sec-update-expressions-static-semantics-early-errors
+                if !is_simple_assignment_target(&target, cursor.strict_mode()) {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        "Invalid left-hand side in assignment".into(),
+                        position,
+                    )));
+                }
+                return Ok(node::UnaryOp::new(UnaryOp::DecrementPre, target).into());
             }
             _ => {}
         }
@@ -96,17 +114,7 @@ where
                         .next(interner)?
                         .expect("Punctuator::Inc token disappeared");
                     // https://tc39.es/ecma262/#sec-update-expressions-static-semantics-early-errors
-                    let ok = match &lhs {
-                        Node::Identifier(_) if !strict => true,
-                        Node::Identifier(ident)
-                            if ![Sym::EVAL, Sym::ARGUMENTS].contains(&ident.sym()) =>
-                        {
-                            true
-                        }
-                        Node::GetConstField(_) | Node::GetField(_) => true,
-                        _ => false,
-                    };
-                    if !ok {
+                    if !is_simple_assignment_target(&lhs, strict) {
                         return Err(ParseError::lex(LexError::Syntax(
                             "Invalid left-hand side in assignment".into(),
                             token_start,
@@ -120,17 +128,7 @@ where
                         .next(interner)?
                         .expect("Punctuator::Dec token disappeared");
                     // https://tc39.es/ecma262/#sec-update-expressions-static-semantics-early-errors
-                    let ok = match &lhs {
-                        Node::Identifier(_) if !strict => true,
-                        Node::Identifier(ident)
-                            if ![Sym::EVAL, Sym::ARGUMENTS].contains(&ident.sym()) =>
-                        {
-                            true
-                        }
-                        Node::GetConstField(_) | Node::GetField(_) => true,
-                        _ => false,
-                    };
-                    if !ok {
+                    if !is_simple_assignment_target(&lhs, strict) {
                         return Err(ParseError::lex(LexError::Syntax(
                             "Invalid left-hand side in assignment".into(),
                             token_start,
@@ -146,3 +144,22 @@ where
         Ok(lhs)
     }
 }
+
+/// Returns true if the operand of an update expression is a simple assignment target.
+///
+/// In strict mode code, `eval` and `arguments` can't be assigned to.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-assignmenttargettype
+fn is_simple_assignment_target(node: &Node, strict: bool) -> bool {
+    match node {
+        Node::Identifier(ident) => !strict || ![Sym::EVAL, Sym::ARGUMENTS].contains(&ident.sym()),
+        Node::GetConstField(_)
+        | Node::GetField(_)
+        | Node::GetPrivateField(_)
+        | Node::GetSuperField(_) => true,
+        _ => false,
+    }
+}
@@ -12,7 +12,7 @@ mod tests;
 
 use crate::{
     syntax::{
-        ast::{node, node::declaration::Declaration, Punctuator},
+        ast::{node, node::declaration::Declaration, Position, Punctuator},
         lexer::{Error as LexError, InputElement, TokenKind},
         parser::{
             expression::Initializer,
@@ -35,6 +35,38 @@ pub(in crate::syntax::parser) struct FormalParameterList {
     pub(in crate::syntax::parser) has_duplicates: bool,
 }
 
+impl FormalParameterList {
+    /// Returns `true` if a parameter is named `eval` or `arguments`, which isn't allowed in strict
+    /// mode code.
+    fn has_eval_or_arguments(&self) -> bool {
+        self.parameters
+            .iter()
+            .flat_map(node::FormalParameter::names)
+            .any(|name| name == Sym::EVAL || name == Sym::ARGUMENTS)
+    }
+
+    /// Checks that a function whose code is strict doesn't bind `eval` or `arguments`, either as
+    /// its name or as one of these parameters.
+    ///
+    /// `strict` must be `true` if the function body contains a `"use strict"` directive or if the
+    /// function is otherwise strict mode code.
+    pub(in crate::syntax::parser) fn check_strict_bindings(
+        &self,
+        strict: bool,
+        name: Option<Sym>,
+        position: Position,
+    ) -> Result<(), ParseError> {
+        let eval_or_arguments_name = matches!(name, Some(Sym::EVAL | Sym::ARGUMENTS));
+        if strict && (eval_or_arguments_name || self.has_eval_or_arguments()) {
+            return Err(ParseError::lex(LexError::Syntax(
+                "Unexpected eval or arguments in strict mode".into(),
+                position,
+            )));
+        }
+        Ok(())
+    }
+}
+
 /// Formal parameters parsing.
 ///
 /// More information:
@@ -426,20 +458,10 @@ where
         let _timer = BoaProfiler::global().start_event("FunctionStatementList", "Parsing");
 
         let global_strict_mode = cursor.strict_mode();
-        let mut strict = false;
 
         if let Some(tk) = cursor.peek(0, interner)? {
-            match tk.kind() {
-                TokenKind::Punctuator(Punctuator::CloseBlock) => {
-                    return Ok(Vec::new().into());
-                }
-                TokenKind::StringLiteral(string)
-                    if interner.resolve_expect(*string) == "use strict" =>
-                {
-                    cursor.set_strict_mode(true);
-                    strict = true;
-                }
-                _ => {}
+            if tk.kind() == &TokenKind::Punctuator(Punctuator::CloseBlock) {
+                return Ok(Vec::new().into());
             }
         }
 
@@ -447,6 +469,7 @@ where
             self.allow_yield,
             self.allow_await,
             true,
+            false,
             true,
             &FUNCTION_BREAK_TOKENS,
         )
@@ -455,8 +478,6 @@ where
         // Reset strict mode back to the global scope.
         cursor.set_strict_mode(global_strict_mode);
 
-        let mut statement_list = statement_list?;
-        statement_list.set_strict(strict);
-        Ok(statement_list)
+        statement_list
     }
 }
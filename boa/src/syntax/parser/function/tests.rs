@@ -5,7 +5,10 @@ use crate::{
             Identifier, Node, Return,
         },
         ast::op::NumOp,
-        parser::{tests::check_parser, Parser},
+        parser::{
+            tests::{check_invalid, check_parser},
+            Parser,
+        },
     },
     Interner,
 };
@@ -65,6 +68,18 @@ fn check_duplicates_strict_on() {
     assert!(res.is_err());
 }
 
+/// Checks that duplicate parameter names are an error in strict mode, arrow functions and
+/// functions with non-simple parameter lists.
+#[test]
+fn check_duplicates_early_errors() {
+    check_invalid("function foo(a, a) { 'use strict'; }");
+    check_invalid("(a, a) => {}");
+    check_invalid("function foo(a, ...a) {}");
+    check_invalid("function foo(a = 1, a) {}");
+    check_invalid("({ foo(a, a) {} })");
+    check_invalid("class A { foo(a, a) {} }");
+}
+
 /// Checks basic function declaration parsing with automatic semicolon insertion.
 #[test]
 fn check_basic_semicolon_insertion() {
@@ -632,3 +647,17 @@ fn check_arrow_assignment_3arg_nobrackets() {
         &mut interner,
     );
 }
+
+/// Checks that only bindings are accepted in the parameters of an arrow function.
+#[test]
+fn check_arrow_invalid_parameters() {
+    check_invalid("(a.b, c) => {}");
+    check_invalid("f(a, b) => {}");
+    check_invalid("(a, b + 1) => {}");
+    check_invalid("(a, ...b = 1) => {}");
+    check_invalid("(a, b)\n=> {}");
+    check_invalid("[a, b] => 1");
+    check_invalid("((a)) => 1");
+    check_invalid("(a, (b)) => 1");
+    check_invalid("(a, ...(b)) => 1");
+}
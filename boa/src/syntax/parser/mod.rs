@@ -4,13 +4,14 @@ mod cursor;
 pub mod error;
 mod expression;
 mod function;
+mod module;
 mod statement;
 #[cfg(test)]
 mod tests;
 
 pub use self::error::{ParseError, ParseResult};
 use crate::{
-    syntax::{ast::node::StatementList, lexer::TokenKind},
+    syntax::ast::node::{ModuleItemList, StatementList},
     Interner,
 };
 
@@ -111,6 +112,15 @@ impl<R> Parser<R> {
     {
         Script.parse(&mut self.cursor, interner)
     }
+
+    /// Parses the source code of a module, which is always strict mode code.
+    pub fn parse_module(&mut self, interner: &mut Interner) -> Result<ModuleItemList, ParseError>
+    where
+        R: Read,
+    {
+        self.cursor.set_strict_mode(true);
+        Module.parse(&mut self.cursor, interner)
+    }
 }
 
 /// Parses a full script.
@@ -134,22 +144,7 @@ where
         interner: &mut Interner,
     ) -> Result<Self::Output, ParseError> {
         match cursor.peek(0, interner)? {
-            Some(tok) => {
-                let mut strict = false;
-                match tok.kind() {
-                    // Set the strict mode
-                    TokenKind::StringLiteral(string)
-                        if interner.resolve_expect(*string) == "use strict" =>
-                    {
-                        cursor.set_strict_mode(true);
-                        strict = true;
-                    }
-                    _ => {}
-                }
-                let mut statement_list = ScriptBody.parse(cursor, interner)?;
-                statement_list.set_strict(strict);
-                Ok(statement_list)
-            }
+            Some(_) => ScriptBody.parse(cursor, interner),
             None => Ok(StatementList::from(Vec::new())),
         }
     }
@@ -175,6 +170,30 @@ where
         cursor: &mut Cursor<R>,
         interner: &mut Interner,
     ) -> Result<Self::Output, ParseError> {
-        self::statement::StatementList::new(false, false, false, false, &[]).parse(cursor, interner)
+        statement::StatementList::new(false, false, false, false, true, &[]).parse(cursor, interner)
+    }
+}
+
+/// Parses a full module.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-Module
+#[derive(Debug, Clone, Copy)]
+pub struct Module;
+
+impl<R> TokenParser<R> for Module
+where
+    R: Read,
+{
+    type Output = ModuleItemList;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        module::ModuleItemList.parse(cursor, interner)
     }
 }
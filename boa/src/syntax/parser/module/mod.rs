@@ -0,0 +1,572 @@
+//! Module parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Modules
+//! [spec]: https://tc39.es/ecma262/#sec-modules
+
+#[cfg(test)]
+mod tests;
+
+use super::{
+    expression::AssignmentExpression,
+    statement::{
+        declared_names, redeclaration_error, BindingIdentifier, ClassTail, Declaration,
+        HoistableDeclaration, StatementListItem, VariableStatement,
+    },
+    Cursor, ParseError, TokenParser,
+};
+use crate::{
+    syntax::{
+        ast::{
+            node::{self, ExportSpecifier, ImportKind, ImportSpecifier},
+            Keyword, Node, Position, Punctuator,
+        },
+        lexer::{Error as LexError, Token, TokenKind},
+    },
+    BoaProfiler,
+};
+use boa_interner::{Interner, Sym};
+use std::{collections::HashSet, io::Read};
+
+/// Parses a module item list.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItemList
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ModuleItemList;
+
+impl<R> TokenParser<R> for ModuleItemList
+where
+    R: Read,
+{
+    type Output = node::ModuleItemList;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ModuleItemList", "Parsing");
+        let mut items = Vec::new();
+
+        while let Some(token) = cursor.peek(0, interner)? {
+            let position = token.span().start();
+            let item = ModuleItem.parse(cursor, interner)?;
+            items.push((item, position));
+
+            // move the cursor forward for any consecutive semicolon.
+            while cursor.next_if(Punctuator::Semicolon, interner)?.is_some() {}
+        }
+
+        // Module code is strict, and its top level declarations are all lexically scoped, like
+        // the declarations of a block.
+        let declarations = items.iter().filter_map(|(item, _)| match item {
+            node::ModuleItem::StatementListItem(node)
+            | node::ModuleItem::ExportDeclaration(
+                node::ExportDeclaration::Declaration(node)
+                | node::ExportDeclaration::DefaultDeclaration(node),
+            ) => Some(node),
+            _ => None,
+        });
+        let (mut lexically_declared_names, var_declared_names) =
+            declared_names(declarations, true, cursor, interner)?;
+
+        // https://tc39.es/ecma262/#sec-module-semantics-static-semantics-early-errors
+        for (item, _) in &items {
+            if let node::ModuleItem::ImportDeclaration(import) = item {
+                for name in import.bound_names() {
+                    if var_declared_names.contains(&name) || !lexically_declared_names.insert(name)
+                    {
+                        return Err(redeclaration_error(name, cursor, interner)?);
+                    }
+                }
+            }
+        }
+
+        let mut exported_names = HashSet::new();
+        for (item, position) in &items {
+            if let node::ModuleItem::ExportDeclaration(export) = item {
+                for name in export.exported_names() {
+                    if !exported_names.insert(name) {
+                        return Err(syntax_error(
+                            format!("Duplicate export of `{}`", interner.resolve_expect(name)),
+                            *position,
+                        ));
+                    }
+                }
+                if let node::ExportDeclaration::List(specifiers) = export {
+                    for specifier in specifiers.iter() {
+                        let name = specifier.private_name();
+                        if !lexically_declared_names.contains(&name)
+                            && !var_declared_names.contains(&name)
+                        {
+                            return Err(syntax_error(
+                                format!(
+                                    "Export of undeclared binding `{}`",
+                                    interner.resolve_expect(name)
+                                ),
+                                *position,
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        let (items, positions): (Vec<_>, Vec<_>) = items.into_iter().unzip();
+        let mut list = node::ModuleItemList::from(items);
+        list.set_positions(positions.into_boxed_slice());
+        Ok(list)
+    }
+}
+
+/// Creates a syntax error with a message that is only known at runtime.
+fn syntax_error(message: String, position: Position) -> ParseError {
+    ParseError::lex(LexError::Syntax(message.into(), position))
+}
+
+/// Parses a module item.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItem
+#[derive(Debug, Clone, Copy)]
+struct ModuleItem;
+
+impl<R> TokenParser<R> for ModuleItem
+where
+    R: Read,
+{
+    type Output = node::ModuleItem;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+
+        match tok.kind() {
+            TokenKind::Keyword(Keyword::Import) => {
+                ImportDeclaration.parse(cursor, interner).map(Into::into)
+            }
+            TokenKind::Keyword(Keyword::Export) => {
+                ExportDeclaration.parse(cursor, interner).map(Into::into)
+            }
+            // The top level of a module can `await`.
+            _ => StatementListItem::new(false, true, false, false)
+                .parse(cursor, interner)
+                .map(Into::into),
+        }
+    }
+}
+
+/// Parses an import declaration.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
+/// [spec]: https://tc39.es/ecma262/#prod-ImportDeclaration
+#[derive(Debug, Clone, Copy)]
+struct ImportDeclaration;
+
+impl<R> TokenParser<R> for ImportDeclaration
+where
+    R: Read,
+{
+    type Output = node::ImportDeclaration;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ImportDeclaration", "Parsing");
+        cursor.expect(Keyword::Import, "import declaration", interner)?;
+
+        let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+        if let TokenKind::StringLiteral(specifier) = *tok.kind() {
+            cursor.next(interner)?;
+            cursor.expect_semicolon("import declaration", interner)?;
+            return Ok(node::ImportDeclaration::new(
+                None,
+                ImportKind::DefaultOrUnnamed,
+                specifier,
+            ));
+        }
+
+        let default = match tok.kind() {
+            TokenKind::Identifier(_) | TokenKind::Keyword(Keyword::Yield | Keyword::Await) => {
+                let binding = BindingIdentifier::new(false, false).parse(cursor, interner)?;
+                if cursor.next_if(Punctuator::Comma, interner)?.is_none() {
+                    let specifier = FromClause.parse(cursor, interner)?;
+                    cursor.expect_semicolon("import declaration", interner)?;
+                    return Ok(node::ImportDeclaration::new(
+                        binding,
+                        ImportKind::DefaultOrUnnamed,
+                        specifier,
+                    ));
+                }
+                Some(binding)
+            }
+            _ => None,
+        };
+
+        let tok = cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?;
+        let kind = match tok.kind() {
+            TokenKind::Punctuator(Punctuator::Mul) => {
+                expect_contextual_keyword("as", "import declaration", cursor, interner)?;
+                ImportKind::Namespace(BindingIdentifier::new(false, false).parse(cursor, interner)?)
+            }
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                let mut specifiers = Vec::new();
+                while cursor.next_if(Punctuator::CloseBlock, interner)?.is_none() {
+                    let start = cursor
+                        .peek(0, interner)?
+                        .ok_or(ParseError::AbruptEnd)?
+                        .span()
+                        .start();
+                    let (export_name, is_identifier) = ModuleExportName.parse(cursor, interner)?;
+                    let binding = if next_is_contextual_keyword("as", cursor, interner)? {
+                        cursor.next(interner)?;
+                        BindingIdentifier::new(false, false).parse(cursor, interner)?
+                    } else if is_identifier {
+                        export_name
+                    } else {
+                        return Err(ParseError::general(
+                            "an import of a string or a reserved word needs a binding name",
+                            start,
+                        ));
+                    };
+                    specifiers.push(ImportSpecifier::new(binding, export_name));
+
+                    if cursor.next_if(Punctuator::Comma, interner)?.is_none() {
+                        cursor.expect(Punctuator::CloseBlock, "import declaration", interner)?;
+                        break;
+                    }
+                }
+                ImportKind::Named(specifiers.into_boxed_slice())
+            }
+            _ => {
+                return Err(ParseError::expected(
+                    ["*".to_owned(), "{".to_owned()],
+                    tok.to_string(interner),
+                    tok.span(),
+                    "import declaration",
+                ))
+            }
+        };
+
+        let specifier = FromClause.parse(cursor, interner)?;
+        cursor.expect_semicolon("import declaration", interner)?;
+        Ok(node::ImportDeclaration::new(default, kind, specifier))
+    }
+}
+
+/// Parses an export declaration.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/export
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+#[derive(Debug, Clone, Copy)]
+struct ExportDeclaration;
+
+impl<R> TokenParser<R> for ExportDeclaration
+where
+    R: Read,
+{
+    type Output = node::ExportDeclaration;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ExportDeclaration", "Parsing");
+        cursor.expect(Keyword::Export, "export declaration", interner)?;
+
+        let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+        let export = match tok.kind() {
+            TokenKind::Punctuator(Punctuator::Mul) => {
+                cursor.next(interner)?;
+                let alias = if next_is_contextual_keyword("as", cursor, interner)? {
+                    cursor.next(interner)?;
+                    Some(ModuleExportName.parse(cursor, interner)?.0)
+                } else {
+                    None
+                };
+                let specifier = FromClause.parse(cursor, interner)?;
+                cursor.expect_semicolon("export declaration", interner)?;
+                node::ExportDeclaration::ReExportAll { alias, specifier }
+            }
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                cursor.next(interner)?;
+                let mut specifiers = Vec::new();
+                // The position of the first local name that can't be referenced, which is only
+                // allowed when re-exporting.
+                let mut invalid_local = None;
+                while cursor.next_if(Punctuator::CloseBlock, interner)?.is_none() {
+                    let start = cursor
+                        .peek(0, interner)?
+                        .ok_or(ParseError::AbruptEnd)?
+                        .span()
+                        .start();
+                    let (private_name, is_identifier) = ModuleExportName.parse(cursor, interner)?;
+                    if !is_identifier && invalid_local.is_none() {
+                        invalid_local = Some(start);
+                    }
+                    let alias = if next_is_contextual_keyword("as", cursor, interner)? {
+                        cursor.next(interner)?;
+                        ModuleExportName.parse(cursor, interner)?.0
+                    } else {
+                        private_name
+                    };
+                    specifiers.push(ExportSpecifier::new(alias, private_name));
+
+                    if cursor.next_if(Punctuator::Comma, interner)?.is_none() {
+                        cursor.expect(Punctuator::CloseBlock, "export declaration", interner)?;
+                        break;
+                    }
+                }
+                let specifiers = specifiers.into_boxed_slice();
+
+                if next_is_contextual_keyword("from", cursor, interner)? {
+                    let specifier = FromClause.parse(cursor, interner)?;
+                    cursor.expect_semicolon("export declaration", interner)?;
+                    node::ExportDeclaration::ReExport {
+                        specifiers,
+                        specifier,
+                    }
+                } else if let Some(position) = invalid_local {
+                    return Err(ParseError::general(
+                        "an export of a string or a reserved word needs a `from` clause",
+                        position,
+                    ));
+                } else {
+                    cursor.expect_semicolon("export declaration", interner)?;
+                    node::ExportDeclaration::List(specifiers)
+                }
+            }
+            TokenKind::Keyword(Keyword::Var) => node::ExportDeclaration::Declaration(
+                VariableStatement::new(false, true)
+                    .parse(cursor, interner)?
+                    .into(),
+            ),
+            TokenKind::Keyword(
+                Keyword::Let | Keyword::Const | Keyword::Function | Keyword::Async | Keyword::Class,
+            ) => node::ExportDeclaration::Declaration(
+                Declaration::new(false, true, true).parse(cursor, interner)?,
+            ),
+            TokenKind::Keyword(Keyword::Default) => {
+                cursor.next(interner)?;
+                ExportDefault.parse(cursor, interner)?
+            }
+            _ => {
+                return Err(ParseError::expected(
+                    [
+                        "*".to_owned(),
+                        "{".to_owned(),
+                        "declaration".to_owned(),
+                        "default".to_owned(),
+                    ],
+                    tok.to_string(interner),
+                    tok.span(),
+                    "export declaration",
+                ))
+            }
+        };
+
+        Ok(export)
+    }
+}
+
+/// Parses the default export of an `export default` declaration.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+#[derive(Debug, Clone, Copy)]
+struct ExportDefault;
+
+impl<R> TokenParser<R> for ExportDefault
+where
+    R: Read,
+{
+    type Output = node::ExportDeclaration;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let is_async_function = matches!(
+            cursor.peek(1, interner)?.map(Token::kind),
+            Some(TokenKind::Keyword(Keyword::Function))
+        );
+        let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+
+        match tok.kind() {
+            TokenKind::Keyword(Keyword::Function) => {
+                Ok(node::ExportDeclaration::DefaultDeclaration(
+                    HoistableDeclaration::new(false, true, true).parse(cursor, interner)?,
+                ))
+            }
+            TokenKind::Keyword(Keyword::Async) if is_async_function => {
+                Ok(node::ExportDeclaration::DefaultDeclaration(
+                    HoistableDeclaration::new(false, true, true).parse(cursor, interner)?,
+                ))
+            }
+            TokenKind::Keyword(Keyword::Class) => {
+                cursor.next(interner)?;
+                let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+                let name = match tok.kind() {
+                    TokenKind::Identifier(_)
+                    | TokenKind::Keyword(Keyword::Yield | Keyword::Await) => {
+                        BindingIdentifier::new(false, false).parse(cursor, interner)?
+                    }
+                    _ => Sym::DEFAULT,
+                };
+                let class = ClassTail::new(name, false, true).parse(cursor, interner)?;
+                Ok(node::ExportDeclaration::DefaultDeclaration(
+                    Node::ClassDecl(class),
+                ))
+            }
+            _ => {
+                // The exported value is bound to `default`, which also names anonymous
+                // functions and classes.
+                let expr = AssignmentExpression::new(Sym::DEFAULT, true, false, true)
+                    .parse(cursor, interner)?;
+                cursor.expect_semicolon("export declaration", interner)?;
+                Ok(node::ExportDeclaration::DefaultAssignmentExpression(expr))
+            }
+        }
+    }
+}
+
+/// Parses the name of an import or export, which can be a string literal.
+///
+/// Returns the name, and whether it is an identifier that can be referenced.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleExportName
+#[derive(Debug, Clone, Copy)]
+struct ModuleExportName;
+
+impl<R> TokenParser<R> for ModuleExportName
+where
+    R: Read,
+{
+    type Output = (Sym, bool);
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let tok = cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?;
+
+        match tok.kind() {
+            TokenKind::Identifier(name) => Ok((*name, true)),
+            TokenKind::StringLiteral(name) => Ok((*name, false)),
+            TokenKind::Keyword(keyword) => Ok((keyword.to_sym(interner), false)),
+            TokenKind::BooleanLiteral(value) => Ok((
+                interner.get_or_intern_static(if *value { "true" } else { "false" }),
+                false,
+            )),
+            TokenKind::NullLiteral => Ok((Sym::NULL, false)),
+            _ => Err(ParseError::expected(
+                ["identifier".to_owned(), "string literal".to_owned()],
+                tok.to_string(interner),
+                tok.span(),
+                "module export name",
+            )),
+        }
+    }
+}
+
+/// Parses the `from` clause of an import or export declaration, returning the module
+/// specifier.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-FromClause
+#[derive(Debug, Clone, Copy)]
+struct FromClause;
+
+impl<R> TokenParser<R> for FromClause
+where
+    R: Read,
+{
+    type Output = Sym;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        expect_contextual_keyword("from", "from clause", cursor, interner)?;
+
+        let tok = cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?;
+        match tok.kind() {
+            TokenKind::StringLiteral(specifier) => Ok(*specifier),
+            _ => Err(ParseError::expected(
+                ["string literal".to_owned()],
+                tok.to_string(interner),
+                tok.span(),
+                "from clause",
+            )),
+        }
+    }
+}
+
+/// Checks if the next token is the contextual keyword `keyword`, which is lexed as an
+/// identifier.
+fn next_is_contextual_keyword<R: Read>(
+    keyword: &str,
+    cursor: &mut Cursor<R>,
+    interner: &mut Interner,
+) -> Result<bool, ParseError> {
+    Ok(match cursor.peek(0, interner)? {
+        Some(token) => match token.kind() {
+            TokenKind::Identifier(name) => interner.resolve_expect(*name) == keyword,
+            _ => false,
+        },
+        None => false,
+    })
+}
+
+/// Consumes the next token, returning an error if it is not the contextual keyword `keyword`.
+fn expect_contextual_keyword<R: Read>(
+    keyword: &'static str,
+    context: &'static str,
+    cursor: &mut Cursor<R>,
+    interner: &mut Interner,
+) -> Result<(), ParseError> {
+    if next_is_contextual_keyword(keyword, cursor, interner)? {
+        cursor.next(interner)?;
+        return Ok(());
+    }
+
+    let tok = cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?;
+    Err(ParseError::expected(
+        [keyword.to_owned()],
+        tok.to_string(interner),
+        tok.span(),
+        context,
+    ))
+}
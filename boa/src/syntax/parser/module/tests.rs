@@ -0,0 +1,232 @@
+//! Tests for the parsing of modules.
+
+use crate::{
+    syntax::{
+        ast::{
+            node::{
+                AwaitExpr, Declaration, DeclarationList, ExportDeclaration, ExportSpecifier,
+                FunctionDecl, ImportDeclaration, ImportKind, ImportSpecifier, ModuleItem,
+                ModuleItemList, Node,
+            },
+            Const,
+        },
+        Parser,
+    },
+    Interner,
+};
+use boa_interner::Sym;
+
+/// Checks that the given module source code gives the expected module items.
+#[track_caller]
+fn check_module<L>(js: &str, items: L, interner: &mut Interner)
+where
+    L: Into<Box<[ModuleItem]>>,
+{
+    assert_eq!(
+        Parser::new(js.as_bytes(), false)
+            .parse_module(interner)
+            .expect("failed to parse"),
+        ModuleItemList::from(items)
+    );
+}
+
+/// Checks that the given module source code gives a parse error.
+#[track_caller]
+fn check_invalid_module(js: &str) {
+    let mut interner = Interner::default();
+    assert!(Parser::new(js.as_bytes(), false)
+        .parse_module(&mut interner)
+        .is_err());
+}
+
+#[test]
+fn import_declarations() {
+    let mut interner = Interner::default();
+    let specifier = interner.get_or_intern_static("./lib.js");
+    let a = interner.get_or_intern_static("a");
+    let b = interner.get_or_intern_static("b");
+    let c = interner.get_or_intern_static("c");
+    let d = interner.get_or_intern_static("d");
+    let e = interner.get_or_intern_static("e");
+    let f = interner.get_or_intern_static("f");
+    let ns = interner.get_or_intern_static("ns");
+    check_module(
+        r#"
+        import "./lib.js";
+        import a from "./lib.js";
+        import b, * as ns from "./lib.js";
+        import { c, d as e, "default" as f } from "./lib.js";
+        "#,
+        vec![
+            ImportDeclaration::new(None, ImportKind::DefaultOrUnnamed, specifier).into(),
+            ImportDeclaration::new(a, ImportKind::DefaultOrUnnamed, specifier).into(),
+            ImportDeclaration::new(b, ImportKind::Namespace(ns), specifier).into(),
+            ImportDeclaration::new(
+                None,
+                ImportKind::Named(
+                    vec![
+                        ImportSpecifier::new(c, c),
+                        ImportSpecifier::new(e, d),
+                        ImportSpecifier::new(f, Sym::DEFAULT),
+                    ]
+                    .into(),
+                ),
+                specifier,
+            )
+            .into(),
+        ],
+        &mut interner,
+    );
+}
+
+#[test]
+fn export_declarations() {
+    let mut interner = Interner::default();
+    let specifier = interner.get_or_intern_static("./lib.js");
+    let a = interner.get_or_intern_static("a");
+    let b = interner.get_or_intern_static("b");
+    let c = interner.get_or_intern_static("c");
+    let f = interner.get_or_intern_static("f");
+    let ns = interner.get_or_intern_static("ns");
+    check_module(
+        r#"
+        export * from "./lib.js";
+        export * as ns from "./lib.js";
+        export { c, b as default } from "./lib.js";
+        export let a = 1;
+        export { a as b };
+        export function f() {}
+        "#,
+        vec![
+            ExportDeclaration::ReExportAll {
+                alias: None,
+                specifier,
+            }
+            .into(),
+            ExportDeclaration::ReExportAll {
+                alias: Some(ns),
+                specifier,
+            }
+            .into(),
+            ExportDeclaration::ReExport {
+                specifiers: vec![
+                    ExportSpecifier::new(c, c),
+                    ExportSpecifier::new(Sym::DEFAULT, b),
+                ]
+                .into(),
+                specifier,
+            }
+            .into(),
+            ExportDeclaration::Declaration(
+                DeclarationList::Let(
+                    vec![Declaration::new_with_identifier(
+                        a,
+                        Some(Const::from(1).into()),
+                    )]
+                    .into(),
+                )
+                .into(),
+            )
+            .into(),
+            ExportDeclaration::List(vec![ExportSpecifier::new(b, a)].into()).into(),
+            ExportDeclaration::Declaration(FunctionDecl::new(f, vec![], vec![]).into()).into(),
+        ],
+        &mut interner,
+    );
+}
+
+#[test]
+fn export_default() {
+    let mut interner = Interner::default();
+    check_module(
+        "export default function () {}",
+        vec![ExportDeclaration::DefaultDeclaration(
+            FunctionDecl::new(Sym::DEFAULT, vec![], vec![]).into(),
+        )
+        .into()],
+        &mut interner,
+    );
+    check_module(
+        "export default 1;",
+        vec![ExportDeclaration::DefaultAssignmentExpression(Const::from(1).into()).into()],
+        &mut interner,
+    );
+}
+
+#[test]
+fn module_early_errors() {
+    // Duplicate exports.
+    check_invalid_module("export let a = 1; export { a };");
+    check_invalid_module("export default 1; export default 2;");
+    // Import bindings are lexical declarations.
+    check_invalid_module("import { a } from 'lib'; let a;");
+    check_invalid_module("import { a } from 'lib'; var a;");
+    check_invalid_module("import a, { a } from 'lib';");
+    // Exported local bindings must be declared.
+    check_invalid_module("export { a };");
+    check_invalid_module("let a; export { 'a' };");
+    // Module code is strict.
+    check_invalid_module("with (a) {}");
+    // Imports and exports are only allowed at the top level.
+    check_invalid_module("{ export let a; }");
+    check_invalid_module("function f() { import a from 'lib'; }");
+    // `await` can't be a binding at the top level, where it is an operator.
+    check_invalid_module("let await;");
+}
+
+#[test]
+fn top_level_await() {
+    let mut interner = Interner::default();
+    let a = interner.get_or_intern_static("a");
+    check_module(
+        "export const a = await 1;",
+        vec![ExportDeclaration::Declaration(
+            DeclarationList::Const(
+                vec![Declaration::new_with_identifier(
+                    a,
+                    Node::from(AwaitExpr::from(Node::from(Const::from(1)))),
+                )]
+                .into(),
+            )
+            .into(),
+        )
+        .into()],
+        &mut interner,
+    );
+}
+
+#[test]
+fn await_in_nested_functions() {
+    // The top level `await` of a module doesn't extend to the functions in it.
+    for code in [
+        "function f() { await 1; }",
+        "export function f() { await 1; }",
+        "export default function () { await 1; }",
+        "(function () { await 1; });",
+        "function* g() { await 1; }",
+        "({ m() { await 1; } });",
+        "({ get x() { return await 1; } });",
+        "class C { m() { await 1; } }",
+        "class C { static m() { await 1; } }",
+        "() => { await 1; };",
+        "{ function f() { await 1; } }",
+        "if (true) { const f = function () { await 1; }; }",
+    ] {
+        let mut interner = Interner::default();
+        assert!(
+            Parser::new(code.as_bytes(), false)
+                .parse_module(&mut interner)
+                .is_err(),
+            "`{code}` must be a syntax error in a module"
+        );
+    }
+
+    // Async functions can still await.
+    let mut interner = Interner::default();
+    assert!(Parser::new(
+        "async function f() { await 1; } ({ async m() { await 1; } }); await 1;".as_bytes(),
+        false
+    )
+    .parse_module(&mut interner)
+    .is_ok());
+}
@@ -90,6 +90,7 @@ where
             self.allow_await,
             self.allow_return,
             true,
+            false,
             &BLOCK_BREAK_TOKENS,
         )
         .parse(cursor, interner)
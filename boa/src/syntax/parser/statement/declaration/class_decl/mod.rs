@@ -0,0 +1,697 @@
+//! Class declaration parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/class
+//! [spec]: https://tc39.es/ecma262/#sec-class-definitions
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    syntax::{
+        ast::{
+            node::{self, Class, FunctionExpr, MethodDefinitionKind, Node},
+            Keyword, Punctuator,
+        },
+        lexer::{Error as LexError, Position, TokenKind},
+        parser::{
+            expression::{
+                AssignmentExpression, LeftHandSideExpression, PropertyDefinition, PropertyName,
+            },
+            function::{FormalParameters, FunctionBody},
+            statement::BindingIdentifier,
+            AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
+        },
+    },
+    BoaProfiler, Interner,
+};
+use boa_interner::Sym;
+use rustc_hash::FxHashMap;
+use std::{collections::hash_map::Entry, io::Read};
+
+/// Class declaration parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/class
+/// [spec]: https://tc39.es/ecma262/#prod-ClassDeclaration
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ClassDeclaration {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassDeclaration {
+    /// Creates a new `ClassDeclaration` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassDeclaration
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("ClassDeclaration", "Parsing");
+
+        cursor.expect(Keyword::Class, "class declaration", interner)?;
+        // All parts of a class declaration are strict mode code.
+        let strict = cursor.strict_mode();
+        cursor.set_strict_mode(true);
+        let name =
+            BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor, interner);
+        cursor.set_strict_mode(strict);
+        let name = name?;
+
+        let class =
+            ClassTail::new(name, self.allow_yield, self.allow_await).parse(cursor, interner)?;
+
+        Ok(Node::ClassDecl(class))
+    }
+}
+
+/// Class tail parsing.
+///
+/// Parses the optional `extends` clause and the class body of a class declaration or
+/// expression.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassTail
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct ClassTail {
+    name: Option<Sym>,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassTail {
+    /// Creates a new `ClassTail` parser.
+    pub(in crate::syntax::parser) fn new<N, Y, A>(name: N, allow_yield: Y, allow_await: A) -> Self
+    where
+        N: Into<Option<Sym>>,
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            name: name.into(),
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassTail
+where
+    R: Read,
+{
+    type Output = Class;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassTail", "Parsing");
+
+        // ClassHeritage[Yield, Await] : extends LeftHandSideExpression[?Yield, ?Await]
+        let super_ref = if cursor.next_if(Keyword::Extends, interner)?.is_some() {
+            Some(
+                LeftHandSideExpression::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?,
+            )
+        } else {
+            None
+        };
+
+        cursor.expect(Punctuator::OpenBlock, "class tail", interner)?;
+        cursor.push_private_environment();
+
+        let mut constructor = None;
+        let mut elements = Vec::new();
+        let mut private_names = FxHashMap::default();
+        loop {
+            if cursor.next_if(Punctuator::CloseBlock, interner)?.is_some() {
+                break;
+            }
+
+            // ClassElement : ;
+            if cursor.next_if(Punctuator::Semicolon, interner)?.is_some() {
+                continue;
+            }
+
+            let position = cursor
+                .peek(0, interner)?
+                .ok_or(ParseError::AbruptEnd)?
+                .span()
+                .start();
+            let element =
+                ClassElement::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
+
+            match &element {
+                node::ClassElement::MethodDefinition(
+                    kind,
+                    node::PropertyName::Literal(Sym::CONSTRUCTOR),
+                    function,
+                ) => {
+                    // Early Error: It is a Syntax Error if PropName of MethodDefinition is "constructor"
+                    // and SpecialMethod of MethodDefinition is true.
+                    if *kind != MethodDefinitionKind::Ordinary {
+                        return Err(ParseError::general(
+                            "class constructor may not be an accessor, generator or async method",
+                            position,
+                        ));
+                    }
+
+                    // Early Error: It is a Syntax Error if PrototypePropertyNameList of ClassElementList
+                    // contains more than one occurrence of "constructor".
+                    if constructor.is_some() {
+                        return Err(ParseError::general(
+                            "a class may only have one constructor",
+                            position,
+                        ));
+                    }
+
+                    constructor = Some(function.clone());
+                }
+                // Early Error: It is a Syntax Error if PropName of MethodDefinition is "prototype".
+                node::ClassElement::StaticMethodDefinition(
+                    _,
+                    node::PropertyName::Literal(Sym::PROTOTYPE),
+                    _,
+                )
+                | node::ClassElement::StaticFieldDefinition(
+                    node::PropertyName::Literal(Sym::PROTOTYPE),
+                    _,
+                ) => {
+                    return Err(ParseError::general(
+                        "classes may not have a static property named 'prototype'",
+                        position,
+                    ));
+                }
+                // Early Error: It is a Syntax Error if PropName of FieldDefinition is "constructor".
+                node::ClassElement::FieldDefinition(
+                    node::PropertyName::Literal(Sym::CONSTRUCTOR),
+                    _,
+                )
+                | node::ClassElement::StaticFieldDefinition(
+                    node::PropertyName::Literal(Sym::CONSTRUCTOR),
+                    _,
+                ) => {
+                    return Err(ParseError::general(
+                        "classes may not have a field named 'constructor'",
+                        position,
+                    ));
+                }
+                node::ClassElement::PrivateMethodDefinition(kind, name, _)
+                | node::ClassElement::PrivateStaticMethodDefinition(kind, name, _) => {
+                    declare_private_name(
+                        &mut private_names,
+                        *name,
+                        Some(*kind),
+                        matches!(
+                            element,
+                            node::ClassElement::PrivateStaticMethodDefinition(_, _, _)
+                        ),
+                        position,
+                        interner,
+                    )?;
+                    elements.push(element);
+                }
+                node::ClassElement::PrivateFieldDefinition(name, _)
+                | node::ClassElement::PrivateStaticFieldDefinition(name, _) => {
+                    declare_private_name(
+                        &mut private_names,
+                        *name,
+                        None,
+                        matches!(
+                            element,
+                            node::ClassElement::PrivateStaticFieldDefinition(_, _)
+                        ),
+                        position,
+                        interner,
+                    )?;
+                    elements.push(element);
+                }
+                _ => elements.push(element),
+            }
+        }
+
+        // Early Error: It is a Syntax Error if AllPrivateIdentifiersValid of ClassBody with the
+        // private names of the class is false.
+        cursor.pop_private_environment(&private_names)?;
+
+        Ok(Class::new(self.name, super_ref, constructor, elements))
+    }
+}
+
+/// Adds a private name to the private names declared by a class body.
+///
+/// The value of each declared name records the kind of method it was declared with, or `None` for
+/// fields, and whether it is static.
+fn declare_private_name(
+    private_names: &mut FxHashMap<Sym, (Option<MethodDefinitionKind>, bool)>,
+    name: Sym,
+    kind: Option<MethodDefinitionKind>,
+    is_static: bool,
+    position: Position,
+    interner: &mut Interner,
+) -> Result<(), ParseError> {
+    // Early Error: It is a Syntax Error if StringValue of PrivateIdentifier is "#constructor".
+    if name == interner.get_or_intern_static("#constructor") {
+        return Err(ParseError::general(
+            "classes may not have a private element named '#constructor'",
+            position,
+        ));
+    }
+
+    // Early Error: It is a Syntax Error if PrivateBoundIdentifiers of ClassElementList contains any
+    // duplicate entries, unless the name is used once for a getter and once for a setter and in
+    // no other entries, and the getter and setter are either both static or both non-static.
+    match private_names.entry(name) {
+        Entry::Vacant(entry) => {
+            entry.insert((kind, is_static));
+        }
+        Entry::Occupied(mut entry) => match (*entry.get(), kind) {
+            (
+                (Some(MethodDefinitionKind::Get), previous_static),
+                Some(MethodDefinitionKind::Set),
+            )
+            | (
+                (Some(MethodDefinitionKind::Set), previous_static),
+                Some(MethodDefinitionKind::Get),
+            ) if previous_static == is_static => {
+                // Any further declaration of the name is a duplicate.
+                entry.insert((None, is_static));
+            }
+            _ => {
+                return Err(ParseError::general(
+                    "private names may only be declared once in a class body",
+                    position,
+                ))
+            }
+        },
+    }
+
+    Ok(())
+}
+
+/// Class element parsing.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassElement
+#[derive(Debug, Clone, Copy)]
+struct ClassElement {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassElement {
+    /// Creates a new `ClassElement` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+/// Checks if a token directly following a name makes that name a field or an ordinary method,
+/// instead of a modifier like `static`, `get`, `set` or `async`.
+fn ends_element_name(kind: &TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::Punctuator(
+            Punctuator::OpenParen
+                | Punctuator::Assign
+                | Punctuator::Semicolon
+                | Punctuator::CloseBlock
+        )
+    )
+}
+
+impl<R> TokenParser<R> for ClassElement
+where
+    R: Read,
+{
+    type Output = node::ClassElement;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassElement", "Parsing");
+
+        // ClassElement : static MethodDefinition | static FieldDefinition ;
+        let token = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+        let is_static = token.kind() == &TokenKind::Identifier(Sym::STATIC)
+            && !ends_element_name(
+                cursor
+                    .peek(1, interner)?
+                    .ok_or(ParseError::AbruptEnd)?
+                    .kind(),
+            );
+        if is_static {
+            cursor.next(interner)?.expect("static token disappeared");
+        }
+
+        // The source text of a method does not include the `static` keyword.
+        let start = cursor
+            .peek(0, interner)?
+            .ok_or(ParseError::AbruptEnd)?
+            .byte_span()
+            .start();
+
+        // ClassElementName : PrivateIdentifier
+        let private_method_prefix = match cursor
+            .peek(0, interner)?
+            .ok_or(ParseError::AbruptEnd)?
+            .kind()
+        {
+            TokenKind::PrivateIdentifier(_) => Some((MethodDefinitionKind::Ordinary, 0)),
+            TokenKind::Punctuator(Punctuator::Mul) => Some((MethodDefinitionKind::Generator, 1)),
+            TokenKind::Identifier(Sym::GET) => Some((MethodDefinitionKind::Get, 1)),
+            TokenKind::Identifier(Sym::SET) => Some((MethodDefinitionKind::Set, 1)),
+            TokenKind::Keyword(Keyword::Async) => {
+                if cursor
+                    .peek(1, interner)?
+                    .ok_or(ParseError::AbruptEnd)?
+                    .kind()
+                    == &TokenKind::Punctuator(Punctuator::Mul)
+                {
+                    Some((MethodDefinitionKind::AsyncGenerator, 2))
+                } else {
+                    Some((MethodDefinitionKind::Async, 1))
+                }
+            }
+            _ => None,
+        };
+        if let Some((kind, prefix_length)) = private_method_prefix {
+            if let TokenKind::PrivateIdentifier(name) = *cursor
+                .peek(prefix_length, interner)?
+                .ok_or(ParseError::AbruptEnd)?
+                .kind()
+            {
+                for _ in 0..=prefix_length {
+                    cursor
+                        .next(interner)?
+                        .expect("class element token disappeared");
+                }
+                return PrivateClassElementTail::new(kind, name, is_static, start)
+                    .parse(cursor, interner);
+            }
+        }
+
+        let token = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+        let is_special_method = match token.kind() {
+            TokenKind::Punctuator(Punctuator::Mul) => true,
+            TokenKind::Keyword(Keyword::Async) | TokenKind::Identifier(Sym::GET | Sym::SET) => {
+                !ends_element_name(
+                    cursor
+                        .peek(1, interner)?
+                        .ok_or(ParseError::AbruptEnd)?
+                        .kind(),
+                )
+            }
+            _ => false,
+        };
+
+        // Generator, async and accessor methods are parsed the same way as in object literals.
+        if is_special_method {
+            let token = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+            let span = token.span();
+            let token = token.to_string(interner);
+            let property = PropertyDefinition::new(self.allow_yield, self.allow_await)
+                .parse(cursor, interner)?;
+            return match &property {
+                node::PropertyDefinition::MethodDefinition(kind, name, function) if is_static => {
+                    Ok(node::ClassElement::StaticMethodDefinition(
+                        *kind,
+                        name.clone(),
+                        function.clone(),
+                    ))
+                }
+                node::PropertyDefinition::MethodDefinition(kind, name, function) => Ok(
+                    node::ClassElement::MethodDefinition(*kind, name.clone(), function.clone()),
+                ),
+                _ => Err(ParseError::unexpected(token, span, "class element")),
+            };
+        }
+
+        let name = PropertyName::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
+
+        // MethodDefinition : ClassElementName ( UniqueFormalParameters ) { FunctionBody }
+        if cursor.next_if(Punctuator::OpenParen, interner)?.is_some() {
+            let function =
+                MethodTail::new(MethodDefinitionKind::Ordinary, start).parse(cursor, interner)?;
+            return Ok(if is_static {
+                node::ClassElement::StaticMethodDefinition(
+                    MethodDefinitionKind::Ordinary,
+                    name,
+                    function,
+                )
+            } else {
+                node::ClassElement::MethodDefinition(MethodDefinitionKind::Ordinary, name, function)
+            });
+        }
+
+        // FieldDefinition : ClassElementName Initializer[opt]
+        let init = if cursor.next_if(Punctuator::Assign, interner)?.is_some() {
+            let function_name = match name {
+                node::PropertyName::Literal(name) => Some(name),
+                node::PropertyName::Computed(_) => None,
+            };
+            // Field initializers are evaluated like methods, so they can use `new.target`.
+            let allow_new_target = cursor.set_allow_new_target(true);
+            let init = AssignmentExpression::new(function_name, true, false, false)
+                .parse(cursor, interner)?;
+            cursor.set_allow_new_target(allow_new_target);
+            Some(init)
+        } else {
+            None
+        };
+        cursor.expect_semicolon("class field definition", interner)?;
+
+        Ok(if is_static {
+            node::ClassElement::StaticFieldDefinition(name, init)
+        } else {
+            node::ClassElement::FieldDefinition(name, init)
+        })
+    }
+}
+
+/// Parses the rest of a class element with a private name, after the name.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassElementName
+#[derive(Debug, Clone, Copy)]
+struct PrivateClassElementTail {
+    kind: MethodDefinitionKind,
+    name: Sym,
+    is_static: bool,
+    start: usize,
+}
+
+impl PrivateClassElementTail {
+    /// Creates a new `PrivateClassElementTail` parser, given the byte offset of the start of the
+    /// element.
+    fn new(kind: MethodDefinitionKind, name: Sym, is_static: bool, start: usize) -> Self {
+        Self {
+            kind,
+            name,
+            is_static,
+            start,
+        }
+    }
+}
+
+impl<R> TokenParser<R> for PrivateClassElementTail
+where
+    R: Read,
+{
+    type Output = node::ClassElement;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        // MethodDefinition : ClassElementName ( UniqueFormalParameters ) { FunctionBody }
+        if self.kind != MethodDefinitionKind::Ordinary
+            || cursor
+                .peek(0, interner)?
+                .ok_or(ParseError::AbruptEnd)?
+                .kind()
+                == &TokenKind::Punctuator(Punctuator::OpenParen)
+        {
+            cursor.expect(Punctuator::OpenParen, "private method definition", interner)?;
+            let function = MethodTail::new(self.kind, self.start).parse(cursor, interner)?;
+            return Ok(if self.is_static {
+                node::ClassElement::PrivateStaticMethodDefinition(self.kind, self.name, function)
+            } else {
+                node::ClassElement::PrivateMethodDefinition(self.kind, self.name, function)
+            });
+        }
+
+        // FieldDefinition : ClassElementName Initializer[opt]
+        let init = if cursor.next_if(Punctuator::Assign, interner)?.is_some() {
+            let allow_new_target = cursor.set_allow_new_target(true);
+            let init =
+                AssignmentExpression::new(self.name, true, false, false).parse(cursor, interner)?;
+            cursor.set_allow_new_target(allow_new_target);
+            Some(init)
+        } else {
+            None
+        };
+        cursor.expect_semicolon("private field definition", interner)?;
+
+        Ok(if self.is_static {
+            node::ClassElement::PrivateStaticFieldDefinition(self.name, init)
+        } else {
+            node::ClassElement::PrivateFieldDefinition(self.name, init)
+        })
+    }
+}
+
+/// Parses the parameters and the body of a class method, after its opening parenthesis.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-MethodDefinition
+#[derive(Debug, Clone, Copy)]
+struct MethodTail {
+    kind: MethodDefinitionKind,
+    start: usize,
+}
+
+impl MethodTail {
+    /// Creates a new `MethodTail` parser for the given kind of method, given the byte offset of
+    /// the start of the method in the source code.
+    fn new(kind: MethodDefinitionKind, start: usize) -> Self {
+        Self { kind, start }
+    }
+}
+
+impl<R> TokenParser<R> for MethodTail
+where
+    R: Read,
+{
+    type Output = FunctionExpr;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        let (params_yield, params_await, body_yield, body_await) = match self.kind {
+            MethodDefinitionKind::Ordinary
+            | MethodDefinitionKind::Get
+            | MethodDefinitionKind::Set => (false, false, false, false),
+            MethodDefinitionKind::Generator => (false, false, true, false),
+            MethodDefinitionKind::Async => (false, true, false, true),
+            MethodDefinitionKind::AsyncGenerator => (true, true, true, true),
+        };
+
+        let params_start_position = match cursor.peek(0, interner)? {
+            Some(token) => token.span().start(),
+            None => return Err(ParseError::AbruptEnd),
+        };
+        let allow_new_target = cursor.set_allow_new_target(true);
+        let params = FormalParameters::new(params_yield, params_await).parse(cursor, interner)?;
+        cursor.expect(Punctuator::CloseParen, "class method definition", interner)?;
+
+        match self.kind {
+            MethodDefinitionKind::Get if !params.parameters.is_empty() => {
+                return Err(ParseError::general(
+                    "getter must not have any parameters",
+                    params_start_position,
+                ));
+            }
+            MethodDefinitionKind::Set if params.parameters.len() != 1 => {
+                return Err(ParseError::general(
+                    "set method definition must have one parameter",
+                    params_start_position,
+                ));
+            }
+            _ => {}
+        }
+
+        // Early Error: UniqueFormalParameters : FormalParameters
+        if params.has_duplicates {
+            return Err(ParseError::lex(LexError::Syntax(
+                "Duplicate parameter name not allowed in this context".into(),
+                params_start_position,
+            )));
+        }
+
+        cursor.expect(Punctuator::OpenBlock, "class method definition", interner)?;
+        let body = FunctionBody::new(body_yield, body_await).parse(cursor, interner)?;
+        cursor.set_allow_new_target(allow_new_target);
+        cursor.expect(Punctuator::CloseBlock, "class method definition", interner)?;
+
+        // Early Error: It is a Syntax Error if FunctionBodyContainsUseStrict of FunctionBody is true
+        // and IsSimpleParameterList of UniqueFormalParameters is false.
+        if body.strict() && !params.is_simple {
+            return Err(ParseError::lex(LexError::Syntax(
+                "Illegal 'use strict' directive in function with non-simple parameter list".into(),
+                params_start_position,
+            )));
+        }
+
+        // Early Error: Class code is strict mode code, so the parameters can't be named `eval` or
+        // `arguments`.
+        params.check_strict_bindings(true, None, params_start_position)?;
+
+        // Early Error: It is a Syntax Error if any element of the BoundNames of UniqueFormalParameters also
+        // occurs in the LexicallyDeclaredNames of FunctionBody.
+        {
+            let lexically_declared_names = body.lexically_declared_names(interner);
+            for param in params.parameters.as_ref() {
+                for param_name in param.names() {
+                    if lexically_declared_names.contains(&param_name) {
+                        return Err(ParseError::lex(LexError::Syntax(
+                            format!(
+                                "Redeclaration of formal parameter `{}`",
+                                interner.resolve_expect(param_name)
+                            )
+                            .into(),
+                            match cursor.peek(0, interner)? {
+                                Some(token) => token.span().end(),
+                                None => Position::new(1, 1),
+                            },
+                        )));
+                    }
+                }
+            }
+        }
+
+        Ok(FunctionExpr::new(None, params.parameters, body)
+            .with_source_span(cursor.byte_span_from(self.start)))
+    }
+}
@@ -0,0 +1,202 @@
+use crate::{
+    syntax::{
+        ast::{
+            node::{
+                Call, Class, ClassElement, Declaration, DeclarationList, FormalParameter,
+                FunctionExpr, GetPrivateField, GetSuperField, Identifier, MethodDefinitionKind,
+                Node, PrivateIn, Return, SuperCall,
+            },
+            Const,
+        },
+        parser::tests::{check_invalid, check_parser},
+    },
+    Interner,
+};
+
+/// Checks parsing of an empty class declaration.
+#[test]
+fn class_declaration_empty() {
+    let mut interner = Interner::default();
+    check_parser(
+        "class A {}",
+        vec![Node::ClassDecl(Class::new(
+            interner.get_or_intern_static("A"),
+            None,
+            None,
+            vec![],
+        ))],
+        &mut interner,
+    );
+}
+
+/// Checks parsing of a derived class with a constructor, a method and fields.
+#[test]
+fn class_declaration_derived() {
+    let mut interner = Interner::default();
+    let x = interner.get_or_intern_static("x");
+    let m = interner.get_or_intern_static("m");
+    check_parser(
+        "class A extends B {
+            constructor(x) { super(x); }
+            m() { return super.m(); }
+            static s = 1;
+            f;
+        }",
+        vec![Node::ClassDecl(Class::new(
+            interner.get_or_intern_static("A"),
+            Node::from(Identifier::new(interner.get_or_intern_static("B"))),
+            FunctionExpr::new(
+                None,
+                vec![FormalParameter::new(
+                    Declaration::new_with_identifier(x, None),
+                    false,
+                )],
+                vec![SuperCall::new(vec![Identifier::new(x).into()]).into()],
+            ),
+            vec![
+                ClassElement::MethodDefinition(
+                    MethodDefinitionKind::Ordinary,
+                    m.into(),
+                    FunctionExpr::new(
+                        None,
+                        vec![],
+                        vec![Return::new(Call::new(GetSuperField::from(m), vec![]), None).into()],
+                    ),
+                ),
+                ClassElement::StaticFieldDefinition(
+                    interner.get_or_intern_static("s").into(),
+                    Some(Const::from(1).into()),
+                ),
+                ClassElement::FieldDefinition(interner.get_or_intern_static("f").into(), None),
+            ],
+        ))],
+        &mut interner,
+    );
+}
+
+/// Checks that `static` can be used as the name of a class element.
+#[test]
+fn class_declaration_static_as_name() {
+    let mut interner = Interner::default();
+    check_parser(
+        "class A { static() {} static static() {} }",
+        vec![Node::ClassDecl(Class::new(
+            interner.get_or_intern_static("A"),
+            None,
+            None,
+            vec![
+                ClassElement::MethodDefinition(
+                    MethodDefinitionKind::Ordinary,
+                    interner.get_or_intern_static("static").into(),
+                    FunctionExpr::new(None, vec![], vec![]),
+                ),
+                ClassElement::StaticMethodDefinition(
+                    MethodDefinitionKind::Ordinary,
+                    interner.get_or_intern_static("static").into(),
+                    FunctionExpr::new(None, vec![], vec![]),
+                ),
+            ],
+        ))],
+        &mut interner,
+    );
+}
+
+/// Checks parsing of an anonymous class expression.
+#[test]
+fn class_expression_anonymous() {
+    let mut interner = Interner::default();
+    check_parser(
+        "let C = class { get x() {} };",
+        vec![DeclarationList::Let(
+            vec![Declaration::new_with_identifier(
+                interner.get_or_intern_static("C"),
+                Node::ClassExpr(Class::new(
+                    None,
+                    None,
+                    None,
+                    vec![ClassElement::MethodDefinition(
+                        MethodDefinitionKind::Get,
+                        interner.get_or_intern_static("x").into(),
+                        FunctionExpr::new(None, vec![], vec![]),
+                    )],
+                )),
+            )]
+            .into(),
+        )
+        .into()],
+        &mut interner,
+    );
+}
+
+/// Checks parsing of private fields, methods and accessors, and of their references.
+#[test]
+fn class_declaration_private_members() {
+    let mut interner = Interner::default();
+    let x = interner.get_or_intern_static("#x");
+    let m = interner.get_or_intern_static("#m");
+    let y = interner.get_or_intern_static("#y");
+    let o = interner.get_or_intern_static("o");
+    check_parser(
+        "class A {
+            #x = 1;
+            static #m(o) { return #x in o; }
+            get #y() { return this.#x; }
+        }",
+        vec![Node::ClassDecl(Class::new(
+            interner.get_or_intern_static("A"),
+            None,
+            None,
+            vec![
+                ClassElement::PrivateFieldDefinition(x, Some(Const::from(1).into())),
+                ClassElement::PrivateStaticMethodDefinition(
+                    MethodDefinitionKind::Ordinary,
+                    m,
+                    FunctionExpr::new(
+                        None,
+                        vec![FormalParameter::new(
+                            Declaration::new_with_identifier(o, None),
+                            false,
+                        )],
+                        vec![Return::new(PrivateIn::new(x, Identifier::new(o)), None).into()],
+                    ),
+                ),
+                ClassElement::PrivateMethodDefinition(
+                    MethodDefinitionKind::Get,
+                    y,
+                    FunctionExpr::new(
+                        None,
+                        vec![],
+                        vec![Return::new(GetPrivateField::new(Node::This, x), None).into()],
+                    ),
+                ),
+            ],
+        ))],
+        &mut interner,
+    );
+}
+
+/// Checks the early errors of class bodies.
+#[test]
+fn class_declaration_invalid() {
+    check_invalid("class A { constructor() {} constructor() {} }");
+    check_invalid("class A { get constructor() {} }");
+    check_invalid("class A { *constructor() {} }");
+    check_invalid("class A { constructor = 1; }");
+    check_invalid("class A { static prototype() {} }");
+    check_invalid("class A { static prototype = 1; }");
+    check_invalid("class A { x = 1 y = 2 }");
+}
+
+/// Checks the early errors of private names.
+#[test]
+fn class_declaration_invalid_private_names() {
+    check_invalid("this.#x;");
+    check_invalid("#x in {};");
+    check_invalid("class A { m() { return this.#x; } }");
+    check_invalid("class A { m() { class B { #x; } return this.#x; } }");
+    check_invalid("class A { #x; #x; }");
+    check_invalid("class A { #x; get #x() {} }");
+    check_invalid("class A { get #x() {} static set #x(v) {} }");
+    check_invalid("class A { #constructor() {} }");
+    check_invalid("class A { #x; m() { delete this.#x; } }");
+}
@@ -82,12 +82,16 @@ where
         cursor: &mut Cursor<R>,
         interner: &mut Interner,
     ) -> Result<Self::Output, ParseError> {
-        cursor.expect(Keyword::Async, "async function declaration", interner)?;
+        let start = cursor
+            .expect(Keyword::Async, "async function declaration", interner)?
+            .byte_span()
+            .start();
         cursor.peek_expect_no_lineterminator(0, "async function declaration", interner)?;
         cursor.expect(Keyword::Function, "async function declaration", interner)?;
 
         let result = parse_callable_declaration(&self, cursor, interner)?;
 
-        Ok(AsyncFunctionDecl::new(result.0, result.1, result.2))
+        Ok(AsyncFunctionDecl::new(result.0, result.1, result.2)
+            .with_source_span(cursor.byte_span_from(start)))
     }
 }
@@ -69,10 +69,10 @@ impl CallableDeclaration for FunctionDeclaration {
         false
     }
     fn body_allow_yield(&self) -> bool {
-        self.allow_yield.0
+        false
     }
     fn body_allow_await(&self) -> bool {
-        self.allow_await.0
+        false
     }
 }
 
@@ -87,10 +87,14 @@ where
         cursor: &mut Cursor<R>,
         interner: &mut Interner,
     ) -> Result<Self::Output, ParseError> {
-        cursor.expect(Keyword::Function, "function declaration", interner)?;
+        let start = cursor
+            .expect(Keyword::Function, "function declaration", interner)?
+            .byte_span()
+            .start();
 
         let result = parse_callable_declaration(&self, cursor, interner)?;
 
-        Ok(FunctionDecl::new(result.0, result.1, result.2))
+        Ok(FunctionDecl::new(result.0, result.1, result.2)
+            .with_source_span(cursor.byte_span_from(start)))
     }
 }
@@ -82,11 +82,15 @@ where
         cursor: &mut Cursor<R>,
         interner: &mut Interner,
     ) -> Result<Self::Output, ParseError> {
-        cursor.expect(Keyword::Function, "generator declaration", interner)?;
+        let start = cursor
+            .expect(Keyword::Function, "generator declaration", interner)?
+            .byte_span()
+            .start();
         cursor.expect(Punctuator::Mul, "generator declaration", interner)?;
 
         let result = parse_callable_declaration(&self, cursor, interner)?;
 
-        Ok(GeneratorDecl::new(result.0, result.1, result.2))
+        Ok(GeneratorDecl::new(result.0, result.1, result.2)
+            .with_source_span(cursor.byte_span_from(start)))
     }
 }
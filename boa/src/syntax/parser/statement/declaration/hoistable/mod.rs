@@ -41,7 +41,7 @@ use std::io::Read;
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-FunctionDeclaration
 #[derive(Debug, Clone, Copy)]
-pub(super) struct HoistableDeclaration {
+pub(in crate::syntax::parser) struct HoistableDeclaration {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     is_default: AllowDefault,
@@ -49,7 +49,11 @@ pub(super) struct HoistableDeclaration {
 
 impl HoistableDeclaration {
     /// Creates a new `HoistableDeclaration` parser.
-    pub(super) fn new<Y, A, D>(allow_yield: Y, allow_await: A, is_default: D) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A, D>(
+        allow_yield: Y,
+        allow_await: A,
+        is_default: D,
+    ) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -97,9 +101,13 @@ where
                     .parse(cursor, interner)
                     .map(Node::from)
                 } else {
-                    AsyncFunctionDeclaration::new(self.allow_yield, self.allow_await, false)
-                        .parse(cursor, interner)
-                        .map(Node::from)
+                    AsyncFunctionDeclaration::new(
+                        self.allow_yield,
+                        self.allow_await,
+                        self.is_default,
+                    )
+                    .parse(cursor, interner)
+                    .map(Node::from)
                 }
             }
             _ => unreachable!("unknown token found: {:?}", tok),
@@ -163,6 +171,7 @@ fn parse_callable_declaration<R: Read, C: CallableDeclaration>(
         .span()
         .end();
 
+    let allow_new_target = cursor.set_allow_new_target(true);
     let params = FormalParameters::new(c.parameters_allow_yield(), c.parameters_allow_await())
         .parse(cursor, interner)?;
 
@@ -171,6 +180,7 @@ fn parse_callable_declaration<R: Read, C: CallableDeclaration>(
 
     let body =
         FunctionBody::new(c.body_allow_yield(), c.body_allow_await()).parse(cursor, interner)?;
+    cursor.set_allow_new_target(allow_new_target);
 
     cursor.expect(Punctuator::CloseBlock, c.error_context(), interner)?;
 
@@ -192,6 +202,10 @@ fn parse_callable_declaration<R: Read, C: CallableDeclaration>(
         )));
     }
 
+    // Early Error: It is a Syntax Error if FunctionBodyContainsUseStrict of the body is true
+    // and the BindingIdentifier or the BoundNames of the parameters are `eval` or `arguments`.
+    params.check_strict_bindings(body.strict(), Some(name), params_start_position)?;
+
     // It is a Syntax Error if any element of the BoundNames of FormalParameters
     // also occurs in the LexicallyDeclaredNames of FunctionBody.
     // https://tc39.es/ecma262/#sec-function-definitions-static-semantics-early-errors
@@ -7,12 +7,14 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements#Declarations
 //! [spec]:https://tc39.es/ecma262/#sec-declarations-and-the-variable-statement
 
+mod class_decl;
 pub(in crate::syntax::parser) mod hoistable;
 mod lexical;
 #[cfg(test)]
 mod tests;
 
-use self::{hoistable::HoistableDeclaration, lexical::LexicalDeclaration};
+use self::{class_decl::ClassDeclaration, lexical::LexicalDeclaration};
+pub(in crate::syntax::parser) use self::{class_decl::ClassTail, hoistable::HoistableDeclaration};
 
 use crate::syntax::lexer::TokenKind;
 use crate::Interner;
@@ -33,14 +35,18 @@ use std::io::Read;
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-Declaration
 #[derive(Debug, Clone, Copy)]
-pub(super) struct Declaration {
+pub(in crate::syntax::parser) struct Declaration {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     const_init_required: bool,
 }
 
 impl Declaration {
-    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, const_init_required: bool) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A>(
+        allow_yield: Y,
+        allow_await: A,
+        const_init_required: bool,
+    ) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -72,6 +78,9 @@ where
                 HoistableDeclaration::new(self.allow_yield, self.allow_await, false)
                     .parse(cursor, interner)
             }
+            TokenKind::Keyword(Keyword::Class) => {
+                ClassDeclaration::new(self.allow_yield, self.allow_await).parse(cursor, interner)
+            }
             TokenKind::Keyword(Keyword::Const | Keyword::Let) => LexicalDeclaration::new(
                 true,
                 self.allow_yield,
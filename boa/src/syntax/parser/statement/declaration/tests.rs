@@ -328,3 +328,51 @@ fn multiple_const_declaration() {
         &mut interner,
     );
 }
+
+/// Checks that redeclarations in the same scope are early errors.
+#[test]
+fn redeclaration_early_errors() {
+    check_invalid("let a; let a;");
+    check_invalid("let a; const a = 1;");
+    check_invalid("let [a, a] = [];");
+    check_invalid("var a; let a;");
+    check_invalid("let a; var a;");
+    check_invalid("{ let a; let a; }");
+    check_invalid("let f; function f() {}");
+    check_invalid("function f() {} let f;");
+    check_invalid("class A {} class A {}");
+    check_invalid("{ var f; function f() {} }");
+    check_invalid("{ function* f() {} function f() {} }");
+    check_invalid("'use strict'; function g() { { let f; function f() {} } }");
+}
+
+/// Checks redeclarations that are allowed.
+#[test]
+fn redeclaration_allowed() {
+    let mut interner = Interner::default();
+    let a = interner.get_or_intern_static("a");
+    check_parser(
+        "var a; var a;",
+        vec![
+            DeclarationList::Var(vec![Declaration::new_with_identifier(a, None)].into()).into(),
+            DeclarationList::Var(vec![Declaration::new_with_identifier(a, None)].into()).into(),
+        ],
+        &mut interner,
+    );
+
+    for src in [
+        "let a; { let a; }",
+        "var f; function f() {}",
+        "function f() {} function f() {}",
+        "{ function f() {} function f() {} }",
+        "function g() { 'use strict'; function f() {} }",
+    ] {
+        let mut interner = Interner::default();
+        assert!(
+            crate::syntax::Parser::new(src.as_bytes(), false)
+                .parse_all(&mut interner)
+                .is_ok(),
+            "{src}"
+        );
+    }
+}
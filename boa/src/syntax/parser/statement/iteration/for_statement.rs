@@ -23,6 +23,7 @@ use crate::{
     },
     BoaProfiler, Interner,
 };
+use boa_interner::Sym;
 use std::io::Read;
 
 /// For statement parsing
@@ -73,6 +74,11 @@ where
     ) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("ForStatement", "Parsing");
         cursor.expect(Keyword::For, "for statement", interner)?;
+        let r#await = if self.allow_await.0 {
+            cursor.next_if(Keyword::Await, interner)?
+        } else {
+            None
+        };
         let init_position = cursor
             .expect(Punctuator::OpenParen, "for statement", interner)?
             .span()
@@ -103,8 +109,19 @@ where
         };
 
         match (init.as_ref(), cursor.peek(0, interner)?) {
+            (_, Some(tok))
+                if r#await.is_some() && tok.kind() != &TokenKind::Keyword(Keyword::Of) =>
+            {
+                return Err(ParseError::expected(
+                    ["of".to_owned()],
+                    tok.to_string(interner),
+                    tok.span(),
+                    "for await of statement",
+                ));
+            }
             (Some(init), Some(tok)) if tok.kind() == &TokenKind::Keyword(Keyword::In) => {
-                let init = node_to_iterable_loop_initializer(init, init_position)?;
+                let init =
+                    node_to_iterable_loop_initializer(init, init_position, cursor.strict_mode())?;
 
                 let _next = cursor.next(interner)?;
                 let expr = Expression::new(None, true, self.allow_yield, self.allow_await)
@@ -126,7 +143,8 @@ where
                 return Ok(ForInLoop::new(init, expr, body).into());
             }
             (Some(init), Some(tok)) if tok.kind() == &TokenKind::Keyword(Keyword::Of) => {
-                let init = node_to_iterable_loop_initializer(init, init_position)?;
+                let init =
+                    node_to_iterable_loop_initializer(init, init_position, cursor.strict_mode())?;
 
                 let _next = cursor.next(interner)?;
                 let iterable = Expression::new(None, true, self.allow_yield, self.allow_await)
@@ -145,7 +163,7 @@ where
                     return Err(ParseError::wrong_function_declaration_non_strict(position));
                 }
 
-                return Ok(ForOfLoop::new(init, iterable, body).into());
+                return Ok(ForOfLoop::new(init, iterable, body, r#await.is_some()).into());
             }
             _ => {}
         }
@@ -197,8 +215,15 @@ where
 fn node_to_iterable_loop_initializer(
     node: &Node,
     position: Position,
+    strict: bool,
 ) -> Result<IterableLoopInitializer, ParseError> {
     match node {
+        Node::Identifier(name) if strict && [Sym::EVAL, Sym::ARGUMENTS].contains(&name.sym()) => {
+            Err(ParseError::lex(LexError::Syntax(
+                "Invalid left-hand side in for loop: assignment to `eval` or `arguments`".into(),
+                position,
+            )))
+        }
         Node::Identifier(name) => Ok(IterableLoopInitializer::Identifier(*name)),
         Node::VarDeclList(ref list) => match list.as_ref() {
             [var] => {
@@ -25,7 +25,6 @@ use self::{
     block::BlockStatement,
     break_stm::BreakStatement,
     continue_stm::ContinueStatement,
-    declaration::Declaration,
     expression::ExpressionStatement,
     if_stm::IfStatement,
     iteration::{DoWhileStatement, ForStatement, WhileStatement},
@@ -34,6 +33,9 @@ use self::{
     switch::SwitchStatement,
     throw::ThrowStatement,
     try_stm::TryStatement,
+};
+pub(super) use self::{
+    declaration::{ClassTail, Declaration, HoistableDeclaration},
     variable::VariableStatement,
 };
 use super::{AllowAwait, AllowIn, AllowReturn, AllowYield, Cursor, ParseError, TokenParser};
@@ -47,10 +49,10 @@ use crate::{
                     DeclarationPatternArray, DeclarationPatternObject,
                 },
             },
-            Keyword, Node, Punctuator,
+            Const, Keyword, Node, Punctuator,
         },
         lexer::{Error as LexError, InputElement, Position, TokenKind},
-        parser::expression::{await_expr::AwaitExpression, Initializer},
+        parser::expression::Initializer,
     },
     BoaProfiler,
 };
@@ -122,9 +124,6 @@ where
         let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
 
         match tok.kind() {
-            TokenKind::Keyword(Keyword::Await) => AwaitExpression::new(self.allow_yield)
-                .parse(cursor, interner)
-                .map(Node::from),
             TokenKind::Keyword(Keyword::If) => {
                 IfStatement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor, interner)
@@ -237,16 +236,21 @@ pub(super) struct StatementList {
     allow_await: AllowAwait,
     allow_return: AllowReturn,
     in_block: bool,
+    directive_prologues: bool,
     break_nodes: &'static [TokenKind],
 }
 
 impl StatementList {
     /// Creates a new `StatementList` parser.
+    ///
+    /// If `directive_prologues` is `true`, the list starts with a directive prologue, and a
+    /// `"use strict"` directive in it enables strict mode for the rest of the list.
     pub(super) fn new<Y, A, R>(
         allow_yield: Y,
         allow_await: A,
         allow_return: R,
         in_block: bool,
+        directive_prologues: bool,
         break_nodes: &'static [TokenKind],
     ) -> Self
     where
@@ -259,6 +263,7 @@ impl StatementList {
             allow_await: allow_await.into(),
             allow_return: allow_return.into(),
             in_block,
+            directive_prologues,
             break_nodes,
         }
     }
@@ -287,14 +292,39 @@ where
     ) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("StatementList", "Parsing");
         let mut items = Vec::new();
+        let mut in_directive_prologue = self.directive_prologues;
+        let mut strict = false;
 
         loop {
+            // A directive is an expression statement made of a single string literal, and the
+            // `"use strict"` directive can't contain escape sequences or line continuations.
+            //
+            // More information:
+            //  - [ECMAScript specification][spec]
+            //
+            // [spec]: https://tc39.es/ecma262/#directive-prologue
+            let mut use_strict = false;
             match cursor.peek(0, interner)? {
                 Some(token) if self.break_nodes.contains(token.kind()) => break,
                 None => break,
+                Some(token) if in_directive_prologue => match token.kind() {
+                    TokenKind::StringLiteral(string) => {
+                        let span = token.span();
+                        use_strict = interner.resolve_expect(*string) == "use strict"
+                            && span.start().line_number() == span.end().line_number()
+                            && span.end().column_number() - span.start().column_number()
+                                == "\"use strict\"".len() as u32;
+                    }
+                    _ => in_directive_prologue = false,
+                },
                 _ => {}
             }
 
+            let position = cursor
+                .peek(0, interner)?
+                .expect("checked that the list hasn't ended")
+                .span()
+                .start();
             let item = StatementListItem::new(
                 self.allow_yield,
                 self.allow_await,
@@ -302,118 +332,166 @@ where
                 self.in_block,
             )
             .parse(cursor, interner)?;
-            items.push(item);
+
+            if in_directive_prologue {
+                if let Node::Const(Const::String(_)) = item {
+                    if use_strict {
+                        cursor.set_strict_mode(true);
+                        strict = true;
+                    }
+                } else {
+                    in_directive_prologue = false;
+                }
+            }
+            items.push((item, position));
 
             // move the cursor forward for any consecutive semicolon.
             while cursor.next_if(Punctuator::Semicolon, interner)?.is_some() {}
         }
 
         // Handle any redeclarations
-        // https://tc39.es/ecma262/#sec-block-static-semantics-early-errors
-        {
-            let mut lexically_declared_names: HashSet<Sym> = HashSet::new();
-            let mut var_declared_names: HashSet<Sym> = HashSet::new();
-
-            // TODO: Use more helpful positions in errors when spans are added to Nodes
-            for item in &items {
-                match item {
-                    Node::LetDeclList(decl_list) | Node::ConstDeclList(decl_list) => {
-                        for decl in decl_list.as_ref() {
-                            // if name in VarDeclaredNames or can't be added to
-                            // LexicallyDeclaredNames, raise an error
-                            match decl {
-                                node::Declaration::Identifier { ident, .. } => {
-                                    if var_declared_names.contains(&ident.sym())
-                                        || !lexically_declared_names.insert(ident.sym())
-                                    {
-                                        return Err(ParseError::lex(LexError::Syntax(
-                                            format!(
-                                                "Redeclaration of variable `{}`",
-                                                interner.resolve_expect(ident.sym())
-                                            )
-                                            .into(),
-                                            match cursor.peek(0, interner)? {
-                                                Some(token) => token.span().end(),
-                                                None => Position::new(1, 1),
-                                            },
-                                        )));
-                                    }
-                                }
-                                node::Declaration::Pattern(p) => {
-                                    for ident in p.idents() {
-                                        if var_declared_names.contains(&ident)
-                                            || !lexically_declared_names.insert(ident)
-                                        {
-                                            return Err(ParseError::lex(LexError::Syntax(
-                                                format!(
-                                                    "Redeclaration of variable `{}`",
-                                                    interner.resolve_expect(ident)
-                                                )
-                                                .into(),
-                                                match cursor.peek(0, interner)? {
-                                                    Some(token) => token.span().end(),
-                                                    None => Position::new(1, 1),
-                                                },
-                                            )));
-                                        }
-                                    }
-                                }
-                            }
+        declared_names(
+            items.iter().map(|(item, _)| item),
+            self.in_block,
+            cursor,
+            interner,
+        )?;
+
+        items.sort_by(|(a, _), (b, _)| Node::hoistable_order(a, b));
+        let (items, positions): (Vec<_>, Vec<_>) = items.into_iter().unzip();
+
+        let mut statement_list = node::StatementList::from(items);
+        statement_list.set_strict(strict);
+        statement_list.set_positions(positions.into_boxed_slice());
+        Ok(statement_list)
+    }
+}
+
+/// Collects the lexically and var declared names of the top level of a statement list, checking
+/// that none of them is redeclared.
+///
+/// Returns the lexically declared names and the var declared names, in that order.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-block-static-semantics-early-errors
+pub(super) fn declared_names<'a, R: Read>(
+    items: impl IntoIterator<Item = &'a Node>,
+    in_block: bool,
+    cursor: &mut Cursor<R>,
+    interner: &mut Interner,
+) -> Result<(HashSet<Sym>, HashSet<Sym>), ParseError> {
+    let mut lexically_declared_names: HashSet<Sym> = HashSet::new();
+    let mut var_declared_names: HashSet<Sym> = HashSet::new();
+    // Names only bound by function declarations of a block, which can be redeclared in
+    // non-strict code.
+    // https://tc39.es/ecma262/#sec-block-duplicates-allowed-static-semantics
+    let mut block_function_names: HashSet<Sym> = HashSet::new();
+    let strict = cursor.strict_mode();
+
+    // TODO: Use more helpful positions in errors when spans are added to Nodes
+    for item in items {
+        match item {
+            Node::LetDeclList(decl_list) | Node::ConstDeclList(decl_list) => {
+                for decl in decl_list.as_ref() {
+                    let names = match decl {
+                        node::Declaration::Identifier { ident, .. } => vec![ident.sym()],
+                        node::Declaration::Pattern(p) => p.idents(),
+                    };
+                    for name in names {
+                        // if name in VarDeclaredNames or can't be added to
+                        // LexicallyDeclaredNames, raise an error
+                        if var_declared_names.contains(&name)
+                            || !lexically_declared_names.insert(name)
+                        {
+                            return Err(redeclaration_error(name, cursor, interner)?);
                         }
+                        block_function_names.remove(&name);
                     }
-                    Node::VarDeclList(decl_list) => {
-                        for decl in decl_list.as_ref() {
-                            match decl {
-                                node::Declaration::Identifier { ident, .. } => {
-                                    // if name in LexicallyDeclaredNames, raise an error
-                                    if lexically_declared_names.contains(&ident.sym()) {
-                                        return Err(ParseError::lex(LexError::Syntax(
-                                            format!(
-                                                "Redeclaration of variable `{}`",
-                                                interner.resolve_expect(ident.sym())
-                                            )
-                                            .into(),
-                                            match cursor.peek(0, interner)? {
-                                                Some(token) => token.span().end(),
-                                                None => Position::new(1, 1),
-                                            },
-                                        )));
-                                    }
-                                    // otherwise, add to VarDeclaredNames
-                                    var_declared_names.insert(ident.sym());
-                                }
-                                node::Declaration::Pattern(p) => {
-                                    for ident in p.idents() {
-                                        // if name in LexicallyDeclaredNames, raise an error
-                                        if lexically_declared_names.contains(&ident) {
-                                            return Err(ParseError::lex(LexError::Syntax(
-                                                format!(
-                                                    "Redeclaration of variable `{}`",
-                                                    interner.resolve_expect(ident)
-                                                )
-                                                .into(),
-                                                match cursor.peek(0, interner)? {
-                                                    Some(token) => token.span().end(),
-                                                    None => Position::new(1, 1),
-                                                },
-                                            )));
-                                        }
-                                        // otherwise, add to VarDeclaredNames
-                                        var_declared_names.insert(ident);
-                                    }
-                                }
-                            }
+                }
+            }
+            Node::ClassDecl(class) => {
+                let name = class.name().expect("class declaration must have a name");
+                if var_declared_names.contains(&name) || !lexically_declared_names.insert(name) {
+                    return Err(redeclaration_error(name, cursor, interner)?);
+                }
+                block_function_names.remove(&name);
+            }
+            Node::FunctionDecl(_)
+            | Node::GeneratorDecl(_)
+            | Node::AsyncFunctionDecl(_)
+            | Node::AsyncGeneratorDecl(_) => {
+                let (name, is_function) = match item {
+                    Node::FunctionDecl(decl) => (decl.name(), true),
+                    Node::GeneratorDecl(decl) => (decl.name(), false),
+                    Node::AsyncFunctionDecl(decl) => (decl.name(), false),
+                    Node::AsyncGeneratorDecl(decl) => (decl.name(), false),
+                    _ => unreachable!("checked by the outer match"),
+                };
+
+                if in_block {
+                    // Function declarations are lexically scoped in blocks.
+                    let redeclares_function =
+                        !strict && is_function && block_function_names.contains(&name);
+                    if var_declared_names.contains(&name)
+                        || (!lexically_declared_names.insert(name) && !redeclares_function)
+                    {
+                        return Err(redeclaration_error(name, cursor, interner)?);
+                    }
+                    if is_function {
+                        block_function_names.insert(name);
+                    } else {
+                        block_function_names.remove(&name);
+                    }
+                } else {
+                    // Top level function declarations are var scoped.
+                    if lexically_declared_names.contains(&name) {
+                        return Err(redeclaration_error(name, cursor, interner)?);
+                    }
+                    var_declared_names.insert(name);
+                }
+            }
+            Node::VarDeclList(decl_list) => {
+                for decl in decl_list.as_ref() {
+                    let names = match decl {
+                        node::Declaration::Identifier { ident, .. } => vec![ident.sym()],
+                        node::Declaration::Pattern(p) => p.idents(),
+                    };
+                    for name in names {
+                        // if name in LexicallyDeclaredNames, raise an error
+                        if lexically_declared_names.contains(&name) {
+                            return Err(redeclaration_error(name, cursor, interner)?);
                         }
+                        // otherwise, add to VarDeclaredNames
+                        var_declared_names.insert(name);
                     }
-                    _ => (),
                 }
             }
+            _ => (),
         }
+    }
 
-        items.sort_by(Node::hoistable_order);
+    Ok((lexically_declared_names, var_declared_names))
+}
 
-        Ok(items.into())
-    }
+/// Creates the early error for a name that is declared more than once in a statement list.
+pub(super) fn redeclaration_error<R: Read>(
+    name: Sym,
+    cursor: &mut Cursor<R>,
+    interner: &mut Interner,
+) -> Result<ParseError, ParseError> {
+    Ok(ParseError::lex(LexError::Syntax(
+        format!(
+            "Redeclaration of variable `{}`",
+            interner.resolve_expect(name)
+        )
+        .into(),
+        match cursor.peek(0, interner)? {
+            Some(token) => token.span().end(),
+            None => Position::new(1, 1),
+        },
+    )))
 }
 
 /// Statement list item parsing
@@ -427,7 +505,7 @@ where
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements
 /// [spec]: https://tc39.es/ecma262/#prod-StatementListItem
 #[derive(Debug, Clone, Copy)]
-struct StatementListItem {
+pub(super) struct StatementListItem {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     allow_return: AllowReturn,
@@ -436,7 +514,12 @@ struct StatementListItem {
 
 impl StatementListItem {
     /// Creates a new `StatementListItem` parser.
-    fn new<Y, A, R>(allow_yield: Y, allow_await: A, allow_return: R, in_block: bool) -> Self
+    pub(super) fn new<Y, A, R>(
+        allow_yield: Y,
+        allow_await: A,
+        allow_return: R,
+        in_block: bool,
+    ) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -476,7 +559,7 @@ where
                 }
                 Declaration::new(self.allow_yield, self.allow_await, true).parse(cursor, interner)
             }
-            TokenKind::Keyword(Keyword::Const | Keyword::Let) => {
+            TokenKind::Keyword(Keyword::Class | Keyword::Const | Keyword::Let) => {
                 Declaration::new(self.allow_yield, self.allow_await, true).parse(cursor, interner)
             }
             _ => Statement::new(self.allow_yield, self.allow_await, self.allow_return)
@@ -538,6 +621,17 @@ where
         let next_token = cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?;
 
         match next_token.kind() {
+            TokenKind::Identifier(ref s)
+                if cursor.strict_mode() && [Sym::EVAL, Sym::ARGUMENTS].contains(s) =>
+            {
+                // Early Error: It is a Syntax Error if the code matched by this production is
+                // contained in strict mode code and the StringValue of Identifier is "arguments"
+                // or "eval".
+                Err(ParseError::general(
+                    "binding identifier `eval` or `arguments` not allowed in strict mode",
+                    next_token.span().start(),
+                ))
+            }
             TokenKind::Identifier(ref s) => Ok(*s),
             TokenKind::Keyword(Keyword::Yield) if self.allow_yield.0 => {
                 // Early Error: It is a Syntax Error if this production has a [Yield] parameter and StringValue of Identifier is "yield".
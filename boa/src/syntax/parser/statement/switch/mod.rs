@@ -140,6 +140,7 @@ where
                         self.allow_await,
                         self.allow_return,
                         false,
+                        false,
                         &CASE_BREAK_TOKENS,
                     )
                     .parse(cursor, interner)?;
@@ -163,6 +164,7 @@ where
                         self.allow_await,
                         self.allow_return,
                         false,
+                        false,
                         &CASE_BREAK_TOKENS,
                     )
                     .parse(cursor, interner)?;
@@ -102,7 +102,25 @@ where
         // FIXME: `lexically_declared_names` only holds part of LexicallyDeclaredNames of the
         // Block e.g. function names are *not* included but should be.
         let lexically_declared_names = catch_block.lexically_declared_names(interner);
-        let var_declared_names = catch_block.var_declared_named();
+
+        // Annex B.3.5: `var` declarations may redeclare a catch parameter that is a simple
+        // identifier, except for the binding of a `for-of` statement.
+        // https://tc39.es/ecma262/#sec-variablestatements-in-catch-blocks
+        let simple_param = matches!(catch_param, Some(node::Declaration::Identifier { .. }));
+        let mut var_declared_names = FxHashSet::default();
+        for node in catch_block.items() {
+            node.visit_var_declarations(&mut |decl, for_of| {
+                if simple_param && !for_of {
+                    return;
+                }
+                match decl {
+                    node::Declaration::Identifier { ident, .. } => {
+                        var_declared_names.insert(ident.sym());
+                    }
+                    node::Declaration::Pattern(p) => var_declared_names.extend(p.idents()),
+                }
+            });
+        }
 
         for ident in set {
             // FIXME: pass correct position once #1295 lands
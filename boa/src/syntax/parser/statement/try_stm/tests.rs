@@ -262,5 +262,56 @@ fn check_invalid_catch_with_lexical_redeclaration() {
 
 #[test]
 fn check_invalid_catch_with_var_redeclaration() {
-    check_invalid("try {} catch(e) { var e = 'oh' }");
+    check_invalid("try {} catch({ e }) { var e = 'oh' }");
+}
+
+#[test]
+fn check_invalid_catch_with_for_of_var_redeclaration() {
+    check_invalid("try {} catch(e) { for (var e of []) {} }");
+    check_invalid("try {} catch(e) { { for (var e of []); } }");
+    check_invalid("try {} catch(e) { if (x) for (var e of []); }");
+    check_invalid("try {} catch(e) { while (x) { try {} finally { for (var [e] of []); } } }");
+    check_invalid("try {} catch(e) { l: for (var e of []); }");
+    check_invalid("try {} catch(e) { l: m: for (var e of []); }");
+    check_invalid("try {} catch(e) { l: { for (var e of []); } }");
+    check_invalid("try {} catch(e) { l: while (x) for (var e of []); }");
+}
+
+#[test]
+fn check_invalid_catch_with_nested_var_redeclaration() {
+    check_invalid("try {} catch({ e }) { { var e = 'oh' } }");
+    check_invalid("try {} catch([e]) { switch (x) { case 1: for (var e in {}); } }");
+    check_invalid("try {} catch({ e }) { l: { var e = 'oh' } }");
+    check_invalid("try {} catch([e]) { l: if (x) var e; }");
+}
+
+#[test]
+fn check_invalid_catch_with_const_redeclaration() {
+    check_invalid("try {} catch([e]) { const e = 'oh' }");
+}
+
+#[test]
+fn check_catch_with_var_redeclaration() {
+    let mut interner = Interner::default();
+    let e = interner.get_or_intern_static("e");
+    check_parser(
+        "try {} catch(e) { var e = 'oh' }",
+        vec![Try::new(
+            vec![],
+            Some(Catch::new(
+                Declaration::new_with_identifier(e, None),
+                vec![DeclarationList::Var(
+                    vec![Declaration::new_with_identifier(
+                        e,
+                        Some(Const::from(interner.get_or_intern_static("oh")).into()),
+                    )]
+                    .into(),
+                )
+                .into()],
+            )),
+            None,
+        )
+        .into()],
+        &mut interner,
+    );
 }
@@ -30,14 +30,14 @@ use std::io::Read;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/var
 /// [spec]: https://tc39.es/ecma262/#prod-VariableStatement
 #[derive(Debug, Clone, Copy)]
-pub(in crate::syntax::parser::statement) struct VariableStatement {
+pub(in crate::syntax::parser) struct VariableStatement {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
 impl VariableStatement {
     /// Creates a new `VariableStatement` parser.
-    pub(in crate::syntax::parser::statement) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -482,3 +482,111 @@ fn hashbang_comment() {
     let mut interner = Interner::default();
     check_parser(r"#!Comment Here", vec![], &mut interner);
 }
+
+/// In strict mode code `eval` and `arguments` can't be bound or assigned to, but they can still be
+/// referenced.
+#[test]
+fn strict_mode_eval_and_arguments() {
+    for name in ["eval", "arguments"] {
+        for code in [
+            format!("let {name} = 1;"),
+            format!("var {name};"),
+            format!("const {name} = 1;"),
+            format!("let [{name}] = [];"),
+            format!("var {{ {name} }} = {{}};"),
+            format!("function {name}() {{}}"),
+            format!("function f({name}) {{}}"),
+            format!("({name}) => 1;"),
+            format!("try {{}} catch ({name}) {{}}"),
+            format!("{name} = 1;"),
+            format!("{name} += 1;"),
+            format!("{name}++;"),
+            format!("--{name};"),
+            format!("({{ {name} }} = {{}});"),
+            format!("({{ a: {name} }} = {{}});"),
+            format!("for ({name} in {{}}) {{}}"),
+            format!("for ({name} of []) {{}}"),
+        ] {
+            check_invalid(&format!("'use strict'; {code}"));
+            // The function becomes strict after its parameters have been parsed.
+            check_invalid(&format!("function g() {{ 'use strict'; {code} }}"));
+
+            let mut interner = Interner::default();
+            assert!(
+                Parser::new(code.as_bytes(), false)
+                    .parse_all(&mut interner)
+                    .is_ok(),
+                "`{code}` must be valid in sloppy mode"
+            );
+        }
+
+        // A 'use strict' directive also applies to the parameters of its function.
+        check_invalid(&format!("function f({name}) {{ 'use strict'; }}"));
+        check_invalid(&format!("(function ({name}) {{ 'use strict'; }});"));
+        check_invalid(&format!("({{ m({name}) {{ 'use strict'; }} }});"));
+        check_invalid(&format!("({name}) => {{ 'use strict'; }};"));
+
+        // ...and to the name of its function.
+        check_invalid(&format!("function {name}() {{ 'use strict'; }}"));
+        check_invalid(&format!("function* {name}() {{ 'use strict'; }}"));
+        check_invalid(&format!("async function {name}() {{ 'use strict'; }}"));
+        check_invalid(&format!("(function {name}() {{ 'use strict'; }});"));
+        check_invalid(&format!("(function* {name}() {{ 'use strict'; }});"));
+        check_invalid(&format!("(async function {name}() {{ 'use strict'; }});"));
+        check_invalid(&format!("(async function* {name}() {{ 'use strict'; }});"));
+
+        // Class definitions are always strict mode code.
+        check_invalid(&format!("class {name} {{}}"));
+        check_invalid(&format!("(class {name} {{}});"));
+        check_invalid(&format!("class C {{ m({name}) {{}} }}"));
+
+        for code in [
+            format!("{name};"),
+            format!("typeof {name};"),
+            format!("{name}.x = 1;"),
+            format!("{name}[0]++;"),
+            format!("({{ a: {name}.x }} = {{}});"),
+            format!("var o = {{ {name}: 1 }}; o.{name} = 2;"),
+        ] {
+            let mut interner = Interner::default();
+            assert!(
+                Parser::new(format!("'use strict'; {code}").as_bytes(), false)
+                    .parse_all(&mut interner)
+                    .is_ok(),
+                "`{code}` must be valid in strict mode"
+            );
+        }
+    }
+}
+
+/// Checks that the rendered diagnostic of a parse error points at the offending column, counting
+/// multi-byte UTF-8 characters as a single column.
+#[test]
+fn render_error_with_multi_byte_line() {
+    let js = "let a = 1;\nlet s = 'ñandú' + ;";
+    let mut interner = Interner::default();
+    let error = Parser::new(js.as_bytes(), false)
+        .parse_all(&mut interner)
+        .expect_err("parsing should fail");
+
+    assert_eq!(
+        error.render(js),
+        "error: unexpected token ';', primary expression at line 2, col 19\n \
+         --> 2:19\n  \
+         |\n\
+         2 | let s = 'ñandú' + ;\n  \
+         |                   ^"
+    );
+}
+
+/// Checks that errors without a position render as their message only.
+#[test]
+fn render_error_without_position() {
+    let js = "let a = {";
+    let mut interner = Interner::default();
+    let error = Parser::new(js.as_bytes(), false)
+        .parse_all(&mut interner)
+        .expect_err("parsing should fail");
+
+    assert_eq!(error.render(js), "error: abrupt end");
+}
@@ -153,6 +153,56 @@ fn spread_with_arguments() {
     ]);
 }
 
+#[test]
+fn spread_iterator_next_throws() {
+    let scenario = r#"
+            var returned = false;
+            var calls = 0;
+            var iterable = {
+                [Symbol.iterator]() {
+                    return {
+                        next() {
+                            calls++;
+                            if (calls === 2) {
+                                throw "boom";
+                            }
+                            return { value: calls, done: false };
+                        },
+                        return() {
+                            returned = true;
+                            return {};
+                        }
+                    };
+                }
+            };
+
+            var arrayError;
+            try {
+                [...iterable];
+            } catch (e) {
+                arrayError = e;
+            }
+            var arrayCalls = calls;
+
+            calls = 0;
+            var callError;
+            try {
+                Math.max(...iterable);
+            } catch (e) {
+                callError = e;
+            }
+        "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq("arrayError", "\"boom\""),
+        TestAction::TestEq("arrayCalls", "2"),
+        TestAction::TestEq("callError", "\"boom\""),
+        TestAction::TestEq("calls", "2"),
+        TestAction::TestEq("returned", "false"),
+    ]);
+}
+
 #[test]
 fn array_rest_with_arguments() {
     let scenario = r#"
@@ -166,6 +216,31 @@ fn array_rest_with_arguments() {
     ]);
 }
 
+#[test]
+fn object_rest_in_binding_pattern() {
+    let scenario = r#"
+            var s = Symbol("s");
+            var source = { a: 1, b: 2, [s]: 3, get c() { return 4; } };
+            Object.defineProperty(source, "hidden", { value: 5, enumerable: false });
+            var { a, ...rest } = source;
+
+            function f({ b, ...others }) {
+                return others;
+            }
+            var others = f(source);
+        "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq("a", "1"),
+        TestAction::TestEq("Object.keys(rest).join()", "\"b,c\""),
+        TestAction::TestEq("rest[s]", "3"),
+        TestAction::TestEq("Object.getOwnPropertyDescriptor(rest, 'c').value", "4"),
+        TestAction::TestEq("'hidden' in rest", "false"),
+        TestAction::TestEq("Object.keys(others).join()", "\"a,c\""),
+    ]);
+}
+
 #[test]
 fn array_field_set() {
     let element_changes = r#"
@@ -672,6 +747,40 @@ fn unary_delete() {
         delete delete delete 1;
     "#;
     assert_eq!(&exec(delete_recursive), "true");
+
+    let delete_non_configurable = r#"
+        const a = {};
+        Object.defineProperty(a, 'b', { value: 5 });
+        const c = delete a.b + '';
+        a.b + c
+    "#;
+    assert_eq!(&exec(delete_non_configurable), "\"5false\"");
+
+    let delete_array_element = r#"
+        const a = [1, 2, 3];
+        const b = delete a[0];
+        `${b} ${0 in a} ${a.length}`
+    "#;
+    assert_eq!(&exec(delete_array_element), "\"true false 3\"");
+
+    let delete_global_property = r#"
+        implicit = 5;
+        const b = delete implicit;
+        `${b} ${typeof implicit} ${delete undeclared} ${delete NaN}`
+    "#;
+    assert_eq!(
+        &exec(delete_global_property),
+        "\"true undefined true false\""
+    );
+
+    let delete_local_binding = r#"
+        function f(p) {
+            var q = 1;
+            return `${delete p} ${delete q} ${delete arguments} ${p} ${q}`;
+        }
+        f(2)
+    "#;
+    assert_eq!(&exec(delete_local_binding), "\"false false false 2 1\"");
 }
 
 #[cfg(test)]
@@ -748,6 +857,27 @@ mod in_operator {
         ]);
     }
 
+    #[test]
+    fn uses_has_property() {
+        let mut context = Context::default();
+        assert_eq!(forward(&mut context, "'x' in {x: 1}"), "true");
+        assert_eq!(
+            forward(
+                &mut context,
+                "'magic' in new Proxy({}, { has(t, k) { return k === 'magic'; } })"
+            ),
+            "true"
+        );
+        assert_eq!(
+            forward(&mut context, "'length' in 'str'"),
+            "Uncaught \"TypeError\": \"right-hand side of 'in' should be an object, got string\""
+        );
+        assert_eq!(
+            forward(&mut context, "'x' in 1"),
+            "Uncaught \"TypeError\": \"right-hand side of 'in' should be an object, got number\""
+        );
+    }
+
     #[test]
     fn should_set_this_value() {
         let scenario = r#"
@@ -802,6 +932,57 @@ mod in_operator {
     }
 }
 
+#[test]
+fn instanceof_symbol_has_instance() {
+    let mut context = Context::default();
+    let init = r#"
+        class Even {
+            static [Symbol.hasInstance](n) {
+                return n % 2 === 0;
+            }
+        }
+        function F() {}
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "2 instanceof Even"), "true");
+    assert_eq!(forward(&mut context, "3 instanceof Even"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "1 instanceof { [Symbol.hasInstance]: () => 1 }"
+        ),
+        "true"
+    );
+
+    // Without a custom method, the prototype chain is walked.
+    assert_eq!(forward(&mut context, "new F() instanceof F"), "true");
+    assert_eq!(
+        forward(&mut context, "new F() instanceof F.bind(null)"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "({}) instanceof F"), "false");
+}
+
+#[test]
+fn instanceof_type_errors() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(&mut context, "1 instanceof 3"),
+        "Uncaught \"TypeError\": \"right-hand side of 'instanceof' should be an object, got number\""
+    );
+    assert_eq!(
+        forward(&mut context, "1 instanceof {}"),
+        "Uncaught \"TypeError\": \"right-hand side of 'instanceof' is not callable\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { 1 instanceof { [Symbol.hasInstance]: 1 } } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
 #[test]
 fn var_decl_hoisting_simple() {
     let scenario = r#"
@@ -1292,6 +1473,99 @@ fn comma_operator() {
     assert_eq!(&exec(scenario), "2");
 }
 
+#[test]
+fn comma_operator_evaluation_order() {
+    let scenario = r#"
+        var log = [];
+        function f(x) { log.push(x); return x; }
+        var a = 1, b = 2;
+        var c = (f(a), f(b), f(3));
+        var d = (a, b) + 1;
+        var g = () => (f(4), 5);
+        for (var i = 0, j = 3; i < j; i++, j--) {}
+        [c, d, g(), i, j, log.join()].join(" ")
+    "#;
+    assert_eq!(&exec(scenario), "\"3 3 5 2 1 1,2,3,4\"");
+
+    let scenario = r#"
+        var h = (a, { b }, [c, , d = 4], ...e) => a + b + c + d + e.length;
+        h(1, { b: 2 }, [3]) + h(1, { b: 2 }, [3, 0, 5], 6, 7)
+    "#;
+    assert_eq!(&exec(scenario), "23");
+}
+
+#[test]
+fn member_assignment_evaluation_order() {
+    let scenario = r#"
+        var log = [];
+        function f(name, value) { log.push(name); return value; }
+        var obj = { k: 1 };
+        var key = { toString() { log.push("toKey"); return "k"; } };
+        function run(fn) { log = []; var result = fn(); return [result, obj.k, log.join()].join(" "); }
+    "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq(
+            r#"run(() => f("obj", obj)[f("key", key)] = f("rhs", 2))"#,
+            "\"2 2 obj,key,toKey,rhs\"",
+        ),
+        TestAction::TestEq(
+            r#"run(() => f("obj", obj).k = f("rhs", 3))"#,
+            "\"3 3 obj,rhs\"",
+        ),
+        TestAction::TestEq(
+            r#"run(() => f("obj", obj)[f("key", key)] += f("rhs", 4))"#,
+            "\"7 7 obj,key,toKey,rhs\"",
+        ),
+        TestAction::TestEq(
+            r#"run(() => f("obj", obj).k *= f("rhs", 2))"#,
+            "\"14 14 obj,rhs\"",
+        ),
+        TestAction::TestEq(
+            r#"run(() => f("obj", obj)[f("key", key)]++)"#,
+            "\"14 15 obj,key,toKey\"",
+        ),
+        TestAction::TestEq(
+            r#"run(() => --f("obj", obj)[f("key", key)])"#,
+            "\"14 14 obj,key,toKey\"",
+        ),
+        TestAction::TestEq(
+            r#"run(() => f("obj", obj)[f("key", key)] ??= f("rhs", 0))"#,
+            "\"14 14 obj,key,toKey\"",
+        ),
+        TestAction::TestEq(
+            r#"run(() => f("obj", obj)[f("key", key)] &&= f("rhs", 0))"#,
+            "\"0 0 obj,key,toKey,rhs\"",
+        ),
+        TestAction::TestEq(
+            r#"run(() => f("obj", obj)[f("key", key)] ||= f("rhs", 5))"#,
+            "\"5 5 obj,key,toKey,rhs\"",
+        ),
+    ]);
+}
+
+#[test]
+fn compound_assignment_gets_and_sets_once() {
+    let scenario = r#"
+        var log = [];
+        var target = { k: 1 };
+        var proxy = new Proxy(target, {
+            get(t, k) { log.push("get " + k); return t[k]; },
+            set(t, k, v) { log.push("set " + k); t[k] = v; return true; },
+        });
+        var key = { toString() { log.push("toKey"); return "k"; } };
+        proxy[key] += 1;
+        proxy[key]++;
+        proxy.k ??= 0;
+        [target.k, log.join()].join(" ")
+    "#;
+    assert_eq!(
+        &exec(scenario),
+        "\"3 toKey,get k,set k,toKey,get k,set k,get k\""
+    );
+}
+
 #[test]
 fn assignment_to_non_assignable() {
     // Relates to the behaviour described at
@@ -1458,6 +1732,34 @@ fn test_strict_mode_delete() {
     )]);
 }
 
+#[test]
+fn test_strict_mode_delete_property() {
+    let mut context = Context::default();
+
+    let init = r#"
+        'use strict';
+        const a = { b: 5 };
+        Object.defineProperty(a, 'c', { value: 5 });
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "'use strict'; delete a.b"), "true");
+    assert_eq!(
+        forward(&mut context, "'use strict'; delete a.missing"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "'use strict'; delete a['c']"),
+        "Uncaught \"TypeError\": \"Cannot delete property\""
+    );
+    assert_eq!(
+        forward(&mut context, "(function() { 'use strict'; delete a.c; })()"),
+        "Uncaught \"TypeError\": \"Cannot delete property\""
+    );
+    assert!(forward(&mut context, "'use strict'; delete (a);")
+        .starts_with("Uncaught \"SyntaxError\": "));
+}
+
 #[test]
 fn test_strict_mode_reserved_name() {
     // Checks that usage of a reserved keyword for an identifier name is
@@ -1521,6 +1823,33 @@ fn test_strict_mode_dup_func_parameters() {
     )]);
 }
 
+#[test]
+fn test_strict_mode_directive_prologue() {
+    // Checks as per https://tc39.es/ecma262/#directive-prologue that a "use strict" directive
+    // anywhere in the directive prologue enables strict mode, and only there.
+
+    let strict_cases = [
+        "'a'; 'use strict'; 023;",
+        "function f() { 'a'\n 'use strict'; 023; }",
+    ];
+    let sloppy_cases = [
+        "var a = 1; 'use strict'; 023;",
+        "'use strict' + 1; 023;",
+        "'use\\x20strict'; 023;",
+        "function f() { ('use strict'); return 023; } f();",
+    ];
+
+    for case in &strict_cases {
+        let mut context = Context::default();
+        let string = forward(&mut context, case);
+        assert!(string.starts_with("Uncaught \"SyntaxError\": "), "{case}");
+    }
+    for case in &sloppy_cases {
+        let mut context = Context::default();
+        assert_eq!(forward(&mut context, case), "19", "{case}");
+    }
+}
+
 #[test]
 fn test_empty_statement() {
     let src = r#"
@@ -1530,3 +1859,1076 @@ fn test_empty_statement() {
     "#;
     assert_eq!(&exec(src), "10");
 }
+
+#[test]
+fn catch_binding_does_not_leak() {
+    let scenario = r#"
+        try {
+            throw 'error';
+        } catch (e) {
+            var x = e;
+        }
+    "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq("typeof e", "\"undefined\""),
+        TestAction::TestEq("x", "\"error\""),
+    ]);
+}
+
+#[test]
+fn catch_binding_shadows_outer_binding() {
+    let scenario = r#"
+        let e = 'outer';
+        let inner;
+        try {
+            throw 'inner';
+        } catch (e) {
+            inner = e;
+        }
+        [e, inner].join()
+    "#;
+    assert_eq!(&exec(scenario), "\"outer,inner\"");
+}
+
+#[test]
+fn catch_binding_var_redeclaration() {
+    // Annex B.3.5: the `var` is hoisted to the enclosing function, but its
+    // initializer assigns to the catch parameter.
+    let scenario = r#"
+        function f() {
+            try {
+                throw 1;
+            } catch (e) {
+                var e = 2;
+                var inner = e;
+            }
+            return [typeof e, inner].join();
+        }
+        f()
+    "#;
+    assert_eq!(&exec(scenario), "\"undefined,2\"");
+}
+
+#[test]
+fn catch_binding_lexical_redeclaration() {
+    let scenario = r#"
+        try {} catch (e) { let e = 1; }
+    "#;
+
+    check_output(&[TestAction::TestStartsWith(
+        scenario,
+        "Uncaught \"SyntaxError\": ",
+    )]);
+}
+
+#[test]
+fn global_not_found_hook() {
+    use crate::property::Attribute;
+    use std::{cell::Cell, rc::Rc};
+
+    let calls = Rc::new(Cell::new(0));
+    let mut context = Context::default();
+    {
+        let calls = calls.clone();
+        context.set_global_not_found_hook(move |name, context| {
+            if name != "lazy" {
+                return Ok(None);
+            }
+            calls.set(calls.get() + 1);
+            let value = JsValue::new("loaded");
+            context.register_global_property("lazy", value.clone(), Attribute::all());
+            Ok(Some(value))
+        });
+    }
+
+    assert_eq!(forward(&mut context, "lazy"), "\"loaded\"");
+    assert_eq!(forward(&mut context, "lazy + '!'"), "\"loaded!\"");
+    assert_eq!(calls.get(), 1);
+    assert_eq!(
+        forward(&mut context, "missing"),
+        "Uncaught \"ReferenceError\": \"missing is not defined\""
+    );
+}
+
+#[test]
+fn custom_script_this() {
+    use crate::{object::ObjectInitializer, property::Attribute};
+
+    let mut context = Context::default();
+    assert_eq!(forward(&mut context, "this === globalThis"), "true");
+
+    let this = ObjectInitializer::new(&mut context)
+        .property("name", "worker", Attribute::all())
+        .build();
+    context.set_script_this(this.into());
+
+    assert_eq!(forward(&mut context, "this.name"), "\"worker\"");
+    assert_eq!(forward(&mut context, "this === globalThis"), "false");
+    assert_eq!(forward(&mut context, "(() => this.name)()"), "\"worker\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "(function () { return this === globalThis; })()"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn run_jobs_drains_promise_jobs() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        r#"
+        var log = [];
+        function schedule() {
+            Promise.resolve().then(() => {
+                log.push("outer");
+                Promise.resolve().then(() => log.push("nested"));
+            });
+        }
+        "#,
+    );
+
+    // Calling the function from Rust doesn't drain the queue, unlike a top-level `eval`.
+    let schedule = context
+        .global_object()
+        .clone()
+        .get("schedule", &mut context)
+        .unwrap();
+    schedule
+        .as_object()
+        .unwrap()
+        .call(&JsValue::undefined(), &[], &mut context)
+        .unwrap();
+    assert_eq!(forward(&mut context, "log.length"), "0");
+
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "log.join()"), "\"outer,nested\"");
+}
+
+#[test]
+fn enqueue_native_job() {
+    use crate::job::NativeJob;
+
+    let mut context = Context::default();
+    forward(&mut context, "var log = [];");
+    context.enqueue_job(NativeJob::new(|context| {
+        context.eval("log.push('first'); Promise.resolve().then(() => log.push('third'))")
+    }));
+    context.enqueue_job(NativeJob::new(|context| context.eval("log.push('second')")));
+
+    context.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"first,second,third\""
+    );
+}
+
+#[test]
+fn eval_with_scope() {
+    let mut context = Context::default();
+
+    let value = context
+        .eval_with_scope("a + b", &[("a", 1.into()), ("b", 2.into())])
+        .unwrap();
+    assert_eq!(value.as_number(), Some(3.0));
+    assert_eq!(forward(&mut context, "typeof a"), "\"undefined\"");
+
+    // Functions created by the code keep access to the scope.
+    let value = context
+        .eval_with_scope("a = a * 2; () => a + '!'", &[("a", 3.into())])
+        .unwrap();
+    let value = value
+        .as_object()
+        .unwrap()
+        .call(&JsValue::undefined(), &[], &mut context)
+        .unwrap();
+    assert_eq!(value.display().to_string(), "\"6!\"");
+
+    forward(&mut context, "var global = 10;");
+    let value = context
+        .eval_with_scope("let c = global + a; c", &[("a", 5.into())])
+        .unwrap();
+    assert_eq!(value.as_number(), Some(15.0));
+    assert_eq!(forward(&mut context, "typeof c"), "\"undefined\"");
+}
+
+#[test]
+fn list_and_delete_global_bindings() {
+    let mut context = Context::default();
+    forward(&mut context, "var user = 'defined'; implicit = 1;");
+
+    let bindings = context.global_bindings();
+    assert!(bindings.contains(&"user".to_string()));
+    assert!(bindings.contains(&"implicit".to_string()));
+    assert!(bindings.contains(&"Array".to_string()));
+
+    assert!(context.delete_global("user"));
+    assert!(!context.global_bindings().contains(&"user".to_string()));
+    assert_eq!(
+        forward(&mut context, "user"),
+        "Uncaught \"ReferenceError\": \"user is not defined\""
+    );
+    assert!(context.delete_global("user"));
+    assert_eq!(forward(&mut context, "implicit"), "1");
+}
+
+#[test]
+fn wrap_global_in_proxy() {
+    let mut context = Context::default();
+    let handler = forward_val(
+        &mut context,
+        r#"
+        var writes = [];
+        var counter = 0;
+        (function (writes, Reflect) {
+            return {
+                set(target, key, value) {
+                    writes.push(key + "=" + value);
+                    return Reflect.set(target, key, value);
+                },
+                has(target, key) {
+                    return key === "virtual" || Reflect.has(target, key);
+                },
+            };
+        })(writes, Reflect)
+        "#,
+    )
+    .unwrap();
+    context.wrap_global_in_proxy(handler.as_object().unwrap().clone());
+
+    forward(&mut context, "counter = counter + 1; created = 'new';");
+    assert_eq!(
+        forward(&mut context, "writes.join()"),
+        "\"counter=1,created=new\""
+    );
+    assert_eq!(forward(&mut context, "counter"), "1");
+    assert_eq!(forward(&mut context, "globalThis.created"), "\"new\"");
+
+    // Identifier resolution goes through the `has` trap.
+    assert_eq!(forward(&mut context, "typeof virtual"), "\"undefined\"");
+    assert_eq!(forward(&mut context, "virtual"), "undefined");
+    assert_eq!(
+        forward(&mut context, "missing"),
+        "Uncaught \"ReferenceError\": \"missing is not defined\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "(function () { 'use strict'; missing = 1; })()"
+        ),
+        "Uncaught \"ReferenceError\": \"missing is not defined\""
+    );
+}
+
+#[test]
+fn realms_are_isolated() {
+    use crate::property::Attribute;
+
+    let mut context = Context::default();
+    let main = context.current_realm();
+    let sandbox = context.create_realm();
+    assert_eq!(context.current_realm(), main);
+
+    forward(&mut context, "var main_global = 1; let main_lexical = 2;");
+    context
+        .eval_in_realm(
+            sandbox,
+            "var sandbox_global = 3; Array.prototype.tainted = true;",
+        )
+        .unwrap();
+
+    assert_eq!(
+        forward(&mut context, "typeof sandbox_global"),
+        "\"undefined\""
+    );
+    assert_eq!(forward(&mut context, "[].tainted"), "undefined");
+    assert_eq!(
+        context
+            .eval_in_realm(sandbox, "typeof main_global + typeof main_lexical")
+            .unwrap(),
+        "undefinedundefined".into()
+    );
+    assert_eq!(context.current_realm(), main);
+
+    // Objects passed between realms keep the prototype chain of the realm that created them.
+    let array = context.eval_in_realm(sandbox, "[1, 2]").unwrap();
+    context.register_global_property("foreign", array, Attribute::all());
+    assert_eq!(forward(&mut context, "foreign instanceof Array"), "false");
+    assert_eq!(
+        forward(&mut context, "Array.isArray(foreign) && foreign.tainted"),
+        "true"
+    );
+
+    let previous = context.enter_realm(sandbox);
+    assert_eq!(previous, main);
+    assert_eq!(forward(&mut context, "sandbox_global"), "3");
+    context.enter_realm(main);
+    assert_eq!(forward(&mut context, "main_global + main_lexical"), "3");
+}
+
+#[test]
+fn identifier_strings_are_shared() {
+    use crate::JsString;
+
+    let mut context = Context::default();
+    let sym = context.interner_mut().get_or_intern("shared_name");
+    let first = context.identifier_string(sym);
+    let second = context.identifier_string(sym);
+    assert!(JsString::ptr_eq(&first, &second));
+
+    // Once the cache is full, the strings of new identifiers are still correct, but aren't
+    // shared.
+    for i in 0..crate::context::IDENTIFIER_STRING_CACHE_LIMIT {
+        let sym = context.interner_mut().get_or_intern(format!("name{i}"));
+        context.identifier_string(sym);
+    }
+    let sym = context.interner_mut().get_or_intern("unshared_name");
+    let first = context.identifier_string(sym);
+    let second = context.identifier_string(sym);
+    assert_eq!(first, second);
+    assert!(!JsString::ptr_eq(&first, &second));
+
+    // Keys computed at runtime still find the properties defined by identifiers.
+    forward(&mut context, "var o = {}; o.shared_name = 1;");
+    assert_eq!(forward(&mut context, "o['shared' + '_name']"), "1");
+    assert_eq!(
+        forward(&mut context, "o['shared' + '_name'] = 2; o.shared_name"),
+        "2"
+    );
+}
+
+#[test]
+fn instruction_budget_aborts_infinite_loop() {
+    let mut context = Context::default();
+    context.set_instruction_budget(Some(10_000));
+
+    assert!(context.eval("while (true) {}").is_err());
+    assert!(context.runtime_limit_exceeded());
+    assert_eq!(context.instruction_budget(), Some(0));
+
+    context.set_instruction_budget(Some(10_000));
+    assert_eq!(forward(&mut context, "1 + 1"), "2");
+    assert!(!context.runtime_limit_exceeded());
+
+    context.set_instruction_budget(None);
+    assert_eq!(
+        forward(
+            &mut context,
+            "let sum = 0; for (let i = 0; i < 10000; i++) { sum += i; } sum"
+        ),
+        "49995000"
+    );
+}
+
+#[test]
+fn instruction_budget_abort_is_uncatchable() {
+    let mut context = Context::default();
+    context.set_instruction_budget(Some(10_000));
+
+    let script = r#"
+        var caught = false;
+        var finalized = false;
+        function spin() {
+            while (true) {}
+        }
+        try {
+            spin();
+        } catch (e) {
+            caught = true;
+            while (true) {}
+        } finally {
+            finalized = true;
+        }
+        "#;
+    assert!(context.eval(script).is_err());
+    assert!(context.runtime_limit_exceeded());
+
+    context.set_instruction_budget(None);
+    assert_eq!(forward(&mut context, "caught"), "false");
+    assert_eq!(forward(&mut context, "finalized"), "false");
+
+    // The abort isn't handed to the handlers, even when the handlers don't run any code of
+    // their own before the script goes on.
+    context.set_instruction_budget(Some(10_000));
+    assert!(context
+        .eval("try { while (true) {} } catch {} globalThis.resumed = true;")
+        .is_err());
+    assert!(context.runtime_limit_exceeded());
+    context.set_instruction_budget(None);
+    assert_eq!(forward(&mut context, "globalThis.resumed"), "undefined");
+}
+
+#[test]
+fn instruction_budget_exact_and_exceeded() {
+    let script = "let sum = 0; for (let i = 0; i < 10; i++) { sum += i; } sum";
+
+    let mut context = Context::default();
+    context.set_instruction_budget(Some(u64::MAX));
+    context.eval(script).unwrap();
+    let used = u64::MAX - context.instruction_budget().unwrap();
+
+    // Using exactly the budget completes the script.
+    let mut context = Context::default();
+    context.set_instruction_budget(Some(used));
+    assert_eq!(context.eval(script).unwrap(), JsValue::new(45));
+    assert_eq!(context.instruction_budget(), Some(0));
+    assert!(!context.runtime_limit_exceeded());
+
+    // One instruction less aborts it.
+    let mut context = Context::default();
+    context.set_instruction_budget(Some(used - 1));
+    assert!(context.eval(script).is_err());
+    assert!(context.runtime_limit_exceeded());
+
+    // A `RangeError` thrown by the script isn't mistaken for the abort.
+    let mut context = Context::default();
+    context.set_instruction_budget(Some(10_000));
+    assert!(context.eval("throw new RangeError('oops')").is_err());
+    assert!(!context.runtime_limit_exceeded());
+}
+
+#[test]
+fn class_field_initializers_run_in_order() {
+    let scenario = r#"
+        class C {
+            x = this.y;
+            y = 1;
+        }
+        var c = new C();
+    "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq("c.x", "undefined"),
+        TestAction::TestEq("c.y", "1"),
+        TestAction::TestEq("Object.keys(c).join()", "\"x,y\""),
+    ]);
+}
+
+#[test]
+fn class_fields_of_derived_class_are_initialized_after_super() {
+    let scenario = r#"
+        var log = [];
+        class A {
+            a = log.push("a field");
+            constructor() { log.push("A constructor"); }
+        }
+        class B extends A {
+            b = log.push("b field") && this.a;
+            constructor() {
+                log.push("B before super");
+                super();
+                log.push("B after super");
+            }
+        }
+        var b = new B();
+    "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq(
+            "log.join()",
+            "\"B before super,a field,A constructor,b field,B after super\"",
+        ),
+        TestAction::TestEq("b.b", "2"),
+    ]);
+}
+
+#[test]
+fn class_methods_accessors_and_statics() {
+    let scenario = r#"
+        class Point {
+            constructor(x, y) {
+                this.x = x;
+                this.y = y;
+            }
+            get length() { return Math.sqrt(this.x * this.x + this.y * this.y); }
+            scale(n) { return new Point(this.x * n, this.y * n); }
+            static origin = new Point(0, 0);
+            static of(x, y) { return new Point(x, y); }
+        }
+        var p = Point.of(3, 4).scale(2);
+    "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq("p.length", "10"),
+        TestAction::TestEq("Point.origin.length", "0"),
+        TestAction::TestEq("p instanceof Point", "true"),
+        TestAction::TestEq("Object.keys(Point.prototype).length", "0"),
+        TestAction::TestEq("typeof Point", "\"function\""),
+    ]);
+}
+
+#[test]
+fn object_literal_accessor_pair() {
+    let scenario = r#"
+        var key = "y";
+        var o = {
+            get x() { return this._x; },
+            set x(v) { this._x = v; },
+            set [key](v) {},
+            get [key]() { return 1; },
+            get [Symbol.iterator]() {},
+        };
+        var x = Object.getOwnPropertyDescriptor(o, "x");
+        var y = Object.getOwnPropertyDescriptor(o, "y");
+        o.x = 5;
+    "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq("Object.keys(o).join()", "\"x,y,_x\""),
+        TestAction::TestEq("typeof x.get + ' ' + typeof x.set", "\"function function\""),
+        TestAction::TestEq("x.enumerable && x.configurable", "true"),
+        TestAction::TestEq("'value' in x", "false"),
+        TestAction::TestEq("o.x", "5"),
+        TestAction::TestEq("x.get.name", "\"get x\""),
+        TestAction::TestEq("x.set.name", "\"set x\""),
+        TestAction::TestEq("y.get.name + ', ' + y.set.name", "\"get y, set y\""),
+        TestAction::TestEq(
+            "Object.getOwnPropertyDescriptor(o, Symbol.iterator).get.name",
+            "\"get [Symbol.iterator]\"",
+        ),
+    ]);
+}
+
+#[test]
+fn class_accessor_pair() {
+    let scenario = r#"
+        var key = "y";
+        class C {
+            get x() { return 1; }
+            set x(v) {}
+            static set [key](v) {}
+            static get [key]() { return 2; }
+        }
+        var x = Object.getOwnPropertyDescriptor(C.prototype, "x");
+        var y = Object.getOwnPropertyDescriptor(C, "y");
+    "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq("typeof x.get + ' ' + typeof x.set", "\"function function\""),
+        TestAction::TestEq("x.enumerable", "false"),
+        TestAction::TestEq("x.get.name + ', ' + x.set.name", "\"get x, set x\""),
+        TestAction::TestEq("y.get.name + ', ' + y.set.name", "\"get y, set y\""),
+        TestAction::TestEq("C.y", "2"),
+    ]);
+}
+
+#[test]
+fn class_super_property_and_default_constructor() {
+    let scenario = r#"
+        class Animal {
+            constructor(name) { this.name = name; }
+            speak() { return this.name + " makes a sound"; }
+        }
+        class Dog extends Animal {
+            speak() { return super.speak() + " (woof)"; }
+        }
+        var d = new Dog("Rex");
+    "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq("d.speak()", "\"Rex makes a sound (woof)\""),
+        TestAction::TestEq("Object.getPrototypeOf(Dog) === Animal", "true"),
+    ]);
+}
+
+#[test]
+fn class_constructor_errors() {
+    let scenario = r#"
+        class A {}
+        class B extends A {
+            constructor() { this.x = 1; }
+        }
+        class C extends A {
+            constructor() {}
+        }
+        class D extends A {
+            constructor() { super(); super(); }
+        }
+    "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestStartsWith("A()", "Uncaught \"TypeError\""),
+        TestAction::TestStartsWith("new B()", "Uncaught \"ReferenceError\""),
+        TestAction::TestStartsWith("new C()", "Uncaught \"ReferenceError\""),
+        TestAction::TestStartsWith("new D()", "Uncaught \"ReferenceError\""),
+        TestAction::TestStartsWith("class E extends 1 {}", "Uncaught \"TypeError\""),
+    ]);
+}
+
+#[test]
+fn class_private_members() {
+    let scenario = r#"
+        class A {
+            #x = 1;
+            static #count = 0;
+            #double() { return this.#x * 2; }
+            get #value() { return this.#x; }
+            set #value(v) { this.#x = v; }
+            constructor() { A.#count++; }
+            read() { return this.#double() + this.#value; }
+            write(v) { this.#value = v; return this.#x; }
+            static count() { return A.#count; }
+            static readFrom(o) { return o.#x; }
+            static callOn(o) { return o.#double(); }
+            static writeMethod(o) { o.#double = 1; }
+        }
+        let a = new A();
+    "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq("a.read()", "3"),
+        TestAction::TestEq("a.write(5)", "5"),
+        TestAction::TestEq("A.readFrom(a)", "5"),
+        TestAction::TestEq("A.count()", "1"),
+        TestAction::TestEq("Object.keys(a).length", "0"),
+        TestAction::TestStartsWith("A.readFrom({})", "Uncaught \"TypeError\""),
+        TestAction::TestStartsWith("A.callOn({})", "Uncaught \"TypeError\""),
+        TestAction::TestStartsWith("A.writeMethod(a)", "Uncaught \"TypeError\""),
+    ]);
+}
+
+#[test]
+fn class_private_names_are_unique_per_evaluation() {
+    let scenario = r#"
+        function makeClass() {
+            return class {
+                #secret = 42;
+                static read(o) { return o.#secret; }
+            };
+        }
+        let C1 = makeClass();
+        let C2 = makeClass();
+    "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq("C1.read(new C1())", "42"),
+        TestAction::TestStartsWith("C1.read(new C2())", "Uncaught \"TypeError\""),
+    ]);
+}
+
+#[test]
+fn class_private_in() {
+    let scenario = r#"
+        class A {
+            #x;
+            #m() {}
+            static hasX(o) { return #x in o; }
+            static hasM(o) { return #m in o; }
+        }
+        class B extends A {}
+    "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq("A.hasX(new A())", "true"),
+        TestAction::TestEq("A.hasM(new A())", "true"),
+        TestAction::TestEq("A.hasX(new B())", "true"),
+        TestAction::TestEq("A.hasX({})", "false"),
+        TestAction::TestEq("A.hasX(A)", "false"),
+        TestAction::TestStartsWith("A.hasX(1)", "Uncaught \"TypeError\""),
+    ]);
+}
+
+#[test]
+fn class_private_async_methods() {
+    let scenario = r#"
+        var results = [];
+        class D {
+            x = 1;
+            async #m(y) { return this.x + (await y); }
+            static async #s() { return await "static"; }
+            static x = 2;
+            run() { return this.#m(Promise.resolve(2)); }
+            static runStatic() { return D.#s(); }
+        }
+        new D().run().then((x) => results.push(x));
+        D.runStatic().then((x) => results.push(x));
+    "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq("typeof D", "\"function\""),
+        TestAction::TestEq("D.x", "2"),
+        TestAction::TestEq("new D().x", "1"),
+        TestAction::TestEq("new D().run() instanceof Promise", "true"),
+        TestAction::TestEq("results.join()", "\"3,static\""),
+    ]);
+}
+
+#[test]
+fn class_private_brand_check_on_proxies() {
+    let scenario = r#"
+        class A {
+            #x = 1;
+            #m() { return "m"; }
+            callM() { return this.#m(); }
+            readX() { return this.#x; }
+            static hasM(o) { return #m in o; }
+        }
+        let a = new A();
+        let traps = [];
+        let proxy = new Proxy(a, {
+            get(target, key, receiver) {
+                traps.push("get");
+                return Reflect.get(target, key, receiver);
+            },
+            has(target, key) {
+                traps.push("has");
+                return Reflect.has(target, key);
+            },
+        });
+    "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq("a.callM()", "\"m\""),
+        TestAction::TestStartsWith("proxy.callM()", "Uncaught \"TypeError\""),
+        TestAction::TestStartsWith("proxy.readX()", "Uncaught \"TypeError\""),
+        TestAction::TestEq("A.hasM(proxy)", "false"),
+        // Only the lookups of `callM` and `readX` go through the proxy.
+        TestAction::TestEq("traps.join()", "\"get,get\""),
+    ]);
+}
+
+#[test]
+fn new_target() {
+    let scenario = r#"
+        function F() { return new.target; }
+        function Arrow() { return (() => new.target)(); }
+        function Default(target = new.target) { this.target = target; }
+        class Base { constructor() { this.name = new.target.name; } }
+        class Derived extends Base {}
+    "#;
+
+    check_output(&[
+        TestAction::Execute(scenario),
+        TestAction::TestEq("F()", "undefined"),
+        TestAction::TestEq("new F() === F", "true"),
+        TestAction::TestEq("Arrow()", "undefined"),
+        TestAction::TestEq("new Arrow() === Arrow", "true"),
+        TestAction::TestEq("new Default().target === Default", "true"),
+        TestAction::TestEq("new Derived().name", "\"Derived\""),
+        TestAction::TestEq("new (class { f = new.target; })().f", "undefined"),
+        TestAction::TestStartsWith("new.target", "Uncaught \"SyntaxError\": "),
+        TestAction::TestStartsWith("(() => new.target)()", "Uncaught \"SyntaxError\": "),
+    ]);
+}
+
+#[test]
+fn check_syntax_reports_early_errors() {
+    let mut context = Context::default();
+
+    assert!(context.check_syntax("let a = 1; { let a = 2; }").is_ok());
+    assert!(context
+        .check_syntax("throw new Error('not evaluated');")
+        .is_ok());
+
+    for src in ["let a; let a;", "1 = 2;", "let a = ;"] {
+        let error = context
+            .check_syntax(src)
+            .expect_err("syntax error was not reported");
+        assert_eq!(
+            error
+                .as_object()
+                .and_then(|e| e.get("name", &mut context).ok()),
+            Some("SyntaxError".into()),
+            "{src}"
+        );
+    }
+
+    // Nothing is evaluated, so no binding is created.
+    assert!(context.check_syntax("var checked = 1;").is_ok());
+    assert_eq!(forward(&mut context, "typeof checked"), "\"undefined\"");
+}
+
+#[test]
+fn error_trace_positions() {
+    use crate::syntax::ast::Position;
+
+    let mut context = Context::default();
+
+    let scenario = r#"
+        function inner() {
+            throw new Error("boom");
+        }
+        function outer() {
+            if (true) {
+                inner();
+            }
+        }
+        outer();
+    "#;
+    assert!(context.eval(scenario).is_err());
+
+    let trace: Vec<_> = context
+        .error_trace()
+        .iter()
+        .map(|frame| {
+            (
+                frame.function_name().to_owned(),
+                frame.position().map(Position::line_number),
+            )
+        })
+        .collect();
+    assert_eq!(
+        trace,
+        [
+            ("inner".to_owned(), Some(3)),
+            ("outer".to_owned(), Some(7)),
+            ("<main>".to_owned(), Some(10)),
+        ]
+    );
+
+    // Errors that are caught don't leave a trace.
+    let scenario = r#"
+        try {
+            null.x;
+        } catch (e) {}
+    "#;
+    assert!(context.eval(scenario).is_ok());
+    assert!(context.error_trace().is_empty());
+
+    let scenario = r#"
+        let a = 1;
+        a = 2;
+        null.x;
+    "#;
+    assert!(context.eval(scenario).is_err());
+    let frame = &context.error_trace()[0];
+    assert_eq!(frame.position().unwrap().line_number(), 4);
+    assert_eq!(frame.position().unwrap().column_number(), 9);
+}
+
+#[test]
+fn eval_transformed_ast() {
+    use crate::syntax::ast::{node::StatementList, Const, Node};
+
+    let mut context = Context::default();
+    let statement_list = context
+        .parse("var calls = 0; function f() { return ++calls; } f(); 21;")
+        .unwrap();
+
+    let items: Vec<_> = statement_list
+        .items()
+        .iter()
+        .map(|node| match node {
+            Node::Const(Const::Int(value)) => Node::Const(Const::Int(value * 2)),
+            node => node.clone(),
+        })
+        .collect();
+    let transformed = StatementList::from(items);
+
+    assert_eq!(context.eval_ast(&transformed).unwrap(), JsValue::new(42));
+    assert_eq!(forward(&mut context, "calls"), "1");
+}
+
+#[test]
+fn native_class_inheritance() {
+    use crate::{
+        builtins::JsArgs,
+        class::{Class, ClassBuilder},
+        gc::{Finalize, Trace},
+        JsResult,
+    };
+
+    #[derive(Debug, Trace, Finalize)]
+    struct Animal {
+        name: String,
+    }
+
+    impl Class for Animal {
+        const NAME: &'static str = "Animal";
+        const LENGTH: usize = 1;
+
+        fn constructor(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<Self> {
+            let name = args.get_or_undefined(0).to_string(context)?;
+            Ok(Self {
+                name: name.to_string(),
+            })
+        }
+
+        fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+            class.method("name", 0, |this, _, context| {
+                match this.as_object().and_then(|object| {
+                    object
+                        .downcast_ref::<Self>()
+                        .map(|animal| animal.name.clone())
+                }) {
+                    Some(name) => Ok(name.into()),
+                    None => context.throw_type_error("'this' is not an Animal"),
+                }
+            });
+            class.static_method("kingdom", 0, |_, _, _| Ok("Animalia".into()));
+            Ok(())
+        }
+    }
+
+    #[derive(Debug, Trace, Finalize)]
+    struct Dog;
+
+    impl Class for Dog {
+        const NAME: &'static str = "Dog";
+        const PARENT: Option<&'static str> = Some("Animal");
+
+        fn constructor(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<Self> {
+            Ok(Self)
+        }
+
+        fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+            class.method("bark", 0, |_, _, _| Ok("woof".into()));
+            Ok(())
+        }
+    }
+
+    let mut context = Context::default();
+    let error = context.register_global_class::<Dog>().unwrap_err();
+    assert_eq!(
+        error.to_string(&mut context).unwrap(),
+        "TypeError: parent class `Animal` of native class `Dog` is not registered"
+    );
+
+    context.register_global_class::<Animal>().unwrap();
+    context.register_global_class::<Dog>().unwrap();
+
+    let init = r#"
+        class Cat extends Animal {
+            constructor(name) {
+                super(name + " the cat");
+                this.lives = 9;
+            }
+            describe() {
+                return this.name() + " has " + this.lives + " lives";
+            }
+        }
+        class Puppy extends Dog {
+            constructor() {
+                super();
+            }
+        }
+        var cat = new Cat("Tom");
+        var dog = new Dog();
+        var puppy = new Puppy();
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "cat.describe()"),
+        "\"Tom the cat has 9 lives\""
+    );
+    assert_eq!(forward(&mut context, "cat instanceof Animal"), "true");
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(Dog) === Animal"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(Dog.prototype) === Animal.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "Dog.kingdom()"), "\"Animalia\"");
+    assert_eq!(forward(&mut context, "dog instanceof Animal"), "true");
+    assert_eq!(forward(&mut context, "dog.bark()"), "\"woof\"");
+    assert_eq!(forward(&mut context, "puppy instanceof Animal"), "true");
+    assert_eq!(forward(&mut context, "puppy.bark()"), "\"woof\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(puppy) === Puppy.prototype"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn needs_more_input() {
+    let mut context = Context::default();
+
+    let incomplete = [
+        "function f() {",
+        "f(1,",
+        "[1, 2",
+        "let x =",
+        "1 +",
+        "if (x)",
+        "class A {",
+        "`template ${",
+        "`template",
+        "/* comment",
+        "'line continuation \\",
+    ];
+    for src in incomplete {
+        assert!(context.needs_more_input(src), "{src}");
+    }
+
+    let complete_or_invalid = [
+        "function f() {}",
+        "function f() }",
+        "a b",
+        "{ let }",
+        "'unterminated",
+        "'unterminated\nstring'",
+        "/regex",
+        "",
+    ];
+    for src in complete_or_invalid {
+        assert!(!context.needs_more_input(src), "{src}");
+    }
+}
+
+#[test]
+fn lazy_builtins() {
+    use crate::property::Attribute;
+
+    let mut context = Context::default();
+
+    // `String.prototype.match` creates a regular expression before `RegExp` is ever used.
+    assert_eq!(forward(&mut context, "'abcb'.match('b').index"), "1");
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            const names = Object.getOwnPropertyNames(globalThis);
+            names.indexOf("Math") < names.indexOf("Intl") && names.indexOf("Intl") < names.indexOf("JSON")
+        "#
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            const desc = Object.getOwnPropertyDescriptor(globalThis, "Date");
+            `${desc.value === Date} ${desc.writable} ${desc.enumerable} ${desc.configurable}`
+        "#
+        ),
+        "\"true true false true\""
+    );
+    assert_eq!(forward(&mut context, "typeof Intl"), "\"object\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(/a/) === RegExp.prototype"
+        ),
+        "true"
+    );
+
+    let mut context = Context::default();
+    context.register_global_property("Date", 1, Attribute::all());
+    assert_eq!(forward(&mut context, "Date"), "1");
+    assert_eq!(forward(&mut context, "RegExp = 2; RegExp"), "2");
+}
@@ -1,4 +1,10 @@
-use crate::{object::ObjectKind, property::PropertyDescriptor};
+use crate::{
+    builtins::Number,
+    object::{JsObject, Object, ObjectKind},
+    property::PropertyDescriptor,
+    symbol::WellKnownSymbols,
+    Context,
+};
 
 use super::{fmt, Display, HashSet, JsValue, PropertyKey};
 
@@ -263,6 +269,241 @@ pub(crate) fn display_obj(v: &JsValue, print_internals: bool) -> String {
     display_obj_internal(v, &mut encounters, 4, print_internals)
 }
 
+/// The nesting depth after which [`JsValue::inspect`] abbreviates objects as `[Object]`.
+const INSPECT_MAX_DEPTH: usize = 2;
+
+/// The number of elements after which [`JsValue::inspect`] stops printing a collection.
+const INSPECT_MAX_ITEMS: usize = 100;
+
+/// The implementation of [`JsValue::inspect`].
+///
+/// `ancestors` contains the objects that are being printed up the current branch, which are
+/// printed as `[Circular]` if they are encountered again.
+pub(crate) fn inspect(
+    value: &JsValue,
+    depth: usize,
+    ancestors: &mut Vec<JsObject>,
+    context: &mut Context,
+) -> String {
+    let object = match value {
+        JsValue::Object(object) => object,
+        _ => return value.display().to_string(),
+    };
+
+    if ancestors
+        .iter()
+        .any(|ancestor| JsObject::equals(ancestor, object))
+    {
+        return String::from("[Circular]");
+    }
+
+    // Proxies are never inspected, since that would run the traps of their handler.
+    if object.borrow().is_proxy() {
+        return String::from("[Proxy]");
+    }
+
+    if object.is_callable() {
+        return match own_data_property(object, "name", context)
+            .and_then(|name| name.as_string().cloned())
+        {
+            Some(name) if !name.is_empty() => format!("[Function: {name}]"),
+            _ => String::from("[Function (anonymous)]"),
+        };
+    }
+
+    if object.is_error() {
+        let name = value
+            .get_property("name")
+            .and_then(|prop| prop.value().cloned());
+        let message = value
+            .get_property("message")
+            .and_then(|prop| prop.value().cloned());
+        return format!(
+            "{}: {}",
+            name.unwrap_or_default(),
+            message.unwrap_or_default()
+        );
+    }
+
+    let boxed = match object.borrow().kind() {
+        ObjectKind::String(ref string) => Some(format!("[String: \"{string}\"]")),
+        ObjectKind::Number(number) => {
+            Some(format!("[Number: {}]", JsValue::new(*number).display()))
+        }
+        ObjectKind::Boolean(boolean) => Some(format!("[Boolean: {boolean}]")),
+        ObjectKind::BigInt(ref bigint) => Some(format!("[BigInt: {bigint}n]")),
+        ObjectKind::Symbol(ref symbol) => Some(format!(
+            "[Symbol: {}]",
+            JsValue::new(symbol.clone()).display()
+        )),
+        _ => None,
+    };
+    if let Some(boxed) = boxed {
+        return boxed;
+    }
+
+    let is_array = object.is_array();
+    if depth > INSPECT_MAX_DEPTH {
+        return String::from(if is_array { "[Array]" } else { "[Object]" });
+    }
+
+    ancestors.push(object.clone());
+    let (prefix, mut entries) = if is_array {
+        (
+            String::new(),
+            inspect_array_elements(object, depth, ancestors, context),
+        )
+    } else {
+        inspect_collection(object, depth, ancestors, context)
+    };
+
+    // The remaining own enumerable properties, skipping the elements of arrays.
+    let keys = object.__own_property_keys__(context).unwrap_or_default();
+    for key in keys {
+        if is_array && matches!(key, PropertyKey::Index(_)) {
+            continue;
+        }
+        let property = match object.__get_own_property__(&key, context) {
+            Ok(Some(property)) if property.expect_enumerable() => property,
+            _ => continue,
+        };
+        let value = inspect_property(&property, depth, ancestors, context);
+        entries.push(format!("{}: {value}", inspect_key(&key)));
+    }
+    ancestors.pop();
+
+    let (open, close) = if is_array { ("[", "]") } else { ("{", "}") };
+    if entries.is_empty() {
+        format!("{prefix}{open}{close}")
+    } else {
+        format!("{prefix}{open} {} {close}", entries.join(", "))
+    }
+}
+
+/// Gets the value of an own data property of `object`, without running any user code.
+fn own_data_property(object: &JsObject, key: &str, context: &mut Context) -> Option<JsValue> {
+    object
+        .__get_own_property__(&key.into(), context)
+        .ok()
+        .flatten()
+        .and_then(|property| property.value().cloned())
+}
+
+/// Inspects the value of a property, or shows which accessors it has.
+fn inspect_property(
+    property: &PropertyDescriptor,
+    depth: usize,
+    ancestors: &mut Vec<JsObject>,
+    context: &mut Context,
+) -> String {
+    if let Some(value) = property.value() {
+        return inspect(value, depth + 1, ancestors, context);
+    }
+
+    let is_defined =
+        |accessor: Option<&JsValue>| accessor.map_or(false, |accessor| !accessor.is_undefined());
+    String::from(
+        match (is_defined(property.get()), is_defined(property.set())) {
+            (true, true) => "[Getter/Setter]",
+            (true, false) => "[Getter]",
+            (false, true) => "[Setter]",
+            (false, false) => "undefined",
+        },
+    )
+}
+
+/// Inspects the elements of an array, abbreviating holes and the elements past the limit.
+fn inspect_array_elements(
+    array: &JsObject,
+    depth: usize,
+    ancestors: &mut Vec<JsObject>,
+    context: &mut Context,
+) -> Vec<String> {
+    let len = own_data_property(array, "length", context)
+        .and_then(|len| len.as_number())
+        .unwrap_or_default() as u32;
+
+    let mut elements = Vec::new();
+    for index in 0..len.min(INSPECT_MAX_ITEMS as u32) {
+        let element = match array.__get_own_property__(&index.into(), context) {
+            Ok(Some(property)) => inspect_property(&property, depth, ancestors, context),
+            _ => String::from("<empty item>"),
+        };
+        elements.push(element);
+    }
+    if len as usize > INSPECT_MAX_ITEMS {
+        elements.push(format!(
+            "... {} more items",
+            len as usize - INSPECT_MAX_ITEMS
+        ));
+    }
+    elements
+}
+
+/// Inspects the entries of a `Map` or `Set`, returning the prefix of the collection and its
+/// entries. Other objects have no prefix and no entries.
+fn inspect_collection(
+    object: &JsObject,
+    depth: usize,
+    ancestors: &mut Vec<JsObject>,
+    context: &mut Context,
+) -> (String, Vec<String>) {
+    // The entries are cloned so that the object isn't borrowed while they are inspected.
+    let (prefix, entries): (_, Vec<_>) = match object.borrow().kind() {
+        ObjectKind::Map(ref map) => (
+            format!("Map({}) ", map.len()),
+            map.iter()
+                .map(|(key, value)| (Some(key.clone()), value.clone()))
+                .collect(),
+        ),
+        ObjectKind::Set(ref set) => (
+            format!("Set({}) ", set.size()),
+            set.iter().map(|value| (None, value.clone())).collect(),
+        ),
+        _ => return (String::new(), Vec::new()),
+    };
+
+    let total = entries.len();
+    let mut result: Vec<_> = entries
+        .into_iter()
+        .take(INSPECT_MAX_ITEMS)
+        .map(|(key, value)| {
+            let value = inspect(&value, depth + 1, ancestors, context);
+            match key {
+                Some(key) => format!(
+                    "{} => {value}",
+                    inspect(&key, depth + 1, ancestors, context)
+                ),
+                None => value,
+            }
+        })
+        .collect();
+    if total > INSPECT_MAX_ITEMS {
+        result.push(format!("... {} more items", total - INSPECT_MAX_ITEMS));
+    }
+    (prefix, result)
+}
+
+/// Formats a property key, quoting it if it isn't a valid identifier.
+fn inspect_key(key: &PropertyKey) -> String {
+    match key {
+        PropertyKey::String(ref name) => {
+            let mut chars = name.chars();
+            let is_identifier = chars.next().map_or(false, |first| {
+                first.is_alphabetic() || first == '_' || first == '$'
+            }) && chars
+                .all(|ch| ch.is_alphanumeric() || ch == '_' || ch == '$');
+            if is_identifier {
+                name.to_string()
+            } else {
+                format!("\"{name}\"")
+            }
+        }
+        PropertyKey::Index(index) => format!("\"{index}\""),
+        PropertyKey::Symbol(ref symbol) => format!("[{}]", JsValue::new(symbol.clone()).display()),
+    }
+}
+
 impl Display for ValueDisplay<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.value {
@@ -282,6 +523,83 @@ impl Display for ValueDisplay<'_> {
     }
 }
 
+/// Formats the value like the [`ToString`][spec] abstract operation, as far as possible without a
+/// `Context`.
+///
+/// No user code is ever run: symbols are formatted as `Symbol(description)` instead of throwing,
+/// and objects are formatted as `[object Tag]`, where the tag is taken from `@@toStringTag` only
+/// if it is a string data property. Use [`JsValue::to_string`] for the spec-compliant conversion,
+/// or [`JsValue::display`] to inspect a value.
+///
+/// Calling `value.to_string()` resolves to the inherent [`JsValue::to_string`], which needs a
+/// `Context`, so this representation is obtained with `format!("{value}")`.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-tostring
+impl Display for JsValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Null => f.write_str("null"),
+            Self::Undefined => f.write_str("undefined"),
+            Self::Boolean(v) => write!(f, "{v}"),
+            Self::Symbol(ref symbol) => match symbol.description() {
+                Some(description) => write!(f, "Symbol({description})"),
+                None => f.write_str("Symbol()"),
+            },
+            Self::String(ref v) => f.write_str(v),
+            Self::Rational(v) => f.write_str(&Number::to_native_string(*v)),
+            Self::Integer(v) => write!(f, "{v}"),
+            Self::BigInt(ref num) => write!(f, "{num}"),
+            Self::Object(ref object) => write!(f, "[object {}]", object_tag(object)),
+        }
+    }
+}
+
+/// Gets the tag that `Object.prototype.toString` would use for the object, without running any
+/// user code.
+///
+/// Objects that are mutably borrowed, e.g. while a native function is updating them, can't be
+/// inspected, so they get the generic `Object` tag.
+fn object_tag(object: &JsObject) -> String {
+    let key = WellKnownSymbols::to_string_tag().into();
+    let mut current = object.clone();
+    loop {
+        let prototype = {
+            let current = match current.try_borrow() {
+                Ok(current) => current,
+                Err(_) => return String::from("Object"),
+            };
+            if let ObjectKind::Proxy(_) = current.kind() {
+                break;
+            }
+            if let Some(property) = current.properties().get(&key) {
+                if let Some(tag) = property.value().and_then(JsValue::as_string) {
+                    return tag.to_string();
+                }
+                break;
+            }
+            match current.prototype() {
+                Some(prototype) => prototype.clone(),
+                None => break,
+            }
+        };
+        current = prototype;
+    }
+
+    let builtin_tag = match object.try_borrow().as_deref().map(Object::kind) {
+        Ok(ObjectKind::Array) => "Array",
+        Ok(ObjectKind::Arguments(_)) => "Arguments",
+        Ok(ObjectKind::Error(_)) => "Error",
+        Ok(ObjectKind::Boolean(_)) => "Boolean",
+        Ok(ObjectKind::Number(_)) => "Number",
+        Ok(ObjectKind::String(_)) => "String",
+        Ok(ObjectKind::Date(_)) => "Date",
+        Ok(ObjectKind::RegExp(_)) => "RegExp",
+        Ok(_) if object.is_callable() => "Function",
+        _ => "Object",
+    };
+    builtin_tag.to_owned()
+}
+
 /// This is different from the ECMAScript compliant number to string, in the printing of `-0`.
 ///
 /// This function prints `-0` as `-0` instead of pasitive `0` as the specification says.
@@ -16,13 +16,17 @@ struct UndefinedHashable;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct NullHashable;
 
+/// A number that is hashed consistently with `SameValueZero`.
+///
+/// `+0` and `-0` must have the same hash, as must every `NaN`, since they are the same key of a
+/// `Map` or a `Set`.
 #[derive(Debug, Clone, Copy)]
 struct RationalHashable(f64);
 
 impl PartialEq for RationalHashable {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        Number::same_value(self.0, other.0)
+        Number::same_value_zero(self.0, other.0)
     }
 }
 
@@ -31,7 +35,14 @@ impl Eq for RationalHashable {}
 impl Hash for RationalHashable {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.0.to_bits().hash(state);
+        let canonical = if self.0.is_nan() {
+            f64::NAN
+        } else if self.0 == 0.0 {
+            0.0
+        } else {
+            self.0
+        };
+        canonical.to_bits().hash(state);
     }
 }
 
@@ -0,0 +1,96 @@
+//! This module contains the [`IntoJs`] trait, used to convert Rust types to JavaScript values.
+
+use crate::{builtins::Array, object::JsObject, Context, JsBigInt, JsString, JsValue};
+
+/// Converts a Rust type into a JavaScript value.
+///
+/// Unlike `Into<JsValue>`, conversions can use the [`Context`], which allows creating objects.
+///
+/// This trait can be derived for structs with named fields using the [`IntoJs`
+/// derive macro](macro@crate::value::IntoJs), which creates a plain JavaScript object with one
+/// property per field.
+///
+/// # Examples
+/// ```
+/// use boa::{property::Attribute, value::IntoJs, Context};
+///
+/// #[derive(IntoJs)]
+/// struct Point {
+///     x: f64,
+///     #[boa(rename = "yCoord")]
+///     y: f64,
+/// }
+///
+/// let mut context = Context::default();
+/// let point = Point { x: 1.0, y: 2.0 }.into_js(&mut context);
+/// context.register_global_property("point", point, Attribute::all());
+///
+/// let value = context.eval("point.x + point.yCoord").unwrap();
+/// assert_eq!(value.as_number(), Some(3.0));
+/// ```
+pub trait IntoJs {
+    /// Converts `self` into a JavaScript value.
+    fn into_js(self, context: &mut Context) -> JsValue;
+}
+
+/// Implements [`IntoJs`] for types that already implement `Into<JsValue>`.
+macro_rules! impl_into_js {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoJs for $ty {
+                #[inline]
+                fn into_js(self, _: &mut Context) -> JsValue {
+                    self.into()
+                }
+            }
+        )*
+    };
+}
+
+impl_into_js!(
+    JsValue, bool, f64, i32, u32, i64, u64, usize, String, &str, JsString, JsBigInt, JsObject
+);
+
+/// Implements [`IntoJs`] for small integer types through `i32`.
+macro_rules! impl_into_js_small_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoJs for $ty {
+                #[inline]
+                fn into_js(self, _: &mut Context) -> JsValue {
+                    i32::from(self).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_into_js_small_integer!(i8, u8, i16, u16);
+
+impl<T> IntoJs for Option<T>
+where
+    T: IntoJs,
+{
+    /// Converts `None` into `undefined`.
+    #[inline]
+    fn into_js(self, context: &mut Context) -> JsValue {
+        match self {
+            Some(value) => value.into_js(context),
+            None => JsValue::undefined(),
+        }
+    }
+}
+
+impl<T> IntoJs for Vec<T>
+where
+    T: IntoJs,
+{
+    /// Converts a `Vec` into an array.
+    fn into_js(self, context: &mut Context) -> JsValue {
+        let elements: Vec<_> = self
+            .into_iter()
+            .map(|element| element.into_js(context))
+            .collect();
+        Array::create_array_from_list(elements, context).into()
+    }
+}
@@ -32,15 +32,20 @@ mod conversions;
 pub(crate) mod display;
 mod equality;
 mod hash;
+mod into_js;
 mod operations;
+mod try_from_js;
 mod r#type;
 
+pub use boa_macros::{IntoJs, TryFromJs};
 pub use conversions::*;
 pub use display::ValueDisplay;
 pub use equality::*;
 pub use hash::*;
+pub use into_js::IntoJs;
 pub use operations::*;
 pub use r#type::Type;
+pub use try_from_js::TryFromJs;
 
 static TWO_E_64: Lazy<BigInt> = Lazy::new(|| {
     const TWO_E_64: u128 = 2u128.pow(64);
@@ -246,6 +251,24 @@ impl JsValue {
         }
     }
 
+    /// Converts a primitive value to a string, like `String(value)`, without running any
+    /// JavaScript code.
+    ///
+    /// Returns `None` for objects, whose conversion could call user code, and for symbols, which
+    /// can't be implicitly converted. Use [`JsValue::to_string`] to convert any value.
+    pub fn as_string_lossy(&self) -> Option<String> {
+        match self {
+            Self::Null => Some("null".to_owned()),
+            Self::Undefined => Some("undefined".to_owned()),
+            Self::Boolean(boolean) => Some(boolean.to_string()),
+            Self::Rational(rational) => Some(Number::to_native_string(*rational)),
+            Self::Integer(integer) => Some(integer.to_string()),
+            Self::String(string) => Some(string.as_str().to_owned()),
+            Self::BigInt(ref bigint) => Some(bigint.to_string()),
+            Self::Symbol(_) | Self::Object(_) => None,
+        }
+    }
+
     /// Returns true if the value is a boolean.
     #[inline]
     pub fn is_boolean(&self) -> bool {
@@ -507,6 +530,28 @@ impl JsValue {
         ValueDisplay { value: self }
     }
 
+    /// Formats the value on a single line like a console does, to show it to the user of a REPL.
+    ///
+    /// Strings are quoted, arrays are printed as `[ 1, 2 ]`, objects as `{ a: 1 }` with their own
+    /// enumerable properties, and functions as `[Function: name]`. Objects nested deeper than two
+    /// levels are abbreviated as `[Object]` or `[Array]`, and an object that contains itself is
+    /// printed as `[Circular]`. Getters and proxy traps are never called, so inspecting a value
+    /// doesn't run any user code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa::Context;
+    ///
+    /// let mut context = Context::default();
+    /// let value = context.eval("({ a: [1, 'two'], f: function g() {} })").unwrap();
+    ///
+    /// assert_eq!(value.inspect(&mut context), r#"{ a: [ 1, "two" ], f: [Function: g] }"#);
+    /// ```
+    pub fn inspect(&self, context: &mut Context) -> String {
+        display::inspect(self, 0, &mut Vec::new(), context)
+    }
+
     /// Converts the value to a string.
     ///
     /// This function is equivalent to `String(value)` in JavaScript.
@@ -417,6 +417,41 @@ fn assign_pow_number_and_string() {
     assert!(value.is_nan());
 }
 
+#[test]
+#[allow(clippy::float_cmp)]
+fn pow_right_associative() {
+    let mut context = Context::default();
+
+    let value = forward_val(&mut context, "2 ** 3 ** 2").unwrap();
+    let value = value.to_number(&mut context).unwrap();
+    assert_eq!(value, 512.0);
+
+    let value = forward_val(&mut context, "(-2) ** 2").unwrap();
+    let value = value.to_number(&mut context).unwrap();
+    assert_eq!(value, 4.0);
+}
+
+#[test]
+fn pow_unparenthesized_unary_operand() {
+    let mut context = Context::default();
+
+    for code in ["-2 ** 2", "typeof 2 ** 2", "!a ** 2"] {
+        let result = forward(&mut context, code);
+        assert!(result.starts_with("Uncaught \"SyntaxError\""), "{}", result);
+    }
+}
+
+#[test]
+fn pow_bigint() {
+    let mut context = Context::default();
+
+    assert_eq!(forward(&mut context, "2n ** 3n ** 2n"), "512n");
+    assert_eq!(
+        forward(&mut context, "let b = 3n; b **= 40n; b"),
+        "12157665459056928801n"
+    );
+}
+
 #[test]
 fn display_string() {
     let s = String::from("Hello");
@@ -480,6 +515,70 @@ fn debug_object() {
     assert_eq!(format!("{value:?}"), format!("{value:?}"));
 }
 
+#[test]
+fn format_primitives() {
+    assert_eq!(format!("{}", JsValue::undefined()), "undefined");
+    assert_eq!(format!("{}", JsValue::null()), "null");
+    assert_eq!(format!("{}", JsValue::new(true)), "true");
+    assert_eq!(format!("{}", JsValue::new(-5)), "-5");
+    assert_eq!(format!("{}", JsValue::new(2.5)), "2.5");
+    assert_eq!(format!("{}", JsValue::new(-0.0)), "0");
+    assert_eq!(format!("{}", JsValue::new(1e21)), "1e+21");
+    assert_eq!(format!("{}", JsValue::nan()), "NaN");
+    assert_eq!(format!("{}", JsValue::negative_infinity()), "-Infinity");
+    assert_eq!(format!("{}", JsValue::new("Hello")), "Hello");
+    assert_eq!(format!("{}", JsValue::new(JsBigInt::from(-10))), "-10");
+    assert_eq!(
+        format!("{}", JsValue::new(JsSymbol::new(Some("desc".into())))),
+        "Symbol(desc)"
+    );
+    assert_eq!(format!("{}", JsValue::new(JsSymbol::new(None))), "Symbol()");
+}
+
+#[test]
+fn format_objects() {
+    let mut context = Context::default();
+    let init = r#"
+        [
+            {},
+            [1, 2],
+            function () {},
+            new Map(),
+            new Error("message"),
+            { [Symbol.toStringTag]: "Custom" },
+            { get [Symbol.toStringTag]() { throw new Error(); } },
+        ]
+    "#;
+    let values = forward_val(&mut context, init).unwrap();
+    let values = values.as_object().unwrap();
+    let formatted: Vec<_> = (0..7)
+        .map(|index| format!("{}", values.get(index, &mut context).unwrap()))
+        .collect();
+    assert_eq!(
+        formatted,
+        [
+            "[object Object]",
+            "[object Array]",
+            "[object Function]",
+            "[object Map]",
+            "[object Error]",
+            "[object Custom]",
+            "[object Object]",
+        ]
+    );
+}
+
+#[test]
+fn format_borrowed_object() {
+    let mut context = Context::default();
+    let array = forward_val(&mut context, "[1, 2]").unwrap();
+    let object = array.as_object().unwrap();
+
+    // Formatting doesn't panic if the object is being mutated, e.g. by a native function.
+    let _borrow = object.borrow_mut();
+    assert_eq!(format!("{array}"), "[object Object]");
+}
+
 #[test]
 #[ignore] // TODO: Once objects are printed in a simpler way this test can be simplified and used
 fn display_object() {
@@ -534,6 +633,58 @@ toString: {
     );
 }
 
+#[test]
+fn inspect_nested_values() {
+    let mut context = Context::default();
+    let init = r#"
+        ({
+            str: "text",
+            list: [1, , [2, [3, [4]]]],
+            nested: { a: { b: { c: {} } } },
+            "not-an-identifier": null,
+            [Symbol("sym")]: 1n,
+            get getter() { throw new Error("never called"); },
+            f: function named() {},
+            map: new Map([["k", [true]]]),
+            set: new Set([NaN]),
+        })
+    "#;
+    let value = forward_val(&mut context, init).unwrap();
+    assert_eq!(
+        value.inspect(&mut context),
+        "{ str: \"text\", list: [ 1, <empty item>, [ 2, [Array] ] ], \
+         nested: { a: { b: [Object] } }, \"not-an-identifier\": null, getter: [Getter], \
+         f: [Function: named], map: Map(1) { \"k\" => [ true ] }, set: Set(1) { NaN }, \
+         [Symbol(sym)]: 1n }"
+    );
+
+    let value = forward_val(&mut context, "[[], {}, () => {}, new Error('bad')]").unwrap();
+    assert_eq!(
+        value.inspect(&mut context),
+        "[ [], {}, [Function (anonymous)], Error: bad ]"
+    );
+}
+
+#[test]
+fn inspect_cyclic_value() {
+    let mut context = Context::default();
+    let init = r#"
+        let o = { name: "o" };
+        o.self = o;
+        o.children = [o, { parent: o }];
+        o
+    "#;
+    let value = forward_val(&mut context, init).unwrap();
+    assert_eq!(
+        value.inspect(&mut context),
+        "{ name: \"o\", self: [Circular], children: [ [Circular], { parent: [Circular] } ] }"
+    );
+
+    // Objects that are referenced twice without a cycle are printed twice.
+    let value = forward_val(&mut context, "let shared = [1]; [shared, shared]").unwrap();
+    assert_eq!(value.inspect(&mut context), "[ [ 1 ], [ 1 ] ]");
+}
+
 #[test]
 fn to_integer_or_infinity() {
     let mut context = Context::default();
@@ -1335,3 +1486,258 @@ mod abstract_relational_comparison {
         check_comparison!(context, "'InvalidBigInt' >= -100n" => false);
     }
 }
+
+mod js_conversions {
+    use crate::{
+        forward, forward_val,
+        object::JsObject,
+        property::Attribute,
+        value::{IntoJs, TryFromJs},
+        Context, JsValue,
+    };
+
+    #[derive(Debug, PartialEq, TryFromJs, IntoJs)]
+    struct Address {
+        street: String,
+        number: u32,
+    }
+
+    #[derive(Debug, PartialEq, TryFromJs, IntoJs)]
+    struct Person {
+        name: String,
+        #[boa(rename = "yearsOld")]
+        age: u8,
+        nickname: Option<String>,
+        address: Address,
+        tags: Vec<String>,
+        previous: Vec<Address>,
+    }
+
+    fn person() -> Person {
+        Person {
+            name: "Jane".to_owned(),
+            age: 42,
+            nickname: None,
+            address: Address {
+                street: "Main Street".to_owned(),
+                number: 1,
+            },
+            tags: vec!["a".to_owned(), "b".to_owned()],
+            previous: vec![Address {
+                street: "Side Street".to_owned(),
+                number: 20,
+            }],
+        }
+    }
+
+    #[test]
+    fn try_from_js_nested() {
+        let mut context = Context::default();
+        let value = context
+            .eval(
+                r#"({
+                    name: "Jane",
+                    yearsOld: 42,
+                    address: { street: "Main Street", number: 1 },
+                    tags: ["a", "b"],
+                    previous: [{ street: "Side Street", number: 20 }],
+                })"#,
+            )
+            .unwrap();
+
+        let converted = Person::try_from_js(&value, &mut context).unwrap();
+        assert_eq!(converted, person());
+    }
+
+    #[test]
+    fn into_js_round_trip() {
+        let mut context = Context::default();
+        let value = person().into_js(&mut context);
+        context.register_global_property("person", value.clone(), Attribute::all());
+
+        assert_eq!(forward(&mut context, "person.yearsOld"), "42");
+        assert_eq!(forward(&mut context, "person.age"), "undefined");
+        assert_eq!(forward(&mut context, "person.nickname"), "undefined");
+        assert_eq!(
+            forward(&mut context, "person.address.street"),
+            "\"Main Street\""
+        );
+        assert_eq!(forward(&mut context, "person.tags.join()"), "\"a,b\"");
+        assert_eq!(forward(&mut context, "person.previous[0].number"), "20");
+
+        let converted = Person::try_from_js(&value, &mut context).unwrap();
+        assert_eq!(converted, person());
+    }
+
+    #[test]
+    fn try_from_js_errors() {
+        let mut context = Context::default();
+
+        let value = context
+            .eval(r#"({ name: "Jane", address: { street: "Main Street", number: 1 } })"#)
+            .unwrap();
+        let err = Person::try_from_js(&value, &mut context).unwrap_err();
+        let message = err
+            .as_object()
+            .unwrap()
+            .get("message", &mut context)
+            .unwrap();
+        assert_eq!(
+            message,
+            JsValue::new("cannot convert value to `Person`: missing field `yearsOld`")
+        );
+
+        let value = context
+            .eval(r#"({ street: "Main Street", number: -1 })"#)
+            .unwrap();
+        let err = Address::try_from_js(&value, &mut context).unwrap_err();
+        assert!(err.as_object().unwrap().is_error());
+
+        let err = Address::try_from_js(&JsValue::new(1), &mut context).unwrap_err();
+        assert!(err.as_object().unwrap().is_error());
+    }
+
+    #[test]
+    fn try_from_js_integer_bounds() {
+        let mut context = Context::default();
+
+        assert_eq!(
+            u8::try_from_js(&JsValue::new(255), &mut context).unwrap(),
+            255
+        );
+        assert!(u8::try_from_js(&JsValue::new(256), &mut context).is_err());
+        assert!(u8::try_from_js(&JsValue::new(-1), &mut context).is_err());
+        assert_eq!(
+            i32::try_from_js(&JsValue::new(i32::MIN), &mut context).unwrap(),
+            i32::MIN
+        );
+        assert!(i32::try_from_js(&JsValue::new(2_147_483_648_f64), &mut context).is_err());
+
+        // `2^63` and `2^64` are the first numbers that don't fit, and the largest numbers below
+        // them are `2^63 - 1024` and `2^64 - 2048`.
+        let two_pow_63 = 2_f64.powi(63);
+        let two_pow_64 = 2_f64.powi(64);
+        assert_eq!(
+            i64::try_from_js(&JsValue::new(-two_pow_63), &mut context).unwrap(),
+            i64::MIN
+        );
+        assert_eq!(
+            i64::try_from_js(&JsValue::new(two_pow_63 - 1024.0), &mut context).unwrap(),
+            i64::MAX - 1023
+        );
+        assert!(i64::try_from_js(&JsValue::new(two_pow_63), &mut context).is_err());
+        assert_eq!(
+            u64::try_from_js(&JsValue::new(two_pow_64 - 2048.0), &mut context).unwrap(),
+            u64::MAX - 2047
+        );
+        assert!(u64::try_from_js(&JsValue::new(two_pow_64), &mut context).is_err());
+        assert!(usize::try_from_js(&JsValue::new(two_pow_64), &mut context).is_err());
+        assert!(usize::try_from_js(&JsValue::new(-1), &mut context).is_err());
+    }
+
+    #[test]
+    fn get_as() {
+        let mut context = Context::default();
+        let value = context
+            .eval(
+                r#"({
+                    width: 3.5,
+                    label: "box",
+                    address: { street: "Main Street", number: 1 },
+                    get broken() { throw new Error("broken"); },
+                })"#,
+            )
+            .unwrap();
+        let object = value.as_object().unwrap();
+
+        assert_eq!(
+            object.get_as::<f64, _>("width", &mut context).unwrap(),
+            Some(3.5)
+        );
+        assert_eq!(
+            object
+                .get_as::<Address, _>("address", &mut context)
+                .unwrap(),
+            Some(Address {
+                street: "Main Street".to_owned(),
+                number: 1,
+            })
+        );
+        let address = object
+            .get_as::<JsObject, _>("address", &mut context)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            address.get_as::<u32, _>("number", &mut context).unwrap(),
+            Some(1)
+        );
+
+        // Type mismatches and missing properties are `None`.
+        assert_eq!(
+            object.get_as::<f64, _>("label", &mut context).unwrap(),
+            None
+        );
+        assert_eq!(object.get_as::<u8, _>("width", &mut context).unwrap(), None);
+        assert_eq!(
+            object.get_as::<Address, _>("label", &mut context).unwrap(),
+            None
+        );
+        assert_eq!(
+            object.get_as::<String, _>("missing", &mut context).unwrap(),
+            None
+        );
+
+        // Errors thrown while getting the property or converting its value are still returned.
+        assert!(object.get_as::<f64, _>("broken", &mut context).is_err());
+        let value = context
+            .eval(
+                r#"({
+                    address: { street: "Main Street", get number() { throw new TypeError(); } },
+                    tags: Object.defineProperty([], 0, { get() { throw new Error(); } }),
+                })"#,
+            )
+            .unwrap();
+        let object = value.as_object().unwrap();
+        assert!(object
+            .get_as::<Address, _>("address", &mut context)
+            .is_err());
+        assert!(object
+            .get_as::<Vec<String>, _>("tags", &mut context)
+            .is_err());
+    }
+
+    #[test]
+    fn as_string_lossy() {
+        let mut context = Context::default();
+
+        assert_eq!(JsValue::new(1.5).as_string_lossy(), Some("1.5".to_owned()));
+        assert_eq!(
+            JsValue::new(true).as_string_lossy(),
+            Some("true".to_owned())
+        );
+        assert_eq!(JsValue::null().as_string_lossy(), Some("null".to_owned()));
+        assert_eq!(
+            JsValue::new("text").as_string_lossy(),
+            Some("text".to_owned())
+        );
+        assert_eq!(
+            forward_val(&mut context, "10n ** 20n")
+                .unwrap()
+                .as_string_lossy(),
+            Some("100000000000000000000".to_owned())
+        );
+        assert_eq!(
+            forward_val(&mut context, "Symbol()")
+                .unwrap()
+                .as_string_lossy(),
+            None
+        );
+        assert_eq!(
+            forward_val(&mut context, "({ toString() { return 'x'; } })")
+                .unwrap()
+                .as_string_lossy(),
+            None
+        );
+        assert_eq!(JsValue::new("text").as_number(), None);
+    }
+}
@@ -0,0 +1,172 @@
+//! This module contains the [`TryFromJs`] trait, used to convert JavaScript values to Rust types.
+
+use crate::{builtins::Number, object::JsObject, Context, JsBigInt, JsResult, JsString, JsValue};
+
+/// Converts a JavaScript value into a Rust type, throwing a `TypeError` if the value has the
+/// wrong type.
+///
+/// Implementations should throw the `TypeError` with [`Context::throw_conversion_error`], so
+/// that it can be told apart from the exceptions thrown while reading the value.
+///
+/// This trait can be derived for structs with named fields using the [`TryFromJs`
+/// derive macro](macro@crate::value::TryFromJs), which converts a plain JavaScript object by
+/// reading each field from the property of the same name.
+///
+/// # Examples
+/// ```
+/// use boa::{value::TryFromJs, Context};
+///
+/// #[derive(TryFromJs)]
+/// struct Point {
+///     x: f64,
+///     #[boa(rename = "yCoord")]
+///     y: f64,
+///     label: Option<String>,
+/// }
+///
+/// let mut context = Context::default();
+/// let value = context.eval("({ x: 1, yCoord: 2 })").unwrap();
+/// let point = Point::try_from_js(&value, &mut context).unwrap();
+///
+/// assert_eq!(point.x, 1.0);
+/// assert_eq!(point.y, 2.0);
+/// assert_eq!(point.label, None);
+/// ```
+pub trait TryFromJs: Sized {
+    /// Converts a JavaScript value into `Self`.
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self>;
+}
+
+impl TryFromJs for JsValue {
+    #[inline]
+    fn try_from_js(value: &JsValue, _: &mut Context) -> JsResult<Self> {
+        Ok(value.clone())
+    }
+}
+
+impl TryFromJs for bool {
+    #[inline]
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        match value {
+            JsValue::Boolean(b) => Ok(*b),
+            _ => context.throw_conversion_error("cannot convert value to a boolean"),
+        }
+    }
+}
+
+impl TryFromJs for JsString {
+    #[inline]
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        match value {
+            JsValue::String(s) => Ok(s.clone()),
+            _ => context.throw_conversion_error("cannot convert value to a string"),
+        }
+    }
+}
+
+impl TryFromJs for String {
+    #[inline]
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        JsString::try_from_js(value, context).map(|s| s.as_str().to_owned())
+    }
+}
+
+impl TryFromJs for JsBigInt {
+    #[inline]
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        match value {
+            JsValue::BigInt(b) => Ok(b.clone()),
+            _ => context.throw_conversion_error("cannot convert value to a BigInt"),
+        }
+    }
+}
+
+impl TryFromJs for JsObject {
+    #[inline]
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        match value {
+            JsValue::Object(o) => Ok(o.clone()),
+            _ => context.throw_conversion_error("cannot convert value to an object"),
+        }
+    }
+}
+
+impl TryFromJs for f64 {
+    #[inline]
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        match value {
+            JsValue::Integer(i) => Ok((*i).into()),
+            JsValue::Rational(r) => Ok(*r),
+            _ => context.throw_conversion_error("cannot convert value to a number"),
+        }
+    }
+}
+
+/// Implements [`TryFromJs`] for integer types, which only accept numbers with an integral value
+/// in the range of the type.
+macro_rules! impl_try_from_js_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl TryFromJs for $ty {
+                #[inline]
+                #[allow(clippy::cast_lossless, clippy::cast_precision_loss)]
+                fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+                    let number = f64::try_from_js(value, context)?;
+                    // `MAX as f64` rounds up to `2^BITS` for the 64-bit types, so the upper bound
+                    // has to be exclusive.
+                    if Number::is_float_integer(number)
+                        && number >= <$ty>::MIN as f64
+                        && number < <$ty>::MAX as f64 + 1.0
+                    {
+                        Ok(number as $ty)
+                    } else {
+                        context.throw_conversion_error(concat!(
+                            "cannot convert value to `",
+                            stringify!($ty),
+                            "`: the number is out of range or not an integer"
+                        ))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_js_integer!(i8, u8, i16, u16, i32, u32, i64, u64, usize);
+
+impl<T> TryFromJs for Option<T>
+where
+    T: TryFromJs,
+{
+    #[inline]
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        if value.is_null_or_undefined() {
+            Ok(None)
+        } else {
+            T::try_from_js(value, context).map(Some)
+        }
+    }
+}
+
+impl<T> TryFromJs for Vec<T>
+where
+    T: TryFromJs,
+{
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let array = match value {
+            JsValue::Object(o) if o.is_array() => o.clone(),
+            _ => {
+                return context
+                    .throw_conversion_error("cannot convert value to a Vec: not an array")
+            }
+        };
+
+        let len = array.length_of_array_like(context)?;
+        let mut vec = Self::with_capacity(len);
+        for index in 0..len {
+            let element = array.get(index, context)?;
+            vec.push(T::try_from_js(&element, context)?);
+        }
+        Ok(vec)
+    }
+}
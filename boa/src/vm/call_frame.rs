@@ -3,15 +3,20 @@
 //! This module will provides everything needed to implement the `CallFrame`
 
 use super::CodeBlock;
-use crate::{gc::Gc, JsValue};
+use crate::{
+    gc::{Finalize, Gc, Trace},
+    object::JsObject,
+    JsValue,
+};
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Finalize, Trace)]
 pub struct CallFrame {
     pub(crate) prev: Option<Box<Self>>,
     pub(crate) code: Gc<CodeBlock>,
     pub(crate) pc: usize,
     pub(crate) this: JsValue,
     pub(crate) catch: Vec<CatchAddresses>,
+    #[unsafe_ignore_trace]
     pub(crate) finally_return: FinallyReturn,
     pub(crate) finally_jump: Vec<Option<u32>>,
     pub(crate) pop_on_return: usize,
@@ -22,10 +27,21 @@ pub struct CallFrame {
 
     // Tracks the number of environments in the current try-catch-finally block.
     // On abrupt returns this is used to decide how many environments need to be pop'ed.
+    #[unsafe_ignore_trace]
     pub(crate) try_env_stack: Vec<TryStackEntry>,
 
     pub(crate) param_count: usize,
     pub(crate) arg_count: usize,
+
+    // Indicates how a generator function that has been called/resumed should return.
+    #[unsafe_ignore_trace]
+    pub(crate) generator_resume_kind: GeneratorResumeKind,
+
+    // The function object of the running code, `None` for scripts.
+    pub(crate) function_object: Option<JsObject>,
+
+    // The `new.target` of the running function, `None` if it was not called as a constructor.
+    pub(crate) new_target: Option<JsObject>,
 }
 
 impl CallFrame {
@@ -88,7 +104,7 @@ pub(crate) struct TryStackEntry {
     pub(crate) num_loop_stack_entries: usize,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Finalize, Trace)]
 pub(crate) struct CatchAddresses {
     pub(crate) next: u32,
     pub(crate) finally: Option<u32>,
@@ -100,3 +116,11 @@ pub(crate) enum FinallyReturn {
     Ok,
     Err,
 }
+
+/// Indicates how a generator function that has been called/resumed should return.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) enum GeneratorResumeKind {
+    Normal,
+    Throw,
+    Return,
+}
@@ -3,9 +3,14 @@
 //! This module is for the `CodeBlock` which implements a function representation in the VM
 
 use crate::{
-    builtins::function::{
-        arguments::Arguments, Captures, ClosureFunctionSignature, Function,
-        NativeFunctionSignature, ThisMode,
+    builtins::{
+        async_function::AsyncFunction,
+        function::{
+            arguments::Arguments, Captures, ClosureFunctionSignature, ConstructorKind, Function,
+            NativeFunctionSignature, ThisMode,
+        },
+        generator::{Generator, GeneratorContext, GeneratorState},
+        promise::PromiseCapability,
     },
     context::StandardObjects,
     environments::{BindingLocator, DeclarativeEnvironmentStack},
@@ -13,12 +18,13 @@ use crate::{
     object::{internal_methods::get_prototype_from_constructor, JsObject, ObjectData},
     profiler::BoaProfiler,
     property::PropertyDescriptor,
-    syntax::ast::node::FormalParameter,
-    vm::{call_frame::FinallyReturn, CallFrame, Opcode},
+    syntax::ast::{node::FormalParameter, ByteSpan, Position},
+    vm::{call_frame::FinallyReturn, CallFrame, GeneratorResumeKind, Opcode},
     Context, JsResult, JsValue,
 };
+use bitflags::bitflags;
 use boa_interner::{Interner, Sym, ToInternedString};
-use std::{convert::TryInto, mem::size_of};
+use std::{convert::TryInto, mem::size_of, rc::Rc};
 
 /// This represents whether a value can be read from [`CodeBlock`] code.
 ///
@@ -42,6 +48,18 @@ unsafe impl Readable for i64 {}
 unsafe impl Readable for f32 {}
 unsafe impl Readable for f64 {}
 
+bitflags! {
+    /// The kind of a function, which decides how calling it runs its code.
+    pub(crate) struct CodeBlockFlags: u8 {
+        /// `[[IsClassConstructor]]`: the function can only be called with `new`.
+        const CLASS_CONSTRUCTOR = 0b0000_0001;
+        /// Calling the function returns a generator that runs its code.
+        const GENERATOR = 0b0000_0010;
+        /// Calling the function returns a promise of the completion of its code.
+        const ASYNC = 0b0000_0100;
+    }
+}
+
 /// The internal representation of a JavaScript function.
 ///
 /// A `CodeBlock` is generated for each function compiled by the
@@ -61,9 +79,16 @@ pub struct CodeBlock {
     /// Is this function a constructor.
     pub(crate) constructor: bool,
 
+    /// The kind of the function: generator, async or class constructor.
+    #[unsafe_ignore_trace]
+    pub(crate) flags: CodeBlockFlags,
+
     /// [[ThisMode]]
     pub(crate) this_mode: ThisMode,
 
+    /// [[ConstructorKind]]
+    pub(crate) constructor_kind: ConstructorKind,
+
     /// Parameters passed to this function.
     pub(crate) params: Box<[FormalParameter]>,
 
@@ -92,6 +117,16 @@ pub struct CodeBlock {
     /// The `arguments` binding location of the function, if set.
     #[unsafe_ignore_trace]
     pub(crate) arguments_binding: Option<BindingLocator>,
+
+    /// The source code the function was compiled from, with the span of the source text of the
+    /// function in it.
+    #[unsafe_ignore_trace]
+    pub(crate) source: Option<(Rc<str>, ByteSpan)>,
+
+    /// The start positions in the source code of the statements, with the location of their
+    /// first instruction, in ascending order.
+    #[unsafe_ignore_trace]
+    pub(crate) positions: Vec<(u32, Position)>,
 }
 
 impl CodeBlock {
@@ -108,13 +143,48 @@ impl CodeBlock {
             length,
             strict,
             constructor,
+            flags: CodeBlockFlags::empty(),
             this_mode: ThisMode::Global,
+            constructor_kind: ConstructorKind::Base,
             params: Vec::new().into_boxed_slice(),
             lexical_name_argument: false,
             arguments_binding: None,
+            source: None,
+            positions: Vec::new(),
         }
     }
 
+    /// Returns `true` if this is the code of a generator function.
+    pub(crate) fn is_generator(&self) -> bool {
+        self.flags.contains(CodeBlockFlags::GENERATOR)
+    }
+
+    /// Returns `true` if this is the code of an async function.
+    pub(crate) fn is_async(&self) -> bool {
+        self.flags.contains(CodeBlockFlags::ASYNC)
+    }
+
+    /// Returns `true` if this is the code of a class constructor, which can't be called without
+    /// `new`.
+    pub(crate) fn is_class_constructor(&self) -> bool {
+        self.flags.contains(CodeBlockFlags::CLASS_CONSTRUCTOR)
+    }
+
+    /// Gets the start position in the source code of the statement that contains the instruction
+    /// before `pc`, if it is known.
+    pub(crate) fn position(&self, pc: usize) -> Option<Position> {
+        let index = self
+            .positions
+            .partition_point(|(start, _)| (*start as usize) < pc);
+        index.checked_sub(1).map(|index| self.positions[index].1)
+    }
+
+    /// Gets the source text of the function, if it was compiled from source code.
+    pub(crate) fn source_text(&self) -> Option<&str> {
+        let (source, span) = self.source.as_ref()?;
+        source.get(span.start()..span.end())
+    }
+
     /// Read type T from code.
     ///
     /// # Safety
@@ -150,6 +220,11 @@ impl CodeBlock {
         let opcode: Opcode = self.code[*pc].try_into().expect("invalid opcode");
         *pc += size_of::<Opcode>();
         match opcode {
+            Opcode::RotateRight => {
+                let result = self.read::<u8>(*pc).to_string();
+                *pc += size_of::<u8>();
+                result
+            }
             Opcode::PushInt8 => {
                 let result = self.read::<i8>(*pc).to_string();
                 *pc += size_of::<i8>();
@@ -182,14 +257,15 @@ impl CodeBlock {
             | Opcode::LogicalOr
             | Opcode::Coalesce
             | Opcode::Call
-            | Opcode::CallWithRest
+            | Opcode::SuperCall
             | Opcode::New
-            | Opcode::NewWithRest
             | Opcode::ForInLoopInitIterator
             | Opcode::ForInLoopNext
+            | Opcode::ForAwaitOfLoopNext
             | Opcode::ConcatToString
             | Opcode::CopyDataProperties
-            | Opcode::PushDeclarativeEnvironment => {
+            | Opcode::PushDeclarativeEnvironment
+            | Opcode::GeneratorNextDelegate => {
                 let result = self.read::<u32>(*pc).to_string();
                 *pc += size_of::<u32>();
                 result
@@ -218,7 +294,8 @@ impl CodeBlock {
             | Opcode::DefInitConst
             | Opcode::GetName
             | Opcode::GetNameOrUndefined
-            | Opcode::SetName => {
+            | Opcode::SetName
+            | Opcode::DeleteName => {
                 let operand = self.read::<u32>(*pc);
                 *pc += size_of::<u32>();
                 format!(
@@ -232,6 +309,10 @@ impl CodeBlock {
             | Opcode::DefineOwnPropertyByName
             | Opcode::SetPropertyGetterByName
             | Opcode::SetPropertySetterByName
+            | Opcode::DefineClassMethodByName
+            | Opcode::DefineClassGetterByName
+            | Opcode::DefineClassSetterByName
+            | Opcode::PushPrivateName
             | Opcode::DeletePropertyByName => {
                 let operand = self.read::<u32>(*pc);
                 *pc += size_of::<u32>();
@@ -284,12 +365,17 @@ impl CodeBlock {
             | Opcode::Inc
             | Opcode::Dec
             | Opcode::GetPropertyByValue
+            | Opcode::GetPropertyByValuePush
             | Opcode::SetPropertyByValue
             | Opcode::DefineOwnPropertyByValue
             | Opcode::SetPropertyGetterByValue
             | Opcode::SetPropertySetterByValue
+            | Opcode::DefineClassMethodByValue
+            | Opcode::DefineClassGetterByValue
+            | Opcode::DefineClassSetterByValue
             | Opcode::DeletePropertyByValue
             | Opcode::ToBoolean
+            | Opcode::ToPropertyKey
             | Opcode::Throw
             | Opcode::TryEnd
             | Opcode::CatchEnd
@@ -297,6 +383,23 @@ impl CodeBlock {
             | Opcode::FinallyStart
             | Opcode::FinallyEnd
             | Opcode::This
+            | Opcode::PushNewTarget
+            | Opcode::Super
+            | Opcode::PushClassPrototype
+            | Opcode::SetClassPrototype
+            | Opcode::PushClassField
+            | Opcode::DefineClassStaticField
+            | Opcode::PushClassPrivateMethod
+            | Opcode::PushClassPrivateGetter
+            | Opcode::PushClassPrivateSetter
+            | Opcode::PushClassPrivateField
+            | Opcode::DefineClassStaticPrivateMethod
+            | Opcode::DefineClassStaticPrivateGetter
+            | Opcode::DefineClassStaticPrivateSetter
+            | Opcode::DefineClassStaticPrivateField
+            | Opcode::GetPrivateField
+            | Opcode::SetPrivateField
+            | Opcode::InPrivate
             | Opcode::Return
             | Opcode::PushFunctionEnvironment
             | Opcode::PopEnvironment
@@ -304,19 +407,28 @@ impl CodeBlock {
             | Opcode::LoopContinue
             | Opcode::LoopEnd
             | Opcode::InitIterator
+            | Opcode::InitIteratorAsync
             | Opcode::IteratorNext
             | Opcode::IteratorNextFull
             | Opcode::IteratorClose
             | Opcode::IteratorToArray
+            | Opcode::ForAwaitOfLoopIterate
             | Opcode::RequireObjectCoercible
             | Opcode::ValueNotNullOrUndefined
             | Opcode::RestParameterInit
             | Opcode::RestParameterPop
             | Opcode::PushValueToArray
+            | Opcode::PushElisionToArray
             | Opcode::PushIteratorToArray
+            | Opcode::CallSpread
+            | Opcode::SuperCallSpread
+            | Opcode::NewSpread
             | Opcode::PushNewArray
             | Opcode::PopOnReturnAdd
             | Opcode::PopOnReturnSub
+            | Opcode::Yield
+            | Opcode::GeneratorNext
+            | Opcode::Await
             | Opcode::Nop => String::new(),
         }
     }
@@ -400,9 +512,20 @@ impl JsVmFunction {
     pub fn new(code: Gc<CodeBlock>, context: &mut Context) -> JsObject {
         let _timer = BoaProfiler::global().start_event("Identifier", "vm");
 
-        let function_prototype = context.standard_objects().function_object().prototype();
-
-        let prototype = context.construct_object();
+        let (function_prototype, prototype) = if code.is_generator() {
+            (
+                context.iterator_prototypes().generator_function(),
+                JsObject::from_proto_and_data(
+                    context.iterator_prototypes().generator(),
+                    ObjectData::ordinary(),
+                ),
+            )
+        } else {
+            (
+                context.standard_objects().function_object().prototype(),
+                context.construct_object(),
+            )
+        };
 
         let name_property = PropertyDescriptor::builder()
             .value(context.interner().resolve_expect(code.name))
@@ -418,35 +541,47 @@ impl JsVmFunction {
             .configurable(true)
             .build();
 
+        let generator = code.is_generator();
+        let is_async = code.is_async();
+
         let function = Function::VmOrdinary {
             code,
             environments: context.realm.environments.clone(),
+            home_object: None,
+            fields: Vec::new(),
+            private_methods: Vec::new(),
         };
 
         let constructor =
             JsObject::from_proto_and_data(function_prototype, ObjectData::function(function));
 
-        let constructor_property = PropertyDescriptor::builder()
-            .value(constructor.clone())
-            .writable(true)
-            .enumerable(false)
-            .configurable(true)
-            .build();
-
-        prototype
-            .define_property_or_throw("constructor", constructor_property, context)
-            .expect("failed to define the constructor property of the function");
-
-        let prototype_property = PropertyDescriptor::builder()
-            .value(prototype)
-            .writable(true)
-            .enumerable(false)
-            .configurable(false)
-            .build();
+        // The prototype of a generator function does not point back to the function.
+        if !generator {
+            let constructor_property = PropertyDescriptor::builder()
+                .value(constructor.clone())
+                .writable(true)
+                .enumerable(false)
+                .configurable(true)
+                .build();
+
+            prototype
+                .define_property_or_throw("constructor", constructor_property, context)
+                .expect("failed to define the constructor property of the function");
+        }
 
-        constructor
-            .define_property_or_throw("prototype", prototype_property, context)
-            .expect("failed to define the prototype property of the function");
+        // Async functions are not constructors and have no prototype property.
+        if !is_async {
+            let prototype_property = PropertyDescriptor::builder()
+                .value(prototype)
+                .writable(true)
+                .enumerable(false)
+                .configurable(false)
+                .build();
+
+            constructor
+                .define_property_or_throw("prototype", prototype_property, context)
+                .expect("failed to define the prototype property of the function");
+        }
         constructor
             .define_property_or_throw("name", name_property, context)
             .expect("failed to define the name property of the function");
@@ -512,7 +647,9 @@ impl JsObject {
                     function: function.clone(),
                     captures: captures.clone(),
                 },
-                Function::VmOrdinary { code, environments } => FunctionBody::Ordinary {
+                Function::VmOrdinary {
+                    code, environments, ..
+                } => FunctionBody::Ordinary {
                     code: code.clone(),
                     environments: environments.clone(),
                 },
@@ -531,15 +668,27 @@ impl JsObject {
                 code,
                 mut environments,
             } => {
+                if code.is_class_constructor() {
+                    return context
+                        .throw_type_error("Class constructor cannot be invoked without 'new'");
+                }
+
                 std::mem::swap(&mut environments, &mut context.realm.environments);
 
                 let lexical_this_mode = code.this_mode == ThisMode::Lexical;
 
+                // Arrow functions use the `new.target` of their enclosing function.
+                let new_target = if lexical_this_mode {
+                    context.realm.environments.get_last_new_target()
+                } else {
+                    None
+                };
+
                 let this = if lexical_this_mode {
                     if let Some(this) = context.realm.environments.get_last_this() {
                         this
                     } else {
-                        context.global_object().clone().into()
+                        context.script_this()
                     }
                 } else if (!code.strict && !context.strict()) && this.is_null_or_undefined() {
                     context.global_object().clone().into()
@@ -547,10 +696,11 @@ impl JsObject {
                     this.clone()
                 };
 
-                context
-                    .realm
-                    .environments
-                    .push_function(code.num_bindings, this.clone());
+                context.realm.environments.push_function(
+                    code.num_bindings,
+                    this.clone(),
+                    new_target,
+                );
 
                 let mut arguments_in_parameter_names = false;
                 let mut is_simple_parameter_list = true;
@@ -598,6 +748,15 @@ impl JsObject {
                     args.to_vec()
                 };
 
+                // A generator or async function runs on its own stack, so that its execution can
+                // be suspended and resumed independently of the caller.
+                let generator = code.is_generator();
+                let is_async = code.is_async();
+                let mut stack = Vec::new();
+                if generator || is_async {
+                    std::mem::swap(&mut context.vm.stack, &mut stack);
+                }
+
                 for arg in args.iter().rev() {
                     context.vm.push(arg);
                 }
@@ -620,10 +779,63 @@ impl JsObject {
                     }],
                     param_count,
                     arg_count,
+                    generator_resume_kind: GeneratorResumeKind::Normal,
+                    function_object: Some(this_function_object.clone()),
+                    new_target: None,
                 });
 
+                // For generators this only runs the parameter initialization, up to the
+                // implicit yield at the start of the body.
                 let result = context.run();
-                context.vm.pop_frame().expect("must have frame");
+                let frame = context.vm.pop_frame().expect("must have frame");
+
+                if generator {
+                    std::mem::swap(&mut context.vm.stack, &mut stack);
+                    std::mem::swap(&mut environments, &mut context.realm.environments);
+                    result?;
+
+                    let prototype = this_function_object
+                        .get("prototype", context)?
+                        .as_object()
+                        .cloned()
+                        .unwrap_or_else(|| context.iterator_prototypes().generator());
+
+                    let generator = Self::from_proto_and_data(
+                        prototype,
+                        ObjectData::generator(Generator {
+                            state: GeneratorState::SuspendedStart,
+                            context: Some(GeneratorContext {
+                                environments,
+                                call_frame: *frame,
+                                stack,
+                            }),
+                        }),
+                    );
+
+                    return Ok(generator.into());
+                }
+
+                if is_async {
+                    std::mem::swap(&mut context.vm.stack, &mut stack);
+                    std::mem::swap(&mut environments, &mut context.realm.environments);
+
+                    let promise_constructor =
+                        context.standard_objects().promise_object().constructor();
+                    let promise_capability =
+                        PromiseCapability::new(&promise_constructor.into(), context)?;
+                    AsyncFunction::handle_completion(
+                        result,
+                        GeneratorContext {
+                            environments,
+                            call_frame: *frame,
+                            stack,
+                        },
+                        promise_capability.clone(),
+                        context,
+                    )?;
+
+                    return Ok(promise_capability.promise().clone().into());
+                }
 
                 context.realm.environments.pop();
                 if has_parameter_expressions {
@@ -632,7 +844,8 @@ impl JsObject {
 
                 std::mem::swap(&mut environments, &mut context.realm.environments);
 
-                result
+                let (result, _) = result?;
+                Ok(result)
             }
         }
     }
@@ -664,7 +877,9 @@ impl JsObject {
                     function: function.clone(),
                     captures: captures.clone(),
                 },
-                Function::VmOrdinary { code, environments } => FunctionBody::Ordinary {
+                Function::VmOrdinary {
+                    code, environments, ..
+                } => FunctionBody::Ordinary {
                     code: code.clone(),
                     environments: environments.clone(),
                 },
@@ -680,9 +895,12 @@ impl JsObject {
                 code,
                 mut environments,
             } => {
-                std::mem::swap(&mut environments, &mut context.realm.environments);
+                let derived = code.constructor_kind.is_derived();
 
-                let this: JsValue = {
+                // The `this` value of a derived constructor is only bound by calling `super`.
+                let this: JsValue = if derived {
+                    JsValue::undefined()
+                } else {
                     // If the prototype of the constructor is not an object, then use the default object
                     // prototype as prototype for the new object
                     // see <https://tc39.es/ecma262/#sec-ordinarycreatefromconstructor>
@@ -692,13 +910,21 @@ impl JsObject {
                         StandardObjects::object_object,
                         context,
                     )?;
-                    Self::from_proto_and_data(prototype, ObjectData::ordinary()).into()
+                    let this = Self::from_proto_and_data(prototype, ObjectData::ordinary());
+
+                    // The fields of a base class are initialized before its constructor runs.
+                    this.initialize_instance_elements(&this_function_object, context)?;
+
+                    this.into()
                 };
 
-                context
-                    .realm
-                    .environments
-                    .push_function(code.num_bindings, this.clone());
+                std::mem::swap(&mut environments, &mut context.realm.environments);
+
+                context.realm.environments.push_function(
+                    code.num_bindings,
+                    this.clone(),
+                    this_target.as_object().cloned(),
+                );
 
                 let mut arguments_in_parameter_names = false;
                 let mut is_simple_parameter_list = true;
@@ -774,13 +1000,16 @@ impl JsObject {
                     }],
                     param_count,
                     arg_count,
+                    generator_resume_kind: GeneratorResumeKind::Normal,
+                    function_object: Some(this_function_object),
+                    new_target: this_target.as_object().cloned(),
                 });
 
                 let result = context.run();
 
                 let frame = context.vm.pop_frame().expect("must have frame");
 
-                let this = frame.this;
+                let this = frame.this.clone();
 
                 context.realm.environments.pop();
                 if has_parameter_expressions {
@@ -789,13 +1018,26 @@ impl JsObject {
 
                 std::mem::swap(&mut environments, &mut context.realm.environments);
 
-                let result = result?;
+                let (result, _) = result?;
 
                 if result.is_object() {
-                    Ok(result)
-                } else {
-                    Ok(this)
+                    return Ok(result);
+                }
+
+                if derived {
+                    if !result.is_undefined() {
+                        return context.throw_type_error(
+                            "Derived constructors may only return object or undefined",
+                        );
+                    }
+                    if this.is_undefined() {
+                        return context.throw_reference_error(
+                            "Must call super constructor in derived class before returning",
+                        );
+                    }
                 }
+
+                Ok(this)
             }
         }
     }
@@ -3,21 +3,35 @@
 //! plus an interpreter to execute those instructions
 
 use crate::{
-    builtins::{iterable::IteratorRecord, Array, ForInIterator, Number},
+    builtins::{
+        function::{set_function_name, ClassFieldDefinition, Function},
+        iterable::{IteratorHint, IteratorRecord},
+        Array, ForInIterator, Number,
+    },
+    gc::{Finalize, Trace},
+    object::{JsObject, ObjectData, PrivateElement},
     property::{DescriptorKind, PropertyDescriptor, PropertyKey},
+    syntax::ast::Position,
     value::Numeric,
     vm::{call_frame::CatchAddresses, code_block::Readable},
-    BoaProfiler, Context, JsBigInt, JsResult, JsString, JsValue,
+    BoaProfiler, Context, JsBigInt, JsResult, JsString, JsSymbol, JsValue,
 };
 use boa_interner::ToInternedString;
-use std::{convert::TryInto, mem::size_of, ops::Neg, time::Instant};
+use std::{
+    convert::TryInto,
+    fmt::{self, Display},
+    mem::size_of,
+    ops::Neg,
+    time::Instant,
+};
 
 mod call_frame;
 mod code_block;
 mod opcode;
 
 pub use call_frame::CallFrame;
-pub(crate) use call_frame::{FinallyReturn, TryStackEntry};
+pub(crate) use call_frame::{FinallyReturn, GeneratorResumeKind, TryStackEntry};
+pub(crate) use code_block::CodeBlockFlags;
 pub use code_block::{CodeBlock, JsVmFunction};
 pub(crate) use opcode::BindingOpcode;
 pub use opcode::Opcode;
@@ -31,6 +45,82 @@ pub struct Vm {
     pub(crate) stack: Vec<JsValue>,
     pub(crate) trace: bool,
     pub(crate) stack_size_limit: usize,
+    pub(crate) instruction_budget: Option<u64>,
+    /// Set when an instruction is run with an exhausted instruction budget, which aborts the
+    /// script.
+    pub(crate) runtime_limit_exceeded: bool,
+    pub(crate) error_trace: Vec<StackTraceFrame>,
+    pub(crate) error_trace_value: Option<JsValue>,
+}
+
+/// The maximum number of frames captured in the stack trace of an error object.
+///
+/// This is the same as the default `Error.stackTraceLimit` of V8.
+pub(crate) const STACK_TRACE_LIMIT: usize = 10;
+
+/// A frame of a stack trace.
+///
+/// See [`Context::error_trace`] for how the frames of an uncaught error are collected.
+#[derive(Debug, Clone, PartialEq, Trace, Finalize)]
+pub struct StackTraceFrame {
+    #[unsafe_ignore_trace]
+    function_name: Box<str>,
+    #[unsafe_ignore_trace]
+    position: Option<Position>,
+}
+
+impl StackTraceFrame {
+    /// Creates the frame of the statement that is executing in `frame`.
+    fn from_call_frame(frame: &CallFrame, context: &Context) -> Self {
+        // The program counter already points past the instruction that is executing.
+        Self {
+            function_name: context.interner().resolve_expect(frame.code.name).into(),
+            position: frame.code.position(frame.pc),
+        }
+    }
+
+    /// Gets the name of the function of this frame.
+    ///
+    /// The name is empty for anonymous functions, and is `<main>` for the top level code of a
+    /// script.
+    #[inline]
+    pub fn function_name(&self) -> &str {
+        &self.function_name
+    }
+
+    /// Gets the position in the source code of the statement that was executing in this frame.
+    #[inline]
+    pub fn position(&self) -> Option<Position> {
+        self.position
+    }
+
+    /// Gets the name of the function of this frame, or `None` for anonymous functions and the
+    /// top level code of a script.
+    pub(crate) fn named_function(&self) -> Option<&str> {
+        match &*self.function_name {
+            "" | "<main>" => None,
+            name => Some(name),
+        }
+    }
+}
+
+impl Display for StackTraceFrame {
+    /// Formats the frame like a line of the `stack` of an error, e.g. `f (2:9)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.named_function(), self.position) {
+            (Some(name), Some(position)) => write!(
+                f,
+                "{name} ({}:{})",
+                position.line_number(),
+                position.column_number()
+            ),
+            (None, Some(position)) => {
+                write!(f, "{}:{}", position.line_number(), position.column_number())
+            }
+            (Some(name), None) => f.write_str(name),
+            (None, None) => f.write_str("<anonymous>"),
+        }
+    }
 }
 
 impl Vm {
@@ -97,8 +187,58 @@ impl Vm {
     }
 }
 
+/// Indicates if the execution should continue, exit or yield.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ShouldExit {
+    True,
+    False,
+    Yield,
+}
+
+/// Indicates if the execution of a codeblock has ended normally or has been yielded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ReturnType {
+    Normal,
+    Yield,
+}
+
 impl Context {
-    fn execute_instruction(&mut self) -> JsResult<bool> {
+    /// Returns from the current frame.
+    ///
+    /// If the return happens inside a `try` block with a `finally` block, execution jumps to the
+    /// `finally` block first and the frame is exited once the block ends.
+    fn frame_return(&mut self) -> ShouldExit {
+        if let Some(finally_address) = self.vm.frame().catch.last().and_then(|c| c.finally) {
+            let frame = self.vm.frame_mut();
+            frame.pc = finally_address as usize;
+            frame.finally_return = FinallyReturn::Ok;
+            frame.catch.pop();
+            let try_stack_entry = self.vm.frame_mut().try_env_stack.pop().expect("must exist");
+            for _ in 0..try_stack_entry.num_env {
+                self.realm.environments.pop();
+            }
+            let mut num_env = try_stack_entry.num_env;
+            for _ in 0..try_stack_entry.num_loop_stack_entries {
+                num_env -= self
+                    .vm
+                    .frame_mut()
+                    .loop_env_stack
+                    .pop()
+                    .expect("must exist");
+            }
+            *self
+                .vm
+                .frame_mut()
+                .loop_env_stack
+                .last_mut()
+                .expect("must exist") -= num_env;
+            ShouldExit::False
+        } else {
+            ShouldExit::True
+        }
+    }
+
+    fn execute_instruction(&mut self) -> JsResult<ShouldExit> {
         macro_rules! bin_op {
             ($op:ident) => {{
                 let rhs = self.vm.pop();
@@ -108,6 +248,17 @@ impl Context {
             }};
         }
 
+        // Once the budget is exhausted every instruction throws, so the script can't go on even
+        // if a native function catches the error, e.g. a promise executor.
+        match self.vm.instruction_budget {
+            Some(0) => {
+                self.vm.runtime_limit_exceeded = true;
+                return self.throw_range_error("Instruction budget exhausted");
+            }
+            Some(ref mut budget) => *budget -= 1,
+            None => {}
+        }
+
         let opcode: Opcode = {
             let _timer = BoaProfiler::global().start_event("Opcode retrieval", "vm");
             let opcode = self.vm.frame().code.code[self.vm.frame().pc]
@@ -137,6 +288,11 @@ impl Context {
                 self.vm.push(first);
                 self.vm.push(second);
             }
+            Opcode::RotateRight => {
+                let n = self.vm.read::<u8>() as usize;
+                let len = self.vm.stack.len();
+                self.vm.stack[len - n..].rotate_right(1);
+            }
             Opcode::PushUndefined => self.vm.push(JsValue::undefined()),
             Opcode::PushNull => self.vm.push(JsValue::null()),
             Opcode::PushTrue => self.vm.push(true),
@@ -179,10 +335,23 @@ impl Context {
                 let array = Array::add_to_array_object(&array, &[value], self)?;
                 self.vm.push(array);
             }
+            Opcode::PushElisionToArray => {
+                let array = self.vm.pop();
+                let length = array.get_field("length", self)?.to_length(self)?;
+                array.set_field("length", length + 1, false, self)?;
+                self.vm.push(array);
+            }
             Opcode::PushIteratorToArray => {
                 let next_function = self.vm.pop();
                 let iterator = self.vm.pop();
-                let array = self.vm.pop();
+                // The array stays on the stack while the iterator is drained, so that an abrupt
+                // completion leaves the stack in the state expected by `pop_on_return`.
+                let array = self
+                    .vm
+                    .stack
+                    .last()
+                    .cloned()
+                    .expect("array must be on the stack");
 
                 let iterator = IteratorRecord::new(iterator, next_function);
                 loop {
@@ -193,8 +362,6 @@ impl Context {
                     }
                     Array::add_to_array_object(&array, &[next.value], self)?;
                 }
-
-                self.vm.push(array);
             }
             Opcode::Add => bin_op!(add),
             Opcode::Sub => bin_op!(sub),
@@ -309,11 +476,8 @@ impl Context {
                 let binding_locator = self.vm.frame().code.bindings[index as usize];
 
                 if binding_locator.is_global() {
-                    let key = self
-                        .interner()
-                        .resolve_expect(binding_locator.name())
-                        .into();
-                    self.global_bindings_mut().entry(key).or_insert(
+                    let key = self.identifier_string(binding_locator.name());
+                    self.global_string_bindings_mut().entry(key).or_insert(
                         PropertyDescriptor::builder()
                             .value(JsValue::Undefined)
                             .writable(true)
@@ -336,10 +500,7 @@ impl Context {
                 binding_locator.throw_mutate_immutable(self)?;
 
                 if binding_locator.is_global() {
-                    let key = self
-                        .interner()
-                        .resolve_expect(binding_locator.name())
-                        .into();
+                    let key = self.identifier_string(binding_locator.name()).into();
                     crate::object::internal_methods::global::global_set_no_receiver(
                         &key, value, self,
                     )?;
@@ -376,26 +537,30 @@ impl Context {
                 binding_locator.throw_mutate_immutable(self)?;
 
                 let value = if binding_locator.is_global() {
-                    let key: JsString = self
-                        .interner()
-                        .resolve_expect(binding_locator.name())
-                        .into();
-                    match self.global_bindings_mut().get(&key) {
-                        Some(desc) => match desc.kind() {
-                            DescriptorKind::Data {
-                                value: Some(value), ..
-                            } => value.clone(),
-                            DescriptorKind::Accessor { get: Some(get), .. }
-                                if !get.is_undefined() =>
-                            {
-                                let get = get.clone();
-                                self.call(&get, &self.global_object().clone().into(), &[])?
-                            }
-                            _ => {
-                                return self.throw_reference_error(format!("{key} is not defined"))
-                            }
-                        },
-                        _ => return self.throw_reference_error(format!("{key} is not defined")),
+                    let key = self.identifier_string(binding_locator.name());
+                    if let Some(binding_object) = self.realm.global_binding_object.clone() {
+                        if binding_object.has_property(key.clone(), self)? {
+                            binding_object.get(key, self)?
+                        } else {
+                            self.global_binding_not_found(&key)?
+                        }
+                    } else {
+                        self.initialize_lazy_global(&key);
+                        match self.global_string_bindings_mut().get(&key) {
+                            Some(desc) => match desc.kind() {
+                                DescriptorKind::Data {
+                                    value: Some(value), ..
+                                } => value.clone(),
+                                DescriptorKind::Accessor { get: Some(get), .. }
+                                    if !get.is_undefined() =>
+                                {
+                                    let get = get.clone();
+                                    self.call(&get, &self.global_object().clone().into(), &[])?
+                                }
+                                _ => self.global_binding_not_found(&key)?,
+                            },
+                            _ => self.global_binding_not_found(&key)?,
+                        }
                     }
                 } else if let Some(value) = self.realm.environments.get_value_optional(
                     binding_locator.environment_index(),
@@ -415,24 +580,30 @@ impl Context {
                 let binding_locator = self.vm.frame().code.bindings[index as usize];
                 binding_locator.throw_mutate_immutable(self)?;
                 let value = if binding_locator.is_global() {
-                    let key: JsString = self
-                        .interner()
-                        .resolve_expect(binding_locator.name())
-                        .into();
-                    match self.global_bindings_mut().get(&key) {
-                        Some(desc) => match desc.kind() {
-                            DescriptorKind::Data {
-                                value: Some(value), ..
-                            } => value.clone(),
-                            DescriptorKind::Accessor { get: Some(get), .. }
-                                if !get.is_undefined() =>
-                            {
-                                let get = get.clone();
-                                self.call(&get, &self.global_object().clone().into(), &[])?
-                            }
+                    let key = self.identifier_string(binding_locator.name());
+                    if let Some(binding_object) = self.realm.global_binding_object.clone() {
+                        if binding_object.has_property(key.clone(), self)? {
+                            binding_object.get(key, self)?
+                        } else {
+                            JsValue::undefined()
+                        }
+                    } else {
+                        self.initialize_lazy_global(&key);
+                        match self.global_string_bindings_mut().get(&key) {
+                            Some(desc) => match desc.kind() {
+                                DescriptorKind::Data {
+                                    value: Some(value), ..
+                                } => value.clone(),
+                                DescriptorKind::Accessor { get: Some(get), .. }
+                                    if !get.is_undefined() =>
+                                {
+                                    let get = get.clone();
+                                    self.call(&get, &self.global_object().clone().into(), &[])?
+                                }
+                                _ => JsValue::undefined(),
+                            },
                             _ => JsValue::undefined(),
-                        },
-                        _ => JsValue::undefined(),
+                        }
                     }
                 } else if let Some(value) = self.realm.environments.get_value_optional(
                     binding_locator.environment_index(),
@@ -452,26 +623,33 @@ impl Context {
                 binding_locator.throw_mutate_immutable(self)?;
 
                 if binding_locator.is_global() {
-                    let key: JsString = self
-                        .interner()
-                        .resolve_expect(binding_locator.name())
-                        .into();
-                    let exists = self.global_bindings_mut().contains_key(&key);
-
-                    if !exists && (self.strict() || self.vm.frame().code.strict) {
-                        return self
-                            .throw_reference_error(format!("binding already exists: {key}"));
-                    }
+                    let key = self.identifier_string(binding_locator.name());
+                    if let Some(binding_object) = self.realm.global_binding_object.clone() {
+                        let strict = self.strict() || self.vm.frame().code.strict;
+                        if !binding_object.has_property(key.clone(), self)? && strict {
+                            return self.throw_reference_error(format!("{key} is not defined"));
+                        }
+                        binding_object.set(key, value, strict, self)?;
+                    } else {
+                        let exists = self.global_string_bindings_mut().contains_key(&key);
 
-                    let success = crate::object::internal_methods::global::global_set_no_receiver(
-                        &key.clone().into(),
-                        value,
-                        self,
-                    )?;
+                        if !exists && (self.strict() || self.vm.frame().code.strict) {
+                            return self
+                                .throw_reference_error(format!("binding already exists: {key}"));
+                        }
 
-                    if !success && (self.strict() || self.vm.frame().code.strict) {
-                        return self
-                            .throw_type_error(format!("cannot set non-writable property: {key}",));
+                        let success =
+                            crate::object::internal_methods::global::global_set_no_receiver(
+                                &key.clone().into(),
+                                value,
+                                self,
+                            )?;
+
+                        if !success && (self.strict() || self.vm.frame().code.strict) {
+                            return self.throw_type_error(format!(
+                                "cannot set non-writable property: {key}",
+                            ));
+                        }
                     }
                 } else if !self.realm.environments.put_value_if_initialized(
                     binding_locator.environment_index(),
@@ -530,6 +708,11 @@ impl Context {
                 let value = self.vm.pop();
                 self.vm.push(value.to_boolean());
             }
+            Opcode::ToPropertyKey => {
+                let value = self.vm.pop();
+                let key = value.to_property_key(self)?;
+                self.vm.push(key);
+            }
             Opcode::GetPropertyByName => {
                 let index = self.vm.read::<u32>();
 
@@ -541,7 +724,7 @@ impl Context {
                 };
 
                 let name = self.vm.frame().code.variables[index as usize];
-                let name: PropertyKey = self.interner().resolve_expect(name).into();
+                let name: PropertyKey = self.identifier_string(name).into();
                 let result = object.get(name, self)?;
 
                 self.vm.push(result);
@@ -560,11 +743,27 @@ impl Context {
 
                 self.vm.push(value);
             }
+            Opcode::GetPropertyByValuePush => {
+                let key = self.vm.pop();
+                let object = self.vm.pop();
+                let base = if let Some(object) = object.as_object() {
+                    object.clone()
+                } else {
+                    object.to_object(self)?
+                };
+
+                let property_key = key.to_property_key(self)?;
+                let value = base.get(property_key, self)?;
+
+                self.vm.push(object);
+                self.vm.push(key);
+                self.vm.push(value);
+            }
             Opcode::SetPropertyByName => {
                 let index = self.vm.read::<u32>();
 
-                let object = self.vm.pop();
                 let value = self.vm.pop();
+                let object = self.vm.pop();
                 let object = if let Some(object) = object.as_object() {
                     object.clone()
                 } else {
@@ -572,14 +771,15 @@ impl Context {
                 };
 
                 let name = self.vm.frame().code.variables[index as usize];
-                let name: PropertyKey = self.interner().resolve_expect(name).into();
+                let name: PropertyKey = self.identifier_string(name).into();
 
                 object.set(
                     name,
-                    value,
+                    value.clone(),
                     self.strict() || self.vm.frame().code.strict,
                     self,
                 )?;
+                self.vm.push(value);
             }
             Opcode::DefineOwnPropertyByName => {
                 let index = self.vm.read::<u32>();
@@ -593,7 +793,7 @@ impl Context {
                 };
 
                 let name = self.vm.frame().code.variables[index as usize];
-                let name = self.interner().resolve_expect(name);
+                let name = self.identifier_string(name);
 
                 object.__define_own_property__(
                     name.into(),
@@ -607,9 +807,9 @@ impl Context {
                 )?;
             }
             Opcode::SetPropertyByValue => {
-                let object = self.vm.pop();
-                let key = self.vm.pop();
                 let value = self.vm.pop();
+                let key = self.vm.pop();
+                let object = self.vm.pop();
                 let object = if let Some(object) = object.as_object() {
                     object.clone()
                 } else {
@@ -619,10 +819,11 @@ impl Context {
                 let key = key.to_property_key(self)?;
                 object.set(
                     key,
-                    value,
+                    value.clone(),
                     self.strict() || self.vm.frame().code.strict,
                     self,
                 )?;
+                self.vm.push(value);
             }
             Opcode::DefineOwnPropertyByValue => {
                 let value = self.vm.pop();
@@ -654,7 +855,10 @@ impl Context {
                 let object = object.to_object(self)?;
 
                 let name = self.vm.frame().code.variables[index as usize];
-                let name = self.interner().resolve_expect(name).into();
+                let name = self.identifier_string(name).into();
+                if let Some(function) = value.as_object() {
+                    set_function_name(function, &name, Some("get"), self);
+                }
                 let set = object
                     .__get_own_property__(&name, self)?
                     .as_ref()
@@ -677,6 +881,9 @@ impl Context {
                 let object = self.vm.pop();
                 let object = object.to_object(self)?;
                 let name = key.to_property_key(self)?;
+                if let Some(function) = value.as_object() {
+                    set_function_name(function, &name, Some("get"), self);
+                }
                 let set = object
                     .__get_own_property__(&name, self)?
                     .as_ref()
@@ -699,7 +906,10 @@ impl Context {
                 let value = self.vm.pop();
                 let object = object.to_object(self)?;
                 let name = self.vm.frame().code.variables[index as usize];
-                let name = self.interner().resolve_expect(name).into();
+                let name = self.identifier_string(name).into();
+                if let Some(function) = value.as_object() {
+                    set_function_name(function, &name, Some("set"), self);
+                }
                 let get = object
                     .__get_own_property__(&name, self)?
                     .as_ref()
@@ -722,6 +932,9 @@ impl Context {
                 let object = self.vm.pop();
                 let object = object.to_object(self)?;
                 let name = key.to_property_key(self)?;
+                if let Some(function) = value.as_object() {
+                    set_function_name(function, &name, Some("set"), self);
+                }
                 let get = object
                     .__get_own_property__(&name, self)?
                     .as_ref()
@@ -738,13 +951,186 @@ impl Context {
                     self,
                 )?;
             }
+            Opcode::DefineClassMethodByName => {
+                let index = self.vm.read::<u32>();
+                let object = self.vm.pop();
+                let function = self.vm.pop();
+                let object = object.to_object(self)?;
+                if let Some(function) = function.as_object() {
+                    if let Some(function) = function.borrow_mut().as_function_mut() {
+                        function.set_home_object(object.clone());
+                    }
+                }
+
+                let name = self.vm.frame().code.variables[index as usize];
+                let name = self.identifier_string(name);
+                object.__define_own_property__(
+                    name.into(),
+                    PropertyDescriptor::builder()
+                        .value(function)
+                        .writable(true)
+                        .enumerable(false)
+                        .configurable(true)
+                        .build(),
+                    self,
+                )?;
+            }
+            Opcode::DefineClassMethodByValue => {
+                let function = self.vm.pop();
+                let key = self.vm.pop();
+                let object = self.vm.pop();
+                let object = object.to_object(self)?;
+                if let Some(function) = function.as_object() {
+                    if let Some(function) = function.borrow_mut().as_function_mut() {
+                        function.set_home_object(object.clone());
+                    }
+                }
+
+                let key = key.to_property_key(self)?;
+                object.__define_own_property__(
+                    key,
+                    PropertyDescriptor::builder()
+                        .value(function)
+                        .writable(true)
+                        .enumerable(false)
+                        .configurable(true)
+                        .build(),
+                    self,
+                )?;
+            }
+            Opcode::DefineClassGetterByName => {
+                let index = self.vm.read::<u32>();
+                let object = self.vm.pop();
+                let function = self.vm.pop();
+                let object = object.to_object(self)?;
+                if let Some(function) = function.as_object() {
+                    if let Some(function) = function.borrow_mut().as_function_mut() {
+                        function.set_home_object(object.clone());
+                    }
+                }
+
+                let name = self.vm.frame().code.variables[index as usize];
+                let name = self.identifier_string(name).into();
+                if let Some(function) = function.as_object() {
+                    set_function_name(function, &name, Some("get"), self);
+                }
+                let set = object
+                    .__get_own_property__(&name, self)?
+                    .as_ref()
+                    .and_then(PropertyDescriptor::set)
+                    .cloned();
+                object.__define_own_property__(
+                    name,
+                    PropertyDescriptor::builder()
+                        .maybe_get(Some(function))
+                        .maybe_set(set)
+                        .enumerable(false)
+                        .configurable(true)
+                        .build(),
+                    self,
+                )?;
+            }
+            Opcode::DefineClassGetterByValue => {
+                let function = self.vm.pop();
+                let key = self.vm.pop();
+                let object = self.vm.pop();
+                let object = object.to_object(self)?;
+                if let Some(function) = function.as_object() {
+                    if let Some(function) = function.borrow_mut().as_function_mut() {
+                        function.set_home_object(object.clone());
+                    }
+                }
+
+                let name = key.to_property_key(self)?;
+                if let Some(function) = function.as_object() {
+                    set_function_name(function, &name, Some("get"), self);
+                }
+                let set = object
+                    .__get_own_property__(&name, self)?
+                    .as_ref()
+                    .and_then(PropertyDescriptor::set)
+                    .cloned();
+                object.__define_own_property__(
+                    name,
+                    PropertyDescriptor::builder()
+                        .maybe_get(Some(function))
+                        .maybe_set(set)
+                        .enumerable(false)
+                        .configurable(true)
+                        .build(),
+                    self,
+                )?;
+            }
+            Opcode::DefineClassSetterByName => {
+                let index = self.vm.read::<u32>();
+                let object = self.vm.pop();
+                let function = self.vm.pop();
+                let object = object.to_object(self)?;
+                if let Some(function) = function.as_object() {
+                    if let Some(function) = function.borrow_mut().as_function_mut() {
+                        function.set_home_object(object.clone());
+                    }
+                }
+
+                let name = self.vm.frame().code.variables[index as usize];
+                let name = self.identifier_string(name).into();
+                if let Some(function) = function.as_object() {
+                    set_function_name(function, &name, Some("set"), self);
+                }
+                let get = object
+                    .__get_own_property__(&name, self)?
+                    .as_ref()
+                    .and_then(PropertyDescriptor::get)
+                    .cloned();
+                object.__define_own_property__(
+                    name,
+                    PropertyDescriptor::builder()
+                        .maybe_set(Some(function))
+                        .maybe_get(get)
+                        .enumerable(false)
+                        .configurable(true)
+                        .build(),
+                    self,
+                )?;
+            }
+            Opcode::DefineClassSetterByValue => {
+                let function = self.vm.pop();
+                let key = self.vm.pop();
+                let object = self.vm.pop();
+                let object = object.to_object(self)?;
+                if let Some(function) = function.as_object() {
+                    if let Some(function) = function.borrow_mut().as_function_mut() {
+                        function.set_home_object(object.clone());
+                    }
+                }
+
+                let name = key.to_property_key(self)?;
+                if let Some(function) = function.as_object() {
+                    set_function_name(function, &name, Some("set"), self);
+                }
+                let get = object
+                    .__get_own_property__(&name, self)?
+                    .as_ref()
+                    .and_then(PropertyDescriptor::get)
+                    .cloned();
+                object.__define_own_property__(
+                    name,
+                    PropertyDescriptor::builder()
+                        .maybe_set(Some(function))
+                        .maybe_get(get)
+                        .enumerable(false)
+                        .configurable(true)
+                        .build(),
+                    self,
+                )?;
+            }
             Opcode::DeletePropertyByName => {
                 let index = self.vm.read::<u32>();
                 let key = self.vm.frame().code.variables[index as usize];
-                let key = self.interner().resolve_expect(key).into();
+                let key = self.identifier_string(key).into();
                 let object = self.vm.pop();
                 let result = object.to_object(self)?.__delete__(&key, self)?;
-                if !result && self.strict() || self.vm.frame().code.strict {
+                if !result && (self.strict() || self.vm.frame().code.strict) {
                     return Err(self.construct_type_error("Cannot delete property"));
                 }
                 self.vm.push(result);
@@ -755,11 +1141,30 @@ impl Context {
                 let result = object
                     .to_object(self)?
                     .__delete__(&key.to_property_key(self)?, self)?;
-                if !result && self.strict() || self.vm.frame().code.strict {
+                if !result && (self.strict() || self.vm.frame().code.strict) {
                     return Err(self.construct_type_error("Cannot delete property"));
                 }
                 self.vm.push(result);
             }
+            Opcode::DeleteName => {
+                let index = self.vm.read::<u32>();
+                let binding_locator = self.vm.frame().code.bindings[index as usize];
+
+                // Declarative bindings can't be deleted, but the properties of the global
+                // object can, as long as they are configurable.
+                let result = if binding_locator.is_global() {
+                    let key: PropertyKey = self.identifier_string(binding_locator.name()).into();
+                    let binding_object = self
+                        .realm
+                        .global_binding_object
+                        .clone()
+                        .unwrap_or_else(|| self.global_object().clone());
+                    binding_object.__delete__(&key, self)?
+                } else {
+                    false
+                };
+                self.vm.push(result);
+            }
             Opcode::CopyDataProperties => {
                 let excluded_key_count = self.vm.read::<u32>();
                 let mut excluded_keys = Vec::with_capacity(excluded_key_count as usize);
@@ -850,7 +1255,7 @@ impl Context {
                         }
                     }
                     FinallyReturn::Ok => {
-                        return Ok(true);
+                        return Ok(ShouldExit::True);
                     }
                     FinallyReturn::Err => {
                         return Err(self.vm.pop());
@@ -868,8 +1273,59 @@ impl Context {
             }
             Opcode::This => {
                 let this = self.vm.frame().this.clone();
+                if this.is_undefined() && self.vm.frame().code.constructor_kind.is_derived() {
+                    return self.throw_reference_error(
+                        "Must call super constructor in derived class before accessing 'this'",
+                    );
+                }
                 self.vm.push(this);
             }
+            Opcode::PushNewTarget => {
+                let new_target = self.realm.environments.get_last_new_target();
+                self.vm
+                    .push(new_target.map_or_else(JsValue::undefined, Into::into));
+            }
+            Opcode::Super => {
+                let home_object = self
+                    .vm
+                    .frame()
+                    .function_object
+                    .as_ref()
+                    .and_then(|function| {
+                        function
+                            .borrow()
+                            .as_function()
+                            .and_then(Function::home_object)
+                            .cloned()
+                    });
+                let home_object = match home_object {
+                    Some(home_object) => home_object,
+                    None => return self.throw_syntax_error("'super' keyword unexpected here"),
+                };
+
+                let base = home_object.__get_prototype_of__(self)?;
+                self.vm.push(base.map_or_else(JsValue::null, JsValue::from));
+            }
+            Opcode::SuperCall => {
+                let argument_count = self.vm.read::<u32>();
+                let mut arguments = Vec::with_capacity(argument_count as usize);
+                for _ in 0..argument_count {
+                    arguments.push(self.vm.pop());
+                }
+                arguments.reverse();
+
+                let result = self.super_call(&arguments)?;
+                self.vm.push(result);
+            }
+            Opcode::SuperCallSpread => {
+                // The arguments array is created by the compiler, so its elements are all data
+                // properties and reading them can't run user code.
+                let arguments_array = self.vm.pop();
+                let arguments = arguments_array.create_list_from_array_like(&[], self)?;
+
+                let result = self.super_call(&arguments)?;
+                self.vm.push(result);
+            }
             Opcode::Case => {
                 let address = self.vm.read::<u32>();
                 let cond = self.vm.pop();
@@ -889,9 +1345,331 @@ impl Context {
             Opcode::GetFunction => {
                 let index = self.vm.read::<u32>();
                 let code = self.vm.frame().code.functions[index as usize].clone();
+                let lexical_this_mode = code.this_mode.is_lexical();
                 let function = JsVmFunction::new(code, self);
+
+                // Arrow functions resolve `super` like the function they are defined in.
+                if lexical_this_mode {
+                    let home_object = self.vm.frame().function_object.as_ref().and_then(|f| {
+                        f.borrow()
+                            .as_function()
+                            .and_then(Function::home_object)
+                            .cloned()
+                    });
+                    if let Some(home_object) = home_object {
+                        function
+                            .borrow_mut()
+                            .as_function_mut()
+                            .expect("must be a function")
+                            .set_home_object(home_object);
+                    }
+                }
+
                 self.vm.push(function);
             }
+            Opcode::PushClassPrototype => {
+                let superclass = self.vm.pop();
+
+                let prototype_parent = if superclass.is_undefined() {
+                    // a. Let protoParent be %Object.prototype%.
+                    Some(self.standard_objects().object_object().prototype())
+                } else if superclass.is_null() {
+                    // e. If superclass is null, then
+                    //     i. Let protoParent be null.
+                    None
+                } else if let Some(constructor) = superclass.as_constructor() {
+                    // g. Else,
+                    //     i. Let protoParent be ? Get(superclass, "prototype").
+                    match &constructor.get("prototype", self)? {
+                        JsValue::Object(prototype) => Some(prototype.clone()),
+                        JsValue::Null => None,
+                        // ii. If protoParent is not an Object and protoParent is not null,
+                        //     throw a TypeError exception.
+                        _ => {
+                            return self.throw_type_error(
+                                "Class extends value does not have valid prototype property",
+                            )
+                        }
+                    }
+                } else {
+                    // f. Else if IsConstructor(superclass) is false, throw a TypeError exception.
+                    return self
+                        .throw_type_error("Class extends value is not a constructor or null");
+                };
+
+                // 9. Let proto be OrdinaryObjectCreate(protoParent).
+                let prototype =
+                    JsObject::from_proto_and_data(prototype_parent, ObjectData::ordinary());
+
+                self.vm.push(superclass);
+                self.vm.push(prototype);
+            }
+            Opcode::SetClassPrototype => {
+                let constructor = self.vm.pop();
+                let prototype = self.vm.pop();
+                let superclass = self.vm.pop();
+                let constructor = constructor
+                    .as_object()
+                    .cloned()
+                    .expect("class constructor must be an object");
+                let prototype = prototype
+                    .as_object()
+                    .cloned()
+                    .expect("class prototype must be an object");
+
+                // The constructor of a derived class inherits the static members of its superclass.
+                if let Some(superclass) = superclass.as_object() {
+                    constructor.set_prototype(Some(superclass.clone()));
+                }
+
+                // 16. Perform MakeConstructor(F, false, proto).
+                constructor.define_property_or_throw(
+                    "prototype",
+                    PropertyDescriptor::builder()
+                        .value(prototype.clone())
+                        .writable(false)
+                        .enumerable(false)
+                        .configurable(false)
+                        .build(),
+                    self,
+                )?;
+
+                // 19. Perform CreateMethodProperty(proto, "constructor", F).
+                prototype.define_property_or_throw(
+                    "constructor",
+                    PropertyDescriptor::builder()
+                        .value(constructor.clone())
+                        .writable(true)
+                        .enumerable(false)
+                        .configurable(true)
+                        .build(),
+                    self,
+                )?;
+
+                self.vm.push(constructor);
+                self.vm.push(prototype);
+            }
+            Opcode::PushClassField => {
+                let initializer = self.vm.pop();
+                let key = self.vm.pop();
+                let class = self.vm.pop();
+                let class = class
+                    .as_object()
+                    .cloned()
+                    .expect("class constructor must be an object");
+
+                let name = key.to_property_key(self)?;
+                let initializer = initializer.as_object().cloned();
+
+                // The initializer of an instance field is a method of the class prototype.
+                if let Some(initializer) = &initializer {
+                    if let Some(prototype) = class.get("prototype", self)?.as_object() {
+                        initializer
+                            .borrow_mut()
+                            .as_function_mut()
+                            .expect("field initializer must be a function")
+                            .set_home_object(prototype.clone());
+                    }
+                }
+
+                class
+                    .borrow_mut()
+                    .as_function_mut()
+                    .expect("class constructor must be a function")
+                    .push_field(ClassFieldDefinition::Public(name, initializer));
+            }
+            Opcode::DefineClassStaticField => {
+                let initializer = self.vm.pop();
+                let key = self.vm.pop();
+                let class = self.vm.pop();
+                let class = class
+                    .as_object()
+                    .cloned()
+                    .expect("class constructor must be an object");
+
+                let name = key.to_property_key(self)?;
+                let initializer = initializer.as_object().cloned();
+
+                // The initializer of a static field is a method of the class constructor.
+                if let Some(initializer) = &initializer {
+                    initializer
+                        .borrow_mut()
+                        .as_function_mut()
+                        .expect("field initializer must be a function")
+                        .set_home_object(class.clone());
+                }
+
+                class.define_field(&ClassFieldDefinition::Public(name, initializer), self)?;
+            }
+            Opcode::PushPrivateName => {
+                let index = self.vm.read::<u32>();
+                let name = self.vm.frame().code.variables[index as usize];
+                let description = self.interner().resolve_expect(name).into();
+                self.vm.push(JsSymbol::new(Some(description)));
+            }
+            Opcode::PushClassPrivateMethod
+            | Opcode::PushClassPrivateGetter
+            | Opcode::PushClassPrivateSetter => {
+                let method = self.vm.pop();
+                let name = self.vm.pop();
+                let class = self.vm.pop();
+                let class = class
+                    .as_object()
+                    .cloned()
+                    .expect("class constructor must be an object");
+                let name = name.as_symbol().expect("private name must be a symbol");
+                let method = method
+                    .as_object()
+                    .cloned()
+                    .expect("private method must be an object");
+
+                // Private instance methods are methods of the class prototype.
+                if let Some(prototype) = class.get("prototype", self)?.as_object() {
+                    if let Some(function) = method.borrow_mut().as_function_mut() {
+                        function.set_home_object(prototype.clone());
+                    }
+                }
+
+                let method = match opcode {
+                    Opcode::PushClassPrivateMethod => PrivateElement::Method(method),
+                    Opcode::PushClassPrivateGetter => PrivateElement::Accessor {
+                        getter: Some(method),
+                        setter: None,
+                    },
+                    _ => PrivateElement::Accessor {
+                        getter: None,
+                        setter: Some(method),
+                    },
+                };
+                class
+                    .borrow_mut()
+                    .as_function_mut()
+                    .expect("class constructor must be a function")
+                    .push_private_method(name, method);
+            }
+            Opcode::PushClassPrivateField => {
+                let initializer = self.vm.pop();
+                let name = self.vm.pop();
+                let class = self.vm.pop();
+                let class = class
+                    .as_object()
+                    .cloned()
+                    .expect("class constructor must be an object");
+                let name = name.as_symbol().expect("private name must be a symbol");
+                let initializer = initializer.as_object().cloned();
+
+                // The initializer of an instance field is a method of the class prototype.
+                if let Some(initializer) = &initializer {
+                    if let Some(prototype) = class.get("prototype", self)?.as_object() {
+                        initializer
+                            .borrow_mut()
+                            .as_function_mut()
+                            .expect("field initializer must be a function")
+                            .set_home_object(prototype.clone());
+                    }
+                }
+
+                class
+                    .borrow_mut()
+                    .as_function_mut()
+                    .expect("class constructor must be a function")
+                    .push_field(ClassFieldDefinition::Private(name, initializer));
+            }
+            Opcode::DefineClassStaticPrivateMethod
+            | Opcode::DefineClassStaticPrivateGetter
+            | Opcode::DefineClassStaticPrivateSetter => {
+                let method = self.vm.pop();
+                let name = self.vm.pop();
+                let class = self.vm.pop();
+                let class = class
+                    .as_object()
+                    .cloned()
+                    .expect("class constructor must be an object");
+                let name = name.as_symbol().expect("private name must be a symbol");
+                let method = method
+                    .as_object()
+                    .cloned()
+                    .expect("private method must be an object");
+
+                if let Some(function) = method.borrow_mut().as_function_mut() {
+                    function.set_home_object(class.clone());
+                }
+
+                let method = match opcode {
+                    Opcode::DefineClassStaticPrivateMethod => PrivateElement::Method(method),
+                    Opcode::DefineClassStaticPrivateGetter => PrivateElement::Accessor {
+                        getter: Some(method),
+                        setter: None,
+                    },
+                    _ => PrivateElement::Accessor {
+                        getter: None,
+                        setter: Some(method),
+                    },
+                };
+                class.borrow_mut().append_private_method(name, method);
+            }
+            Opcode::DefineClassStaticPrivateField => {
+                let initializer = self.vm.pop();
+                let name = self.vm.pop();
+                let class = self.vm.pop();
+                let class = class
+                    .as_object()
+                    .cloned()
+                    .expect("class constructor must be an object");
+                let name = name.as_symbol().expect("private name must be a symbol");
+                let initializer = initializer.as_object().cloned();
+
+                // The initializer of a static field is a method of the class constructor.
+                if let Some(initializer) = &initializer {
+                    initializer
+                        .borrow_mut()
+                        .as_function_mut()
+                        .expect("field initializer must be a function")
+                        .set_home_object(class.clone());
+                }
+
+                class.define_field(&ClassFieldDefinition::Private(name, initializer), self)?;
+            }
+            Opcode::GetPrivateField => {
+                let name = self.vm.pop();
+                let object = self.vm.pop();
+                let name = name.as_symbol().expect("private name must be a symbol");
+                let object = object.to_object(self)?;
+
+                let value = object.private_get(&name, self)?;
+                self.vm.push(value);
+            }
+            Opcode::SetPrivateField => {
+                let name = self.vm.pop();
+                let value = self.vm.pop();
+                let object = self.vm.pop();
+                let name = name.as_symbol().expect("private name must be a symbol");
+                let object = object.to_object(self)?;
+
+                object.private_set(&name, value.clone(), self)?;
+                self.vm.push(value);
+            }
+            Opcode::InPrivate => {
+                let name = self.vm.pop();
+                let object = self.vm.pop();
+                let name = name.as_symbol().expect("private name must be a symbol");
+
+                // 4. If Type(rval) is not Object, throw a TypeError exception.
+                let object = match object.as_object() {
+                    Some(object) => object.clone(),
+                    None => {
+                        return self.throw_type_error(format!(
+                            "right-hand side of 'in' should be an object, got {}",
+                            object.type_of()
+                        ))
+                    }
+                };
+
+                // 6. If PrivateElementFind(rval, privateName) is not empty, return true.
+                // 7. Return false.
+                let has = object.borrow().private_element_find(&name).is_some();
+                self.vm.push(has);
+            }
             Opcode::Call => {
                 if self.vm.stack_size_limit <= self.vm.stack.len() {
                     return self.throw_range_error("Maximum call stack size exceeded");
@@ -911,7 +1689,13 @@ impl Context {
                     _ => return self.throw_type_error("not a callable function"),
                 };
 
-                if this.is_null_or_undefined() {
+                // Functions compiled from JavaScript code only replace an undefined `this` with
+                // the global object in sloppy mode, so that strict functions receive it as is.
+                let is_vm_function = matches!(
+                    object.borrow().as_function(),
+                    Some(Function::VmOrdinary { .. })
+                );
+                if this.is_null_or_undefined() && !is_vm_function {
                     this = self.global_object().clone().into();
                 }
 
@@ -919,37 +1703,28 @@ impl Context {
 
                 self.vm.push(result);
             }
-            Opcode::CallWithRest => {
+            Opcode::CallSpread => {
                 if self.vm.stack_size_limit <= self.vm.stack.len() {
                     return self.throw_range_error("Maximum call stack size exceeded");
                 }
-                let argument_count = self.vm.read::<u32>();
-                let rest_argument = self.vm.pop();
-                let mut arguments = Vec::with_capacity(argument_count as usize);
-                for _ in 0..(argument_count - 1) {
-                    arguments.push(self.vm.pop());
-                }
-                arguments.reverse();
+                let arguments_array = self.vm.pop();
+                let arguments = arguments_array.create_list_from_array_like(&[], self)?;
+
                 let func = self.vm.pop();
                 let mut this = self.vm.pop();
 
-                let iterator_record = rest_argument.get_iterator(self, None, None)?;
-                let mut rest_arguments = Vec::new();
-                loop {
-                    let next = iterator_record.next(self)?;
-                    if next.done {
-                        break;
-                    }
-                    rest_arguments.push(next.value);
-                }
-                arguments.append(&mut rest_arguments);
-
                 let object = match func {
                     JsValue::Object(ref object) if object.is_callable() => object.clone(),
                     _ => return self.throw_type_error("not a callable function"),
                 };
 
-                if this.is_null_or_undefined() {
+                // Functions compiled from JavaScript code only replace an undefined `this` with
+                // the global object in sloppy mode, so that strict functions receive it as is.
+                let is_vm_function = matches!(
+                    object.borrow().as_function(),
+                    Some(Function::VmOrdinary { .. })
+                );
+                if this.is_null_or_undefined() && !is_vm_function {
                     this = self.global_object().clone().into();
                 }
 
@@ -976,30 +1751,14 @@ impl Context {
 
                 self.vm.push(result);
             }
-            Opcode::NewWithRest => {
+            Opcode::NewSpread => {
                 if self.vm.stack_size_limit <= self.vm.stack.len() {
                     return self.throw_range_error("Maximum call stack size exceeded");
                 }
-                let argument_count = self.vm.read::<u32>();
-                let rest_argument = self.vm.pop();
-                let mut arguments = Vec::with_capacity(argument_count as usize);
-                for _ in 0..(argument_count - 1) {
-                    arguments.push(self.vm.pop());
-                }
-                arguments.reverse();
+                let arguments_array = self.vm.pop();
+                let arguments = arguments_array.create_list_from_array_like(&[], self)?;
                 let func = self.vm.pop();
 
-                let iterator_record = rest_argument.get_iterator(self, None, None)?;
-                let mut rest_arguments = Vec::new();
-                loop {
-                    let next = iterator_record.next(self)?;
-                    if next.done {
-                        break;
-                    }
-                    rest_arguments.push(next.value);
-                }
-                arguments.append(&mut rest_arguments);
-
                 let result = func
                     .as_constructor()
                     .ok_or_else(|| self.construct_type_error("not a constructor"))
@@ -1007,37 +1766,7 @@ impl Context {
 
                 self.vm.push(result);
             }
-            Opcode::Return => {
-                if let Some(finally_address) = self.vm.frame().catch.last().and_then(|c| c.finally)
-                {
-                    let frame = self.vm.frame_mut();
-                    frame.pc = finally_address as usize;
-                    frame.finally_return = FinallyReturn::Ok;
-                    frame.catch.pop();
-                    let try_stack_entry =
-                        self.vm.frame_mut().try_env_stack.pop().expect("must exist");
-                    for _ in 0..try_stack_entry.num_env {
-                        self.realm.environments.pop();
-                    }
-                    let mut num_env = try_stack_entry.num_env;
-                    for _ in 0..try_stack_entry.num_loop_stack_entries {
-                        num_env -= self
-                            .vm
-                            .frame_mut()
-                            .loop_env_stack
-                            .pop()
-                            .expect("must exist");
-                    }
-                    *self
-                        .vm
-                        .frame_mut()
-                        .loop_env_stack
-                        .last_mut()
-                        .expect("must exist") -= num_env;
-                } else {
-                    return Ok(true);
-                }
-            }
+            Opcode::Return => return Ok(self.frame_return()),
             Opcode::PushDeclarativeEnvironment => {
                 let num_bindings = self.vm.read::<u32>();
                 self.realm
@@ -1055,10 +1784,11 @@ impl Context {
                 } else {
                     JsValue::undefined()
                 };
+                let new_target = self.realm.environments.get_last_new_target();
 
                 self.realm
                     .environments
-                    .push_function(num_bindings as usize, this);
+                    .push_function(num_bindings as usize, this, new_target);
             }
             Opcode::PopEnvironment => {
                 self.realm.environments.pop();
@@ -1121,6 +1851,12 @@ impl Context {
                 self.vm.push(iterator.iterator_object());
                 self.vm.push(iterator.next_function());
             }
+            Opcode::InitIteratorAsync => {
+                let object = self.vm.pop();
+                let iterator = object.get_iterator(self, Some(IteratorHint::Async), None)?;
+                self.vm.push(iterator.iterator_object());
+                self.vm.push(iterator.next_function());
+            }
             Opcode::IteratorNext => {
                 let next_function = self.vm.pop();
                 let iterator = self.vm.pop();
@@ -1198,6 +1934,36 @@ impl Context {
                     self.vm.push(iterator_result.value);
                 }
             }
+            Opcode::ForAwaitOfLoopIterate => {
+                let next_function = self.vm.pop();
+                let iterator = self.vm.pop();
+
+                let next_result = self.call(&next_function, &iterator, &[])?;
+
+                self.vm.push(iterator);
+                self.vm.push(next_function);
+                self.vm.push(next_result);
+            }
+            Opcode::ForAwaitOfLoopNext => {
+                let address = self.vm.read::<u32>();
+
+                let next_result = self.vm.pop();
+                let next_result = if let Some(next_result) = next_result.as_object() {
+                    next_result.clone()
+                } else {
+                    return self.throw_type_error("next value should be an object");
+                };
+
+                if next_result.get("done", self)?.to_boolean() {
+                    self.vm.frame_mut().pc = address as usize;
+                    self.vm.frame_mut().loop_env_stack_dec();
+                    self.vm.frame_mut().try_env_stack_dec();
+                    self.realm.environments.pop();
+                } else {
+                    let value = next_result.get("value", self)?;
+                    self.vm.push(value);
+                }
+            }
             Opcode::ConcatToString => {
                 let value_count = self.vm.read::<u32>();
                 let mut strings = Vec::with_capacity(value_count as usize);
@@ -1259,12 +2025,153 @@ impl Context {
             Opcode::PopOnReturnSub => {
                 self.vm.frame_mut().pop_on_return -= 1;
             }
+            // Async functions are suspended like generators, the awaited value is handled by
+            // the caller of the code block.
+            Opcode::Yield | Opcode::Await => return Ok(ShouldExit::Yield),
+            Opcode::GeneratorNext => match self.vm.frame().generator_resume_kind {
+                GeneratorResumeKind::Normal => return Ok(ShouldExit::False),
+                GeneratorResumeKind::Throw => {
+                    let received = self.vm.pop();
+                    return Err(received);
+                }
+                GeneratorResumeKind::Return => return Ok(self.frame_return()),
+            },
+            Opcode::GeneratorNextDelegate => {
+                let done_address = self.vm.read::<u32>();
+                let received = self.vm.pop();
+                let next_function = self.vm.pop();
+                let iterator = self.vm.pop();
+
+                let result = match self.vm.frame().generator_resume_kind {
+                    GeneratorResumeKind::Normal => {
+                        self.call(&next_function, &iterator, &[received])?
+                    }
+                    GeneratorResumeKind::Throw => {
+                        if let Some(throw) = iterator.get_method("throw", self)? {
+                            throw.call(&iterator, &[received], self)?
+                        } else {
+                            // The delegate has no way to handle the exception, so it is closed
+                            // before the protocol violation is reported.
+                            let iterator_record =
+                                IteratorRecord::new(iterator.clone(), next_function.clone());
+                            iterator_record.close(Ok(JsValue::undefined()), self)?;
+                            return self.throw_type_error("iterator does not have a throw method");
+                        }
+                    }
+                    GeneratorResumeKind::Return => {
+                        if let Some(r#return) = iterator.get_method("return", self)? {
+                            r#return.call(&iterator, &[received], self)?
+                        } else {
+                            self.vm.push(received);
+                            return Ok(self.frame_return());
+                        }
+                    }
+                };
+
+                let result = result
+                    .as_object()
+                    .ok_or_else(|| self.construct_type_error("iterator result is not an object"))?;
+                let done = result.get("done", self)?.to_boolean();
+                let value = result.get("value", self)?;
+
+                if done {
+                    self.vm.push(value);
+                    if self.vm.frame().generator_resume_kind == GeneratorResumeKind::Return {
+                        return Ok(self.frame_return());
+                    }
+                    self.vm.frame_mut().pc = done_address as usize;
+                } else {
+                    self.vm.push(iterator);
+                    self.vm.push(next_function);
+                    self.vm.push(value);
+                    return Ok(ShouldExit::Yield);
+                }
+            }
         }
 
-        Ok(false)
+        Ok(ShouldExit::False)
     }
 
-    pub(crate) fn run(&mut self) -> JsResult<JsValue> {
+    /// Runtime semantics of a `super(...)` call in the constructor of a derived class.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-super-keyword-runtime-semantics-evaluation
+    fn super_call(&mut self, arguments: &[JsValue]) -> JsResult<JsValue> {
+        let frame = self.vm.frame();
+        let (active_function, new_target) = match (&frame.function_object, &frame.new_target) {
+            (Some(function), Some(new_target)) if frame.code.constructor_kind.is_derived() => {
+                (function.clone(), new_target.clone())
+            }
+            _ => return self.throw_syntax_error("'super' keyword unexpected here"),
+        };
+
+        // 3. Let func be GetSuperConstructor().
+        let func = active_function.__get_prototype_of__(self)?;
+
+        // 5. If IsConstructor(func) is false, throw a TypeError exception.
+        let func = match func {
+            Some(func) if func.is_constructor() => func,
+            _ => return self.throw_type_error("Super constructor is not a constructor"),
+        };
+
+        // 6. Let result be ? Construct(func, argList, newTarget).
+        let result = func.construct(arguments, &new_target.into(), self)?;
+
+        // 7. Let thisER be GetThisEnvironment().
+        // 8. Perform ? thisER.BindThisValue(result).
+        if !self.vm.frame().this.is_undefined() {
+            return self.throw_reference_error("Super constructor may only be called once");
+        }
+        self.vm.frame_mut().this = result.clone();
+        self.realm.environments.bind_this(result.clone());
+
+        // 9. Let F be thisER.[[FunctionObject]].
+        // 10. Perform ? InitializeInstanceElements(result, F).
+        if let Some(object) = result.as_object() {
+            object.initialize_instance_elements(&active_function, self)?;
+        }
+
+        // 11. Return result.
+        Ok(result)
+    }
+
+    /// Adds the current frame to the stack trace of the uncaught error `error`.
+    ///
+    /// The trace is restarted if `error` is not the error that is already being traced.
+    fn record_error_frame(&mut self, error: &JsValue) {
+        let same_error = self
+            .vm
+            .error_trace_value
+            .as_ref()
+            .map_or(false, |traced| traced.strict_equals(error));
+        if !same_error {
+            self.vm.error_trace.clear();
+            self.vm.error_trace_value = Some(error.clone());
+        }
+
+        let frame = StackTraceFrame::from_call_frame(self.vm.frame(), self);
+        self.vm.error_trace.push(frame);
+    }
+
+    /// Captures the stack trace of the code that is executing, starting from the innermost frame.
+    ///
+    /// At most [`STACK_TRACE_LIMIT`] frames are captured, and native functions don't have frames.
+    pub(crate) fn capture_stack_trace(&self) -> Vec<StackTraceFrame> {
+        let mut trace = Vec::new();
+        let mut frame = self.vm.frame.as_deref();
+        while let Some(current) = frame {
+            if trace.len() == STACK_TRACE_LIMIT {
+                break;
+            }
+            trace.push(StackTraceFrame::from_call_frame(current, self));
+            frame = current.prev.as_deref();
+        }
+        trace
+    }
+
+    pub(crate) fn run(&mut self) -> JsResult<(JsValue, ReturnType)> {
         const COLUMN_WIDTH: usize = 26;
         const TIME_COLUMN_WIDTH: usize = COLUMN_WIDTH / 2;
         const OPCODE_COLUMN_WIDTH: usize = COLUMN_WIDTH;
@@ -1296,7 +2203,6 @@ impl Context {
             );
         }
 
-        self.vm.frame_mut().pc = 0;
         while self.vm.frame().pc < self.vm.frame().code.code.len() {
             let result = if self.vm.trace {
                 let mut pc = self.vm.frame().pc;
@@ -1341,14 +2247,24 @@ impl Context {
             };
 
             match result {
-                Ok(should_exit) => {
-                    if should_exit {
-                        let result = self.vm.pop();
-                        return Ok(result);
-                    }
+                Ok(ShouldExit::True) => {
+                    let result = self.vm.pop();
+                    return Ok((result, ReturnType::Normal));
+                }
+                Ok(ShouldExit::Yield) => {
+                    let result = self.vm.pop();
+                    return Ok((result, ReturnType::Yield));
                 }
+                Ok(ShouldExit::False) => {}
                 Err(e) => {
-                    if let Some(address) = self.vm.frame().catch.last() {
+                    // An exhausted instruction budget aborts the script, so the error skips the
+                    // `catch` and `finally` handlers of the script.
+                    let handler = if self.runtime_limit_exceeded() {
+                        None
+                    } else {
+                        self.vm.frame().catch.last()
+                    };
+                    if let Some(address) = handler {
                         let address = address.next;
                         let try_stack_entry = self
                             .vm
@@ -1381,11 +2297,15 @@ impl Context {
                         for _ in 0..self.vm.frame().pop_on_return {
                             self.vm.pop();
                         }
+                        self.vm.frame_mut().pop_on_return = 0;
                         self.vm.frame_mut().pc = address as usize;
                         self.vm.frame_mut().catch.pop();
                         self.vm.frame_mut().finally_return = FinallyReturn::Err;
                         self.vm.push(e);
+                        self.vm.error_trace.clear();
+                        self.vm.error_trace_value = None;
                     } else {
+                        self.record_error_frame(&e);
                         return Err(e);
                     }
                 }
@@ -1416,9 +2336,9 @@ impl Context {
         }
 
         if self.vm.stack.is_empty() {
-            return Ok(JsValue::undefined());
+            return Ok((JsValue::undefined(), ReturnType::Normal));
         }
 
-        Ok(self.vm.pop())
+        Ok((self.vm.pop(), ReturnType::Normal))
     }
 }
@@ -25,6 +25,13 @@ pub enum Opcode {
     /// Stack: second, first **=>** first, second
     Swap,
 
+    /// Rotate the top `n` values of the stack to the right, moving the top value below the others.
+    ///
+    /// Operands: n: `u8`
+    ///
+    /// Stack: a, ..., y, z **=>** z, a, ..., y
+    RotateRight,
+
     /// Push integer `0` on the stack.
     ///
     /// Operands:
@@ -147,6 +154,13 @@ pub enum Opcode {
     /// Stack: array, value **=>** array
     PushValueToArray,
 
+    /// Push an elision to an array, leaving a hole at the end of it.
+    ///
+    /// Operands:
+    ///
+    /// Stack: array **=>** array
+    PushElisionToArray,
+
     /// Push all iterator values to an array.
     ///
     /// Operands:
@@ -473,13 +487,22 @@ pub enum Opcode {
     /// Stack: key, object **=>** value
     GetPropertyByValue,
 
+    /// Get a property by value from an object, keeping the object and the key on the stack.
+    ///
+    /// Like `object[key]` in `object[key] += value`
+    ///
+    /// Operands:
+    ///
+    /// Stack: object, key **=>** object, key, value
+    GetPropertyByValuePush,
+
     /// Sets a property by name of an object.
     ///
     /// Like `object.name = value`
     ///
     /// Operands: name_index: `u32`
     ///
-    /// Stack: value, object **=>**
+    /// Stack: object, value **=>** value
     SetPropertyByName,
 
     /// Defines a own property of an object by name.
@@ -495,7 +518,7 @@ pub enum Opcode {
     ///
     /// Operands:
     ///
-    /// Stack: value, key, object **=>**
+    /// Stack: object, key, value **=>** value
     SetPropertyByValue,
 
     /// Defines a own property of an object by value.
@@ -541,6 +564,60 @@ pub enum Opcode {
     /// Stack: object, key, value **=>**
     SetPropertySetterByValue,
 
+    /// Defines a method of a class by name.
+    ///
+    /// The method is not enumerable and its home object is set to the object.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: function, object **=>**
+    DefineClassMethodByName,
+
+    /// Defines a method of a class by value.
+    ///
+    /// The method is not enumerable and its home object is set to the object.
+    ///
+    /// Operands:
+    ///
+    /// Stack: object, key, function **=>**
+    DefineClassMethodByValue,
+
+    /// Defines a getter of a class by name.
+    ///
+    /// Like `get name() value` in a class body.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: function, object **=>**
+    DefineClassGetterByName,
+
+    /// Defines a getter of a class by value.
+    ///
+    /// Like `get [key]() value` in a class body.
+    ///
+    /// Operands:
+    ///
+    /// Stack: object, key, function **=>**
+    DefineClassGetterByValue,
+
+    /// Defines a setter of a class by name.
+    ///
+    /// Like `set name() value` in a class body.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: function, object **=>**
+    DefineClassSetterByName,
+
+    /// Defines a setter of a class by value.
+    ///
+    /// Like `set [key]() value` in a class body.
+    ///
+    /// Operands:
+    ///
+    /// Stack: object, key, function **=>**
+    DefineClassSetterByValue,
+
     /// Deletes a property by name of an object.
     ///
     /// Like `delete object.key.`
@@ -559,6 +636,15 @@ pub enum Opcode {
     /// Stack: key, object **=>**
     DeletePropertyByValue,
 
+    /// Deletes a binding by name.
+    ///
+    /// Like `delete name` in non-strict code.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: **=>** result
+    DeleteName,
+
     /// Copy all properties of one object to another object.
     ///
     /// Operands: excluded_key_count: `u32`
@@ -663,6 +749,13 @@ pub enum Opcode {
     /// Stack: value **=>** (`ToBoolean(value)`)
     ToBoolean,
 
+    /// Convert a value to a property key.
+    ///
+    /// Operands:
+    ///
+    /// Stack: value **=>** (`ToPropertyKey(value)`)
+    ToPropertyKey,
+
     /// Pushes `this` value
     ///
     /// Operands:
@@ -670,6 +763,36 @@ pub enum Opcode {
     /// Stack: **=>** this
     This,
 
+    /// Pushes the `new.target` value of the running function, or `undefined` if there is none.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>** new_target
+    PushNewTarget,
+
+    /// Pushes the object on which `super` property lookups start.
+    ///
+    /// This is the prototype of the home object of the running function.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>** super
+    Super,
+
+    /// Calls the constructor of the parent class and binds the result to `this`.
+    ///
+    /// Operands: argument_count: `u32`
+    ///
+    /// Stack: argument_1, ... argument_n **=>** this
+    SuperCall,
+
+    /// Calls the constructor of the parent class with the elements of an array as arguments.
+    ///
+    /// Operands:
+    ///
+    /// Stack: arguments_array **=>** this
+    SuperCallSpread,
+
     /// Pop the two values of the stack, strict equal compares the two values,
     /// if true jumps to address, otherwise push the second pop'ed value.
     ///
@@ -692,6 +815,132 @@ pub enum Opcode {
     /// Stack: **=>** func
     GetFunction,
 
+    /// Creates the prototype object of a class.
+    ///
+    /// The superclass is `undefined` if the class has no `extends` clause.
+    ///
+    /// Operands:
+    ///
+    /// Stack: superclass **=>** superclass, prototype
+    PushClassPrototype,
+
+    /// Links a class constructor with its prototype and its superclass.
+    ///
+    /// Operands:
+    ///
+    /// Stack: superclass, prototype, constructor **=>** constructor, prototype
+    SetClassPrototype,
+
+    /// Adds an instance field to a class constructor.
+    ///
+    /// The initializer is `undefined` if the field has no initializer.
+    ///
+    /// Operands:
+    ///
+    /// Stack: class, key, initializer **=>**
+    PushClassField,
+
+    /// Evaluates a static field of a class and defines it on the class constructor.
+    ///
+    /// The initializer is `undefined` if the field has no initializer.
+    ///
+    /// Operands:
+    ///
+    /// Stack: class, key, initializer **=>**
+    DefineClassStaticField,
+
+    /// Creates a new private name of a class, which is unique to each evaluation of the class.
+    ///
+    /// Operands: name_index: `u32`
+    ///
+    /// Stack: **=>** private_name
+    PushPrivateName,
+
+    /// Adds a private instance method to a class constructor.
+    ///
+    /// Operands:
+    ///
+    /// Stack: class, private_name, method **=>**
+    PushClassPrivateMethod,
+
+    /// Adds a private instance getter to a class constructor.
+    ///
+    /// Operands:
+    ///
+    /// Stack: class, private_name, getter **=>**
+    PushClassPrivateGetter,
+
+    /// Adds a private instance setter to a class constructor.
+    ///
+    /// Operands:
+    ///
+    /// Stack: class, private_name, setter **=>**
+    PushClassPrivateSetter,
+
+    /// Adds a private instance field to a class constructor.
+    ///
+    /// The initializer is `undefined` if the field has no initializer.
+    ///
+    /// Operands:
+    ///
+    /// Stack: class, private_name, initializer **=>**
+    PushClassPrivateField,
+
+    /// Adds a private static method to a class constructor.
+    ///
+    /// Operands:
+    ///
+    /// Stack: class, private_name, method **=>**
+    DefineClassStaticPrivateMethod,
+
+    /// Adds a private static getter to a class constructor.
+    ///
+    /// Operands:
+    ///
+    /// Stack: class, private_name, getter **=>**
+    DefineClassStaticPrivateGetter,
+
+    /// Adds a private static setter to a class constructor.
+    ///
+    /// Operands:
+    ///
+    /// Stack: class, private_name, setter **=>**
+    DefineClassStaticPrivateSetter,
+
+    /// Evaluates a private static field of a class and adds it to the class constructor.
+    ///
+    /// The initializer is `undefined` if the field has no initializer.
+    ///
+    /// Operands:
+    ///
+    /// Stack: class, private_name, initializer **=>**
+    DefineClassStaticPrivateField,
+
+    /// Get a private field, method or accessor of an object.
+    ///
+    /// Throws a `TypeError` if the object does not have the private element.
+    ///
+    /// Operands:
+    ///
+    /// Stack: object, private_name **=>** value
+    GetPrivateField,
+
+    /// Set a private field or accessor of an object.
+    ///
+    /// Throws a `TypeError` if the object does not have the private element.
+    ///
+    /// Operands:
+    ///
+    /// Stack: object, value, private_name **=>** value
+    SetPrivateField,
+
+    /// Checks if an object has a private element.
+    ///
+    /// Operands:
+    ///
+    /// Stack: object, private_name **=>** (private_name in object)
+    InPrivate,
+
     /// Call a function.
     ///
     /// Operands: argument_count: `u32`
@@ -699,12 +948,12 @@ pub enum Opcode {
     /// Stack: func, this, argument_1, ... argument_n **=>** result
     Call,
 
-    /// Call a function where the last argument is a rest parameter.
+    /// Call a function with the elements of an array as arguments.
     ///
-    /// Operands: argument_count: `u32`
+    /// Operands:
     ///
-    /// Stack: func, this, argument_1, ... argument_n **=>** result
-    CallWithRest,
+    /// Stack: func, this, arguments_array **=>** result
+    CallSpread,
 
     /// Call construct on a function.
     ///
@@ -713,12 +962,12 @@ pub enum Opcode {
     /// Stack: func, argument_1, ... argument_n **=>** result
     New,
 
-    /// Call construct on a function where the last argument is a rest parameter.
+    /// Call construct on a function with the elements of an array as arguments.
     ///
-    /// Operands: argument_count: `u32`
+    /// Operands:
     ///
-    /// Stack: func, argument_1, ... argument_n **=>** result
-    NewWithRest,
+    /// Stack: func, arguments_array **=>** result
+    NewSpread,
 
     /// Return from a function.
     ///
@@ -783,6 +1032,13 @@ pub enum Opcode {
     /// Stack: object **=>** iterator, next_function
     InitIterator,
 
+    /// Initialize an async iterator.
+    ///
+    /// Operands:
+    ///
+    /// Stack: object **=>** iterator, next_function
+    InitIteratorAsync,
+
     /// Advance the iterator by one and put the value on the stack.
     ///
     /// Operands:
@@ -820,6 +1076,22 @@ pub enum Opcode {
     /// Stack: iterator, next_function **=>** iterator, next_function, next_result
     ForInLoopNext,
 
+    /// Move to the next value in a for await..of loop.
+    ///
+    /// Operands:
+    ///
+    /// Stack: iterator, next_function **=>** iterator, next_function, next_result
+    ForAwaitOfLoopIterate,
+
+    /// Get the value from a for await..of loop next result or jump to exit of the loop if done.
+    ///
+    /// Note: next_value is only pushed if the iterator is not done.
+    ///
+    /// Operands: address: `u32`
+    ///
+    /// Stack: next_result **=>** next_value
+    ForAwaitOfLoopNext,
+
     /// Concat multiple stack objects into a string.
     ///
     /// Operands: value_count: `u32`
@@ -869,6 +1141,37 @@ pub enum Opcode {
     /// Stack: **=>**
     PopOnReturnSub,
 
+    /// Yield from the current execution.
+    ///
+    /// Operands:
+    ///
+    /// Stack: value **=>**
+    Yield,
+
+    /// Resumes the current generator function.
+    ///
+    /// Operands:
+    ///
+    /// Stack: received **=>** received
+    GeneratorNext,
+
+    /// Delegates the current generator function to another iterator.
+    ///
+    /// Operands: done_address: `u32`
+    ///
+    /// Stack: iterator, next_function, received **=>** iterator, next_function
+    GeneratorNextDelegate,
+
+    /// Suspends the current async function until the awaited value settles.
+    ///
+    /// The async function is resumed with the settled value, at the following `GeneratorNext`
+    /// instruction.
+    ///
+    /// Operands:
+    ///
+    /// Stack: value **=>**
+    Await,
+
     /// No-operation instruction, does nothing.
     ///
     /// Operands:
@@ -894,6 +1197,7 @@ impl Opcode {
             Opcode::Pop => "Pop",
             Opcode::Dup => "Dup",
             Opcode::Swap => "Swap",
+            Opcode::RotateRight => "RotateRight",
             Opcode::PushZero => "PushZero",
             Opcode::PushOne => "PushOne",
             Opcode::PushInt8 => "PushInt8",
@@ -911,6 +1215,7 @@ impl Opcode {
             Opcode::PushEmptyObject => "PushEmptyObject",
             Opcode::PushNewArray => "PushNewArray",
             Opcode::PushValueToArray => "PushValueToArray",
+            Opcode::PushElisionToArray => "PushElisionToArray",
             Opcode::PushIteratorToArray => "PushIteratorToArray",
             Opcode::Add => "Add",
             Opcode::Sub => "Sub",
@@ -956,6 +1261,7 @@ impl Opcode {
             Opcode::SetName => "SetName",
             Opcode::GetPropertyByName => "GetPropertyByName",
             Opcode::GetPropertyByValue => "GetPropertyByValue",
+            Opcode::GetPropertyByValuePush => "GetPropertyByValuePush",
             Opcode::SetPropertyByName => "SetPropertyByName",
             Opcode::DefineOwnPropertyByName => "DefineOwnPropertyByName",
             Opcode::SetPropertyByValue => "SetPropertyByValue",
@@ -964,8 +1270,15 @@ impl Opcode {
             Opcode::SetPropertyGetterByValue => "SetPropertyGetterByValue",
             Opcode::SetPropertySetterByName => "SetPropertySetterByName",
             Opcode::SetPropertySetterByValue => "SetPropertySetterByValue",
+            Opcode::DefineClassMethodByName => "DefineClassMethodByName",
+            Opcode::DefineClassMethodByValue => "DefineClassMethodByValue",
+            Opcode::DefineClassGetterByName => "DefineClassGetterByName",
+            Opcode::DefineClassGetterByValue => "DefineClassGetterByValue",
+            Opcode::DefineClassSetterByName => "DefineClassSetterByName",
+            Opcode::DefineClassSetterByValue => "DefineClassSetterByValue",
             Opcode::DeletePropertyByName => "DeletePropertyByName",
             Opcode::DeletePropertyByValue => "DeletePropertyByValue",
+            Opcode::DeleteName => "DeleteName",
             Opcode::CopyDataProperties => "CopyDataProperties",
             Opcode::Jump => "Jump",
             Opcode::JumpIfFalse => "JumpIfFalse",
@@ -980,14 +1293,35 @@ impl Opcode {
             Opcode::FinallyEnd => "FinallyEnd",
             Opcode::FinallySetJump => "FinallySetJump",
             Opcode::ToBoolean => "ToBoolean",
+            Opcode::ToPropertyKey => "ToPropertyKey",
             Opcode::This => "This",
+            Opcode::PushNewTarget => "PushNewTarget",
+            Opcode::Super => "Super",
+            Opcode::SuperCall => "SuperCall",
+            Opcode::SuperCallSpread => "SuperCallSpread",
             Opcode::Case => "Case",
             Opcode::Default => "Default",
             Opcode::GetFunction => "GetFunction",
+            Opcode::PushClassPrototype => "PushClassPrototype",
+            Opcode::SetClassPrototype => "SetClassPrototype",
+            Opcode::PushClassField => "PushClassField",
+            Opcode::DefineClassStaticField => "DefineClassStaticField",
+            Opcode::PushPrivateName => "PushPrivateName",
+            Opcode::PushClassPrivateMethod => "PushClassPrivateMethod",
+            Opcode::PushClassPrivateGetter => "PushClassPrivateGetter",
+            Opcode::PushClassPrivateSetter => "PushClassPrivateSetter",
+            Opcode::PushClassPrivateField => "PushClassPrivateField",
+            Opcode::DefineClassStaticPrivateMethod => "DefineClassStaticPrivateMethod",
+            Opcode::DefineClassStaticPrivateGetter => "DefineClassStaticPrivateGetter",
+            Opcode::DefineClassStaticPrivateSetter => "DefineClassStaticPrivateSetter",
+            Opcode::DefineClassStaticPrivateField => "DefineClassStaticPrivateField",
+            Opcode::GetPrivateField => "GetPrivateField",
+            Opcode::SetPrivateField => "SetPrivateField",
+            Opcode::InPrivate => "InPrivate",
             Opcode::Call => "Call",
-            Opcode::CallWithRest => "CallWithRest",
+            Opcode::CallSpread => "CallSpread",
             Opcode::New => "New",
-            Opcode::NewWithRest => "NewWithRest",
+            Opcode::NewSpread => "NewSpread",
             Opcode::Return => "Return",
             Opcode::PushDeclarativeEnvironment => "PushDeclarativeEnvironment",
             Opcode::PushFunctionEnvironment => "PushFunctionEnvironment",
@@ -997,11 +1331,14 @@ impl Opcode {
             Opcode::LoopEnd => "LoopEnd",
             Opcode::ForInLoopInitIterator => "ForInLoopInitIterator",
             Opcode::InitIterator => "InitIterator",
+            Opcode::InitIteratorAsync => "InitIteratorAsync",
             Opcode::IteratorNext => "IteratorNext",
             Opcode::IteratorNextFull => "IteratorNextFull",
             Opcode::IteratorClose => "IteratorClose",
             Opcode::IteratorToArray => "IteratorToArray",
             Opcode::ForInLoopNext => "ForInLoopNext",
+            Opcode::ForAwaitOfLoopIterate => "ForAwaitOfLoopIterate",
+            Opcode::ForAwaitOfLoopNext => "ForAwaitOfLoopNext",
             Opcode::ConcatToString => "ConcatToString",
             Opcode::RequireObjectCoercible => "RequireObjectCoercible",
             Opcode::ValueNotNullOrUndefined => "ValueNotNullOrUndefined",
@@ -1009,6 +1346,10 @@ impl Opcode {
             Opcode::RestParameterPop => "RestParameterPop",
             Opcode::PopOnReturnAdd => "PopOnReturnAdd",
             Opcode::PopOnReturnSub => "PopOnReturnSub",
+            Opcode::Yield => "Yield",
+            Opcode::GeneratorNext => "GeneratorNext",
+            Opcode::GeneratorNextDelegate => "GeneratorNextDelegate",
+            Opcode::Await => "Await",
             Opcode::Nop => "Nop",
         }
     }
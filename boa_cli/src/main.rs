@@ -151,7 +151,7 @@ where
     let src_bytes = src.as_ref();
     Parser::new(src_bytes, false)
         .parse_all(interner)
-        .map_err(|e| format!("ParsingError: {e}"))
+        .map_err(|e| e.render(&String::from_utf8_lossy(src_bytes)))
 }
 
 /// Dumps the AST to stdout with format controlled by the given arguments.
@@ -206,7 +206,7 @@ pub fn main() -> Result<(), std::io::Error> {
             }
         } else {
             match context.eval(&buffer) {
-                Ok(v) => println!("{}", v.display()),
+                Ok(v) => println!("{}", v.inspect(&mut context)),
                 Err(v) => eprintln!("Uncaught {}", v.display()),
             }
         }
@@ -245,7 +245,7 @@ pub fn main() -> Result<(), std::io::Error> {
                         }
                     } else {
                         match context.eval(line.trim_end()) {
-                            Ok(v) => println!("{}", v.display()),
+                            Ok(v) => println!("{}", v.inspect(&mut context)),
                             Err(v) => {
                                 eprintln!(
                                     "{}: {}",
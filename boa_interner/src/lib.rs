@@ -291,6 +291,15 @@ impl Sym {
     /// Symbol for the `"raw"` string.
     pub const RAW: Self = unsafe { Self::from_raw(NonZeroUsize::new_unchecked(12)) };
 
+    /// Symbol for the `"static"` string.
+    pub const STATIC: Self = unsafe { Self::from_raw(NonZeroUsize::new_unchecked(13)) };
+
+    /// Symbol for the `"prototype"` string.
+    pub const PROTOTYPE: Self = unsafe { Self::from_raw(NonZeroUsize::new_unchecked(14)) };
+
+    /// Symbol for the `"constructor"` string.
+    pub const CONSTRUCTOR: Self = unsafe { Self::from_raw(NonZeroUsize::new_unchecked(15)) };
+
     /// Creates a `Sym` from a raw `NonZeroUsize`.
     const fn from_raw(value: NonZeroUsize) -> Self {
         Self { value }
@@ -341,7 +350,7 @@ impl Interner {
     /// List of commonly used static strings.
     ///
     /// Make sure that any string added as a `Sym` constant is also added here.
-    const STATIC_STRINGS: [&'static str; 12] = [
+    const STATIC_STRINGS: [&'static str; 15] = [
         "",
         "arguments",
         "await",
@@ -354,5 +363,8 @@ impl Interner {
         "set",
         "<main>",
         "raw",
+        "static",
+        "prototype",
+        "constructor",
     ];
 }
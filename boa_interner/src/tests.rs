@@ -29,6 +29,9 @@ fn check_constants() {
     assert_eq!(Sym::SET, sym_from_usize(10));
     assert_eq!(Sym::MAIN, sym_from_usize(11));
     assert_eq!(Sym::RAW, sym_from_usize(12));
+    assert_eq!(Sym::STATIC, sym_from_usize(13));
+    assert_eq!(Sym::PROTOTYPE, sym_from_usize(14));
+    assert_eq!(Sym::CONSTRUCTOR, sym_from_usize(15));
 }
 
 #[test]
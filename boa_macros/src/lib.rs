@@ -0,0 +1,204 @@
+//! Procedural macros for the Boa JavaScript engine.
+//!
+//! This crate provides the `TryFromJs` and `IntoJs` derive macros, which implement the traits of
+//! the same name from `boa::value` for structs with named fields. Each field is read from (or
+//! written to) the property of a plain JavaScript object with the same name.
+//!
+//! The name of the property can be changed with the `#[boa(rename = "name")]` field attribute.
+
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/boa-dev/boa/main/assets/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/boa-dev/boa/main/assets/logo.svg"
+)]
+#![warn(
+    clippy::perf,
+    clippy::single_match_else,
+    clippy::dbg_macro,
+    clippy::doc_markdown,
+    clippy::wildcard_imports,
+    clippy::struct_excessive_bools,
+    clippy::semicolon_if_nothing_returned,
+    clippy::pedantic
+)]
+#![deny(
+    clippy::all,
+    unused_qualifications,
+    unused_import_braces,
+    unused_lifetimes,
+    unreachable_pub,
+    trivial_numeric_casts,
+    missing_debug_implementations,
+    missing_copy_implementations,
+    rust_2018_idioms,
+    future_incompatible,
+    nonstandard_style
+)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, Data, DeriveInput, Error, Fields, Ident, Lit, Meta, NestedMeta, Result,
+};
+
+/// Derives `boa::value::TryFromJs` for a struct with named fields.
+///
+/// The value being converted must be an object. Each field is converted from the property with
+/// the same name (or the name given by `#[boa(rename = "name")]`). A missing property is only
+/// accepted for fields whose type accepts `undefined`, such as `Option<T>`; for any other field
+/// a `TypeError` naming the field is thrown.
+#[proc_macro_derive(TryFromJs, attributes(boa))]
+pub fn derive_try_from_js(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_try_from_js(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derives `boa::value::IntoJs` for a struct with named fields.
+///
+/// The struct is converted into a plain object with one property per field, named like the field
+/// (or with the name given by `#[boa(rename = "name")]`).
+#[proc_macro_derive(IntoJs, attributes(boa))]
+pub fn derive_into_js(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_into_js(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// A struct field, together with the name of the property it's mapped to.
+struct Field<'a> {
+    ident: &'a Ident,
+    property: String,
+}
+
+/// Gets the named fields of the struct, with their property names.
+fn fields(input: &DeriveInput) -> Result<Vec<Field<'_>>> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new_spanned(
+                &input.ident,
+                "only structs with named fields can be converted from and to JavaScript objects",
+            )),
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "only structs can be converted from and to JavaScript objects",
+            ))
+        }
+    };
+
+    fields
+        .iter()
+        .map(|field| {
+            let ident = field
+                .ident
+                .as_ref()
+                .expect("named fields have an identifier");
+            let mut property = ident.to_string();
+
+            for attr in field.attrs.iter().filter(|attr| attr.path.is_ident("boa")) {
+                let meta_list = match attr.parse_meta()? {
+                    Meta::List(list) => list,
+                    meta => return Err(Error::new_spanned(meta, "expected `boa(...)`")),
+                };
+
+                for nested in meta_list.nested {
+                    match nested {
+                        NestedMeta::Meta(Meta::NameValue(name_value))
+                            if name_value.path.is_ident("rename") =>
+                        {
+                            match name_value.lit {
+                                Lit::Str(name) => property = name.value(),
+                                lit => {
+                                    return Err(Error::new_spanned(
+                                        lit,
+                                        "expected a string literal",
+                                    ))
+                                }
+                            }
+                        }
+                        nested => {
+                            return Err(Error::new_spanned(
+                                nested,
+                                "unknown attribute, expected `rename = \"...\"`",
+                            ))
+                        }
+                    }
+                }
+            }
+
+            Ok(Field { ident, property })
+        })
+        .collect()
+}
+
+fn expand_try_from_js(input: &DeriveInput) -> Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let not_object = format!("cannot convert value to `{}`: expected an object", name);
+
+    let fields = fields(input)?.into_iter().map(|Field { ident, property }| {
+        let missing = format!(
+            "cannot convert value to `{}`: missing field `{}`",
+            name, property
+        );
+        quote! {
+            #ident: {
+                let value = object.get(#property, context)?;
+                match ::boa::value::TryFromJs::try_from_js(&value, context) {
+                    ::std::result::Result::Ok(value) => value,
+                    ::std::result::Result::Err(_) if value.is_undefined() => {
+                        return context.throw_conversion_error(#missing);
+                    }
+                    ::std::result::Result::Err(err) => return ::std::result::Result::Err(err),
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::boa::value::TryFromJs for #name #ty_generics #where_clause {
+            fn try_from_js(
+                value: &::boa::JsValue,
+                context: &mut ::boa::Context,
+            ) -> ::boa::JsResult<Self> {
+                let object = match value.as_object() {
+                    ::std::option::Option::Some(object) => object.clone(),
+                    ::std::option::Option::None => return context.throw_conversion_error(#not_object),
+                };
+
+                ::std::result::Result::Ok(Self {
+                    #(#fields,)*
+                })
+            }
+        }
+    })
+}
+
+fn expand_into_js(input: &DeriveInput) -> Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = fields(input)?.into_iter().map(|Field { ident, property }| {
+        quote! {
+            let value = ::boa::value::IntoJs::into_js(self.#ident, context);
+            object
+                .create_data_property_or_throw(#property, value, context)
+                .expect("defining a property on a new ordinary object cannot fail");
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::boa::value::IntoJs for #name #ty_generics #where_clause {
+            fn into_js(self, context: &mut ::boa::Context) -> ::boa::JsValue {
+                let object = context.construct_object();
+                #(#fields)*
+                object.into()
+            }
+        }
+    })
+}